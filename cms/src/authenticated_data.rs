@@ -26,6 +26,7 @@ use crate::signed_data::EncapsulatedContentInfo;
 /// ```
 ///
 /// [RFC 5652 Section 9.1]: https://www.rfc-editor.org/rfc/rfc5652#section-9.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct AuthenticatedData {
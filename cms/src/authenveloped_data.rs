@@ -23,6 +23,7 @@ use crate::{
 /// ```
 ///
 /// [RFC 5083 Section 4]: https://www.rfc-editor.org/rfc/rfc5083#section-4
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Sequence)]
 #[allow(missing_docs)]
 pub struct AuthEnvelopedData {
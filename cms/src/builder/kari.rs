@@ -61,6 +61,7 @@ use elliptic_curve::{
 /// ```
 ///
 /// [RFC 5753 Section 7.2]: https://www.rfc-editor.org/rfc/rfc5753#section-7.2
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 pub struct EccCmsSharedInfo {
     /// Object identifier of the key-encryption algorithm
@@ -24,6 +24,7 @@ use x509_cert::serial_number::SerialNumber;
 /// ```
 ///
 /// [RFC 5652 Section 10.2.2]: https://www.rfc-editor.org/rfc/rfc5652#section-10.2.2
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Choice)]
 #[allow(missing_docs)]
 #[allow(clippy::large_enum_variant)]
@@ -57,6 +58,7 @@ impl ValueOrd for CertificateChoices {
 /// ```
 ///
 /// [RFC 5652 Section 10.2.2]: https://www.rfc-editor.org/rfc/rfc5652#section-10.2.2
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct OtherCertificateFormat {
@@ -73,6 +75,7 @@ pub struct OtherCertificateFormat {
 /// ```
 ///
 /// [RFC 5652 Section 10.2.4]: https://datatracker.ietf.org/doc/html/rfc5652#section-10.2.4
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct IssuerAndSerialNumber {
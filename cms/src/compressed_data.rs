@@ -16,6 +16,7 @@ use crate::signed_data::EncapsulatedContentInfo;
 /// ```
 ///
 /// [RFC 3274 Section 1.1]: https://www.rfc-editor.org/rfc/rfc3274#section-1.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct CompressedData {
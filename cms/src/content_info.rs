@@ -17,6 +17,7 @@ use x509_cert::{Certificate, PkiPath};
 /// ```
 ///
 /// [RFC 5652 Section 10.2.5]: https://www.rfc-editor.org/rfc/rfc5652#section-10.2.5
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Enumerated)]
 #[asn1(type = "INTEGER")]
 #[repr(u8)]
@@ -47,6 +48,7 @@ impl ValueOrd for CmsVersion {
 /// ```
 ///
 /// [RFC 5652 Section 3]: https://www.rfc-editor.org/rfc/rfc5652#section-3
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct ContentInfo {
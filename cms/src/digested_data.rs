@@ -18,6 +18,7 @@ use crate::signed_data::EncapsulatedContentInfo;
 /// ```
 ///
 /// [RFC 5652 Section 7]: https://www.rfc-editor.org/rfc/rfc5652#section-7
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct DigestedData {
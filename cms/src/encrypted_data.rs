@@ -18,6 +18,7 @@ use crate::enveloped_data::EncryptedContentInfo;
 /// ```
 ///
 /// [RFC 5652 Section 8]: https://www.rfc-editor.org/rfc/rfc5652#section-8
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct EncryptedData {
@@ -32,6 +32,7 @@ pub const ID_ORI_KEM: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.1
 ///     encryptedKey EncryptedKey }
 /// ```
 /// [RFC9629 Section 3]: https://datatracker.ietf.org/doc/html/rfc9629#section-3
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct KemRecipientInfo {
@@ -40,6 +41,7 @@ pub struct KemRecipientInfo {
     pub kem: AlgorithmIdentifierOwned,
     pub kem_ct: OctetString,
     pub kdf: AlgorithmIdentifierOwned,
+    #[asn1(range = "1..=65535")]
     pub kek_length: u16,
     #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub ukm: Option<UserKeyingMaterial>,
@@ -55,10 +57,12 @@ pub struct KemRecipientInfo {
 ///         ukm [0] EXPLICIT UserKeyingMaterial OPTIONAL }
 /// ```
 /// [RFC9629 Section 5]: https://datatracker.ietf.org/doc/html/rfc9629#section-5
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct CmsOriForKemOtherInfo {
     pub wrap: AlgorithmIdentifierOwned,
+    #[asn1(range = "1..=65535")]
     pub kek_length: u16,
     #[asn1(context_specific = "0", tag_mode = "EXPLICIT", optional = "true")]
     pub ukm: Option<UserKeyingMaterial>,
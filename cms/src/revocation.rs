@@ -29,6 +29,7 @@ impl_newtype!(RevocationInfoChoices, SetOfVec<RevocationInfoChoice>);
 /// ```
 ///
 /// [RFC 5652 Section 10.2.1]: https://www.rfc-editor.org/rfc/rfc5652#section-10.2.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Choice)]
 #[allow(missing_docs)]
 #[allow(clippy::large_enum_variant)]
@@ -67,6 +68,7 @@ impl TryFrom<std::vec::Vec<RevocationInfoChoice>> for RevocationInfoChoices {
 /// ```
 ///
 /// [RFC 5652 Section 10.2.1]: https://www.rfc-editor.org/rfc/rfc5652#section-10.2.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct OtherRevocationInfoFormat {
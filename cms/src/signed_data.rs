@@ -25,6 +25,7 @@ use x509_cert::impl_newtype;
 /// ```
 ///
 /// [RFC 5652 Section 5.1]: https://www.rfc-editor.org/rfc/rfc5652#section-5.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct SignedData {
@@ -99,6 +100,7 @@ impl TryFrom<std::vec::Vec<SignerInfo>> for SignerInfos {
 /// ```
 ///
 /// [RFC 5652 Section 5.2]: https://www.rfc-editor.org/rfc/rfc5652#section-5.2
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct EncapsulatedContentInfo {
@@ -122,6 +124,7 @@ pub struct EncapsulatedContentInfo {
 /// ```
 ///
 /// [RFC 5652 Section 5.3]: https://www.rfc-editor.org/rfc/rfc5652#section-5.3
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
 #[allow(missing_docs)]
 pub struct SignerInfo {
@@ -164,6 +167,7 @@ pub type SignedAttributes = Attributes;
 /// ```
 ///
 /// [RFC 5652 Section 5.3]: https://datatracker.ietf.org/doc/html/rfc5652#section-5.3
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Choice)]
 #[allow(missing_docs)]
 pub enum SignerIdentifier {
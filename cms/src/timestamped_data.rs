@@ -14,6 +14,7 @@ use x509_cert::{
     crl::CertificateList,
 };
 
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Enumerated)]
 #[asn1(type = "INTEGER")]
 #[repr(u8)]
@@ -56,6 +57,7 @@ pub struct TimeStampedData<'a> {
 ///     otherMetaData        Attributes OPTIONAL
 /// }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct MetaData {
@@ -75,6 +77,7 @@ pub struct MetaData {
 ///     otherEvidence  [2] OtherEvidence
 /// }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Choice)]
 #[allow(missing_docs)]
 pub enum Evidence {
@@ -97,6 +100,7 @@ pub type TimeStampTokenEvidence = Vec<TimeStampAndCrl>;
 ///     crl         CertificateList OPTIONAL -- according to RFC 5280
 /// }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct TimeStampAndCrl {
@@ -119,6 +123,7 @@ pub type TimeStampToken = ContentInfo;
 ///     archiveTimeStampSequence  ArchiveTimeStampSequence
 /// }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct EvidenceRecord {
@@ -142,6 +147,7 @@ pub type CryptoInfos = Vec<Attribute>;
 ///     encryptionInfoValue    ANY DEFINED BY encryptionInfoType
 /// }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct EncryptionInfo {
@@ -168,6 +174,7 @@ pub type ArchiveTimeStampChain = Vec<ArchiveTimeStamp>;
 ///     reducedHashtree [2] SEQUENCE OF PartialHashtree OPTIONAL,
 ///     timeStamp       ContentInfo }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct ArchiveTimeStamp {
@@ -191,6 +198,7 @@ pub type PartialHashtree = Vec<OctetString>;
 ///     oeType               OBJECT IDENTIFIER,
 ///     oeValue              ANY DEFINED BY oeType }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct OtherEvidence {
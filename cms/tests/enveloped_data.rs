@@ -499,3 +499,24 @@ fn reencode_enveloped_data_multi_test() {
     // should match the original
     assert_eq!(reencoded_data_in_ci, der_ci)
 }
+
+#[cfg(feature = "jer")]
+#[test]
+fn enveloped_data_jer_round_trip() {
+    use der::jer::{JerDecode, JerEncode};
+
+    for der_ci in [
+        &include_bytes!("examples/enveloped_data_ktri.bin")[..],
+        &include_bytes!("examples/enveloped_data_kari.bin")[..],
+        &include_bytes!("examples/enveloped_data_kekri.bin")[..],
+        &include_bytes!("examples/enveloped_data_pwri.bin")[..],
+    ] {
+        let ci = ContentInfo::from_der(der_ci).unwrap();
+        let bytes = ci.content.to_der().unwrap();
+
+        let data = EnvelopedData::from_der(bytes.as_slice()).unwrap();
+        let decoded = EnvelopedData::from_jer(&data.to_jer().unwrap()).unwrap();
+        assert_eq!(decoded, data);
+        assert_eq!(decoded.to_der().unwrap(), bytes);
+    }
+}
@@ -143,3 +143,18 @@ fn certs_to_p7b() {
     let p7b_buf2 = p7b_ee.to_der().unwrap();
     assert_eq!(p7b_buf, p7b_buf2.as_slice());
 }
+
+#[cfg(feature = "jer")]
+#[test]
+fn signed_data_jer_round_trip() {
+    use der::jer::{JerDecode, JerEncode};
+
+    let der_signed_data_in_ci = include_bytes!("examples/sd.cms");
+    let ci = ContentInfo::from_der(der_signed_data_in_ci).unwrap();
+    let bytes = ci.content.to_der().unwrap();
+
+    let sd = SignedData::from_der(bytes.as_slice()).unwrap();
+    let decoded = SignedData::from_jer(&sd.to_jer().unwrap()).unwrap();
+    assert_eq!(decoded, sd);
+    assert_eq!(decoded.to_der().unwrap(), bytes);
+}
@@ -3,7 +3,7 @@ use cms::encrypted_data::EncryptedData;
 use cms::signed_data::SignedData;
 use const_oid::ObjectIdentifier;
 use der::asn1::OctetStringRef;
-use der::{AnyRef, Decode, Encode};
+use der::{AnyRef, Decode, Encode, Tagged};
 use hex_literal::hex;
 use pkcs5::pbes2::Pbkdf2Params;
 
@@ -105,6 +105,31 @@ fn cms_decode_signed_scep_example() {
     assert_eq!(reencoded_der_signed_data_in_ci, der_signed_data_in_ci)
 }
 
+#[test]
+fn cms_decode_ber_indefinite_length() {
+    let ber_signed_data_in_ci = include_bytes!("../tests/examples/cms_ber.bin");
+    let ci = ContentInfo::from_ber(ber_signed_data_in_ci).unwrap();
+    assert_eq!(ci.content_type, const_oid::db::rfc5911::ID_SIGNED_DATA);
+    assert_eq!(ci.content.tag(), der::Tag::Sequence);
+
+    // Indefinite lengths are rejected by DER
+    assert!(ContentInfo::from_der(ber_signed_data_in_ci).is_err());
+
+    // eContent is a constructed OCTET STRING, which gets reassembled
+    let bytes = ci.content.to_der().unwrap();
+    let sd = SignedData::from_ber(bytes.as_slice()).unwrap();
+    assert_eq!(
+        sd.encap_content_info
+            .econtent
+            .unwrap()
+            .decode_as::<OctetStringRef>()
+            .unwrap()
+            .as_bytes()
+            .len(),
+        10034
+    );
+}
+
 #[test]
 fn cms_decode_signed_der() {
     let der_signed_data_in_ci = include_bytes!("../tests/examples/cms_der.bin");
@@ -3,7 +3,7 @@
 use crate::{Error, Result};
 
 #[cfg(doc)]
-use crate::ObjectIdentifier;
+use crate::{ObjectIdentifier, RelativeOid};
 
 /// Type alias used to represent an "arc", i.e. integer identifier value, where an OID comprises a
 /// sequence of arcs.
@@ -33,7 +33,8 @@ const ARC_MAX_BYTES: usize = (Arc::BITS as usize).div_ceil(7);
 /// Maximum value of the last byte in an arc.
 const ARC_MAX_LAST_OCTET: u8 = 0b11110000; // Max bytes of leading 1-bits
 
-/// [`Iterator`] over [`Arc`] values (a.k.a. nodes) in an [`ObjectIdentifier`].
+/// [`Iterator`] over [`Arc`] values (a.k.a. nodes) in an [`ObjectIdentifier`] or
+/// [`RelativeOid`].
 ///
 /// This iterates over all arcs in an OID, including the root.
 pub struct Arcs<'a> {
@@ -42,6 +43,9 @@ pub struct Arcs<'a> {
 
     /// Current position within the serialized BER bytes of this OID.
     cursor: Option<usize>,
+
+    /// Do the bytes begin with the root arcs, i.e. is this an absolute OID?
+    root: bool,
 }
 
 impl<'a> Arcs<'a> {
@@ -50,6 +54,16 @@ impl<'a> Arcs<'a> {
         Self {
             bytes,
             cursor: None,
+            root: true,
+        }
+    }
+
+    /// Create a new iterator over a relative OID encoded as BER bytes, which has no root arcs.
+    pub(crate) fn new_relative(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            cursor: Some(0),
+            root: false,
         }
     }
 
@@ -66,7 +80,7 @@ impl<'a> Arcs<'a> {
                 self.cursor = Some(0);
                 Ok(Some(root.first_arc()))
             }
-            Some(0) => {
+            Some(0) if self.root => {
                 let root = RootArcs::try_from(self.bytes[0])?;
                 self.cursor = Some(1);
                 Ok(Some(root.second_arc()))
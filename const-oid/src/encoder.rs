@@ -1,7 +1,7 @@
 //! OID encoder with `const` support.
 
 use crate::{
-    Arc, Buffer, Error, ObjectIdentifier, Result,
+    Arc, Buffer, Error, ObjectIdentifier, RelativeOid, Result,
     arcs::{ARC_MAX_FIRST, ARC_MAX_SECOND},
 };
 
@@ -41,6 +41,15 @@ impl<const MAX_SIZE: usize> Encoder<MAX_SIZE> {
         }
     }
 
+    /// Create a new encoder for a relative OID, which has no root arcs.
+    pub(crate) const fn new_relative() -> Self {
+        Self {
+            state: State::Body,
+            bytes: [0u8; MAX_SIZE],
+            cursor: 0,
+        }
+    }
+
     /// Extend an existing OID.
     pub(crate) const fn extend(oid: ObjectIdentifier<MAX_SIZE>) -> Self {
         Self {
@@ -80,16 +89,32 @@ impl<const MAX_SIZE: usize> Encoder<MAX_SIZE> {
 
     /// Finish encoding an OID.
     pub(crate) const fn finish(self) -> Result<ObjectIdentifier<MAX_SIZE>> {
+        // TODO(tarcieri): use `?` when stable in `const fn`
+        match self.finish_buffer() {
+            Ok(ber) => Ok(ObjectIdentifier { ber }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Finish encoding a relative OID.
+    pub(crate) const fn finish_relative(self) -> Result<RelativeOid<MAX_SIZE>> {
+        // TODO(tarcieri): use `?` when stable in `const fn`
+        match self.finish_buffer() {
+            Ok(ber) => Ok(RelativeOid { ber }),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Finish encoding, returning the buffer of BER bytes.
+    const fn finish_buffer(self) -> Result<Buffer<MAX_SIZE>> {
         if self.cursor == 0 {
             return Err(Error::Empty);
         }
 
-        let ber = Buffer {
+        Ok(Buffer {
             bytes: self.bytes,
             length: self.cursor as u8,
-        };
-
-        Ok(ObjectIdentifier { ber })
+        })
     }
 
     /// Encode base 128.
@@ -167,4 +192,12 @@ mod tests {
         let encoder = encoder.arc(1).unwrap();
         assert_eq!(&encoder.bytes[..encoder.cursor], EXAMPLE_OID_BER);
     }
+
+    #[test]
+    fn encode_relative() {
+        let encoder = Encoder::<7>::new_relative();
+        let encoder = encoder.arc(840).unwrap();
+        let encoder = encoder.arc(10045).unwrap();
+        assert_eq!(&encoder.bytes[..encoder.cursor], &EXAMPLE_OID_BER[1..5]);
+    }
 }
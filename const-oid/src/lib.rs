@@ -27,6 +27,7 @@ mod buffer;
 mod encoder;
 mod error;
 mod parser;
+mod relative;
 mod traits;
 
 #[cfg(feature = "db")]
@@ -36,6 +37,7 @@ pub use crate::{
     arcs::{Arc, Arcs},
     buffer::Buffer,
     error::{Error, Result},
+    relative::RelativeOid,
     traits::{AssociatedOid, DynAssociatedOid},
 };
 
@@ -1,6 +1,6 @@
 //! OID string parser with `const` support.
 
-use crate::{Arc, Error, ObjectIdentifier, Result, encoder::Encoder};
+use crate::{Arc, Error, ObjectIdentifier, RelativeOid, Result, encoder::Encoder};
 
 /// Const-friendly OID string parser.
 ///
@@ -17,6 +17,16 @@ pub(crate) struct Parser {
 impl Parser {
     /// Parse an OID from a dot-delimited string e.g. `1.2.840.113549.1.1.1`
     pub(crate) const fn parse(s: &str) -> Result<Self> {
+        Self::parse_with(s, Encoder::new())
+    }
+
+    /// Parse a relative OID from a dot-delimited string e.g. `8571.3.2`
+    pub(crate) const fn parse_relative(s: &str) -> Result<Self> {
+        Self::parse_with(s, Encoder::new_relative())
+    }
+
+    /// Parse a dot-delimited string using the given encoder.
+    const fn parse_with(s: &str, encoder: Encoder<{ ObjectIdentifier::MAX_SIZE }>) -> Result<Self> {
         let bytes = s.as_bytes();
 
         if bytes.is_empty() {
@@ -26,7 +36,7 @@ impl Parser {
         match bytes[0] {
             b'0'..=b'9' => Self {
                 current_arc: None,
-                encoder: Encoder::new(),
+                encoder,
             }
             .parse_bytes(bytes),
             actual => Err(Error::DigitExpected { actual }),
@@ -38,6 +48,11 @@ impl Parser {
         self.encoder.finish()
     }
 
+    /// Finish parsing a relative OID, returning the result
+    pub(crate) const fn finish_relative(self) -> Result<RelativeOid> {
+        self.encoder.finish_relative()
+    }
+
     /// Parse the remaining bytes
     const fn parse_bytes(mut self, bytes: &[u8]) -> Result<Self> {
         match bytes {
@@ -106,6 +121,15 @@ mod tests {
         assert_eq!(oid, "1.23.456".parse().unwrap());
     }
 
+    #[test]
+    fn parse_relative() {
+        let oid = Parser::parse_relative("8571.3.2")
+            .unwrap()
+            .finish_relative()
+            .unwrap();
+        assert_eq!(oid.as_bytes(), &[0xC2, 0x7B, 0x03, 0x02]);
+    }
+
     #[test]
     fn reject_empty_string() {
         assert_eq!(Parser::parse("").err().unwrap(), Error::Empty);
@@ -0,0 +1,178 @@
+//! Relative object identifiers.
+
+use crate::{Arc, Arcs, Buffer, DEFAULT_MAX_SIZE, Error, Result, encoder::Encoder, parser};
+use core::{fmt, str::FromStr};
+
+/// Relative object identifier (`RELATIVE-OID`).
+///
+/// Relative OIDs identify an object relative to some known OID, and consist of one or more arcs
+/// which are encoded the same way as the arcs of an [`ObjectIdentifier`][`crate::ObjectIdentifier`]
+/// following its first two, e.g. `8571.3.2`.
+///
+/// # Validity
+///
+/// In order for a relative OID to be considered valid by this library, it must meet the
+/// following criteria:
+///
+/// - The relative OID MUST have at least 1 arc
+/// - The BER/DER encoding of the relative OID MUST be shorter than [`RelativeOid::MAX_SIZE`]
+#[derive(Clone, Copy, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RelativeOid<const MAX_SIZE: usize = DEFAULT_MAX_SIZE> {
+    /// Buffer containing BER/DER-serialized bytes (sans ASN.1 tag/length)
+    pub(crate) ber: Buffer<MAX_SIZE>,
+}
+
+impl RelativeOid {
+    /// Maximum size of a BER/DER-encoded relative OID in bytes.
+    pub const MAX_SIZE: usize = DEFAULT_MAX_SIZE;
+
+    /// Parse a [`RelativeOid`] from the dot-delimited string form, panicking on parse errors.
+    ///
+    /// See [`ObjectIdentifier::new_unwrap`][`crate::ObjectIdentifier::new_unwrap`].
+    pub const fn new_unwrap(s: &str) -> Self {
+        match Self::new(s) {
+            Ok(oid) => oid,
+            Err(err) => err.panic(),
+        }
+    }
+
+    /// Parse a [`RelativeOid`] from the dot-delimited string form.
+    pub const fn new(s: &str) -> Result<Self> {
+        // TODO(tarcieri): use `?` when stable in `const fn`
+        match parser::Parser::parse_relative(s) {
+            Ok(parser) => parser.finish_relative(),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Parse a relative OID from a slice of [`Arc`] values (i.e. integers).
+    pub fn from_arcs(arcs: impl IntoIterator<Item = Arc>) -> Result<Self> {
+        let mut encoder = Encoder::new_relative();
+
+        for arc in arcs {
+            encoder = encoder.arc(arc)?;
+        }
+
+        encoder.finish_relative()
+    }
+
+    /// Parse a relative OID from from its BER/DER encoding.
+    pub fn from_bytes(ber_bytes: &[u8]) -> Result<Self> {
+        if ber_bytes.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        // Ensure arcs are well-formed
+        let mut arcs = Arcs::new_relative(ber_bytes);
+        while arcs.try_next()?.is_some() {}
+
+        let len = ber_bytes.len();
+
+        if len > Self::MAX_SIZE {
+            return Err(Error::Length);
+        }
+
+        let mut bytes = [0u8; Self::MAX_SIZE];
+        bytes[..len].copy_from_slice(ber_bytes);
+
+        let ber = Buffer {
+            bytes,
+            length: len as u8,
+        };
+
+        Ok(Self { ber })
+    }
+}
+
+impl<const MAX_SIZE: usize> RelativeOid<MAX_SIZE> {
+    /// Get the BER/DER serialization of this relative OID as bytes.
+    ///
+    /// Note that this encoding omits the ASN.1 tag/length, and only contains the value portion of
+    /// the encoded relative OID.
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.ber.as_bytes()
+    }
+
+    /// Return the arc with the given index, if it exists.
+    pub fn arc(&self, index: usize) -> Option<Arc> {
+        self.arcs().nth(index)
+    }
+
+    /// Iterate over the arcs (a.k.a. nodes) of a [`RelativeOid`].
+    ///
+    /// Returns [`Arcs`], an iterator over [`Arc`] values.
+    pub fn arcs(&self) -> Arcs<'_> {
+        Arcs::new_relative(self.as_bytes())
+    }
+
+    /// Get the length of this [`RelativeOid`] in arcs.
+    pub fn len(&self) -> usize {
+        self.arcs().count()
+    }
+}
+
+impl<const MAX_SIZE: usize> AsRef<[u8]> for RelativeOid<MAX_SIZE> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl FromStr for RelativeOid {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self> {
+        Self::new(string)
+    }
+}
+
+impl TryFrom<&[u8]> for RelativeOid {
+    type Error = Error;
+
+    fn try_from(ber_bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes(ber_bytes)
+    }
+}
+
+impl<const MAX_SIZE: usize> fmt::Debug for RelativeOid<MAX_SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RelativeOid({})", self)
+    }
+}
+
+impl<const MAX_SIZE: usize> fmt::Display for RelativeOid<MAX_SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, arc) in self.arcs().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+
+            write!(f, "{}", arc)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Implement by hand because the derive would create invalid values.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RelativeOid {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut encoder = Encoder::new_relative()
+            .arc(u.arbitrary()?)
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        for arc in u.arbitrary_iter()? {
+            encoder = encoder
+                .arc(arc?)
+                .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        }
+
+        encoder
+            .finish_relative()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        (Arc::size_hint(depth).0, None)
+    }
+}
@@ -0,0 +1,71 @@
+//! Tests for `RelativeOid`.
+
+use const_oid::{Error, RelativeOid};
+use hex_literal::hex;
+use std::string::ToString;
+
+/// Example relative OID value.
+const EXAMPLE_STR: &str = "8571.3.2";
+const EXAMPLE_BER: &[u8] = &hex!("C27B0302");
+const EXAMPLE: RelativeOid = RelativeOid::new_unwrap(EXAMPLE_STR);
+
+/// Example relative OID value with a single large arc (namely `u32::MAX`).
+const EXAMPLE_LARGE_ARC_STR: &str = "4294967295";
+const EXAMPLE_LARGE_ARC_BER: &[u8] = &hex!("8FFFFFFF7F");
+
+#[test]
+fn from_bytes() {
+    let oid = RelativeOid::from_bytes(EXAMPLE_BER).unwrap();
+    assert_eq!(oid, EXAMPLE);
+    assert_eq!(oid.arcs().collect::<Vec<_>>(), [8571, 3, 2]);
+    assert_eq!(oid.len(), 3);
+
+    let oid = RelativeOid::from_bytes(EXAMPLE_LARGE_ARC_BER).unwrap();
+    assert_eq!(oid.arc(0), Some(u32::MAX));
+    assert_eq!(oid.len(), 1);
+}
+
+#[test]
+fn from_bytes_errors() {
+    assert_eq!(RelativeOid::from_bytes(&[]), Err(Error::Empty));
+
+    // Truncated arc
+    assert_eq!(RelativeOid::from_bytes(&hex!("0381")), Err(Error::Base128));
+
+    // Arc larger than `u32::MAX`
+    assert_eq!(
+        RelativeOid::from_bytes(&hex!("FFFFFFFFFF7F")),
+        Err(Error::ArcTooBig)
+    );
+}
+
+#[test]
+fn from_arcs() {
+    assert_eq!(RelativeOid::from_arcs([8571, 3, 2]).unwrap(), EXAMPLE);
+    assert_eq!(RelativeOid::from_arcs([]), Err(Error::Empty));
+}
+
+#[test]
+fn parse() {
+    assert_eq!(EXAMPLE.as_bytes(), EXAMPLE_BER);
+    assert_eq!(
+        EXAMPLE_LARGE_ARC_STR
+            .parse::<RelativeOid>()
+            .unwrap()
+            .as_bytes(),
+        EXAMPLE_LARGE_ARC_BER
+    );
+
+    // Unlike absolute OIDs, the first arcs aren't constrained
+    assert_eq!("99.0".parse::<RelativeOid>().unwrap().as_bytes(), &[99, 0]);
+
+    assert_eq!(RelativeOid::new(""), Err(Error::Empty));
+    assert_eq!(RelativeOid::new("1..2"), Err(Error::RepeatedDot));
+    assert_eq!(RelativeOid::new("1.2."), Err(Error::TrailingDot));
+}
+
+#[test]
+fn display() {
+    assert_eq!(EXAMPLE.to_string(), EXAMPLE_STR);
+    assert_eq!(format!("{:?}", EXAMPLE), "RelativeOid(8571.3.2)");
+}
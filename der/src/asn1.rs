@@ -11,11 +11,15 @@ mod bmp_string;
 mod boolean;
 mod choice;
 mod context_specific;
+mod date;
+mod duration;
 mod general_string;
 mod generalized_time;
 mod ia5_string;
 mod integer;
+mod local_date_time;
 mod null;
+mod numeric_string;
 mod octet_string;
 #[cfg(feature = "oid")]
 mod oid;
@@ -23,33 +27,47 @@ mod optional;
 mod printable_string;
 #[cfg(feature = "real")]
 mod real;
+#[cfg(feature = "oid")]
+mod relative_oid;
+pub(crate) mod segments;
 mod sequence;
 mod sequence_of;
-mod set_of;
+pub(crate) mod set_of;
 mod teletex_string;
+mod time_of_day;
+mod universal_string;
 mod utc_time;
 mod utf8_string;
 mod videotex_string;
+mod visible_string;
 
 pub use self::{
     any::AnyRef,
     bit_string::{BitStringIter, BitStringRef},
     choice::Choice,
     context_specific::{ContextSpecific, ContextSpecificRef},
+    date::Date,
+    duration::Duration,
     general_string::GeneralStringRef,
     generalized_time::GeneralizedTime,
     ia5_string::Ia5StringRef,
     integer::{int::IntRef, uint::UintRef},
+    local_date_time::LocalDateTime,
     null::Null,
-    octet_string::OctetStringRef,
+    numeric_string::NumericStringRef,
+    octet_string::{OctetStringArray, OctetStringRef},
     printable_string::PrintableStringRef,
+    segments::StringSegments,
     sequence::{Sequence, SequenceRef},
     sequence_of::{SequenceOf, SequenceOfIter},
     set_of::{SetOf, SetOfIter},
     teletex_string::TeletexStringRef,
+    time_of_day::TimeOfDay,
+    universal_string::UniversalStringRef,
     utc_time::UtcTime,
     utf8_string::Utf8StringRef,
     videotex_string::VideotexStringRef,
+    visible_string::VisibleStringRef,
 };
 
 #[cfg(feature = "alloc")]
@@ -59,11 +77,20 @@ pub use self::{
     bmp_string::BmpString,
     ia5_string::Ia5String,
     integer::{int::Int, uint::Uint},
+    numeric_string::NumericString,
     octet_string::OctetString,
     printable_string::PrintableString,
     set_of::SetOfVec,
     teletex_string::TeletexString,
+    universal_string::UniversalString,
+    visible_string::VisibleString,
 };
 
+#[cfg(feature = "bytes")]
+pub use self::{any::AnyBytes, bit_string::BitStringBytes, octet_string::OctetStringBytes};
+
 #[cfg(feature = "oid")]
-pub use const_oid::ObjectIdentifier;
+pub use const_oid::{ObjectIdentifier, RelativeOid};
+
+#[cfg(feature = "real")]
+pub use self::real::{DecimalForm, RealDecimalRef};
@@ -3,8 +3,8 @@
 #![cfg_attr(feature = "arbitrary", allow(clippy::arithmetic_side_effects))]
 
 use crate::{
-    BytesRef, Choice, Decode, DecodeValue, DerOrd, EncodeValue, Error, ErrorKind, Header, Length,
-    Reader, SliceReader, Tag, Tagged, ValueOrd, Writer,
+    BytesRef, Choice, Decode, DecodeValue, DerOrd, EncodeValue, EncodingRules, Error, ErrorKind,
+    Header, Length, Reader, SliceReader, Tag, Tagged, ValueOrd, Writer,
 };
 use core::cmp::Ordering;
 
@@ -59,6 +59,18 @@ impl<'a> AnyRef<'a> {
 
     /// Attempt to decode this [`AnyRef`] type into the inner value.
     pub fn decode_as<T>(self) -> Result<T, <T as DecodeValue<'a>>::Error>
+    where
+        T: Choice<'a> + DecodeValue<'a>,
+    {
+        self.decode_as_encoding(EncodingRules::Der)
+    }
+
+    /// Attempt to decode this [`AnyRef`] type into the inner value, using the
+    /// given [`EncodingRules`].
+    pub fn decode_as_encoding<T>(
+        self,
+        encoding: EncodingRules,
+    ) -> Result<T, <T as DecodeValue<'a>>::Error>
     where
         T: Choice<'a> + DecodeValue<'a>,
     {
@@ -66,12 +78,9 @@ impl<'a> AnyRef<'a> {
             return Err(self.tag.unexpected_error(None).into());
         }
 
-        let header = Header {
-            tag: self.tag,
-            length: self.value.len(),
-        };
+        let header = Header::new(self.tag, self.value.len())?;
 
-        let mut decoder = SliceReader::new(self.value())?;
+        let mut decoder = SliceReader::new_with_encoding_rules(self.value(), encoding)?;
         let result = T::decode_value(&mut decoder, header)?;
         Ok(decoder.finish(result)?)
     }
@@ -106,14 +115,20 @@ impl<'a> Decode<'a> for AnyRef<'a> {
 
     fn decode<R: Reader<'a>>(reader: &mut R) -> Result<AnyRef<'a>, Error> {
         let header = Header::decode(reader)?;
-        Self::decode_value(reader, header)
+        reader.read_value(header, |r| Self::decode_value(r, header))
     }
 }
 
 impl<'a> DecodeValue<'a> for AnyRef<'a> {
     type Error = Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Error> {
+        // BER constructed string types can't be borrowed since they need to be reassembled
+        if header.is_constructed() != header.tag.is_constructed() {
+            return Err(header.tag.non_canonical_error());
+        }
+
         Ok(Self {
             tag: header.tag,
             value: BytesRef::decode_value(reader, header)?,
@@ -163,7 +178,11 @@ pub use self::allocating::Any;
 #[cfg(feature = "alloc")]
 mod allocating {
     use super::*;
-    use crate::{BytesOwned, referenced::*};
+    use crate::{
+        BytesOwned,
+        asn1::{BitString, OctetString},
+        referenced::*,
+    };
     use alloc::boxed::Box;
 
     /// ASN.1 `ANY`: represents any explicitly tagged ASN.1 value.
@@ -174,10 +193,10 @@ mod allocating {
     #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
     pub struct Any {
         /// Tag representing the type of the encoded value.
-        tag: Tag,
+        pub(super) tag: Tag,
 
         /// Inner value encoded as bytes.
-        value: BytesOwned,
+        pub(super) value: BytesOwned,
     }
 
     impl Any {
@@ -203,6 +222,18 @@ mod allocating {
             AnyRef::from(self).decode_as()
         }
 
+        /// Attempt to decode this [`Any`] type into the inner value, using the
+        /// given [`EncodingRules`].
+        pub fn decode_as_encoding<'a, T>(
+            &'a self,
+            encoding: EncodingRules,
+        ) -> Result<T, <T as DecodeValue<'a>>::Error>
+        where
+            T: Choice<'a> + DecodeValue<'a>,
+        {
+            AnyRef::from(self).decode_as_encoding(encoding)
+        }
+
         /// Encode the provided type as an [`Any`] value.
         pub fn encode_from<T>(msg: &T) -> Result<Self, Error>
         where
@@ -246,15 +277,27 @@ mod allocating {
 
         fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self, Error> {
             let header = Header::decode(reader)?;
-            Self::decode_value(reader, header)
+            reader.read_value(header, |r| Self::decode_value(r, header))
         }
     }
 
     impl<'a> DecodeValue<'a> for Any {
         type Error = Error;
+        const INDEFINITE_LENGTH: bool = true;
 
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Error> {
-            let value = reader.read_vec(header.length)?;
+            // Reassemble BER constructed string types into their primitive form
+            if header.is_constructed() != header.tag.is_constructed() {
+                return match header.tag {
+                    Tag::BitString => Self::encode_from(&BitString::decode_value(reader, header)?),
+                    Tag::OctetString => {
+                        Self::encode_from(&OctetString::decode_value(reader, header)?)
+                    }
+                    tag => Err(tag.non_canonical_error()),
+                };
+            }
+
+            let value = reader.read_vec(header.value_len(reader)?)?;
             Self::new(header.tag, value)
         }
     }
@@ -325,3 +368,168 @@ mod allocating {
         }
     }
 }
+
+#[cfg(feature = "bytes")]
+pub use self::shared::AnyBytes;
+
+#[cfg(feature = "bytes")]
+mod shared {
+    use super::*;
+    use crate::{BytesShared, asn1::Any, referenced::OwnedToRef};
+    use bytes::Bytes;
+
+    /// ASN.1 `ANY`: represents any explicitly tagged ASN.1 value.
+    ///
+    /// This type provides the same functionality as [`Any`], but its value is a [`Bytes`] buffer.
+    /// When decoded with a [`BytesReader`][`crate::BytesReader`], the value shares the allocation
+    /// of the input rather than being copied out of it.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+    pub struct AnyBytes {
+        /// Tag representing the type of the encoded value.
+        tag: Tag,
+
+        /// Inner value encoded as bytes.
+        value: BytesShared,
+    }
+
+    impl AnyBytes {
+        /// Create a new [`AnyBytes`] from the provided [`Tag`] and DER bytes.
+        pub fn new(tag: Tag, bytes: impl Into<Bytes>) -> Result<Self, Error> {
+            let value = BytesShared::new(bytes)?;
+
+            // Ensure the tag and value are a valid `AnyRef`.
+            AnyRef::new(tag, value.as_slice())?;
+            Ok(Self { tag, value })
+        }
+
+        /// Allow access to value
+        pub fn value(&self) -> &[u8] {
+            self.value.as_slice()
+        }
+
+        /// Get the [`Bytes`] buffer holding the value.
+        pub fn value_bytes(&self) -> &Bytes {
+            self.value.as_bytes()
+        }
+
+        /// Attempt to decode this [`AnyBytes`] type into the inner value.
+        pub fn decode_as<'a, T>(&'a self) -> Result<T, <T as DecodeValue<'a>>::Error>
+        where
+            T: Choice<'a> + DecodeValue<'a>,
+        {
+            AnyRef::from(self).decode_as()
+        }
+
+        /// Attempt to decode this [`AnyBytes`] type into the inner value, using the
+        /// given [`EncodingRules`].
+        pub fn decode_as_encoding<'a, T>(
+            &'a self,
+            encoding: EncodingRules,
+        ) -> Result<T, <T as DecodeValue<'a>>::Error>
+        where
+            T: Choice<'a> + DecodeValue<'a>,
+        {
+            AnyRef::from(self).decode_as_encoding(encoding)
+        }
+
+        /// Attempt to decode this value an ASN.1 `SEQUENCE`, creating a new
+        /// nested reader and calling the provided argument with it.
+        pub fn sequence<'a, F, T, E>(&'a self, f: F) -> Result<T, E>
+        where
+            F: FnOnce(&mut SliceReader<'a>) -> Result<T, E>,
+            E: From<Error>,
+        {
+            AnyRef::from(self).sequence(f)
+        }
+
+        /// Is this value an ASN.1 `NULL` value?
+        pub fn is_null(&self) -> bool {
+            AnyRef::from(self) == AnyRef::NULL
+        }
+    }
+
+    impl Choice<'_> for AnyBytes {
+        fn can_decode(_: Tag) -> bool {
+            true
+        }
+    }
+
+    impl<'a> Decode<'a> for AnyBytes {
+        type Error = Error;
+
+        fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self, Error> {
+            let header = Header::decode(reader)?;
+            reader.read_value(header, |r| Self::decode_value(r, header))
+        }
+    }
+
+    impl<'a> DecodeValue<'a> for AnyBytes {
+        type Error = Error;
+        const INDEFINITE_LENGTH: bool = true;
+
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Error> {
+            // BER constructed string types need to be reassembled, which requires a copy
+            if header.is_constructed() != header.tag.is_constructed() {
+                return Any::decode_value(reader, header).map(Into::into);
+            }
+
+            let value = BytesShared::decode_value(reader, header)?;
+            Self::new(header.tag, value)
+        }
+    }
+
+    impl EncodeValue for AnyBytes {
+        fn value_len(&self) -> Result<Length, Error> {
+            self.value.value_len()
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> Result<(), Error> {
+            self.value.encode_value(writer)
+        }
+    }
+
+    impl<'a> From<&'a AnyBytes> for AnyRef<'a> {
+        fn from(any: &'a AnyBytes) -> AnyRef<'a> {
+            AnyRef {
+                tag: any.tag,
+                value: any.value.owned_to_ref(),
+            }
+        }
+    }
+
+    impl From<AnyRef<'_>> for AnyBytes {
+        fn from(any: AnyRef<'_>) -> AnyBytes {
+            Self {
+                tag: any.tag(),
+                value: any.value.into(),
+            }
+        }
+    }
+
+    impl From<Any> for AnyBytes {
+        fn from(any: Any) -> AnyBytes {
+            Self {
+                tag: any.tag,
+                value: any.value.into(),
+            }
+        }
+    }
+
+    impl From<AnyBytes> for Any {
+        fn from(any: AnyBytes) -> Any {
+            AnyRef::from(&any).into()
+        }
+    }
+
+    impl Tagged for AnyBytes {
+        fn tag(&self) -> Tag {
+            self.tag
+        }
+    }
+
+    impl ValueOrd for AnyBytes {
+        fn value_cmp(&self, other: &Self) -> Result<Ordering, Error> {
+            AnyRef::from(self).value_cmp(&AnyRef::from(other))
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
 use core::{cmp::Ordering, iter::FusedIterator};
 
 #[cfg(feature = "flagset")]
-use core::mem::size_of_val;
+use {crate::DecodeQuirk, core::mem::size_of_val};
 
 /// ASN.1 `BIT STRING` type.
 ///
@@ -139,10 +139,12 @@ impl<'a> DecodeValue<'a> for BitStringRef<'a> {
     type Error = Error;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        let header = Header {
-            tag: header.tag,
-            length: (header.length - Length::ONE)?,
-        };
+        // The constructed form can't be borrowed: use `BitString` or `StringSegments` instead
+        if header.is_constructed() {
+            return Err(Self::TAG.non_canonical_error());
+        }
+
+        let header = Header::new(header.tag, (header.length - Length::ONE)?)?;
 
         let unused_bits = reader.read_byte()?;
         let inner = BytesRef::decode_value(reader, header)?;
@@ -248,7 +250,7 @@ pub use self::allocating::BitString;
 #[cfg(feature = "alloc")]
 mod allocating {
     use super::*;
-    use crate::referenced::*;
+    use crate::{asn1::segments::read_segments, referenced::*};
     use alloc::vec::Vec;
 
     /// Owned form of ASN.1 `BIT STRING` type.
@@ -351,11 +353,32 @@ mod allocating {
 
     impl<'a> DecodeValue<'a> for BitString {
         type Error = Error;
+        const INDEFINITE_LENGTH: bool = true;
 
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-            let inner_len = (header.length - Length::ONE)?;
-            let unused_bits = reader.read_byte()?;
-            let inner = reader.read_vec(inner_len)?;
+            let mut inner = Vec::new();
+            let mut unused_bits = 0;
+
+            // Reassemble the segments of values using the BER constructed form
+            read_segments(reader, header, Self::TAG, |reader, segment| {
+                // Only the final segment may have unused bits
+                if unused_bits != 0 {
+                    return Err(Self::TAG.value_error());
+                }
+
+                unused_bits = reader.read_byte()?;
+
+                let mut bytes = reader.read_vec((segment.length - Length::ONE)?)?;
+
+                if inner.is_empty() {
+                    inner = bytes;
+                } else {
+                    inner.append(&mut bytes);
+                }
+
+                Ok(())
+            })?;
+
             Self::new(unused_bits, inner)
         }
     }
@@ -449,6 +472,192 @@ mod allocating {
     }
 }
 
+#[cfg(feature = "bytes")]
+pub use self::shared::BitStringBytes;
+
+#[cfg(feature = "bytes")]
+mod shared {
+    use super::*;
+    use crate::{BytesShared, asn1::BitString, referenced::OwnedToRef};
+    use alloc::vec::Vec;
+    use bytes::Bytes;
+
+    /// Form of ASN.1 `BIT STRING` type backed by a [`Bytes`] buffer.
+    ///
+    /// This type provides the same functionality as [`BitString`], but when decoded with a
+    /// [`BytesReader`][`crate::BytesReader`] its value shares the allocation of the input rather
+    /// than being copied out of it.
+    #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct BitStringBytes {
+        /// Number of unused bits in the final octet.
+        unused_bits: u8,
+
+        /// Length of this `BIT STRING` in bits.
+        bit_length: usize,
+
+        /// Bitstring represented as a [`Bytes`] buffer.
+        inner: BytesShared,
+    }
+
+    impl BitStringBytes {
+        /// Maximum number of unused bits allowed.
+        pub const MAX_UNUSED_BITS: u8 = 7;
+
+        /// Create a new ASN.1 `BIT STRING` from a [`Bytes`] buffer.
+        ///
+        /// Accepts an optional number of "unused bits" (0-7) which are omitted
+        /// from the final octet. This number is 0 if the value is octet-aligned.
+        pub fn new(unused_bits: u8, bytes: impl Into<Bytes>) -> Result<Self> {
+            let inner = BytesShared::new(bytes)?;
+
+            // Ensure parameters parse successfully as a `BitStringRef`.
+            let bit_length = BitStringRef::new(unused_bits, inner.as_slice())?.bit_length;
+
+            Ok(Self {
+                unused_bits,
+                bit_length,
+                inner,
+            })
+        }
+
+        /// Create a new ASN.1 `BIT STRING` from the given bytes.
+        ///
+        /// The "unused bits" are set to 0.
+        pub fn from_bytes(bytes: impl Into<Bytes>) -> Result<Self> {
+            Self::new(0, bytes)
+        }
+
+        /// Get the number of unused bits in the octet serialization of this
+        /// `BIT STRING`.
+        pub fn unused_bits(&self) -> u8 {
+            self.unused_bits
+        }
+
+        /// Is the number of unused bits a value other than 0?
+        pub fn has_unused_bits(&self) -> bool {
+            self.unused_bits != 0
+        }
+
+        /// Get the length of this `BIT STRING` in bits.
+        pub fn bit_len(&self) -> usize {
+            self.bit_length
+        }
+
+        /// Is the inner byte slice empty?
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+
+        /// Borrow the inner byte slice.
+        ///
+        /// Returns `None` if the number of unused bits is *not* equal to zero,
+        /// i.e. if the `BIT STRING` is not octet aligned.
+        ///
+        /// Use [`BitStringBytes::raw_bytes`] to obtain access to the raw value
+        /// regardless of the presence of unused bits.
+        pub fn as_bytes(&self) -> Option<&[u8]> {
+            if self.has_unused_bits() {
+                None
+            } else {
+                Some(self.raw_bytes())
+            }
+        }
+
+        /// Borrow the raw bytes of this `BIT STRING`.
+        pub fn raw_bytes(&self) -> &[u8] {
+            self.inner.as_slice()
+        }
+
+        /// Get the [`Bytes`] buffer holding the raw bytes of this `BIT STRING`.
+        pub fn raw_bytes_shared(&self) -> &Bytes {
+            self.inner.as_bytes()
+        }
+
+        /// Iterator over the bits of this `BIT STRING`.
+        pub fn bits(&self) -> BitStringIter<'_> {
+            BitStringRef::from(self).bits()
+        }
+
+        /// Returns Some(bit) if index is valid
+        pub fn get(&self, position: usize) -> Option<bool> {
+            BitStringRef::from(self).get(position)
+        }
+    }
+
+    impl_any_conversions!(BitStringBytes);
+
+    impl<'a> DecodeValue<'a> for BitStringBytes {
+        type Error = Error;
+        const INDEFINITE_LENGTH: bool = true;
+
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            // Values using the BER constructed form need to be reassembled
+            if header.is_constructed() {
+                return BitString::decode_value(reader, header).map(Into::into);
+            }
+
+            let unused_bits = reader.read_byte()?;
+            let inner = reader.read_bytes((header.length - Length::ONE)?)?;
+            Self::new(unused_bits, inner)
+        }
+    }
+
+    impl EncodeValue for BitStringBytes {
+        fn value_len(&self) -> Result<Length> {
+            Length::ONE + self.inner.len()
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+            writer.write_byte(self.unused_bits)?;
+            self.inner.encode_value(writer)
+        }
+    }
+
+    impl FixedTag for BitStringBytes {
+        const TAG: Tag = Tag::BitString;
+    }
+
+    impl<'a> From<&'a BitStringBytes> for BitStringRef<'a> {
+        fn from(bit_string: &'a BitStringBytes) -> BitStringRef<'a> {
+            BitStringRef {
+                unused_bits: bit_string.unused_bits,
+                bit_length: bit_string.bit_length,
+                inner: bit_string.inner.owned_to_ref(),
+            }
+        }
+    }
+
+    impl From<BitStringRef<'_>> for BitStringBytes {
+        fn from(bit_string: BitStringRef<'_>) -> BitStringBytes {
+            Self {
+                unused_bits: bit_string.unused_bits,
+                bit_length: bit_string.bit_length,
+                inner: bit_string.inner.into(),
+            }
+        }
+    }
+
+    impl From<BitString> for BitStringBytes {
+        fn from(bit_string: BitString) -> BitStringBytes {
+            BitStringRef::from(&bit_string).into()
+        }
+    }
+
+    impl From<BitStringBytes> for BitString {
+        fn from(bit_string: BitStringBytes) -> BitString {
+            // Ensured to parse successfully in constructor
+            BitString::new(bit_string.unused_bits, Vec::from(bit_string.raw_bytes()))
+                .expect("invalid BIT STRING")
+        }
+    }
+
+    impl ValueOrd for BitStringBytes {
+        fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+            BitStringRef::from(self).value_cmp(&BitStringRef::from(other))
+        }
+    }
+}
+
 /// Iterator over the bits of a [`BitString`].
 pub struct BitStringIter<'a> {
     /// [`BitString`] being iterated over.
@@ -518,6 +727,12 @@ where
             return Err(Error::new(ErrorKind::Overlength, position));
         }
 
+        // DER requires trailing zero bits to be removed from named bit lists (X.690 Section 11.2.2),
+        // but these have always been accepted, so they're only reported
+        if reader.encoding_rules().is_der() && bits.bits().last() == Some(false) {
+            reader.record_quirk(DecodeQuirk::BitStringTrailingZeros);
+        }
+
         for (i, bit) in bits.bits().enumerate() {
             flags |= T::Type::from(bit) << i;
         }
@@ -616,4 +831,18 @@ mod tests {
             Tag::BitString.value_error().kind()
         )
     }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decode_ber_constructed() {
+        use crate::{Decode, asn1::BitString};
+
+        // Constructed `BIT STRING` with a definite length and two segments
+        let bs = BitString::from_ber(&hex!("230A 0303006E5D 030306C000")).unwrap();
+        assert_eq!(bs.unused_bits(), 6);
+        assert_eq!(bs.raw_bytes(), &hex!("6E5DC000"));
+
+        // Only the final segment may have unused bits
+        assert!(BitString::from_ber(&hex!("2309 030206C0 0303006E5D")).is_err());
+    }
 }
@@ -1,8 +1,8 @@
 //! ASN.1 `BOOLEAN` support.
 
 use crate::{
-    DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader, Result, Tag,
-    Writer, asn1::AnyRef, ord::OrdIsValueOrd,
+    DecodeQuirk, DecodeValue, EncodeValue, Error, ErrorKind, FixedLen, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer, asn1::AnyRef, ord::OrdIsValueOrd,
 };
 
 /// Byte used to encode `true` in ASN.1 DER. From X.690 Section 11.1:
@@ -25,6 +25,7 @@ impl<'a> DecodeValue<'a> for bool {
         match reader.read_byte()? {
             FALSE_OCTET => Ok(false),
             TRUE_OCTET => Ok(true),
+            _ if reader.allow_quirk(DecodeQuirk::NonCanonicalBoolean) => Ok(true),
             _ => Err(Self::TAG.non_canonical_error()),
         }
     }
@@ -44,6 +45,10 @@ impl FixedTag for bool {
     const TAG: Tag = Tag::Boolean;
 }
 
+impl FixedLen for bool {
+    const ENCODED_LEN: Length = Length::ONE.for_tlv_const(Self::TAG);
+}
+
 impl OrdIsValueOrd for bool {}
 
 impl TryFrom<AnyRef<'_>> for bool {
@@ -1,8 +1,8 @@
 //! Context-specific field.
 
 use crate::{
-    Choice, Decode, DecodeValue, DerOrd, Encode, EncodeValue, EncodeValueRef, Error, Header,
-    Length, Reader, Tag, TagMode, TagNumber, Tagged, ValueOrd, Writer, asn1::AnyRef,
+    Choice, Decode, DecodeValue, DerOrd, Encode, EncodeValue, EncodeValueRef, Error, ErrorKind,
+    Header, Length, Reader, Tag, TagMode, TagNumber, Tagged, ValueOrd, Writer, asn1::AnyRef,
 };
 use core::cmp::Ordering;
 
@@ -66,13 +66,23 @@ impl<T> ContextSpecific<T> {
             // Decode IMPLICIT header
             let header = Header::decode(reader)?;
 
-            // read_nested checks if header matches decoded length
-            let value = reader.read_nested(header.length, |reader| {
+            if header.is_indefinite() && !T::INDEFINITE_LENGTH {
+                return Err(reader.error(ErrorKind::IndefiniteLength).into());
+            }
+
+            // read_value checks if header matches decoded length
+            let value = reader.read_value(header, |reader| {
                 // Decode inner IMPLICIT value
                 T::decode_value(reader, header)
             })?;
 
-            if header.tag.is_constructed() != value.tag().is_constructed() {
+            // BER allows string types to use the constructed form, which is handled by their decoder
+            let ber_constructed_string = reader.encoding_rules().is_ber()
+                && matches!(value.tag(), Tag::BitString | Tag::OctetString);
+
+            if header.tag.is_constructed() != value.tag().is_constructed()
+                && !ber_constructed_string
+            {
                 return Err(header.tag.non_canonical_error().into());
             }
 
@@ -135,7 +145,7 @@ where
             } => Ok(Self {
                 tag_number: number,
                 tag_mode: TagMode::default(),
-                value: reader.read_nested(header.length, |reader| {
+                value: reader.read_value(header, |reader| {
                     // Decode inner tag-length-value of EXPLICIT
                     T::decode(reader)
                 })?,
@@ -162,6 +172,16 @@ where
             TagMode::Implicit => self.value.encode_value(writer),
         }
     }
+
+    fn string_tag(&self) -> Option<Tag> {
+        match self.tag_mode {
+            TagMode::Explicit => None,
+            TagMode::Implicit => self.value.string_tag().or_else(|| {
+                let tag = self.value.tag();
+                tag.is_string().then_some(tag)
+            }),
+        }
+    }
 }
 
 impl<T> Tagged for ContextSpecific<T>
@@ -253,6 +273,10 @@ where
     fn encode_value(&self, writer: &mut impl Writer) -> Result<(), Error> {
         self.encoder().encode_value(writer)
     }
+
+    fn string_tag(&self) -> Option<Tag> {
+        self.encoder().string_tag()
+    }
 }
 
 impl<T> Tagged for ContextSpecificRef<'_, T>
@@ -292,6 +316,22 @@ mod tests {
         assert_eq!(encoded, EXAMPLE_BYTES);
     }
 
+    #[test]
+    fn decode_ber_indefinite_length() {
+        // `[1] EXPLICIT` with an indefinite length wrapping the same `BIT STRING`
+        const BER_BYTES: &[u8] =
+            &hex!("A180032100A3A7EAE3A8373830BC47E1167BC50E1DB551999651E0E2DC587623438EAC3F310000");
+
+        let field = ContextSpecific::<BitStringRef<'_>>::from_ber(BER_BYTES).unwrap();
+        assert_eq!(field.tag_number.value(), 1);
+        assert_eq!(
+            field.value,
+            BitStringRef::from_bytes(&EXAMPLE_BYTES[5..]).unwrap()
+        );
+
+        assert!(ContextSpecific::<BitStringRef<'_>>::from_der(BER_BYTES).is_err());
+    }
+
     #[test]
     fn context_specific_with_explicit_field() {
         let tag_number = TagNumber(0);
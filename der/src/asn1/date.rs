@@ -0,0 +1,171 @@
+//! ASN.1 `DATE` support.
+
+use crate::{
+    DecodeValue, EncodeValue, Error, ErrorKind, FixedLen, FixedTag, Header, Length, Reader, Result,
+    Tag, Writer, datetime, ord::OrdIsValueOrd,
+};
+use core::fmt;
+
+/// ASN.1 `DATE` type.
+///
+/// Calendar date with a four digit year (X.680 Section 38.4.1), encoded as `YYYYMMDD`.
+///
+/// Years are limited to 1582-9999, i.e. the `Year=Basic` setting of X.680.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Date {
+    /// Full year (e.g. 2000).
+    year: u16,
+
+    /// Month (1-12).
+    month: u8,
+
+    /// Day of the month (1-31).
+    day: u8,
+}
+
+impl Date {
+    /// Length of an ASN.1 DER-encoded [`Date`].
+    pub const LENGTH: usize = 8;
+
+    /// Minimum year that can be represented as a `DATE`.
+    pub const MIN_YEAR: u16 = 1582;
+
+    /// Maximum year that can be represented as a `DATE`.
+    pub const MAX_YEAR: u16 = 9999;
+
+    /// Create a new [`Date`] from the given components.
+    pub const fn new(year: u16, month: u8, day: u8) -> Result<Self> {
+        if year < Self::MIN_YEAR
+            || year > Self::MAX_YEAR
+            || month < 1
+            || month > 12
+            || day < 1
+            || day > days_in_month(year, month)
+        {
+            return Err(Error::from_kind(ErrorKind::DateTime));
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Get the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Get the month.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Get the day of the month.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Decode the `YYYYMMDD` digits of a date which is part of a value with the given tag.
+    pub(crate) fn decode_digits(tag: Tag, bytes: [u8; Self::LENGTH]) -> Result<Self> {
+        let [year1, year2, year3, year4, mon1, mon2, day1, day2] = bytes;
+        let century = u16::from(datetime::decode_decimal(tag, year1, year2)?);
+        let year = u16::from(datetime::decode_decimal(tag, year3, year4)?);
+        let month = datetime::decode_decimal(tag, mon1, mon2)?;
+        let day = datetime::decode_decimal(tag, day1, day2)?;
+
+        // The decimals are at most 99, so this can't overflow
+        #[allow(clippy::arithmetic_side_effects)]
+        Self::new(century * 100 + year, month, day).map_err(|_| tag.value_error())
+    }
+
+    /// Encode the `YYYYMMDD` digits of a date which is part of a value with the given tag.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn encode_digits(&self, tag: Tag, writer: &mut impl Writer) -> Result<()> {
+        datetime::encode_decimal(writer, tag, (self.year / 100) as u8)?;
+        datetime::encode_decimal(writer, tag, (self.year % 100) as u8)?;
+        datetime::encode_decimal(writer, tag, self.month)?;
+        datetime::encode_decimal(writer, tag, self.day)
+    }
+}
+
+impl_any_conversions!(Date);
+
+impl<'a> DecodeValue<'a> for Date {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        if Self::LENGTH != usize::try_from(header.length)? {
+            return Err(Self::TAG.length_error());
+        }
+
+        let mut bytes = [0u8; Self::LENGTH];
+        reader.read_into(&mut bytes)?;
+        Self::decode_digits(Self::TAG, bytes)
+    }
+}
+
+impl EncodeValue for Date {
+    fn value_len(&self) -> Result<Length> {
+        Self::LENGTH.try_into()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.encode_digits(Self::TAG, writer)
+    }
+}
+
+impl FixedTag for Date {
+    const TAG: Tag = Tag::Date;
+}
+
+impl FixedLen for Date {
+    #[allow(clippy::cast_possible_truncation)]
+    const ENCODED_LEN: Length = Length::new(Self::LENGTH as u16).for_tlv_const(Self::TAG);
+}
+
+impl OrdIsValueOrd for Date {}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Number of days in the given month of the given year of the Gregorian calendar.
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::Date;
+    use crate::{Decode, Encode, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let example_bytes = hex!("1F1F 08 32 30 32 34 30 32 32 39");
+        let date = Date::from_der(&example_bytes).unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+
+        let mut buf = [0u8; 11];
+        assert_eq!(date.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn invalid_dates() {
+        assert!(Date::new(2023, 2, 29).is_err());
+        assert!(Date::new(1581, 12, 31).is_err());
+        assert!(Date::new(2000, 13, 1).is_err());
+
+        let err = Date::from_der(&hex!("1F1F 08 32 30 32 33 30 32 32 39")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Date });
+
+        let err = Date::from_der(&hex!("1F1F 06 32 30 32 33 30 32")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Length { tag: Tag::Date });
+    }
+}
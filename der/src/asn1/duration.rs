@@ -0,0 +1,383 @@
+//! ASN.1 `DURATION` support.
+
+use crate::{
+    DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result, Tag, Writer,
+    datetime, ord::OrdIsValueOrd,
+};
+use core::{fmt, str};
+
+/// Maximum length of the encoding of a [`Duration`]: every component at its largest, along
+/// with the designators and the fraction of a second.
+const MAX_LENGTH: usize = 1 + 4 * 11 + 1 + 3 * 11 + 10;
+
+/// ASN.1 `DURATION` type.
+///
+/// Duration expressed in calendar components (X.680 Section 38.4.4), encoded in the ISO 8601
+/// format, e.g. `P1Y2M3DT4H5M6.5S` or `P2W`.
+///
+/// Components which are zero are omitted when encoding. ISO 8601 permits a fraction on the last
+/// component, but this type only supports a fraction of a second, i.e. [`Duration::nanoseconds`].
+///
+/// As in ISO 8601, weeks can't be combined with other components.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Duration {
+    /// Years.
+    pub years: u32,
+
+    /// Months.
+    pub months: u32,
+
+    /// Weeks.
+    pub weeks: u32,
+
+    /// Days.
+    pub days: u32,
+
+    /// Hours.
+    pub hours: u32,
+
+    /// Minutes.
+    pub minutes: u32,
+
+    /// Seconds.
+    pub seconds: u32,
+
+    /// Fraction of a second in nanoseconds (0-999,999,999).
+    pub nanoseconds: u32,
+}
+
+impl Duration {
+    /// Parse a [`Duration`] from its ISO 8601 encoding.
+    fn parse(mut bytes: &[u8]) -> Result<Self> {
+        let err = || Self::TAG.value_error();
+
+        bytes = bytes.strip_prefix(b"P").ok_or_else(err)?;
+
+        let mut duration = Self::default();
+        let mut time = false;
+        let mut last = None;
+
+        while let Some((&byte, rest)) = bytes.split_first() {
+            if byte == b'T' {
+                if time || rest.is_empty() {
+                    return Err(err());
+                }
+
+                time = true;
+                bytes = rest;
+                continue;
+            }
+
+            let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+
+            let value = str::from_utf8(&bytes[..digits])
+                .ok()
+                .and_then(|digits| digits.parse::<u32>().ok())
+                .ok_or_else(err)?;
+
+            bytes = &bytes[digits..];
+
+            let nanoseconds = match bytes {
+                [b'.' | b',', rest @ ..] => {
+                    let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+                    bytes = &rest[digits..];
+                    Some(datetime::decode_nanoseconds(&rest[..digits]).ok_or_else(err)?)
+                }
+                _ => None,
+            };
+
+            let (&designator, rest) = bytes.split_first().ok_or_else(err)?;
+            bytes = rest;
+
+            // Components must appear in order, and only seconds may have a fraction
+            let component = match (time, designator) {
+                (false, b'Y') => &mut duration.years,
+                (false, b'M') => &mut duration.months,
+                (false, b'W') => &mut duration.weeks,
+                (false, b'D') => &mut duration.days,
+                (true, b'H') => &mut duration.hours,
+                (true, b'M') => &mut duration.minutes,
+                (true, b'S') => &mut duration.seconds,
+                _ => return Err(err()),
+            };
+
+            let position = (time, designator_position(designator));
+
+            if last.is_some_and(|last| last >= position)
+                || (nanoseconds.is_some() && designator != b'S')
+            {
+                return Err(err());
+            }
+
+            *component = value;
+            duration.nanoseconds = nanoseconds.unwrap_or(0);
+            last = Some(position);
+        }
+
+        if last.is_none() {
+            return Err(err());
+        }
+
+        duration.validate()?;
+        Ok(duration)
+    }
+
+    /// Check that the components can be encoded.
+    fn validate(&self) -> Result<()> {
+        let others = [
+            self.years,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.nanoseconds,
+        ];
+
+        if self.nanoseconds >= 1_000_000_000 || (self.weeks != 0 && others.iter().any(|&c| c != 0))
+        {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(())
+    }
+
+    /// Encode this [`Duration`] in the ISO 8601 format, returning a buffer along with the length
+    /// of its used prefix.
+    fn encode_iso8601(&self) -> Result<([u8; MAX_LENGTH], usize)> {
+        self.validate()?;
+
+        let mut buf = Buffer {
+            bytes: [0; MAX_LENGTH],
+            len: 0,
+        };
+
+        buf.push(b'P');
+
+        if self.weeks != 0 {
+            buf.push_component(self.weeks, b'W');
+            return Ok((buf.bytes, buf.len));
+        }
+
+        buf.push_component(self.years, b'Y');
+        buf.push_component(self.months, b'M');
+        buf.push_component(self.days, b'D');
+
+        if self.hours != 0 || self.minutes != 0 || self.seconds != 0 || self.nanoseconds != 0 {
+            buf.push(b'T');
+            buf.push_component(self.hours, b'H');
+            buf.push_component(self.minutes, b'M');
+
+            if self.seconds != 0 || self.nanoseconds != 0 {
+                buf.push_number(self.seconds);
+
+                if self.nanoseconds != 0 {
+                    let (digits, len) = datetime::encode_nanoseconds(self.nanoseconds);
+                    buf.push(b'.');
+                    digits[..len].iter().for_each(|&digit| buf.push(digit));
+                }
+
+                buf.push(b'S');
+            }
+        } else if buf.len == 1 {
+            // Zero duration
+            buf.push_number(0);
+            buf.push(b'D');
+        }
+
+        Ok((buf.bytes, buf.len))
+    }
+}
+
+impl_any_conversions!(Duration);
+
+impl<'a> DecodeValue<'a> for Duration {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let mut bytes = [0u8; MAX_LENGTH];
+
+        let bytes = bytes
+            .get_mut(..usize::try_from(header.length)?)
+            .ok_or_else(|| Self::TAG.length_error())?;
+
+        Self::parse(reader.read_into(bytes)?)
+    }
+}
+
+impl EncodeValue for Duration {
+    fn value_len(&self) -> Result<Length> {
+        self.encode_iso8601()?.1.try_into()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        let (bytes, len) = self.encode_iso8601()?;
+        writer.write(&bytes[..len])
+    }
+}
+
+impl FixedTag for Duration {
+    const TAG: Tag = Tag::Duration;
+}
+
+impl OrdIsValueOrd for Duration {}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (bytes, len) = self.encode_iso8601().map_err(|_| fmt::Error)?;
+        f.write_str(str::from_utf8(&bytes[..len]).map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Position of a designator among the components of the date or time part of a duration.
+fn designator_position(designator: u8) -> u8 {
+    match designator {
+        b'Y' | b'H' => 0,
+        b'M' => 1,
+        b'W' => 2,
+        _ => 3,
+    }
+}
+
+/// Buffer for the ISO 8601 encoding of a [`Duration`].
+struct Buffer {
+    bytes: [u8; MAX_LENGTH],
+    len: usize,
+}
+
+impl Buffer {
+    // `MAX_LENGTH` leaves room for every component
+    #[allow(clippy::arithmetic_side_effects)]
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn push_number(&mut self, number: u32) {
+        let mut digits = [0u8; 10];
+        let mut n = number;
+        let mut len = 0;
+
+        loop {
+            digits[len] = b'0' + (n % 10) as u8;
+            n /= 10;
+            len += 1;
+
+            if n == 0 {
+                break;
+            }
+        }
+
+        digits[..len]
+            .iter()
+            .rev()
+            .for_each(|&digit| self.push(digit));
+    }
+
+    /// Push a component unless it's zero.
+    fn push_component(&mut self, value: u32, designator: u8) {
+        if value != 0 {
+            self.push_number(value);
+            self.push(designator);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::Duration;
+    use crate::{Decode, Encode, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        // P1Y2M3DT4H5M6.5S
+        let example_bytes = hex!("1F22 10 50 31 59 32 4D 33 44 54 34 48 35 4D 36 2E 35 53");
+        let duration = Duration::from_der(&example_bytes).unwrap();
+
+        assert_eq!(
+            duration,
+            Duration {
+                years: 1,
+                months: 2,
+                days: 3,
+                hours: 4,
+                minutes: 5,
+                seconds: 6,
+                nanoseconds: 500_000_000,
+                ..Default::default()
+            }
+        );
+
+        let mut buf = [0u8; 32];
+        assert_eq!(duration.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn encode() {
+        let mut buf = [0u8; 32];
+
+        for (duration, expected) in [
+            (Duration::default(), &b"P0D"[..]),
+            (
+                Duration {
+                    weeks: 2,
+                    ..Default::default()
+                },
+                b"P2W",
+            ),
+            (
+                Duration {
+                    minutes: 90,
+                    ..Default::default()
+                },
+                b"PT90M",
+            ),
+            (
+                Duration {
+                    days: 1,
+                    nanoseconds: 1_000,
+                    ..Default::default()
+                },
+                b"P1DT0.000001S",
+            ),
+        ] {
+            let encoded = duration.encode_to_slice(&mut buf).unwrap();
+            assert_eq!(&encoded[3..], expected);
+            assert_eq!(Duration::from_der(encoded).unwrap(), duration);
+        }
+
+        let invalid = Duration {
+            weeks: 1,
+            days: 1,
+            ..Default::default()
+        };
+        assert!(invalid.encode_to_slice(&mut buf).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn decode_errors() {
+        for invalid in [
+            &b"P"[..],
+            b"1D",
+            b"PT",
+            b"P1DT",
+            b"P1D2Y",
+            b"P1.5D",
+            b"PT1S2M",
+            b"P1W1D",
+            b"P1H",
+            b"PT1D",
+            b"PD",
+        ] {
+            let mut der = [0x1F, 0x22, invalid.len() as u8, 0, 0, 0, 0, 0, 0];
+            der[3..][..invalid.len()].copy_from_slice(invalid);
+
+            let err = Duration::from_der(&der[..3 + invalid.len()]).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Value { tag: Tag::Duration });
+        }
+    }
+}
@@ -25,6 +25,18 @@ use time::PrimitiveDateTime;
 /// > (i.e., times are `YYYYMMDDHHMMSSZ`), even where the number of seconds
 /// > is zero.  GeneralizedTime values MUST NOT include fractional seconds.
 ///
+/// Values with a fraction of a second, e.g. those created from a [`DateTime`] with
+/// [`DateTime::with_nanoseconds`], are encoded with fractional seconds as described in
+/// X.690 Section 11.7, i.e. `YYYYMMDDHHMMSS.FFFZ` without trailing zeros, as used by e.g.
+/// RFC 3161 and OCSP. Such values are accepted when decoding DER, so profiles which forbid
+/// them, like RFC 5280's, need to reject them separately, e.g. by checking
+/// [`DateTime::nanoseconds`].
+///
+/// When decoding BER, the forms described in X.680 Section 46 are also accepted: minutes and
+/// seconds may be omitted, the fraction may apply to the hour or minute and use `,` as decimal
+/// mark, and the time may be local time, which is treated as UTC, or have a `+HHMM` or
+/// `-HHMM` offset from UTC, which is applied.
+///
 /// [1]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5.2
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -34,6 +46,9 @@ impl GeneralizedTime {
     /// Length of an RFC 5280-flavored ASN.1 DER-encoded [`GeneralizedTime`].
     const LENGTH: usize = 15;
 
+    /// Maximum length of a BER-encoded [`GeneralizedTime`] accepted when decoding.
+    const MAX_LENGTH: usize = 64;
+
     /// Create a [`GeneralizedTime`] from a [`DateTime`].
     pub const fn from_date_time(datetime: DateTime) -> Self {
         Self(datetime)
@@ -45,7 +60,7 @@ impl GeneralizedTime {
     }
 
     /// Create a new [`GeneralizedTime`] given a [`Duration`] since `UNIX_EPOCH`
-    /// (a.k.a. "Unix time")
+    /// (a.k.a. "Unix time"), discarding the fraction of a second.
     pub fn from_unix_duration(unix_duration: Duration) -> Result<Self> {
         DateTime::from_unix_duration(unix_duration)
             .map(Into::into)
@@ -57,7 +72,7 @@ impl GeneralizedTime {
         self.0.unix_duration()
     }
 
-    /// Instantiate from [`SystemTime`].
+    /// Instantiate from [`SystemTime`], discarding the fraction of a second.
     #[cfg(feature = "std")]
     pub fn from_system_time(time: SystemTime) -> Result<Self> {
         DateTime::try_from(time)
@@ -78,15 +93,23 @@ impl<'a> DecodeValue<'a> for GeneralizedTime {
     type Error = Error;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        if Self::LENGTH != usize::try_from(header.length)? {
+        let len = usize::try_from(header.length)?;
+
+        if len > Self::MAX_LENGTH {
             return Err(Self::TAG.value_error());
         }
 
-        let mut bytes = [0u8; Self::LENGTH];
-        reader.read_into(&mut bytes)?;
+        let encoding_rules = reader.encoding_rules();
+        let mut buffer = [0u8; Self::MAX_LENGTH];
+        let bytes = reader.read_into(&mut buffer[..len])?;
+
+        if encoding_rules.is_ber() {
+            return decode_ber(bytes).map(Self);
+        }
 
-        match bytes {
-            // RFC 5280 requires mandatory seconds and Z-normalized time zone
+        match *bytes {
+            // RFC 5280 requires mandatory seconds and Z-normalized time zone, while DER also
+            // allows fractional seconds without trailing zeros
             [
                 y1,
                 y2,
@@ -102,8 +125,16 @@ impl<'a> DecodeValue<'a> for GeneralizedTime {
                 min2,
                 sec1,
                 sec2,
-                b'Z',
+                ref zone @ ..,
             ] => {
+                let nanoseconds = match zone {
+                    [b'Z'] => 0,
+                    [b'.', digits @ .., b'Z'] if digits.last() != Some(&b'0') => {
+                        datetime::decode_nanoseconds(digits).ok_or(Self::TAG.value_error())?
+                    }
+                    _ => return Err(Self::TAG.value_error()),
+                };
+
                 let year = u16::from(datetime::decode_decimal(Self::TAG, y1, y2)?)
                     .checked_mul(100)
                     .and_then(|y| {
@@ -116,18 +147,123 @@ impl<'a> DecodeValue<'a> for GeneralizedTime {
                 let minute = datetime::decode_decimal(Self::TAG, min1, min2)?;
                 let second = datetime::decode_decimal(Self::TAG, sec1, sec2)?;
 
-                DateTime::new(year, month, day, hour, minute, second)
+                DateTime::new_with_nanoseconds(year, month, day, hour, minute, second, nanoseconds)
+                    .map(Self)
                     .map_err(|_| Self::TAG.value_error())
-                    .and_then(|dt| Self::from_unix_duration(dt.unix_duration()))
             }
             _ => Err(Self::TAG.value_error()),
         }
     }
 }
 
+/// Decode any of the forms of `GeneralizedTime` allowed by BER (X.680 Section 46), converting
+/// it to UTC.
+fn decode_ber(bytes: &[u8]) -> Result<DateTime> {
+    let tag = GeneralizedTime::TAG;
+    let decimal_at = |pos: usize| match bytes.get(pos..pos.saturating_add(2)) {
+        Some(&[hi, lo]) => datetime::decode_decimal(tag, hi, lo),
+        _ => Err(tag.value_error()),
+    };
+    let is_digit_at = |pos: usize| bytes.get(pos).is_some_and(u8::is_ascii_digit);
+
+    let year = u16::from(decimal_at(0)?) * 100 + u16::from(decimal_at(2)?);
+    let month = decimal_at(4)?;
+    let day = decimal_at(6)?;
+    let hour = decimal_at(8)?;
+    let mut minute = 0;
+    let mut second = 0;
+    let mut pos = 10;
+
+    // Number of seconds in the smallest unit present, to which a fraction applies
+    let mut unit = 3600;
+
+    if is_digit_at(pos) {
+        minute = decimal_at(pos)?;
+        pos += 2;
+        unit = 60;
+
+        if is_digit_at(pos) {
+            second = decimal_at(pos)?;
+            pos += 2;
+            unit = 1;
+        }
+    }
+
+    let mut duration = DateTime::new(year, month, day, hour, minute, second)
+        .map_err(|_| tag.value_error())?
+        .unix_duration();
+
+    if matches!(bytes.get(pos), Some(b'.' | b',')) {
+        let digits = bytes[pos + 1..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+        if digits == 0 {
+            return Err(tag.value_error());
+        }
+
+        duration += fraction(unit, &bytes[pos + 1..pos + 1 + digits])?;
+        pos += 1 + digits;
+    }
+
+    duration = match bytes[pos..] {
+        // UTC, or local time which is treated as UTC
+        [] | [b'Z'] => duration,
+        [sign @ (b'+' | b'-'), h1, h2, ref minutes @ ..] => {
+            let hours = datetime::decode_decimal(tag, h1, h2)?;
+            let minutes = match *minutes {
+                [] => 0,
+                [m1, m2] => datetime::decode_decimal(tag, m1, m2)?,
+                _ => return Err(tag.value_error()),
+            };
+
+            if hours > 23 || minutes > 59 {
+                return Err(tag.value_error());
+            }
+
+            let offset = Duration::from_secs(u64::from(hours) * 3600 + u64::from(minutes) * 60);
+
+            // Local time is ahead of UTC when the offset is positive
+            if sign == b'+' {
+                duration.checked_sub(offset)
+            } else {
+                duration.checked_add(offset)
+            }
+            .ok_or(tag.value_error())?
+        }
+        _ => return Err(tag.value_error()),
+    };
+
+    DateTime::from_unix_duration(duration)
+        .and_then(|datetime| datetime.with_nanoseconds(duration.subsec_nanos()))
+        .map_err(|_| tag.value_error())
+}
+
+/// Compute the fraction of a unit of the given number of seconds represented by decimal digits.
+///
+/// Digits beyond the precision of a nanosecond are ignored.
+fn fraction(unit: u64, digits: &[u8]) -> Result<Duration> {
+    let (numerator, denominator) =
+        digits
+            .iter()
+            .take(18)
+            .fold((0u128, 1u128), |(numerator, denominator), digit| {
+                (numerator * 10 + u128::from(digit - b'0'), denominator * 10)
+            });
+
+    let nanoseconds = numerator * u128::from(unit) * 1_000_000_000 / denominator;
+    Ok(Duration::from_nanos(u64::try_from(nanoseconds)?))
+}
+
 impl EncodeValue for GeneralizedTime {
     fn value_len(&self) -> Result<Length> {
-        Self::LENGTH.try_into()
+        let fraction_len = match datetime::encode_nanoseconds(self.0.nanoseconds()).1 {
+            0 => 0,
+            len => len + 1,
+        };
+
+        (Self::LENGTH + fraction_len).try_into()
     }
 
     fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
@@ -141,6 +277,15 @@ impl EncodeValue for GeneralizedTime {
         datetime::encode_decimal(writer, Self::TAG, self.0.hour())?;
         datetime::encode_decimal(writer, Self::TAG, self.0.minutes())?;
         datetime::encode_decimal(writer, Self::TAG, self.0.seconds())?;
+
+        // X.690 Section 11.7: fractional seconds without trailing zeros
+        let (digits, len) = datetime::encode_nanoseconds(self.0.nanoseconds());
+
+        if len != 0 {
+            writer.write_byte(b'.')?;
+            writer.write(&digits[..len])?;
+        }
+
         writer.write_byte(b'Z')
     }
 }
@@ -334,6 +479,7 @@ impl TryFrom<GeneralizedTime> for PrimitiveDateTime {
 mod tests {
     use super::GeneralizedTime;
     use crate::{Decode, Encode, SliceWriter};
+    use core::time::Duration;
     use hex_literal::hex;
 
     #[test]
@@ -395,4 +541,57 @@ mod tests {
         let example_bytes = "\x18\x0f99991231235960Z".as_bytes();
         assert!(GeneralizedTime::from_der(example_bytes).is_err());
     }
+
+    #[test]
+    fn fractional_seconds() {
+        let example_bytes = "\x18\x1220230607112626.12Z".as_bytes();
+        let time = GeneralizedTime::from_der(example_bytes).unwrap();
+        assert_eq!(
+            time.to_unix_duration(),
+            Duration::new(1686137186, 120_000_000)
+        );
+        let mut buf = [0u8; 32];
+        assert_eq!(time.encode_to_slice(&mut buf).unwrap(), example_bytes);
+
+        // DER requires trailing zeros to be stripped, and at least one digit
+        for example in ["\x18\x1320230607112626.120Z", "\x18\x1020230607112626.Z"] {
+            assert!(GeneralizedTime::from_der(example.as_bytes()).is_err());
+        }
+
+        // A whole number of seconds has no fraction
+        let time =
+            GeneralizedTime::from_date_time(time.to_date_time().with_nanoseconds(0).unwrap());
+        assert_eq!(
+            time.encode_to_slice(&mut buf).unwrap(),
+            b"\x18\x0f20230607112626Z"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ber_forms() {
+        let expected = Duration::new(1686137186, 120_000_000);
+
+        for example in [
+            "20230607112626.120Z",
+            "20230607112626,12",
+            "20230607132626.12+0200",
+            "20230607082626.12-03",
+            "202306071126.43533333333",
+            "2023060711.44058888888889",
+        ] {
+            let mut bytes = [0x18, u8::try_from(example.len()).unwrap()].to_vec();
+            bytes.extend_from_slice(example.as_bytes());
+
+            let time = GeneralizedTime::from_ber(&bytes).unwrap();
+            let delta = time.to_unix_duration().abs_diff(expected);
+            assert!(delta < Duration::from_micros(1), "{example}: {time:?}");
+        }
+
+        for example in ["20230607112626.Z", "20230607112626+2400", "2023060711262Z"] {
+            let mut bytes = [0x18, u8::try_from(example.len()).unwrap()].to_vec();
+            bytes.extend_from_slice(example.as_bytes());
+            assert!(GeneralizedTime::from_ber(&bytes).is_err(), "{example}");
+        }
+    }
 }
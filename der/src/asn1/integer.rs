@@ -5,7 +5,7 @@ pub(super) mod uint;
 
 use core::{cmp::Ordering, mem::size_of};
 
-use crate::{EncodeValue, Result, SliceWriter};
+use crate::{DecodeQuirk, EncodeValue, Header, Reader, Result, SliceWriter, Tag};
 
 /// Is the highest bit of the first byte in the slice set to `1`? (if present)
 #[inline]
@@ -16,6 +16,85 @@ fn is_highest_bit_set(bytes: &[u8]) -> bool {
         .unwrap_or(false)
 }
 
+/// Read the contents of a primitive `INTEGER` into `buf`.
+///
+/// If the reader tolerates [`DecodeQuirk::NonMinimalInteger`], redundant leading octets are
+/// skipped, so that values which don't fit in `buf` only because of them can still be decoded.
+fn read_primitive<'a, 'b, R: Reader<'a>>(
+    reader: &mut R,
+    header: Header,
+    buf: &'b mut [u8],
+) -> Result<&'b [u8]> {
+    let mut len = usize::try_from(header.length)?;
+
+    if len == 0 {
+        return Err(Tag::Integer.length_error());
+    }
+
+    if len <= buf.len() {
+        let bytes = reader.read_into(&mut buf[..len])?;
+        return Ok(strip_redundant(reader, bytes));
+    }
+
+    if !reader.allow_quirk(DecodeQuirk::NonMinimalInteger) {
+        return Err(Tag::Integer.non_canonical_error());
+    }
+
+    let padding = reader.read_byte()?;
+    len = len.saturating_sub(1);
+
+    while len > buf.len() {
+        if reader.read_byte()? != padding {
+            return Err(Tag::Integer.non_canonical_error());
+        }
+
+        len = len.saturating_sub(1);
+    }
+
+    let bytes = reader.read_into(&mut buf[..len])?;
+
+    // The skipped octets must all be sign extension of the value which remains
+    let sign = if is_highest_bit_set(bytes) {
+        0xFF
+    } else {
+        0x00
+    };
+
+    if padding != sign {
+        return Err(Tag::Integer.non_canonical_error());
+    }
+
+    // The quirk has already been allowed, and is only recorded once per value
+    Ok(without_redundant(bytes))
+}
+
+/// Strip redundant leading `0x00` or `0xFF` octets from the contents of an `INTEGER`, if the
+/// reader tolerates [`DecodeQuirk::NonMinimalInteger`].
+///
+/// Otherwise the contents are returned as-is, and decoders reject them as non-canonical.
+fn strip_redundant<'a, 'b, R: Reader<'a>>(reader: &mut R, bytes: &'b [u8]) -> &'b [u8] {
+    let stripped = without_redundant(bytes);
+
+    if stripped.len() != bytes.len() && reader.allow_quirk(DecodeQuirk::NonMinimalInteger) {
+        stripped
+    } else {
+        bytes
+    }
+}
+
+/// Get the contents of an `INTEGER` without any redundant leading `0x00` or `0xFF` octets.
+fn without_redundant(mut bytes: &[u8]) -> &[u8] {
+    while let [first @ (0x00 | 0xFF), second, ..] = bytes {
+        if (*first == 0xFF) != (*second >= 0x80) {
+            break;
+        }
+
+        bytes = &bytes[1..];
+    }
+
+    bytes
+}
+
 /// Compare two integer values
 fn value_cmp<T>(a: T, b: T) -> Result<Ordering>
 where
@@ -1,6 +1,6 @@
 //! Support for encoding signed integers
 
-use super::{is_highest_bit_set, uint, value_cmp};
+use super::{is_highest_bit_set, read_primitive, strip_redundant, uint, value_cmp};
 use crate::{
     AnyRef, BytesRef, DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader,
     Result, Tag, ValueOrd, Writer, ord::OrdIsValueOrd,
@@ -18,17 +18,7 @@ macro_rules! impl_encoding_traits {
 
                 fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> $crate::Result<Self> {
                     let mut buf = [0u8; Self::BITS as usize / 8];
-                    let max_length = u32::from(header.length) as usize;
-
-                    if max_length == 0 {
-                        return Err(Tag::Integer.length_error());
-                    }
-
-                    if max_length > buf.len() {
-                        return Err(Self::TAG.non_canonical_error());
-                    }
-
-                    let bytes = reader.read_into(&mut buf[..max_length])?;
+                    let bytes = read_primitive(reader, header, &mut buf)?;
 
                     // We actually want the conversion to overflow here
                     #[allow(clippy::cast_possible_wrap)]
@@ -39,7 +29,7 @@ macro_rules! impl_encoding_traits {
                     };
 
                     // Ensure we compute the same encoded length as the original any value
-                    if header.length != result.value_len()? {
+                    if Length::try_from(bytes.len())? != result.value_len()? {
                         return Err(Self::TAG.non_canonical_error());
                     }
 
@@ -137,13 +127,14 @@ impl<'a> DecodeValue<'a> for IntRef<'a> {
     type Error = Error;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        let bytes = BytesRef::decode_value(reader, header)?;
-        validate_canonical(bytes.as_slice())?;
+        let bytes = BytesRef::decode_value(reader, header)?.as_slice();
+        let bytes = strip_redundant(reader, bytes);
+        validate_canonical(bytes)?;
 
-        let result = Self::new(bytes.as_slice())?;
+        let result = Self::new(bytes)?;
 
         // Ensure we compute the same encoded length as the original any value.
-        if result.value_len()? != header.length {
+        if result.value_len()? != Length::try_from(bytes.len())? {
             return Err(Self::TAG.non_canonical_error());
         }
 
@@ -176,7 +167,7 @@ impl OrdIsValueOrd for IntRef<'_> {}
 
 #[cfg(feature = "alloc")]
 mod allocating {
-    use super::{IntRef, strip_leading_ones, validate_canonical};
+    use super::{IntRef, strip_leading_ones, strip_redundant, validate_canonical};
     use crate::{
         BytesOwned, DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader,
         Result, Tag, Writer,
@@ -232,12 +223,13 @@ mod allocating {
 
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
             let bytes = BytesOwned::decode_value(reader, header)?;
-            validate_canonical(bytes.as_slice())?;
+            let bytes = strip_redundant(reader, bytes.as_slice());
+            validate_canonical(bytes)?;
 
-            let result = Self::new(bytes.as_slice())?;
+            let result = Self::new(bytes)?;
 
             // Ensure we compute the same encoded length as the original any value.
-            if result.value_len()? != header.length {
+            if result.value_len()? != Length::try_from(bytes.len())? {
                 return Err(Self::TAG.non_canonical_error());
             }
 
@@ -1,6 +1,6 @@
 //! Unsigned integer decoders/encoders.
 
-use super::value_cmp;
+use super::{read_primitive, strip_redundant, value_cmp};
 use crate::{
     AnyRef, BytesRef, DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader,
     Result, Tag, ValueOrd, Writer, ord::OrdIsValueOrd,
@@ -22,21 +22,11 @@ macro_rules! impl_encoding_traits {
                     const UNSIGNED_HEADROOM: usize = 1;
 
                     let mut buf = [0u8; (Self::BITS as usize / 8) + UNSIGNED_HEADROOM];
-                    let max_length = u32::from(header.length) as usize;
-
-                    if max_length == 0 {
-                        return Err(Tag::Integer.length_error());
-                    }
-
-                    if max_length > buf.len() {
-                        return Err(Self::TAG.non_canonical_error());
-                    }
-
-                    let bytes = reader.read_into(&mut buf[..max_length])?;
+                    let bytes = read_primitive(reader, header, &mut buf)?;
                     let result = Self::from_be_bytes(decode_to_array(bytes)?);
 
                     // Ensure we compute the same encoded length as the original any value
-                    if header.length != result.value_len()? {
+                    if Length::try_from(bytes.len())? != result.value_len()? {
                         return Err(Self::TAG.non_canonical_error());
                     }
 
@@ -123,10 +113,11 @@ impl<'a> DecodeValue<'a> for UintRef<'a> {
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
         let bytes = BytesRef::decode_value(reader, header)?.as_slice();
+        let bytes = strip_redundant(reader, bytes);
         let result = Self::new(decode_to_slice(bytes)?)?;
 
         // Ensure we compute the same encoded length as the original any value.
-        if result.value_len()? != header.length {
+        if result.value_len()? != Length::try_from(bytes.len())? {
             return Err(Self::TAG.non_canonical_error());
         }
 
@@ -163,7 +154,7 @@ impl OrdIsValueOrd for UintRef<'_> {}
 
 #[cfg(feature = "alloc")]
 mod allocating {
-    use super::{UintRef, decode_to_slice, encoded_len, strip_leading_zeroes};
+    use super::{UintRef, decode_to_slice, encoded_len, strip_leading_zeroes, strip_redundant};
     use crate::{
         BytesOwned, DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader,
         Result, Tag, Writer,
@@ -217,10 +208,11 @@ mod allocating {
 
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
             let bytes = BytesOwned::decode_value(reader, header)?;
-            let result = Self::new(decode_to_slice(bytes.as_slice())?)?;
+            let bytes = strip_redundant(reader, bytes.as_slice());
+            let result = Self::new(decode_to_slice(bytes)?)?;
 
             // Ensure we compute the same encoded length as the original any value.
-            if result.value_len()? != header.length {
+            if result.value_len()? != Length::try_from(bytes.len())? {
                 return Err(Self::TAG.non_canonical_error());
             }
 
@@ -67,6 +67,12 @@ macro_rules! impl_string_type {
 
             impl<$($li),*> OrdIsValueOrd for $type {}
 
+            impl<$($li),*> $crate::constraint::Size for $type {
+                fn size(&self) -> usize {
+                    self.as_str().chars().count()
+                }
+            }
+
             impl<$($li),*> fmt::Display for $type {
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                     f.write_str(self.as_str())
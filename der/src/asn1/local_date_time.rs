@@ -0,0 +1,160 @@
+//! ASN.1 `DATE-TIME` support.
+
+use super::{Date, TimeOfDay};
+use crate::{
+    DateTime, DecodeValue, EncodeValue, Error, FixedLen, FixedTag, Header, Length, Reader, Result,
+    Tag, Writer, ord::OrdIsValueOrd,
+};
+use core::fmt;
+
+/// ASN.1 `DATE-TIME` type.
+///
+/// Local date and time of day in whole seconds (X.680 Section 38.4.3), encoded as
+/// `YYYYMMDDHHMMSS`.
+///
+/// Unlike `GeneralizedTime` and [`DateTime`], the time has no time zone, hence the name.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct LocalDateTime {
+    /// Date.
+    date: Date,
+
+    /// Time of day.
+    time: TimeOfDay,
+}
+
+impl LocalDateTime {
+    /// Length of an ASN.1 DER-encoded [`LocalDateTime`].
+    pub const LENGTH: usize = Date::LENGTH + TimeOfDay::LENGTH;
+
+    /// Create a new [`LocalDateTime`] from a date and time of day.
+    pub const fn new(date: Date, time: TimeOfDay) -> Self {
+        Self { date, time }
+    }
+
+    /// Get the date.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Get the time of day.
+    pub fn time(&self) -> TimeOfDay {
+        self.time
+    }
+}
+
+impl_any_conversions!(LocalDateTime);
+
+impl<'a> DecodeValue<'a> for LocalDateTime {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        if Self::LENGTH != usize::try_from(header.length)? {
+            return Err(Self::TAG.length_error());
+        }
+
+        let mut date = [0u8; Date::LENGTH];
+        let mut time = [0u8; TimeOfDay::LENGTH];
+        reader.read_into(&mut date)?;
+        reader.read_into(&mut time)?;
+
+        Ok(Self {
+            date: Date::decode_digits(Self::TAG, date)?,
+            time: TimeOfDay::decode_digits(Self::TAG, time)?,
+        })
+    }
+}
+
+impl EncodeValue for LocalDateTime {
+    fn value_len(&self) -> Result<Length> {
+        Self::LENGTH.try_into()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.date.encode_digits(Self::TAG, writer)?;
+        self.time.encode_digits(Self::TAG, writer)
+    }
+}
+
+impl FixedTag for LocalDateTime {
+    const TAG: Tag = Tag::DateTime;
+}
+
+impl FixedLen for LocalDateTime {
+    #[allow(clippy::cast_possible_truncation)]
+    const ENCODED_LEN: Length = Length::new(Self::LENGTH as u16).for_tlv_const(Self::TAG);
+}
+
+impl OrdIsValueOrd for LocalDateTime {}
+
+impl fmt::Display for LocalDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+/// Drops the time zone, failing if there's a fraction of a second.
+impl TryFrom<DateTime> for LocalDateTime {
+    type Error = Error;
+
+    fn try_from(datetime: DateTime) -> Result<Self> {
+        if datetime.nanoseconds() != 0 {
+            return Err(Self::TAG.value_error());
+        }
+
+        Ok(Self {
+            date: Date::new(datetime.year(), datetime.month(), datetime.day())?,
+            time: TimeOfDay::new(datetime.hour(), datetime.minutes(), datetime.seconds())?,
+        })
+    }
+}
+
+/// Interprets the local time as UTC, failing if it's before the Unix epoch.
+impl TryFrom<LocalDateTime> for DateTime {
+    type Error = Error;
+
+    fn try_from(local: LocalDateTime) -> Result<Self> {
+        DateTime::new(
+            local.date.year(),
+            local.date.month(),
+            local.date.day(),
+            local.time.hour(),
+            local.time.minutes(),
+            local.time.seconds(),
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::LocalDateTime;
+    use crate::{
+        DateTime, Decode, Encode,
+        asn1::{Date, TimeOfDay},
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let example_bytes = hex!("1F21 0E 31 39 36 39 30 37 32 30 32 30 31 37 34 30");
+        let local = LocalDateTime::from_der(&example_bytes).unwrap();
+        assert_eq!(local.date(), Date::new(1969, 7, 20).unwrap());
+        assert_eq!(local.time(), TimeOfDay::new(20, 17, 40).unwrap());
+
+        let mut buf = [0u8; 17];
+        assert_eq!(local.encode_to_slice(&mut buf).unwrap(), example_bytes);
+
+        // Before the Unix epoch
+        assert!(DateTime::try_from(local).is_err());
+    }
+
+    #[test]
+    fn date_time_conversions() {
+        let datetime = DateTime::new(2024, 5, 6, 7, 8, 9).unwrap();
+        let local = LocalDateTime::try_from(datetime).unwrap();
+        assert_eq!(DateTime::try_from(local).unwrap(), datetime);
+
+        let datetime = datetime.with_nanoseconds(1).unwrap();
+        assert!(LocalDateTime::try_from(datetime).is_err());
+    }
+}
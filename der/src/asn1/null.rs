@@ -1,8 +1,8 @@
 //! ASN.1 `NULL` support.
 
 use crate::{
-    BytesRef, DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader, Result,
-    Tag, Writer, asn1::AnyRef, ord::OrdIsValueOrd,
+    BytesRef, DecodeValue, EncodeValue, Error, ErrorKind, FixedLen, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer, asn1::AnyRef, ord::OrdIsValueOrd,
 };
 
 /// ASN.1 `NULL` type.
@@ -37,6 +37,10 @@ impl FixedTag for Null {
     const TAG: Tag = Tag::Null;
 }
 
+impl FixedLen for Null {
+    const ENCODED_LEN: Length = Length::ZERO.for_tlv_const(Self::TAG);
+}
+
 impl OrdIsValueOrd for Null {}
 
 impl<'a> From<Null> for AnyRef<'a> {
@@ -82,6 +86,10 @@ impl FixedTag for () {
     const TAG: Tag = Tag::Null;
 }
 
+impl FixedLen for () {
+    const ENCODED_LEN: Length = Length::ZERO.for_tlv_const(Self::TAG);
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -0,0 +1,205 @@
+//! ASN.1 `NumericString` support.
+
+use crate::{FixedTag, Result, StrRef, Tag, asn1::AnyRef};
+use core::{fmt, ops::Deref};
+
+macro_rules! impl_numeric_string {
+    ($type: ty) => {
+        impl_numeric_string!($type,);
+    };
+    ($type: ty, $($li: lifetime)?) => {
+        impl_string_type!($type, $($li),*);
+
+        impl<$($li),*> FixedTag for $type {
+            const TAG: Tag = Tag::NumericString;
+        }
+
+        impl<$($li),*> fmt::Debug for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "NumericString({:?})", self.as_str())
+            }
+        }
+    };
+}
+
+/// ASN.1 `NumericString` type.
+///
+/// Supports the digits `0..9` and "` `" (i.e. space).
+///
+/// For the printable subset of ASCII, use
+/// [`PrintableStringRef`][`crate::asn1::PrintableStringRef`] instead.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct NumericStringRef<'a> {
+    /// Inner value
+    inner: StrRef<'a>,
+}
+
+impl<'a> NumericStringRef<'a> {
+    /// Create a new ASN.1 `NumericString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        // Validate all characters are within NumericString's allowed set
+        if input.iter().any(|&c| !matches!(c, b'0'..=b'9' | b' ')) {
+            return Err(Self::TAG.value_error());
+        }
+
+        StrRef::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+}
+
+impl_numeric_string!(NumericStringRef<'a>, 'a);
+
+impl<'a> Deref for NumericStringRef<'a> {
+    type Target = StrRef<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a> From<&NumericStringRef<'a>> for NumericStringRef<'a> {
+    fn from(value: &NumericStringRef<'a>) -> NumericStringRef<'a> {
+        *value
+    }
+}
+
+impl<'a> From<NumericStringRef<'a>> for AnyRef<'a> {
+    fn from(numeric_string: NumericStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::NumericString, numeric_string.inner.into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use self::allocation::NumericString;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::NumericStringRef;
+
+    use crate::{
+        BytesRef, Error, FixedTag, Result, StrOwned, Tag,
+        asn1::AnyRef,
+        referenced::{OwnedToRef, RefToOwned},
+    };
+    use alloc::string::String;
+    use core::{fmt, ops::Deref};
+
+    /// ASN.1 `NumericString` type.
+    ///
+    /// Supports the digits `0..9` and "` `" (i.e. space).
+    ///
+    /// For the printable subset of ASCII, use
+    /// [`PrintableString`][`crate::asn1::PrintableString`] instead.
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct NumericString {
+        /// Inner value
+        inner: StrOwned,
+    }
+
+    impl NumericString {
+        /// Create a new ASN.1 `NumericString`.
+        pub fn new<T>(input: &T) -> Result<Self>
+        where
+            T: AsRef<[u8]> + ?Sized,
+        {
+            let input = input.as_ref();
+            NumericStringRef::new(input)?;
+
+            StrOwned::from_bytes(input)
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.value_error())
+        }
+    }
+
+    impl_numeric_string!(NumericString);
+
+    impl Deref for NumericString {
+        type Target = StrOwned;
+
+        fn deref(&self) -> &Self::Target {
+            &self.inner
+        }
+    }
+
+    impl<'a> From<NumericStringRef<'a>> for NumericString {
+        fn from(value: NumericStringRef<'a>) -> NumericString {
+            let inner =
+                StrOwned::from_bytes(value.inner.as_bytes()).expect("Invalid NumericString");
+            Self { inner }
+        }
+    }
+
+    impl<'a> From<&'a NumericString> for AnyRef<'a> {
+        fn from(numeric_string: &'a NumericString) -> AnyRef<'a> {
+            AnyRef::from_tag_and_value(
+                Tag::NumericString,
+                BytesRef::new(numeric_string.inner.as_bytes()).expect("Invalid NumericString"),
+            )
+        }
+    }
+
+    impl<'a> From<&'a NumericString> for NumericStringRef<'a> {
+        fn from(numeric_string: &'a NumericString) -> NumericStringRef<'a> {
+            numeric_string.owned_to_ref()
+        }
+    }
+
+    impl<'a> RefToOwned<'a> for NumericStringRef<'a> {
+        type Owned = NumericString;
+        fn ref_to_owned(&self) -> Self::Owned {
+            NumericString {
+                inner: self.inner.ref_to_owned(),
+            }
+        }
+    }
+
+    impl OwnedToRef for NumericString {
+        type Borrowed<'a> = NumericStringRef<'a>;
+        fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+            NumericStringRef {
+                inner: self.inner.owned_to_ref(),
+            }
+        }
+    }
+
+    impl TryFrom<String> for NumericString {
+        type Error = Error;
+
+        fn try_from(input: String) -> Result<Self> {
+            NumericStringRef::new(&input)?;
+
+            StrOwned::new(input)
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.value_error())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::NumericStringRef;
+    use crate::{Decode, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        let numeric_string =
+            NumericStringRef::from_der(&hex!("12 07 31 32 33 20 34 35 36")).unwrap();
+        assert_eq!(numeric_string.as_str(), "123 456");
+    }
+
+    #[test]
+    fn reject_invalid() {
+        let err = NumericStringRef::from_der(&hex!("12 03 31 2D 32")).unwrap_err();
+        assert_eq!(err.kind(), Tag::NumericString.value_error().kind());
+    }
+}
@@ -1,8 +1,8 @@
 //! ASN.1 `OCTET STRING` support.
 
 use crate::{
-    BytesRef, Decode, DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader,
-    Tag, Writer, asn1::AnyRef, ord::OrdIsValueOrd,
+    BytesRef, Decode, DecodeValue, EncodeValue, Error, ErrorKind, FixedLen, FixedTag, Header,
+    Length, Reader, Tag, Writer, asn1::AnyRef, ord::OrdIsValueOrd,
 };
 
 /// ASN.1 `OCTET STRING` type: borrowed form.
@@ -57,6 +57,11 @@ impl<'a> DecodeValue<'a> for OctetStringRef<'a> {
     type Error = Error;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Error> {
+        // The constructed form can't be borrowed: use `OctetString` or `StringSegments` instead
+        if header.is_constructed() {
+            return Err(Self::TAG.non_canonical_error());
+        }
+
         let inner = BytesRef::decode_value(reader, header)?;
         Ok(Self { inner })
     }
@@ -153,13 +158,113 @@ impl<'a, const N: usize> TryFrom<&'a heapless::Vec<u8, N>> for OctetStringRef<'a
     }
 }
 
+/// ASN.1 `OCTET STRING` type: fixed-size form.
+///
+/// Octet strings of a length known in advance, e.g. digests or raw keys, stored inline. Since
+/// their encoding has a constant length, this type impls [`FixedLen`]. Decoding an
+/// `OCTET STRING` of any length other than `N` is an error.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct OctetStringArray<const N: usize> {
+    /// Inner value
+    inner: [u8; N],
+}
+
+impl<const N: usize> OctetStringArray<N> {
+    /// Create a new ASN.1 `OCTET STRING` from a byte array.
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self { inner: bytes }
+    }
+
+    /// Borrow the inner byte array.
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.inner
+    }
+
+    /// Take the inner byte array.
+    pub fn into_bytes(self) -> [u8; N] {
+        self.inner
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for OctetStringArray<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.inner
+    }
+}
+
+impl<'a, const N: usize> DecodeValue<'a> for OctetStringArray<N> {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Error> {
+        if header.is_constructed() {
+            return Err(Self::TAG.non_canonical_error());
+        }
+
+        if usize::try_from(header.length)? != N {
+            return Err(reader.error(ErrorKind::Length { tag: Self::TAG }));
+        }
+
+        let mut inner = [0u8; N];
+        reader.read_into(&mut inner)?;
+        Ok(Self { inner })
+    }
+}
+
+impl<const N: usize> EncodeValue for OctetStringArray<N> {
+    fn value_len(&self) -> Result<Length, Error> {
+        N.try_into()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<(), Error> {
+        writer.write(&self.inner)
+    }
+}
+
+impl<const N: usize> FixedTag for OctetStringArray<N> {
+    const TAG: Tag = Tag::OctetString;
+}
+
+impl<const N: usize> FixedLen for OctetStringArray<N> {
+    const ENCODED_LEN: Length = Length::ONE.mul_const(N).for_tlv_const(Self::TAG);
+}
+
+impl<const N: usize> OrdIsValueOrd for OctetStringArray<N> {}
+
+impl<const N: usize> From<[u8; N]> for OctetStringArray<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<const N: usize> From<OctetStringArray<N>> for [u8; N] {
+    fn from(octet_string: OctetStringArray<N>) -> [u8; N] {
+        octet_string.into_bytes()
+    }
+}
+
+impl<'a, const N: usize> TryFrom<OctetStringRef<'a>> for OctetStringArray<N> {
+    type Error = Error;
+
+    fn try_from(octet_string: OctetStringRef<'a>) -> Result<Self, Error> {
+        <[u8; N]>::try_from(octet_string).map(Self::new)
+    }
+}
+
+impl<'a, const N: usize> TryFrom<AnyRef<'a>> for OctetStringArray<N> {
+    type Error = Error;
+
+    fn try_from(any: AnyRef<'a>) -> Result<Self, Error> {
+        any.decode_as()
+    }
+}
+
 #[cfg(feature = "alloc")]
 pub use self::allocating::OctetString;
 
 #[cfg(feature = "alloc")]
 mod allocating {
     use super::*;
-    use crate::referenced::*;
+    use crate::{asn1::segments::read_segments, referenced::*};
     use alloc::vec::Vec;
 
     /// ASN.1 `OCTET STRING` type: owned form..
@@ -216,9 +321,25 @@ mod allocating {
 
     impl<'a> DecodeValue<'a> for OctetString {
         type Error = Error;
+        const INDEFINITE_LENGTH: bool = true;
 
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Error> {
-            Self::new(reader.read_vec(header.length)?)
+            let mut inner = Vec::new();
+
+            // Reassemble the segments of values using the BER constructed form
+            read_segments(reader, header, Self::TAG, |reader, segment| {
+                let mut bytes = reader.read_vec(segment.length)?;
+
+                if inner.is_empty() {
+                    inner = bytes;
+                } else {
+                    inner.append(&mut bytes);
+                }
+
+                Ok(())
+            })?;
+
+            Self::new(inner)
         }
     }
 
@@ -296,20 +417,140 @@ mod allocating {
     }
 }
 
+#[cfg(feature = "bytes")]
+pub use self::bytes::OctetStringBytes;
+
 #[cfg(feature = "bytes")]
 mod bytes {
     use super::{OctetString, OctetStringRef};
     use crate::{
-        DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result, Tag, Writer,
+        BytesShared, DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result,
+        Tag, Writer, ord::OrdIsValueOrd, referenced::OwnedToRef,
     };
     use alloc::vec::Vec;
     use bytes::Bytes;
 
+    /// ASN.1 `OCTET STRING` type: form backed by a [`Bytes`] buffer.
+    ///
+    /// This type provides the same functionality as [`OctetString`], but when decoded with a
+    /// [`BytesReader`][`crate::BytesReader`] its value shares the allocation of the input rather
+    /// than being copied out of it.
+    #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct OctetStringBytes {
+        /// Octet string represented as a [`Bytes`] buffer.
+        inner: BytesShared,
+    }
+
+    impl OctetStringBytes {
+        /// Create a new ASN.1 `OCTET STRING`.
+        pub fn new(bytes: impl Into<Bytes>) -> Result<Self> {
+            BytesShared::new(bytes).map(|inner| Self { inner })
+        }
+
+        /// Borrow the inner byte slice.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.inner.as_slice()
+        }
+
+        /// Take ownership of the inner [`Bytes`] buffer.
+        pub fn into_bytes(self) -> Bytes {
+            self.inner.into()
+        }
+
+        /// Get the length of the inner byte slice.
+        pub fn len(&self) -> Length {
+            self.inner.len()
+        }
+
+        /// Is the inner byte slice empty?
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+    }
+
+    impl_any_conversions!(OctetStringBytes);
+
+    impl AsRef<[u8]> for OctetStringBytes {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl<'a> DecodeValue<'a> for OctetStringBytes {
+        type Error = Error;
+        const INDEFINITE_LENGTH: bool = true;
+
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            Bytes::decode_value(reader, header).and_then(Self::new)
+        }
+    }
+
+    impl EncodeValue for OctetStringBytes {
+        fn value_len(&self) -> Result<Length> {
+            self.inner.value_len()
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+            self.inner.encode_value(writer)
+        }
+    }
+
+    impl FixedTag for OctetStringBytes {
+        const TAG: Tag = Tag::OctetString;
+    }
+
+    impl<'a> From<&'a OctetStringBytes> for OctetStringRef<'a> {
+        fn from(octet_string: &'a OctetStringBytes) -> OctetStringRef<'a> {
+            OctetStringRef {
+                inner: octet_string.inner.owned_to_ref(),
+            }
+        }
+    }
+
+    impl OrdIsValueOrd for OctetStringBytes {}
+
+    impl From<OctetStringRef<'_>> for OctetStringBytes {
+        fn from(octet_string: OctetStringRef<'_>) -> OctetStringBytes {
+            Self {
+                inner: octet_string.inner.into(),
+            }
+        }
+    }
+
+    impl From<OctetString> for OctetStringBytes {
+        fn from(octet_string: OctetString) -> OctetStringBytes {
+            Self {
+                inner: BytesShared::new(octet_string.inner).expect("invalid OCTET STRING"),
+            }
+        }
+    }
+
+    impl From<OctetStringBytes> for OctetString {
+        fn from(octet_string: OctetStringBytes) -> OctetString {
+            Self {
+                inner: Vec::from(octet_string.as_bytes()),
+            }
+        }
+    }
+
+    impl From<OctetStringBytes> for Bytes {
+        fn from(octet_string: OctetStringBytes) -> Bytes {
+            octet_string.into_bytes()
+        }
+    }
+
     impl<'a> DecodeValue<'a> for Bytes {
         type Error = Error;
+        const INDEFINITE_LENGTH: bool = true;
 
         fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-            OctetString::decode_value(reader, header).map(|octet_string| octet_string.inner.into())
+            // Values using the BER constructed form need to be reassembled
+            if header.is_constructed() {
+                return OctetString::decode_value(reader, header)
+                    .map(|octet_string| octet_string.inner.into());
+            }
+
+            reader.read_bytes(header.length)
         }
     }
 
@@ -343,7 +584,13 @@ mod bytes {
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::asn1::{OctetStringRef, PrintableStringRef};
+    use crate::{
+        Decode, Encode, ErrorKind, FixedLen, Length, Tag,
+        asn1::{OctetStringArray, OctetStringRef, PrintableStringRef},
+    };
+
+    #[cfg(feature = "alloc")]
+    use {crate::asn1::OctetString, hex_literal::hex};
 
     #[test]
     fn octet_string_decode_into() {
@@ -354,4 +601,37 @@ mod tests {
         let res = oct.decode_into::<PrintableStringRef<'_>>().unwrap();
         assert_eq!(AsRef::<str>::as_ref(&res), "hi");
     }
+
+    #[test]
+    fn octet_string_array() {
+        let der = [0x04, 0x03, 0xAA, 0xBB, 0xCC];
+        let oct = OctetStringArray::<3>::from_der(&der).unwrap();
+        assert_eq!(oct.as_bytes(), &[0xAA, 0xBB, 0xCC]);
+
+        assert_eq!(OctetStringArray::<3>::ENCODED_LEN, Length::new(5));
+        assert_eq!(oct.encode_to_array().unwrap(), der);
+
+        // Other lengths are rejected
+        let err = OctetStringArray::<2>::from_der(&der).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Length {
+                tag: Tag::OctetString
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn decode_ber_constructed() {
+        // Constructed `OCTET STRING` with an indefinite length and two segments
+        let ber = hex!("2480 0402AABB 0401CC 0000");
+
+        let oct = OctetString::from_ber(&ber).unwrap();
+        assert_eq!(oct.as_bytes(), &hex!("AABBCC"));
+
+        // DER and borrowed forms only accept the primitive form
+        assert!(OctetString::from_der(&ber).is_err());
+        assert!(OctetStringRef::from_ber(&ber).is_err());
+    }
 }
@@ -1,196 +1,321 @@
 //! ASN.1 `REAL` support.
 
-// TODO(tarcieri): checked arithmetic
-#![allow(
-    clippy::cast_lossless,
-    clippy::cast_sign_loss,
-    clippy::arithmetic_side_effects
-)]
+mod decimal;
+
+pub use self::decimal::{DecimalForm, RealDecimalRef};
 
 use crate::{
-    BytesRef, DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result, StrRef,
-    Tag, Writer,
+    BytesRef, DecodeValue, EncodeValue, EncodingRules, Error, FixedTag, Header, Length, Reader,
+    Result, Tag, Writer,
 };
 
-use super::integer::uint::strip_leading_zeroes;
+/// First contents octet of the special real value `PLUS-INFINITY` (X.690 Section 8.5.9).
+const PLUS_INFINITY: u8 = 0b0100_0000;
 
-impl<'a> DecodeValue<'a> for f64 {
-    type Error = Error;
+/// First contents octet of the special real value `MINUS-INFINITY` (X.690 Section 8.5.9).
+const MINUS_INFINITY: u8 = 0b0100_0001;
 
-    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        let bytes = BytesRef::decode_value(reader, header)?.as_slice();
+/// First contents octet of the special real value `NOT-A-NUMBER` (X.690 Section 8.5.9).
+const NOT_A_NUMBER: u8 = 0b0100_0010;
 
-        if header.length == Length::ZERO {
-            Ok(0.0)
-        } else if is_nth_bit_one::<7>(bytes) {
-            // Binary encoding from section 8.5.7 applies
-            let sign: u64 = u64::from(is_nth_bit_one::<6>(bytes));
+/// First contents octet of the special real value minus zero (X.690 Section 8.5.9).
+const MINUS_ZERO: u8 = 0b0100_0011;
 
-            // Section 8.5.7.2: Check the base -- the DER specs say that only base 2 should be supported in DER
-            let base = mnth_bits_to_u8::<5, 4>(bytes);
+/// Maximum length of the contents octets of an `f64`: the first octet, a 2-octet exponent and
+/// a 7-octet mantissa.
+const MAX_F64_LEN: usize = 10;
 
-            if base != 0 {
-                // Real related error: base is not DER compliant (base encoded in enum)
-                return Err(Tag::Real.value_error());
-            }
+/// Value of a `REAL` as given by its contents octets.
+#[derive(Clone, Copy, Debug)]
+enum RealValue<'a> {
+    /// Plus zero, which has no contents octets (X.690 Section 8.5.2).
+    Zero,
 
-            // Section 8.5.7.3
-            let scaling_factor = mnth_bits_to_u8::<3, 2>(bytes);
+    /// Special real value (X.690 Section 8.5.9).
+    Special(f64),
 
-            // Section 8.5.7.4
-            let mantissa_start;
-            let exponent = match mnth_bits_to_u8::<1, 0>(bytes) {
-                0 => {
-                    mantissa_start = 2;
-                    let ebytes = (i16::from_be_bytes([0x0, bytes[1]])).to_be_bytes();
-                    u64::from_be_bytes([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, ebytes[0], ebytes[1]])
-                }
-                1 => {
-                    mantissa_start = 3;
-                    let ebytes = (i16::from_be_bytes([bytes[1], bytes[2]])).to_be_bytes();
-                    u64::from_be_bytes([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, ebytes[0], ebytes[1]])
-                }
-                _ => {
-                    // Real related error: encoded exponent cannot be represented on an IEEE-754 double
+    /// Binary encoding (X.690 Section 8.5.7) of `mantissa * 2^exponent`, normalized to base 2
+    /// and a scaling factor of 0.
+    Binary {
+        negative: bool,
+        mantissa: u64,
+        exponent: i64,
+    },
+
+    /// Decimal encoding (X.690 Section 8.5.8).
+    Decimal(RealDecimalRef<'a>),
+}
+
+impl<'a> RealValue<'a> {
+    /// Decode the contents octets of a `REAL`.
+    fn decode<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let encoding_rules = reader.encoding_rules();
+        let bytes = BytesRef::decode_value(reader, header)?.as_slice();
+
+        let Some((&first, rest)) = bytes.split_first() else {
+            return Ok(RealValue::Zero);
+        };
+
+        match first >> 6 {
+            0b10 | 0b11 => decode_binary(first, rest, encoding_rules),
+            0b01 => {
+                if !rest.is_empty() {
                     return Err(Tag::Real.value_error());
                 }
-            };
-            // Section 8.5.7.5: Read the remaining bytes for the mantissa
-            let mut n_bytes = [0x0; 8];
-            for (pos, byte) in bytes[mantissa_start..].iter().rev().enumerate() {
-                n_bytes[7 - pos] = *byte;
-            }
-            let n = u64::from_be_bytes(n_bytes);
-            // Multiply byte 2^F corresponds to just a left shift
-            let mantissa = n << scaling_factor;
-            // Create the f64
-            Ok(encode_f64(sign, exponent, mantissa))
-        } else if is_nth_bit_one::<6>(bytes) {
-            // This either a special value, or it's the value minus zero is encoded, section 8.5.9 applies
-            match mnth_bits_to_u8::<1, 0>(bytes) {
-                0 => Ok(f64::INFINITY),
-                1 => Ok(f64::NEG_INFINITY),
-                2 => Ok(f64::NAN),
-                3 => Ok(-0.0_f64),
-                _ => Err(Tag::Real.value_error()),
+
+                match first {
+                    PLUS_INFINITY => Ok(RealValue::Special(f64::INFINITY)),
+                    MINUS_INFINITY => Ok(RealValue::Special(f64::NEG_INFINITY)),
+                    NOT_A_NUMBER => Ok(RealValue::Special(f64::NAN)),
+                    MINUS_ZERO => Ok(RealValue::Special(-0.0)),
+                    _ => Err(Tag::Real.value_error()),
+                }
             }
-        } else {
-            let astr = StrRef::from_bytes(&bytes[1..])?;
-            match astr.inner.parse::<f64>() {
-                Ok(val) => Ok(val),
-                // Real related error: encoding not supported or malformed
-                Err(_) => Err(Tag::Real.value_error()),
+            _ => {
+                let form = DecimalForm::try_from(first)?;
+                let text = core::str::from_utf8(rest).map_err(|_| Tag::Real.value_error())?;
+                RealDecimalRef::from_form(form, text).map(RealValue::Decimal)
             }
         }
     }
+
+    /// Convert to the nearest `f64`, failing if it's out of range.
+    fn to_f64(self) -> Result<f64> {
+        match self {
+            RealValue::Zero => Ok(0.0),
+            RealValue::Special(value) => Ok(value),
+            RealValue::Binary {
+                negative,
+                mantissa,
+                exponent,
+            } => binary_to_f64(negative, mantissa, exponent),
+            RealValue::Decimal(decimal) => f64::try_from(decimal),
+        }
+    }
 }
 
-impl EncodeValue for f64 {
-    fn value_len(&self) -> Result<Length> {
-        if self.is_sign_positive() && (*self) < f64::MIN_POSITIVE {
-            // Zero: positive yet smaller than the minimum positive number
-            Ok(Length::ZERO)
-        } else if self.is_nan()
-            || self.is_infinite()
-            || (self.is_sign_negative() && -self < f64::MIN_POSITIVE)
+/// Decode the binary encoding of a `REAL` (X.690 Section 8.5.7), given its first contents
+/// octet and the remaining ones.
+///
+/// Under DER, the encoding must be canonical as described in X.690 Section 11.3.1.
+fn decode_binary(
+    first: u8,
+    bytes: &[u8],
+    encoding_rules: EncodingRules,
+) -> Result<RealValue<'static>> {
+    let negative = first & 0b0100_0000 != 0;
+
+    // Section 8.5.7.2: base 2, 8 or 16, i.e. each unit of the exponent is worth 1, 3 or 4 bits
+    let base_bits: i64 = match (first >> 4) & 0b11 {
+        0 => 1,
+        1 => 3,
+        2 => 4,
+        _ => return Err(Tag::Real.value_error()),
+    };
+
+    // Section 8.5.7.3
+    let scaling_factor = i64::from((first >> 2) & 0b11);
+
+    // Section 8.5.7.4: the exponent is 1, 2 or 3 octets long, or its length comes first
+    let (exponent_len, bytes) = match first & 0b11 {
+        0b11 => {
+            let (&len, rest) = bytes.split_first().ok_or(Tag::Real.value_error())?;
+            (usize::from(len), rest)
+        }
+        len => (usize::from(len) + 1, bytes),
+    };
+
+    if exponent_len == 0 || bytes.len() <= exponent_len {
+        return Err(Tag::Real.value_error());
+    }
+
+    let (exponent, mantissa) = bytes.split_at(exponent_len);
+
+    if !encoding_rules.is_ber() {
+        let long_exponent = first & 0b11 == 0b11 && exponent_len <= 3;
+        let redundant_exponent = matches!(exponent, [0x00, next, ..] if next & 0x80 == 0)
+            || matches!(exponent, [0xFF, next, ..] if next & 0x80 != 0);
+        let even_mantissa = mantissa.last().is_some_and(|byte| byte & 1 == 0);
+
+        if base_bits != 1
+            || scaling_factor != 0
+            || long_exponent
+            || redundant_exponent
+            || mantissa[0] == 0
+            || even_mantissa
         {
-            // NaN, infinite (positive or negative), or negative zero (negative but its negative is less than the min positive number)
-            Ok(Length::ONE)
-        } else {
-            // The length is that of the first octets plus those needed for the exponent plus those needed for the mantissa
-            let (_sign, exponent, mantissa) = decode_f64(*self);
-
-            let exponent_len = if exponent == 0 {
-                // Section 8.5.7.4: there must be at least one octet for exponent encoding
-                // But, if the exponent is zero, it'll be skipped, so we make sure force it to 1
-                Length::ONE
-            } else {
-                let ebytes = exponent.to_be_bytes();
-                Length::try_from(strip_leading_zeroes(&ebytes).len())?
-            };
-
-            let mantissa_len = if mantissa == 0 {
-                Length::ONE
-            } else {
-                let mbytes = mantissa.to_be_bytes();
-                Length::try_from(strip_leading_zeroes(&mbytes).len())?
-            };
-
-            exponent_len + mantissa_len + Length::ONE
+            return Err(Tag::Real.value_error());
         }
     }
 
-    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
-        // Check if special value
-        // Encode zero first, if it's zero
-        // Special value from section 8.5.9 if non zero
-        if self.is_nan()
-            || self.is_infinite()
-            || (self.is_sign_negative() && -self < f64::MIN_POSITIVE)
-            || (self.is_sign_positive() && (*self) < f64::MIN_POSITIVE)
-        {
-            if self.is_sign_positive() && (*self) < f64::MIN_POSITIVE {
-                // Zero
-                return Ok(());
-            } else if self.is_nan() {
-                // Not a number
-                writer.write_byte(0b0100_0010)?;
-            } else if self.is_infinite() {
-                if self.is_sign_negative() {
-                    // Negative infinity
-                    writer.write_byte(0b0100_0001)?;
-                } else {
-                    // Plus infinity
-                    writer.write_byte(0b0100_0000)?;
-                }
-            } else {
-                // Minus zero
-                writer.write_byte(0b0100_0011)?;
-            }
-        } else {
-            // Always use binary encoding, set bit 8 to 1
-            let mut first_byte = 0b1000_0000;
+    let initial = if exponent[0] & 0x80 != 0 { -1 } else { 0 };
+    let exponent = exponent
+        .iter()
+        .try_fold(initial, |acc: i64, &byte| {
+            acc.checked_mul(0x100).map(|acc| acc | i64::from(byte))
+        })
+        .ok_or(Tag::Real.value_error())?;
+
+    // Section 8.5.7.5: the mantissa is an unsigned integer, whose trailing zero octets can be
+    // moved to the exponent
+    let leading_zeros = mantissa.iter().take_while(|&&byte| byte == 0).count();
+    let mantissa = &mantissa[leading_zeros..];
+    let trailing_zeros = mantissa.iter().rev().take_while(|&&byte| byte == 0).count();
+    let mantissa = &mantissa[..mantissa.len() - trailing_zeros];
+
+    // Zero must be encoded without contents octets (Section 8.5.2), and mantissas wider than
+    // 64 bits have more precision than is supported
+    if mantissa.is_empty() || mantissa.len() > 8 {
+        return Err(Tag::Real.value_error());
+    }
 
-            if self.is_sign_negative() {
-                // Section 8.5.7.1: set bit 7 to 1 if negative
-                first_byte |= 0b0100_0000;
-            }
+    let mantissa = mantissa
+        .iter()
+        .fold(0u64, |acc, &byte| (acc << 8) | u64::from(byte));
+
+    let exponent = i64::try_from(trailing_zeros)
+        .ok()
+        .and_then(|zeros| zeros.checked_mul(8))
+        .and_then(|zeros| exponent.checked_mul(base_bits)?.checked_add(zeros))
+        .and_then(|exponent| exponent.checked_add(scaling_factor))
+        .ok_or(Tag::Real.value_error())?;
+
+    Ok(RealValue::Binary {
+        negative,
+        mantissa,
+        exponent,
+    })
+}
 
-            // Bits 6 and 5 are set to 0 to specify that binary encoding is used
-            //
-            // NOTE: the scaling factor is only used to align the implicit point of the mantissa.
-            // This is unnecessary in DER because the base is 2, and therefore necessarily aligned.
-            // Therefore, we do not modify the mantissa in anyway after this function call, which
-            // already adds the implicit one of the IEEE 754 representation.
-            let (_sign, exponent, mantissa) = decode_f64(*self);
-
-            // Encode the exponent as two's complement on 16 bits and remove the bias
-            let exponent_bytes = exponent.to_be_bytes();
-            let ebytes = strip_leading_zeroes(&exponent_bytes);
-
-            match ebytes.len() {
-                0 | 1 => {}
-                2 => first_byte |= 0b0000_0001,
-                3 => first_byte |= 0b0000_0010,
-                _ => {
-                    // TODO: support multi octet exponent encoding?
-                    return Err(Tag::Real.value_error());
-                }
-            }
+/// Compute `mantissa * 2^exponent`, rounded to the nearest `f64`.
+///
+/// Returns an error if the result is too large to be represented.
+#[allow(clippy::cast_precision_loss)]
+fn binary_to_f64(negative: bool, mantissa: u64, exponent: i64) -> Result<f64> {
+    // Beyond this range, the result is either zero or infinite whatever the mantissa
+    let mut exponent = exponent.clamp(-1140, 1100);
+    let mut value = mantissa as f64;
+
+    while exponent > 1023 {
+        value *= pow2(1023);
+        exponent -= 1023;
+    }
+
+    while exponent < -1022 {
+        value *= pow2(-1022);
+        exponent += 1022;
+    }
+
+    value *= pow2(exponent);
+
+    if value.is_infinite() {
+        return Err(Tag::Real.value_error());
+    }
+
+    Ok(if negative { -value } else { value })
+}
+
+/// Compute `2^exponent` for an exponent within the range of normal `f64` values.
+#[allow(clippy::cast_sign_loss)]
+fn pow2(exponent: i64) -> f64 {
+    debug_assert!((-1022..=1023).contains(&exponent));
+    f64::from_bits(((exponent + 1023) as u64) << 52)
+}
 
-            writer.write_byte(first_byte)?;
+/// Decompose a finite, non-zero `f64` into its sign, an odd mantissa and an exponent.
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn decompose(value: f64) -> (bool, u64, i16) {
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7FF) as i16;
+    let fraction = bits & ((1 << 52) - 1);
 
-            // Encode both bytes or just the last one, handled by encode_bytes directly
-            // Rust already encodes the data as two's complement, so no further processing is needed
-            writer.write(ebytes)?;
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        // Subnormal
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+
+    let shift = mantissa.trailing_zeros();
+    (bits >> 63 == 1, mantissa >> shift, exponent + shift as i16)
+}
+
+/// Compute the contents octets of the canonical encoding of an `f64` (X.690 Section 11.3.1):
+/// binary encoding with base 2, a scaling factor of 0 and an odd mantissa.
+///
+/// Returns a buffer along with the length of its used prefix.
+fn encode_f64(value: f64) -> ([u8; MAX_F64_LEN], usize) {
+    let mut buffer = [0; MAX_F64_LEN];
+
+    if value.is_nan() {
+        buffer[0] = NOT_A_NUMBER;
+        return (buffer, 1);
+    }
 
-            // Now, encode the mantissa as unsigned binary number
-            let mantissa_bytes = mantissa.to_be_bytes();
-            let mbytes = strip_leading_zeroes(&mantissa_bytes);
-            writer.write(mbytes)?;
+    if value.is_infinite() {
+        buffer[0] = if value.is_sign_negative() {
+            MINUS_INFINITY
+        } else {
+            PLUS_INFINITY
+        };
+        return (buffer, 1);
+    }
+
+    if value == 0.0 {
+        if value.is_sign_negative() {
+            buffer[0] = MINUS_ZERO;
+            return (buffer, 1);
         }
 
-        Ok(())
+        return (buffer, 0);
+    }
+
+    let (negative, mantissa, exponent) = decompose(value);
+
+    // Section 8.5.7.4: the exponent is encoded in the fewest octets possible
+    let exponent_bytes = exponent.to_be_bytes();
+    let exponent_bytes = if i8::try_from(exponent).is_ok() {
+        &exponent_bytes[1..]
+    } else {
+        &exponent_bytes[..]
+    };
+
+    let mantissa_bytes = mantissa.to_be_bytes();
+    let mantissa_bytes = &mantissa_bytes[(mantissa.leading_zeros() / 8) as usize..];
+
+    buffer[0] = 0b1000_0000;
+
+    if negative {
+        buffer[0] |= 0b0100_0000;
+    }
+
+    if exponent_bytes.len() == 2 {
+        buffer[0] |= 0b0000_0001;
+    }
+
+    let exponent_end = 1 + exponent_bytes.len();
+    let end = exponent_end + mantissa_bytes.len();
+    buffer[1..exponent_end].copy_from_slice(exponent_bytes);
+    buffer[exponent_end..end].copy_from_slice(mantissa_bytes);
+    (buffer, end)
+}
+
+impl<'a> DecodeValue<'a> for f64 {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        RealValue::decode(reader, header)?.to_f64()
+    }
+}
+
+impl EncodeValue for f64 {
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from(encode_f64(*self).1)
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        let (buffer, len) = encode_f64(*self);
+        writer.write(&buffer[..len])
     }
 }
 
@@ -198,61 +323,48 @@ impl FixedTag for f64 {
     const TAG: Tag = Tag::Real;
 }
 
-/// Is the N-th bit 1 in the first octet?
-/// NOTE: this function is zero indexed
-pub(crate) fn is_nth_bit_one<const N: usize>(bytes: &[u8]) -> bool {
-    if N < 8 {
-        bytes
-            .first()
-            .map(|byte| byte & (1 << N) != 0)
-            .unwrap_or(false)
-    } else {
-        false
+impl<'a> DecodeValue<'a> for f32 {
+    type Error = Error;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        match RealValue::decode(reader, header)? {
+            // Parse decimal values directly to avoid rounding twice
+            RealValue::Decimal(decimal) => f32::try_from(decimal),
+            value => {
+                let value = value.to_f64()?;
+                let rounded = value as f32;
+
+                if rounded.is_infinite() && value.is_finite() {
+                    return Err(Tag::Real.value_error());
+                }
+
+                Ok(rounded)
+            }
+        }
     }
 }
 
-/// Convert bits M, N into a u8, in the first octet only
-pub(crate) fn mnth_bits_to_u8<const M: usize, const N: usize>(bytes: &[u8]) -> u8 {
-    let bit_m = is_nth_bit_one::<M>(bytes);
-    let bit_n = is_nth_bit_one::<N>(bytes);
-    ((bit_m as u8) << 1) | bit_n as u8
-}
+/// `f32` values are encoded exactly like the `f64` value they convert to.
+impl EncodeValue for f32 {
+    fn value_len(&self) -> Result<Length> {
+        f64::from(*self).value_len()
+    }
 
-/// Decode an f64 as its sign, exponent, and mantissa in u64 and in that order, using bit shifts and masks.
-/// Note: this function **removes** the 1023 bias from the exponent and adds the implicit 1
-#[allow(clippy::cast_possible_truncation)]
-pub(crate) fn decode_f64(f: f64) -> (u64, u64, u64) {
-    let bits = f.to_bits();
-    let sign = bits >> 63;
-    let exponent = (bits >> 52) & 0x7ff;
-    let exponent_bytes_no_bias = (exponent as i16 - 1023).to_be_bytes();
-    let exponent_no_bias = u64::from_be_bytes([
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        exponent_bytes_no_bias[0],
-        exponent_bytes_no_bias[1],
-    ]);
-    let mantissa = bits & 0xfffffffffffff;
-    (sign, exponent_no_bias, mantissa + 1)
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        f64::from(*self).encode_value(writer)
+    }
 }
 
-/// Encode an f64 from its sign, exponent (**without** the 1023 bias), and (mantissa - 1) using bit shifts as received by ASN1
-pub(crate) fn encode_f64(sign: u64, exponent: u64, mantissa: u64) -> f64 {
-    // Add the bias to the exponent
-    let exponent_with_bias =
-        (i16::from_be_bytes([exponent.to_be_bytes()[6], exponent.to_be_bytes()[7]]) + 1023) as u64;
-    let bits = (sign << 63) | (exponent_with_bias << 52) | (mantissa - 1);
-    f64::from_bits(bits)
+impl FixedTag for f32 {
+    const TAG: Tag = Tag::Real;
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use crate::{Decode, Encode};
+    use hex_literal::hex;
 
     #[test]
     fn decode_subnormal() {
@@ -289,515 +401,194 @@ mod tests {
 
     #[test]
     fn encdec_normal() {
-        // The comments correspond to the decoded value from the ASN.1 playground when the bytes are inputted.
-        {
+        for (val, expected) in [
             // rec1value R ::= 0
-            let val = 0.0;
-            let expected = &[0x09, 0x0];
-            let mut buffer = [0u8; 2];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
+            (0.0, &hex!("09 00")[..]),
             // rec1value R ::= { mantissa 1, base 2, exponent 0 }
-            let val = 1.0;
-            let expected = &[0x09, 0x03, 0x80, 0x00, 0x01];
-            let mut buffer = [0u8; 5];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
+            (1.0, &hex!("09 03 80 00 01")),
             // rec1value R ::= { mantissa -1, base 2, exponent 0 }
-            let val = -1.0;
-            let expected = &[0x09, 0x03, 0xc0, 0x00, 0x01];
-            let mut buffer = [0u8; 5];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            // rec1value R ::= { mantissa -1, base 2, exponent 1 }
-            let val = -1.0000000000000002;
-            let expected = &[0x09, 0x03, 0xc0, 0x00, 0x02];
-            let mut buffer = [0u8; 5];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
+            (-1.0, &hex!("09 03 C0 00 01")),
+            // rec1value R ::= { mantissa 1, base 2, exponent -1 }
+            (0.5, &hex!("09 03 80 FF 01")),
+            // rec1value R ::= { mantissa -4503599627370497, base 2, exponent -52 }
+            (
+                -1.0000000000000002,
+                &hex!("09 09 C0 CC 10 00 00 00 00 00 01"),
+            ),
             // rec1value R ::= { mantissa 1, base 2, exponent -1022 }
-            // NOTE: f64::MIN_EXP == -1021 so the exponent decoded by ASN.1 is what we expect
-            let val = f64::MIN_POSITIVE;
-            let expected = &[0x09, 0x04, 0x81, 0xfc, 0x02, 0x01];
-            let mut buffer = [0u8; 7];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            // rec4value R ::= { mantissa 1, base 2, exponent 3 }
-            let val = 1.0000000000000016;
-            let expected = &[0x09, 0x03, 0x80, 0x00, 0x08];
-            let mut buffer = [0u8; 5];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            // rec5value R ::= { mantissa 4222124650659841, base 2, exponent 4 }
-            let val = 31.0;
-            let expected = &[
-                0x9, 0x9, 0x80, 0x04, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
-            ];
-            let mut buffer = [0u8; 11];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
+            (f64::MIN_POSITIVE, &hex!("09 04 81 FC 02 01")),
+            // rec1value R ::= { mantissa 1, base 2, exponent -1074 }
+            (5e-324, &hex!("09 04 81 FB CE 01")),
+            // rec1value R ::= { mantissa 9007199254740991, base 2, exponent 971 }
+            (f64::MAX, &hex!("09 0A 81 03 CB 1F FF FF FF FF FF FF")),
+            // rec1value R ::= { mantissa 4503599627370503, base 2, exponent -52 }
+            (
+                1.0000000000000016,
+                &hex!("09 09 80 CC 10 00 00 00 00 00 07"),
+            ),
+            // rec1value R ::= { mantissa 31, base 2, exponent 0 }
+            (31.0, &hex!("09 03 80 00 1F")),
+        ] {
+            assert_encdec(val, expected);
         }
     }
 
     #[test]
     fn encdec_irrationals() {
-        {
-            let val = core::f64::consts::PI;
-            let expected = &[
-                0x09, 0x09, 0x80, 0x01, 0x09, 0x21, 0xfb, 0x54, 0x44, 0x2d, 0x19,
-            ];
-            let mut buffer = [0u8; 11];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            let val = core::f64::consts::E;
-            let expected = &[
-                0x09, 0x09, 0x80, 0x01, 0x05, 0xbf, 0x0a, 0x8b, 0x14, 0x57, 0x6a,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-        {
-            let val = core::f64::consts::LN_2;
-            let expected = &[
-                0x09, 0x0a, 0x81, 0xff, 0xff, 0x6, 0x2e, 0x42, 0xfe, 0xfa, 0x39, 0xf0,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
+        for (val, expected) in [
+            (
+                core::f64::consts::PI,
+                hex!("09 09 80 D0 03 24 3F 6A 88 85 A3"),
+            ),
+            (
+                core::f64::consts::E,
+                hex!("09 09 80 CD 15 BF 0A 8B 14 57 69"),
+            ),
+            (
+                core::f64::consts::LN_2,
+                hex!("09 09 80 CB 16 2E 42 FE FA 39 EF"),
+            ),
+        ] {
+            assert_encdec(val, &expected);
         }
     }
 
     #[test]
     fn encdec_reasonable_f64() {
-        // Tests the encoding and decoding of reals with some arbitrary numbers
-        {
-            // rec1value R ::= { mantissa 2414341043715239, base 2, exponent 21 }
-            let val = 3221417.1584163485;
-            let expected = &[
-                0x9, 0x9, 0x80, 0x15, 0x8, 0x93, 0xd4, 0x94, 0x46, 0xfc, 0xa7,
-            ];
-            let mut buffer = [0u8; 11];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            // rec1value R ::= { mantissa 2671155248072715, base 2, exponent 23 }
-            let val = 13364022.365665454;
-            let expected = &[
-                0x09, 0x09, 0x80, 0x17, 0x09, 0x7d, 0x66, 0xcb, 0xb3, 0x88, 0x0b,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            // rec1value R ::= { mantissa -4386812962460287, base 2, exponent 14 }
-            let val = -32343.132588105735;
-            let expected = &[
-                0x09, 0x09, 0xc0, 0x0e, 0x0f, 0x95, 0xc8, 0x7c, 0x52, 0xd2, 0x7f,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            let val = -27084.866751869475;
-            let expected = &[
-                0x09, 0x09, 0xc0, 0x0e, 0x0a, 0x73, 0x37, 0x78, 0xdc, 0xd5, 0x4a,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            // rec1value R ::= { mantissa -4372913134428149, base 2, exponent 7 }
-            let val = -252.28566647111404;
-            let expected = &[
-                0x09, 0x09, 0xc0, 0x07, 0x0f, 0x89, 0x24, 0x2e, 0x02, 0xdf, 0xf5,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            let val = -14.399709612928548;
-            let expected = &[
-                0x09, 0x09, 0xc0, 0x03, 0x0c, 0xcc, 0xa6, 0xbd, 0x06, 0xd9, 0x92,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
-
-        {
-            let val = -0.08340570261832964;
-            let expected = &[
-                0x09, 0x0a, 0xc1, 0xff, 0xfc, 0x05, 0x5a, 0x13, 0x7d, 0x0b, 0xae, 0x3d,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
+        for (val, expected) in [
+            (3221417.1584163485, hex!("09 09 80 E2 0C 49 EA 4A 23 7E 53")),
+            (13364022.365665454, hex!("09 09 80 E4 0C BE B3 65 D9 C4 05")),
+            (
+                -32343.132588105735,
+                hex!("09 09 C0 DB 0F CA E4 3E 29 69 3F"),
+            ),
+            (
+                -27084.866751869475,
+                hex!("09 09 C0 DA 1A 73 37 78 DC D5 49"),
+            ),
+            (
+                -252.28566647111404,
+                hex!("09 09 C0 D5 07 E2 49 0B 80 B7 FD"),
+            ),
+            (
+                -14.399709612928548,
+                hex!("09 09 C0 CF 1C CC A6 BD 06 D9 91"),
+            ),
+            (
+                -0.08340570261832964,
+                hex!("09 09 C0 CA 05 56 84 DF 42 EB 8F"),
+            ),
+            (
+                0.00536851453803701,
+                hex!("09 09 80 C5 0A FE A5 D2 F3 A6 49"),
+            ),
+            (
+                0.00045183525648866433,
+                hex!("09 09 80 C3 03 B3 91 34 CB 26 67"),
+            ),
+            (
+                0.000033869092002682955,
+                hex!("09 09 80 BD 11 C1 D5 23 D5 54 7B"),
+            ),
+            (
+                0.0000011770891033600088,
+                hex!("09 09 80 B8 13 BF 8F 27 F4 62 55"),
+            ),
+            (
+                0.00000005549514041997082,
+                hex!("09 09 80 B4 0E E5 98 D5 B7 5C 6B"),
+            ),
+            (
+                0.0000000012707044685547803,
+                hex!("09 09 80 AF 0A EA 4F 05 79 7F 8F"),
+            ),
+            (
+                0.00000000002969611878378562,
+                hex!("09 09 80 A9 10 53 5B 6F 97 EE B5"),
+            ),
+        ] {
+            assert_encdec(val, &expected);
         }
+    }
 
-        {
-            let val = 0.00536851453803701;
-            let expected = &[
-                0x09, 0x0a, 0x81, 0xff, 0xf8, 0x05, 0xfd, 0x4b, 0xa5, 0xe7, 0x4c, 0x93,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
+    /// Check the DER encoding of `val`, and that it decodes to `val` both as an `f64` and as
+    /// an `f32` rounded from it.
+    fn assert_encdec(val: f64, expected: &[u8]) {
+        let encoded = val.to_der().unwrap();
+        assert_eq!(
+            expected, encoded,
+            "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
+            val, encoded, expected
+        );
+        assert_eq!(f64::from_der(&encoded).unwrap().to_bits(), val.to_bits());
 
-        {
-            let val = 0.00045183525648866433;
-            let expected = &[
-                0x09, 0x0a, 0x81, 0xff, 0xf4, 0x0d, 0x9c, 0x89, 0xa6, 0x59, 0x33, 0x39,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
+        #[allow(clippy::cast_possible_truncation)]
+        let val = val as f32;
+        let encoded = val.to_der().unwrap();
+        assert_eq!(f32::from_der(&encoded).unwrap().to_bits(), val.to_bits());
+    }
 
-        {
-            let val = 0.000033869092002682955;
-            let expected = &[
-                0x09, 0x0a, 0x81, 0xff, 0xf1, 0x01, 0xc1, 0xd5, 0x23, 0xd5, 0x54, 0x7c,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
+    #[test]
+    fn decode_ber_binary() {
+        for (ber, expected) in [
+            // Base 8: 1 * 8^2
+            (&hex!("09 03 90 02 01")[..], 64.0),
+            // Base 16 with a scaling factor of 1: 3 * 2^1 * 16^-1
+            (&hex!("09 03 A4 FF 03"), 0.375),
+            // Even mantissa: 4 * 2^0
+            (&hex!("09 03 80 00 04")[..], 4.0),
+            // Redundant exponent and mantissa octets: -1 * 2^1
+            (&hex!("09 06 C1 00 01 00 00 01"), -2.0),
+            // Exponent with its length first: 5 * 2^-1
+            (&hex!("09 04 83 01 FF 05"), 2.5),
+            // Trailing zero mantissa octets beyond 64 bits: 1 * 2^64
+            (
+                &hex!("09 0B 80 00 01 00 00 00 00 00 00 00 00"),
+                18446744073709551616.0,
+            ),
+        ] {
+            assert_eq!(f64::from_ber(ber).unwrap(), expected, "{ber:x?}");
+            assert!(f64::from_der(ber).is_err(), "{ber:x?}");
         }
 
-        {
-            let val = 0.0000011770891033600088;
-            let expected = &[
-                0x09, 0x0a, 0x81, 0xff, 0xec, 0x03, 0xbf, 0x8f, 0x27, 0xf4, 0x62, 0x56,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
+        // Reserved base, zero mantissa, missing mantissa and out of range values
+        for ber in [
+            &hex!("09 03 B0 00 01")[..],
+            &hex!("09 03 80 00 00"),
+            &hex!("09 02 80 00"),
+            &hex!("09 04 81 7F FF 01"),
+            &hex!("09 02 44 00"),
+        ] {
+            assert!(f64::from_ber(ber).is_err(), "{ber:x?}");
         }
 
-        {
-            let val = 0.00000005549514041997082;
-            let expected = &[
-                0x09, 0x0a, 0x81, 0xff, 0xe7, 0x0d, 0xcb, 0x31, 0xab, 0x6e, 0xb8, 0xd7,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
-        }
+        // Too large for an `f32`, but not an `f64`
+        assert!(f32::from_der(&hex!("09 04 81 04 00 01")).is_err());
+    }
 
-        {
-            let val = 0.0000000012707044685547803;
-            let expected = &[
-                0x09, 0x0a, 0x81, 0xff, 0xe2, 0x05, 0xd4, 0x9e, 0x0a, 0xf2, 0xff, 0x1f,
-            ];
-            let mut buffer = [0u8; 12];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn decode_ber_decimal() {
+        for (ber, expected) in [
+            // NR1
+            (&hex!("09 04 01 20 2D 37")[..], -7.0),
+            // NR2 with a comma as decimal mark
+            (&hex!("09 04 02 31 2C 35")[..], 1.5),
+            // NR3 with a lowercase exponent mark
+            (&hex!("09 06 03 32 35 2E 65 31")[..], 250.0),
+        ] {
+            assert_eq!(f64::from_ber(ber).unwrap(), expected, "{ber:x?}");
+            assert_eq!(f32::from_ber(ber).unwrap(), expected as f32, "{ber:x?}");
         }
 
-        {
-            let val = 0.00000000002969611878378562;
-            let expected = &[
-                0x09, 0x09, 0x81, 0xff, 0xdd, 0x53, 0x5b, 0x6f, 0x97, 0xee, 0xb6,
-            ];
-            let mut buffer = [0u8; 11];
-            let encoded = val.encode_to_slice(&mut buffer).unwrap();
-            assert_eq!(
-                expected, encoded,
-                "invalid encoding of {}:\ngot  {:x?}\nwant: {:x?}",
-                val, encoded, expected
-            );
-            let decoded = f64::from_der(encoded).unwrap();
-            assert!(
-                (decoded - val).abs() < f64::EPSILON,
-                "wanted: {}\tgot: {}",
-                val,
-                decoded
-            );
+        // Reserved form, and text not matching its form
+        for ber in [
+            &hex!("09 02 04 31")[..],
+            &hex!("09 03 01 31 2E"),
+            &hex!("09 03 02 31 31"),
+            &hex!("09 04 03 31 2E 30"),
+        ] {
+            assert!(f64::from_ber(ber).is_err(), "{ber:x?}");
         }
     }
 
@@ -806,35 +597,6 @@ mod tests {
         assert!(f64::from_der(&[0x09, 0x81, 0x00]).is_err());
     }
 
-    #[test]
-    fn encdec_f64() {
-        use super::{decode_f64, encode_f64};
-        // Test that the extraction and recreation works
-        for val in [
-            1.0,
-            0.1,
-            -0.1,
-            -1.0,
-            0.0,
-            f64::MIN_POSITIVE,
-            f64::MAX,
-            f64::MIN,
-            core::f64::consts::PI,
-            -core::f64::consts::PI,
-            951.2357864,
-            -951.2357864,
-        ] {
-            let (s, e, m) = decode_f64(val);
-            let val2 = encode_f64(s, e, m);
-            assert!(
-                (val - val2).abs() < f64::EPSILON,
-                "fail - want {}\tgot {}",
-                val,
-                val2
-            );
-        }
-    }
-
     #[test]
     fn validation_cases() {
         // Caveat: these test cases are validated on the ASN.1 playground: https://asn1.io/asn1playground/ .
@@ -0,0 +1,400 @@
+//! Decimal encoding of ASN.1 `REAL` values.
+
+use super::{MINUS_ZERO, RealValue};
+use crate::{
+    DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result, Tag, Writer,
+};
+use core::str::FromStr;
+
+/// Maximum length of decimal text which can be converted to a floating point value.
+const MAX_PARSE_LEN: usize = 64;
+
+/// Numerical representation of the decimal encoding of a `REAL`, as defined in ISO 6093 and
+/// identified by the first contents octet (X.690 Section 8.5.8).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum DecimalForm {
+    /// NR1: an integer, e.g. `-42`.
+    Nr1 = 1,
+
+    /// NR2: a number with a decimal mark, e.g. `-4.2`.
+    Nr2 = 2,
+
+    /// NR3: a number with a decimal mark and an exponent, e.g. `-4.2E+1`.
+    Nr3 = 3,
+}
+
+impl TryFrom<u8> for DecimalForm {
+    type Error = Error;
+
+    fn try_from(byte: u8) -> Result<Self> {
+        match byte {
+            1 => Ok(DecimalForm::Nr1),
+            2 => Ok(DecimalForm::Nr2),
+            3 => Ok(DecimalForm::Nr3),
+            _ => Err(Tag::Real.value_error()),
+        }
+    }
+}
+
+/// ASN.1 `REAL` value in decimal form (X.690 Section 8.5.8).
+///
+/// Unlike [`f64`], this type represents decimal values such as `0.1` exactly, by keeping the
+/// ISO 6093 text it was constructed with or decoded from. Values are encoded in the canonical
+/// NR3 form described in X.690 Section 11.3.2, e.g. `-4.20` is encoded as `-42.E-1`.
+///
+/// Equality compares the text rather than the value, so `1.0` and `1` aren't equal.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RealDecimalRef<'a> {
+    /// Numerical representation of `text`.
+    form: DecimalForm,
+
+    /// ISO 6093 text.
+    text: &'a str,
+
+    /// Is the value negative?
+    negative: bool,
+
+    /// Digits before the decimal mark.
+    integer: &'a str,
+
+    /// Digits after the decimal mark.
+    fraction: &'a str,
+
+    /// Power of 10 to multiply the digits by.
+    exponent: i64,
+}
+
+impl<'a> RealDecimalRef<'a> {
+    /// Plus zero, which has no decimal encoding.
+    const ZERO: Self = Self {
+        form: DecimalForm::Nr1,
+        text: "0",
+        negative: false,
+        integer: "0",
+        fraction: "",
+        exponent: 0,
+    };
+
+    /// Minus zero, which has no decimal encoding.
+    const MINUS_ZERO: Self = Self {
+        form: DecimalForm::Nr1,
+        text: "-0",
+        negative: true,
+        integer: "0",
+        fraction: "",
+        exponent: 0,
+    };
+
+    /// Create a new decimal value from ISO 6093 text, whose form is inferred: NR3 if it has an
+    /// exponent, NR2 if it has a decimal mark, and NR1 otherwise.
+    pub fn new(text: &'a str) -> Result<Self> {
+        let form = if text.contains(['E', 'e']) {
+            DecimalForm::Nr3
+        } else if text.contains(['.', ',']) {
+            DecimalForm::Nr2
+        } else {
+            DecimalForm::Nr1
+        };
+
+        Self::from_form(form, text)
+    }
+
+    /// Parse ISO 6093 text in the given form.
+    pub(super) fn from_form(form: DecimalForm, text: &'a str) -> Result<Self> {
+        let s = text.trim_start_matches(' ');
+
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+
+        let (mantissa, exponent) = match form {
+            DecimalForm::Nr3 => s.split_once(['E', 'e']).ok_or(Tag::Real.value_error())?,
+            _ => (s, ""),
+        };
+
+        let (integer, fraction) = match form {
+            DecimalForm::Nr1 => (mantissa, ""),
+            _ => mantissa
+                .split_once(['.', ','])
+                .ok_or(Tag::Real.value_error())?,
+        };
+
+        let is_digits = |s: &str| s.bytes().all(|byte| byte.is_ascii_digit());
+
+        if !is_digits(integer) || !is_digits(fraction) || integer.len() + fraction.len() == 0 {
+            return Err(Tag::Real.value_error());
+        }
+
+        let exponent = match form {
+            DecimalForm::Nr3 => {
+                let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+
+                if digits.is_empty() || !is_digits(digits) {
+                    return Err(Tag::Real.value_error());
+                }
+
+                exponent.parse().map_err(|_| Tag::Real.value_error())?
+            }
+            _ => 0,
+        };
+
+        Ok(Self {
+            form,
+            text,
+            negative,
+            integer,
+            fraction,
+            exponent,
+        })
+    }
+
+    /// Get the numerical representation of this value.
+    pub fn form(&self) -> DecimalForm {
+        self.form
+    }
+
+    /// Get the ISO 6093 text of this value.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Is this value zero, either plus or minus?
+    pub fn is_zero(&self) -> bool {
+        self.digits().all(|digit| digit == b'0')
+    }
+
+    /// Digits of the mantissa, ignoring the decimal mark.
+    fn digits(&self) -> impl DoubleEndedIterator<Item = u8> + 'a {
+        self.integer.bytes().chain(self.fraction.bytes())
+    }
+
+    /// Compute the canonical NR3 form of this non-zero value (X.690 Section 11.3.2): the number
+    /// of leading zero digits to skip, the number of significant digits, and the exponent.
+    fn canonical(&self) -> Result<(usize, usize, i64)> {
+        let leading_zeros = self.digits().take_while(|&digit| digit == b'0').count();
+        let trailing_zeros = self
+            .digits()
+            .rev()
+            .take_while(|&digit| digit == b'0')
+            .count();
+        let len = self.integer.len() + self.fraction.len() - leading_zeros - trailing_zeros;
+
+        let exponent = i64::try_from(trailing_zeros)
+            .ok()
+            .zip(i64::try_from(self.fraction.len()).ok())
+            .and_then(|(zeros, fraction)| self.exponent.checked_add(zeros)?.checked_sub(fraction))
+            .ok_or(Tag::Real.value_error())?;
+
+        Ok((leading_zeros, len, exponent))
+    }
+
+    /// Parse this value as a floating point number, rounding to the nearest value.
+    fn parse<T: FromStr>(&self) -> Result<T> {
+        let text = self.text.trim_start_matches(' ');
+        let mut buffer = [0u8; MAX_PARSE_LEN];
+
+        // Rust only supports `.` as a decimal mark
+        let text = match text.find(',') {
+            Some(pos) => {
+                let buffer = buffer
+                    .get_mut(..text.len())
+                    .ok_or(Tag::Real.value_error())?;
+
+                buffer.copy_from_slice(text.as_bytes());
+                buffer[pos] = b'.';
+                core::str::from_utf8(buffer).map_err(|_| Tag::Real.value_error())?
+            }
+            None => text,
+        };
+
+        text.parse().map_err(|_| Tag::Real.value_error())
+    }
+}
+
+/// Decimal digits of the absolute value of an exponent, returned as a buffer along with the
+/// offset of its used suffix.
+#[allow(clippy::cast_possible_truncation)]
+fn exponent_digits(exponent: i64) -> ([u8; 20], usize) {
+    let mut buffer = [0u8; 20];
+    let mut pos = buffer.len();
+    let mut n = exponent.unsigned_abs();
+
+    loop {
+        pos -= 1;
+        buffer[pos] = b'0' + (n % 10) as u8;
+        n /= 10;
+
+        if n == 0 {
+            return (buffer, pos);
+        }
+    }
+}
+
+impl<'a> DecodeValue<'a> for RealDecimalRef<'a> {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        match RealValue::decode(reader, header)? {
+            RealValue::Decimal(decimal) => Ok(decimal),
+            RealValue::Zero => Ok(Self::ZERO),
+            RealValue::Special(0.0) => Ok(Self::MINUS_ZERO),
+            _ => Err(Tag::Real.value_error()),
+        }
+    }
+}
+
+impl EncodeValue for RealDecimalRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        if self.is_zero() {
+            return Ok(if self.negative {
+                Length::ONE
+            } else {
+                Length::ZERO
+            });
+        }
+
+        let (_, len, exponent) = self.canonical()?;
+        let exponent_len = match exponent {
+            0 => 2,
+            _ => 20 - exponent_digits(exponent).1 + usize::from(exponent < 0),
+        };
+
+        // Form, sign, digits, `.E` and exponent
+        Length::try_from(1 + usize::from(self.negative) + len + 2 + exponent_len)
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        if self.is_zero() {
+            return if self.negative {
+                writer.write_byte(MINUS_ZERO)
+            } else {
+                Ok(())
+            };
+        }
+
+        let (leading_zeros, len, exponent) = self.canonical()?;
+        writer.write_byte(DecimalForm::Nr3 as u8)?;
+
+        if self.negative {
+            writer.write_byte(b'-')?;
+        }
+
+        for digit in self.digits().skip(leading_zeros).take(len) {
+            writer.write_byte(digit)?;
+        }
+
+        writer.write(b".E")?;
+
+        if exponent == 0 {
+            return writer.write(b"+0");
+        }
+
+        if exponent < 0 {
+            writer.write_byte(b'-')?;
+        }
+
+        let (buffer, pos) = exponent_digits(exponent);
+        writer.write(&buffer[pos..])
+    }
+}
+
+impl FixedTag for RealDecimalRef<'_> {
+    const TAG: Tag = Tag::Real;
+}
+
+impl TryFrom<RealDecimalRef<'_>> for f64 {
+    type Error = Error;
+
+    fn try_from(decimal: RealDecimalRef<'_>) -> Result<f64> {
+        let value: f64 = decimal.parse()?;
+
+        if value.is_infinite() {
+            return Err(Tag::Real.value_error());
+        }
+
+        Ok(value)
+    }
+}
+
+impl TryFrom<RealDecimalRef<'_>> for f32 {
+    type Error = Error;
+
+    fn try_from(decimal: RealDecimalRef<'_>) -> Result<f32> {
+        let value: f32 = decimal.parse()?;
+
+        if value.is_infinite() {
+            return Err(Tag::Real.value_error());
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{DecimalForm, RealDecimalRef};
+    use crate::{Decode, Encode};
+    use hex_literal::hex;
+
+    #[test]
+    fn new() {
+        for (text, form) in [
+            ("42", DecimalForm::Nr1),
+            (" -42", DecimalForm::Nr1),
+            ("4.2", DecimalForm::Nr2),
+            ("+4,", DecimalForm::Nr2),
+            (".2", DecimalForm::Nr2),
+            ("4.2E-1", DecimalForm::Nr3),
+            ("4,e+10", DecimalForm::Nr3),
+        ] {
+            let decimal = RealDecimalRef::new(text).unwrap();
+            assert_eq!(decimal.form(), form, "{text}");
+            assert_eq!(decimal.as_str(), text);
+        }
+
+        for text in [
+            "",
+            "-",
+            ".",
+            "4.2.1",
+            "4E1",
+            "4.2E",
+            "4.2E+",
+            "1.e99999999999999999999",
+            "inf",
+        ] {
+            assert!(RealDecimalRef::new(text).is_err(), "{text}");
+        }
+    }
+
+    #[test]
+    fn encode_canonical() {
+        for (text, der) in [
+            ("-4.20", &hex!("09 08 03 2D 34 32 2E 45 2D 31")[..]),
+            ("00101", &hex!("09 08 03 31 30 31 2E 45 2B 30")),
+            ("1000", &hex!("09 05 03 31 2E 45 33")),
+            (".05E-2", &hex!("09 06 03 35 2E 45 2D 34")),
+            ("0.00", &hex!("09 00")),
+            ("-0", &hex!("09 01 43")),
+        ] {
+            let decimal = RealDecimalRef::new(text).unwrap();
+            assert_eq!(decimal.to_der().unwrap(), der, "{text}");
+        }
+    }
+
+    #[test]
+    fn decode() {
+        let decimal = RealDecimalRef::from_ber(&hex!("09 06 02 20 31 2C 32 35")).unwrap();
+        assert_eq!(decimal.form(), DecimalForm::Nr2);
+        assert_eq!(decimal.as_str(), " 1,25");
+        assert_eq!(f64::try_from(decimal).unwrap(), 1.25);
+        assert_eq!(f32::try_from(decimal).unwrap(), 1.25);
+
+        assert!(RealDecimalRef::from_der(&hex!("09 00")).unwrap().is_zero());
+        assert!(RealDecimalRef::from_der(&hex!("09 03 80 00 01")).is_err());
+    }
+}
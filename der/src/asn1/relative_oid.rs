@@ -0,0 +1,101 @@
+//! ASN.1 `RELATIVE-OID`
+
+use crate::{
+    DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result, Tag, Tagged, Writer,
+    asn1::AnyRef, ord::OrdIsValueOrd,
+};
+use const_oid::RelativeOid;
+
+#[cfg(feature = "alloc")]
+use super::Any;
+
+impl<'a> DecodeValue<'a> for RelativeOid {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        let mut buf = [0u8; RelativeOid::MAX_SIZE];
+        let slice = buf
+            .get_mut(..header.length.try_into()?)
+            .ok_or_else(|| Self::TAG.length_error())?;
+
+        let actual_len = reader.read_into(slice)?.len();
+        debug_assert_eq!(actual_len, header.length.try_into()?);
+        Ok(Self::from_bytes(slice)?)
+    }
+}
+
+impl EncodeValue for RelativeOid {
+    fn value_len(&self) -> Result<Length> {
+        Length::try_from(self.as_bytes().len())
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+impl FixedTag for RelativeOid {
+    const TAG: Tag = Tag::RelativeOid;
+}
+
+impl OrdIsValueOrd for RelativeOid {}
+
+impl<'a> From<&'a RelativeOid> for AnyRef<'a> {
+    fn from(oid: &'a RelativeOid) -> AnyRef<'a> {
+        // Note: as with `ObjectIdentifier`, this relies on the invariant that
+        // `RelativeOid::MAX_SIZE <= Length::max()`, checked by the `length()` test below.
+        let value = oid
+            .as_bytes()
+            .try_into()
+            .expect("relative OID length invariant violated");
+
+        AnyRef::from_tag_and_value(Tag::RelativeOid, value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<RelativeOid> for Any {
+    fn from(oid: RelativeOid) -> Any {
+        AnyRef::from(&oid).into()
+    }
+}
+
+impl TryFrom<AnyRef<'_>> for RelativeOid {
+    type Error = Error;
+
+    fn try_from(any: AnyRef<'_>) -> Result<RelativeOid> {
+        any.tag().assert_eq(Tag::RelativeOid)?;
+        Ok(RelativeOid::from_bytes(any.value())?)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::RelativeOid;
+    use crate::{Decode, Encode, Length};
+
+    const EXAMPLE_OID: RelativeOid = RelativeOid::new_unwrap("8571.3.2");
+    const EXAMPLE_OID_BYTES: &[u8; 6] = &[0x0d, 0x04, 0xc2, 0x7b, 0x03, 0x02];
+
+    #[test]
+    fn decode() {
+        let oid = RelativeOid::from_der(EXAMPLE_OID_BYTES).unwrap();
+        assert_eq!(EXAMPLE_OID, oid);
+    }
+
+    #[test]
+    fn encode() {
+        let mut buffer = [0u8; 6];
+        assert_eq!(
+            EXAMPLE_OID_BYTES,
+            EXAMPLE_OID.encode_to_slice(&mut buffer).unwrap()
+        );
+    }
+
+    #[test]
+    fn length() {
+        // Ensure an infallible `From` conversion to `Any` will never panic
+        assert!(RelativeOid::MAX_SIZE <= Length::MAX.try_into().unwrap());
+    }
+}
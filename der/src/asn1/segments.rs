@@ -0,0 +1,323 @@
+//! Support for BER constructed-form string types.
+//!
+//! BER allows `OCTET STRING` and `BIT STRING` values to be split into segments using the
+//! constructed form (X.690 Sections 8.6.3 and 8.7.3), where each segment is itself either a
+//! primitive or constructed encoding of the same type. DER always uses the primitive form.
+
+use crate::{
+    Decode, DecodeValue, EncodingRules, Error, FixedTag, Header, Length, Reader, Result,
+    SliceReader, Tag,
+    length::indefinite::{peek_eoc, read_eoc},
+};
+use core::{iter::FusedIterator, marker::PhantomData};
+
+/// Maximum nesting depth of constructed segments.
+///
+/// Constructed segments may in turn contain constructed segments. In practice producers only use a
+/// single level of segmentation, so a small limit is used to bound the amount of recursion.
+const MAX_DEPTH: usize = 8;
+
+/// Zero-copy iterator over the segments of a BER-encoded string type.
+///
+/// Yields each primitive segment of a constructed `OCTET STRING` or `BIT STRING` in order,
+/// borrowing from the input, which allows callers to e.g. hash or decrypt large contents chunk by
+/// chunk without reassembling them first. Nested constructed segments are flattened. A value
+/// encoded using the primitive form yields a single segment.
+///
+/// `T` is the type of each segment, e.g. [`OctetStringRef`][`crate::asn1::OctetStringRef`] or
+/// [`BitStringRef`][`crate::asn1::BitStringRef`]. Note that for `BIT STRING` only the final
+/// segment may contain unused bits.
+///
+/// This type implements [`Decode`][`crate::Decode`] with the same tag as `T`, so it can be
+/// decoded directly from a BER message, e.g. using [`Decode::from_ber`][`crate::Decode::from_ber`].
+/// Decoding it requires a reader which supports [`Reader::read_slice`].
+#[derive(Clone, Debug)]
+pub struct StringSegments<'a, T> {
+    /// Reader over the contents of the value.
+    reader: SliceReader<'a>,
+
+    /// Header of a value encoded using the primitive form, which is yielded as a single segment.
+    primitive: Option<Header>,
+
+    /// End positions of the nested constructed segments currently being iterated over, or `None`
+    /// for ones which use the indefinite length form and end at their end-of-contents octets.
+    nested: [Option<Length>; MAX_DEPTH],
+
+    /// Number of entries in `nested` which are in use.
+    depth: usize,
+
+    /// Has iteration completed, either successfully or due to an error?
+    finished: bool,
+
+    /// Segment type.
+    segment: PhantomData<T>,
+}
+
+impl<'a, T> StringSegments<'a, T>
+where
+    T: DecodeValue<'a> + FixedTag,
+{
+    /// Create a new iterator over the contents of a value with the given [`Header`].
+    pub fn new(header: Header, contents: &'a [u8]) -> Result<Self> {
+        let reader = SliceReader::new_with_encoding_rules(contents, EncodingRules::Ber)?;
+
+        if !header.is_indefinite() && reader.input_len() != header.length {
+            return Err(T::TAG.length_error());
+        }
+
+        Ok(Self {
+            reader,
+            primitive: (!header.is_constructed()).then_some(header),
+            nested: [None; MAX_DEPTH],
+            depth: 0,
+            finished: false,
+            segment: PhantomData,
+        })
+    }
+
+    /// Decode the next segment, if any.
+    fn next_segment(&mut self) -> core::result::Result<Option<T>, T::Error> {
+        if let Some(header) = self.primitive.take() {
+            self.finished = true;
+            return self
+                .reader
+                .read_nested(header.length, |r| T::decode_value(r, header))
+                .map(Some);
+        }
+
+        loop {
+            self.leave_finished_segments()?;
+
+            if self.reader.is_finished() {
+                // Nested segments must be terminated before the end of the contents
+                if self.depth > 0 {
+                    return Err(T::TAG.length_error().into());
+                }
+
+                self.finished = true;
+                return Ok(None);
+            }
+
+            let header = Header::decode(&mut self.reader)?;
+            header.tag.assert_eq(T::TAG)?;
+
+            if !header.is_constructed() {
+                return self
+                    .reader
+                    .read_nested(header.length, |r| T::decode_value(r, header))
+                    .map(Some);
+            }
+
+            // Descend into a nested constructed segment
+            let end = if header.is_indefinite() {
+                None
+            } else {
+                Some((self.reader.position() + header.length)?)
+            };
+
+            let entry = self
+                .nested
+                .get_mut(self.depth)
+                .ok_or_else(|| T::TAG.value_error())?;
+
+            *entry = end;
+            self.depth += 1;
+        }
+    }
+
+    /// Leave any nested constructed segments whose contents have been fully consumed, reading
+    /// their end-of-contents octets if they use the indefinite length form.
+    fn leave_finished_segments(&mut self) -> Result<()> {
+        while let Some(&end) = self.depth.checked_sub(1).and_then(|d| self.nested.get(d)) {
+            match end {
+                Some(end) => {
+                    let position = self.reader.position();
+
+                    if position < end {
+                        break;
+                    } else if position > end {
+                        return Err(T::TAG.length_error());
+                    }
+                }
+                None if peek_eoc(&self.reader) => read_eoc(&mut self.reader)?,
+                None => break,
+            }
+
+            self.depth -= 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T> DecodeValue<'a> for StringSegments<'a, T>
+where
+    T: DecodeValue<'a> + FixedTag,
+{
+    type Error = Error;
+    const INDEFINITE_LENGTH: bool = true;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        if header.is_constructed() && !reader.encoding_rules().is_ber() {
+            return Err(T::TAG.non_canonical_error());
+        }
+
+        let len = header.value_len(reader)?;
+        let contents = reader.read_slice(len)?;
+        Self::new(header, contents)
+    }
+}
+
+impl<T: FixedTag> FixedTag for StringSegments<'_, T> {
+    const TAG: Tag = T::TAG;
+}
+
+impl<'a, T> Iterator for StringSegments<'a, T>
+where
+    T: DecodeValue<'a> + FixedTag,
+{
+    type Item = core::result::Result<T, T::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let result = self.next_segment();
+
+        if result.is_err() {
+            self.finished = true;
+        }
+
+        result.transpose()
+    }
+}
+
+impl<'a, T> FusedIterator for StringSegments<'a, T> where T: DecodeValue<'a> + FixedTag {}
+
+/// Read the contents of a value of the string type identified by `tag`, calling the provided
+/// callback with the header of each primitive segment.
+///
+/// Values which use the constructed form are only accepted when using BER.
+///
+/// The callback is responsible for reading the contents of each segment, and is called exactly
+/// once for a value which uses the primitive form.
+#[cfg(feature = "alloc")]
+pub(crate) fn read_segments<'a, R, F>(
+    reader: &mut R,
+    header: Header,
+    tag: Tag,
+    mut f: F,
+) -> Result<()>
+where
+    R: Reader<'a>,
+    F: FnMut(&mut R, Header) -> Result<()>,
+{
+    if !header.is_constructed() {
+        return reader.read_nested(header.length, |r| f(r, header));
+    }
+
+    if !reader.encoding_rules().is_ber() {
+        return Err(tag.non_canonical_error());
+    }
+
+    reader.read_contents(header, |r| read_nested_segments(r, tag, 0, &mut f))
+}
+
+/// Read the segments within a constructed value, recursing into nested constructed segments.
+#[cfg(feature = "alloc")]
+fn read_nested_segments<'a, R, F>(reader: &mut R, tag: Tag, depth: usize, f: &mut F) -> Result<()>
+where
+    R: Reader<'a>,
+    F: FnMut(&mut R, Header) -> Result<()>,
+{
+    if depth >= MAX_DEPTH {
+        return Err(tag.value_error());
+    }
+
+    while !reader.is_finished() {
+        let header = Header::decode(reader)?;
+        header.tag.assert_eq(tag)?;
+
+        if header.is_constructed() {
+            reader.read_value(header, |r| read_nested_segments(r, tag, depth + 1, f))?;
+        } else {
+            reader.read_nested(header.length, |r| f(r, header))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::StringSegments;
+    use crate::{
+        Decode, ErrorKind,
+        asn1::{BitStringRef, OctetStringRef},
+    };
+    use hex_literal::hex;
+
+    /// Constructed `OCTET STRING` with an indefinite length and two segments.
+    const CONSTRUCTED_OCTET_STRING: &[u8] = &hex!("2480 0402AABB 0401CC 0000");
+
+    #[test]
+    fn octet_string_segments() {
+        let mut segments =
+            StringSegments::<OctetStringRef<'_>>::from_ber(CONSTRUCTED_OCTET_STRING).unwrap();
+
+        assert_eq!(segments.next().unwrap().unwrap().as_bytes(), &hex!("AABB"));
+        assert_eq!(segments.next().unwrap().unwrap().as_bytes(), &hex!("CC"));
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn nested_segments() {
+        let mut segments =
+            StringSegments::<OctetStringRef<'_>>::from_ber(&hex!("240A 2403 0401AA 0403BBCCDD"))
+                .unwrap();
+
+        assert_eq!(segments.next().unwrap().unwrap().as_bytes(), &hex!("AA"));
+        assert_eq!(
+            segments.next().unwrap().unwrap().as_bytes(),
+            &hex!("BBCCDD")
+        );
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn primitive_segment() {
+        let mut segments =
+            StringSegments::<OctetStringRef<'_>>::from_der(&hex!("0402AABB")).unwrap();
+
+        assert_eq!(segments.next().unwrap().unwrap().as_bytes(), &hex!("AABB"));
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn bit_string_segments() {
+        let mut segments =
+            StringSegments::<BitStringRef<'_>>::from_ber(&hex!("2380 030200AA 030206C0 0000"))
+                .unwrap();
+
+        assert_eq!(segments.next().unwrap().unwrap().raw_bytes(), &hex!("AA"));
+        assert_eq!(segments.next().unwrap().unwrap().unused_bits(), 6);
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn mismatched_segment_tag() {
+        let mut segments =
+            StringSegments::<OctetStringRef<'_>>::from_ber(&hex!("2480 0201AA 0000")).unwrap();
+
+        let err = segments.next().unwrap().unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TagUnexpected { .. }));
+        assert!(segments.next().is_none());
+    }
+
+    #[test]
+    fn reject_constructed_der() {
+        assert!(StringSegments::<OctetStringRef<'_>>::from_der(CONSTRUCTED_OCTET_STRING).is_err());
+    }
+}
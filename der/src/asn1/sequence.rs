@@ -48,6 +48,7 @@ impl AsRef<[u8]> for SequenceRef<'_> {
 
 impl<'a> DecodeValue<'a> for SequenceRef<'a> {
     type Error = Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
         Ok(Self {
@@ -1,8 +1,8 @@
 //! ASN.1 `SEQUENCE OF` support.
 
 use crate::{
-    ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue, Error, FixedTag, Header, Length,
-    Reader, Tag, ValueOrd, Writer, arrayvec, ord::iter_cmp,
+    ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue, Error, FixedLen, FixedTag, Header,
+    Length, Reader, Tag, ValueOrd, Writer, arrayvec, ord::iter_cmp,
 };
 use core::cmp::Ordering;
 
@@ -67,13 +67,21 @@ where
     T: Decode<'a>,
 {
     type Error = T::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Self::Error> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             let mut sequence_of = Self::new();
 
             while !reader.is_finished() {
-                sequence_of.add(T::decode(reader)?)?;
+                let index = sequence_of.len();
+                reader
+                    .limits()
+                    .check_elements(index + 1)
+                    .map_err(|kind| reader.error(kind))?;
+                let elem = T::decode(reader)
+                    .map_err(|err| Error::annotate(err, |err| err.in_element(index)))?;
+                sequence_of.add(elem)?;
             }
 
             Ok(sequence_of)
@@ -134,6 +142,7 @@ where
     T: Decode<'a>,
 {
     type Error = T::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Self::Error> {
         let sequence_of = SequenceOf::<T, N>::decode_value(reader, header)?;
@@ -172,6 +181,13 @@ impl<T, const N: usize> FixedTag for [T; N] {
     const TAG: Tag = Tag::Sequence;
 }
 
+impl<T, const N: usize> FixedLen for [T; N]
+where
+    T: FixedLen,
+{
+    const ENCODED_LEN: Length = T::ENCODED_LEN.mul_const(N).for_tlv_const(Self::TAG);
+}
+
 impl<T, const N: usize> ValueOrd for [T; N]
 where
     T: DerOrd,
@@ -187,13 +203,22 @@ where
     T: Decode<'a>,
 {
     type Error = T::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Self::Error> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             let mut sequence_of = Self::new();
 
             while !reader.is_finished() {
-                sequence_of.push(T::decode(reader)?);
+                let index = sequence_of.len();
+                reader
+                    .limits()
+                    .check_elements(index + 1)
+                    .map_err(|kind| reader.error(kind))?;
+                sequence_of.push(
+                    T::decode(reader)
+                        .map_err(|err| Error::annotate(err, |err| err.in_element(index)))?,
+                );
             }
 
             Ok(sequence_of)
@@ -8,11 +8,12 @@
 //! of that, we must also follow suit.
 //!
 //! However, all types in this module sort elements of a set at decode-time,
-//! ensuring they'll be in the proper order if reserialized.
+//! ensuring they'll be in the proper order if reserialized, and report this
+//! to the reader as [`DecodeQuirk::UnsortedSetOf`].
 
 use crate::{
-    ArrayVec, Decode, DecodeValue, DerOrd, Encode, EncodeValue, Error, ErrorKind, FixedTag, Header,
-    Length, Reader, Tag, ValueOrd, Writer, arrayvec, ord::iter_cmp,
+    ArrayVec, Decode, DecodeQuirk, DecodeValue, DerOrd, Encode, EncodeValue, Error, ErrorKind,
+    FixedTag, Header, Length, Reader, Tag, ValueOrd, Writer, arrayvec, ord::iter_cmp,
 };
 use core::cmp::Ordering;
 
@@ -109,17 +110,25 @@ where
     T: Decode<'a> + DerOrd,
 {
     type Error = T::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Self::Error> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             let mut result = Self::new();
 
             while !reader.is_finished() {
-                result.inner.push(T::decode(reader)?)?;
+                let index = result.inner.len();
+                reader
+                    .limits()
+                    .check_elements(index + 1)
+                    .map_err(|kind| reader.error(kind))?;
+                let elem = T::decode(reader)
+                    .map_err(|err| Error::annotate(err, |err| err.in_element(index)))?;
+                result.inner.push(elem)?;
             }
 
             // Ensure elements of the `SetOf` are sorted and will serialize as valid DER
-            der_sort(result.inner.as_mut())?;
+            sort_decoded(reader, result.inner.as_mut())?;
             Ok(result)
         })
     }
@@ -329,16 +338,25 @@ where
     T: Decode<'a> + DerOrd,
 {
     type Error = T::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Self::Error> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             let mut inner = Vec::new();
 
             while !reader.is_finished() {
-                inner.push(T::decode(reader)?);
+                let index = inner.len();
+                reader
+                    .limits()
+                    .check_elements(index + 1)
+                    .map_err(|kind| reader.error(kind))?;
+                inner.push(
+                    T::decode(reader)
+                        .map_err(|err| Error::annotate(err, |err| err.in_element(index)))?,
+                );
             }
 
-            der_sort(inner.as_mut())?;
+            sort_decoded(reader, inner.as_mut())?;
             Ok(Self { inner })
         })
     }
@@ -443,6 +461,22 @@ fn check_der_ordering<T: DerOrd>(a: &T, b: &T) -> Result<(), Error> {
     }
 }
 
+/// Sort the elements of a decoded `SET OF`, recording [`DecodeQuirk::UnsortedSetOf`] if they
+/// weren't sorted to begin with.
+fn sort_decoded<'a, R: Reader<'a>, T: DerOrd>(
+    reader: &mut R,
+    slice: &mut [T],
+) -> Result<(), Error> {
+    if slice
+        .windows(2)
+        .any(|pair| matches!(pair[0].der_cmp(&pair[1]), Ok(Ordering::Greater)))
+    {
+        reader.record_quirk(DecodeQuirk::UnsortedSetOf);
+    }
+
+    der_sort(slice)
+}
+
 /// Sort a mut slice according to its [`DerOrd`], returning any errors which
 /// might occur during the comparison.
 ///
@@ -453,7 +487,7 @@ fn check_der_ordering<T: DerOrd>(a: &T, b: &T) -> Result<(), Error> {
 /// to support heapless `no_std` targets as well as to enable bubbling up
 /// sorting errors.
 #[allow(clippy::arithmetic_side_effects)]
-fn der_sort<T: DerOrd>(slice: &mut [T]) -> Result<(), Error> {
+pub(crate) fn der_sort<T: DerOrd>(slice: &mut [T]) -> Result<(), Error> {
     for i in 0..slice.len() {
         let mut j = i;
 
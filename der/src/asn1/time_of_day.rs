@@ -0,0 +1,149 @@
+//! ASN.1 `TIME-OF-DAY` support.
+
+use crate::{
+    DecodeValue, EncodeValue, Error, ErrorKind, FixedLen, FixedTag, Header, Length, Reader, Result,
+    Tag, Writer, datetime, ord::OrdIsValueOrd,
+};
+use core::fmt;
+
+/// ASN.1 `TIME-OF-DAY` type.
+///
+/// Local time of day in whole seconds (X.680 Section 38.4.2), encoded as `HHMMSS`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    /// Hour (0-23).
+    hour: u8,
+
+    /// Minutes (0-59).
+    minutes: u8,
+
+    /// Seconds (0-59).
+    seconds: u8,
+}
+
+impl TimeOfDay {
+    /// Length of an ASN.1 DER-encoded [`TimeOfDay`].
+    pub const LENGTH: usize = 6;
+
+    /// Create a new [`TimeOfDay`] from the given components.
+    pub const fn new(hour: u8, minutes: u8, seconds: u8) -> Result<Self> {
+        if hour > 23 || minutes > 59 || seconds > 59 {
+            return Err(Error::from_kind(ErrorKind::DateTime));
+        }
+
+        Ok(Self {
+            hour,
+            minutes,
+            seconds,
+        })
+    }
+
+    /// Get the hour.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Get the minutes.
+    pub fn minutes(&self) -> u8 {
+        self.minutes
+    }
+
+    /// Get the seconds.
+    pub fn seconds(&self) -> u8 {
+        self.seconds
+    }
+
+    /// Decode the `HHMMSS` digits of a time which is part of a value with the given tag.
+    pub(crate) fn decode_digits(tag: Tag, bytes: [u8; Self::LENGTH]) -> Result<Self> {
+        let [hour1, hour2, min1, min2, sec1, sec2] = bytes;
+        let hour = datetime::decode_decimal(tag, hour1, hour2)?;
+        let minutes = datetime::decode_decimal(tag, min1, min2)?;
+        let seconds = datetime::decode_decimal(tag, sec1, sec2)?;
+        Self::new(hour, minutes, seconds).map_err(|_| tag.value_error())
+    }
+
+    /// Encode the `HHMMSS` digits of a time which is part of a value with the given tag.
+    pub(crate) fn encode_digits(&self, tag: Tag, writer: &mut impl Writer) -> Result<()> {
+        datetime::encode_decimal(writer, tag, self.hour)?;
+        datetime::encode_decimal(writer, tag, self.minutes)?;
+        datetime::encode_decimal(writer, tag, self.seconds)
+    }
+}
+
+impl_any_conversions!(TimeOfDay);
+
+impl<'a> DecodeValue<'a> for TimeOfDay {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        if Self::LENGTH != usize::try_from(header.length)? {
+            return Err(Self::TAG.length_error());
+        }
+
+        let mut bytes = [0u8; Self::LENGTH];
+        reader.read_into(&mut bytes)?;
+        Self::decode_digits(Self::TAG, bytes)
+    }
+}
+
+impl EncodeValue for TimeOfDay {
+    fn value_len(&self) -> Result<Length> {
+        Self::LENGTH.try_into()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.encode_digits(Self::TAG, writer)
+    }
+}
+
+impl FixedTag for TimeOfDay {
+    const TAG: Tag = Tag::TimeOfDay;
+}
+
+impl FixedLen for TimeOfDay {
+    #[allow(clippy::cast_possible_truncation)]
+    const ENCODED_LEN: Length = Length::new(Self::LENGTH as u16).for_tlv_const(Self::TAG);
+}
+
+impl OrdIsValueOrd for TimeOfDay {}
+
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            self.hour, self.minutes, self.seconds
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::TimeOfDay;
+    use crate::{Decode, Encode, ErrorKind, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn round_trip() {
+        let example_bytes = hex!("1F20 06 32 33 35 39 30 31");
+        let time = TimeOfDay::from_der(&example_bytes).unwrap();
+        assert_eq!(time, TimeOfDay::new(23, 59, 1).unwrap());
+
+        let mut buf = [0u8; 9];
+        assert_eq!(time.encode_to_slice(&mut buf).unwrap(), example_bytes);
+    }
+
+    #[test]
+    fn invalid_times() {
+        assert!(TimeOfDay::new(24, 0, 0).is_err());
+
+        let err = TimeOfDay::from_der(&hex!("1F20 06 31 32 36 30 30 30")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::Value {
+                tag: Tag::TimeOfDay
+            }
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! ASN.1 `UniversalString` support.
+
+use crate::{
+    BytesRef, DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Result, Tag,
+    Writer, asn1::AnyRef, constraint::Size, ord::OrdIsValueOrd,
+};
+use core::fmt;
+
+/// ASN.1 `UniversalString` type.
+///
+/// Encodes the full Unicode (ISO 10646) character set as UCS-4, i.e. each character as its
+/// code point in four big endian bytes.
+///
+/// This is a zero-copy reference type which borrows the UCS-4 encoding from the input data.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct UniversalStringRef<'a> {
+    /// UCS-4 encoding of the string.
+    inner: BytesRef<'a>,
+}
+
+impl<'a> UniversalStringRef<'a> {
+    /// Create a new ASN.1 `UniversalString` from its UCS-4 encoding.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        if input.len() % 4 != 0 {
+            return Err(Tag::UniversalString.length_error());
+        }
+
+        // Validate all code points are Unicode scalar values
+        if code_points(input).any(|code_point| char::from_u32(code_point).is_none()) {
+            return Err(Tag::UniversalString.value_error());
+        }
+
+        BytesRef::new(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Tag::UniversalString.length_error())
+    }
+
+    /// Borrow the encoded UCS-4 as bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.inner.as_slice()
+    }
+
+    /// Get an iterator over characters in the string.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        chars(self.as_bytes())
+    }
+}
+
+impl_any_conversions!(UniversalStringRef<'a>, 'a);
+
+impl AsRef<[u8]> for UniversalStringRef<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'a> DecodeValue<'a> for UniversalStringRef<'a> {
+    type Error = Error;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        Self::new(BytesRef::decode_value(reader, header)?.as_slice())
+    }
+}
+
+impl EncodeValue for UniversalStringRef<'_> {
+    fn value_len(&self) -> Result<Length> {
+        Ok(self.inner.len())
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.as_bytes())
+    }
+}
+
+impl FixedTag for UniversalStringRef<'_> {
+    const TAG: Tag = Tag::UniversalString;
+}
+
+impl OrdIsValueOrd for UniversalStringRef<'_> {}
+
+impl Size for UniversalStringRef<'_> {
+    fn size(&self) -> usize {
+        self.as_bytes().len() / 4
+    }
+}
+
+impl<'a> From<UniversalStringRef<'a>> for AnyRef<'a> {
+    fn from(universal_string: UniversalStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::UniversalString, universal_string.inner)
+    }
+}
+
+impl fmt::Debug for UniversalStringRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UniversalString(\"{}\")", self)
+    }
+}
+
+impl fmt::Display for UniversalStringRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+/// Get an iterator over the code points of a UCS-4 encoding.
+fn code_points(bytes: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+}
+
+/// Get an iterator over the characters of a validated UCS-4 encoding.
+fn chars(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    code_points(bytes)
+        .map(|code_point| char::from_u32(code_point).expect("code points checked in constructor"))
+}
+
+#[cfg(feature = "alloc")]
+pub use self::allocation::UniversalString;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::{UniversalStringRef, chars};
+
+    use crate::{
+        BytesOwned, BytesRef, DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader,
+        Result, Tag, Writer,
+        asn1::AnyRef,
+        constraint::Size,
+        ord::OrdIsValueOrd,
+        referenced::{OwnedToRef, RefToOwned},
+    };
+    use alloc::{boxed::Box, string::String, vec::Vec};
+    use core::{fmt, str::FromStr};
+
+    /// ASN.1 `UniversalString` type.
+    ///
+    /// Encodes the full Unicode (ISO 10646) character set as UCS-4, i.e. each character as its
+    /// code point in four big endian bytes.
+    ///
+    /// Keeps both the UCS-4 encoding and its UTF-8 equivalent, so it can be borrowed as a
+    /// [`str`] as well as a [`UniversalStringRef`].
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct UniversalString {
+        /// UCS-4 encoding of the string.
+        bytes: BytesOwned,
+
+        /// UTF-8 encoding of the string.
+        utf8: String,
+    }
+
+    impl UniversalString {
+        /// Create a new [`UniversalString`] from its UCS-4 encoding.
+        pub fn from_ucs4(bytes: impl Into<Box<[u8]>>) -> Result<Self> {
+            let bytes = bytes.into();
+            let utf8 = UniversalStringRef::new(&*bytes)?.chars().collect();
+
+            Ok(Self {
+                bytes: bytes.try_into()?,
+                utf8,
+            })
+        }
+
+        /// Create a new [`UniversalString`] from a UTF-8 string.
+        pub fn from_utf8(utf8: &str) -> Result<Self> {
+            let capacity = utf8
+                .chars()
+                .count()
+                .checked_mul(4)
+                .ok_or_else(|| Tag::UniversalString.length_error())?;
+
+            let mut bytes = Vec::with_capacity(capacity);
+
+            for c in utf8.chars() {
+                bytes.extend(u32::from(c).to_be_bytes());
+            }
+
+            Ok(Self {
+                bytes: bytes.try_into()?,
+                utf8: utf8.into(),
+            })
+        }
+
+        /// Borrow the encoded UCS-4 as bytes.
+        pub fn as_bytes(&self) -> &[u8] {
+            self.bytes.as_slice()
+        }
+
+        /// Borrow the string as UTF-8.
+        pub fn as_str(&self) -> &str {
+            &self.utf8
+        }
+
+        /// Get an iterator over characters in the string.
+        pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+            self.utf8.chars()
+        }
+    }
+
+    impl_any_conversions!(UniversalString);
+
+    impl AsRef<str> for UniversalString {
+        fn as_ref(&self) -> &str {
+            self.as_str()
+        }
+    }
+
+    impl AsRef<[u8]> for UniversalString {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl<'a> DecodeValue<'a> for UniversalString {
+        type Error = Error;
+
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            Self::from_ucs4(reader.read_vec(header.length)?)
+        }
+    }
+
+    impl EncodeValue for UniversalString {
+        fn value_len(&self) -> Result<Length> {
+            Ok(self.bytes.len())
+        }
+
+        fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+            writer.write(self.as_bytes())
+        }
+    }
+
+    impl FixedTag for UniversalString {
+        const TAG: Tag = Tag::UniversalString;
+    }
+
+    impl OrdIsValueOrd for UniversalString {}
+
+    impl Size for UniversalString {
+        fn size(&self) -> usize {
+            self.as_bytes().len() / 4
+        }
+    }
+
+    impl<'a> From<UniversalStringRef<'a>> for UniversalString {
+        fn from(value: UniversalStringRef<'a>) -> UniversalString {
+            value.ref_to_owned()
+        }
+    }
+
+    impl<'a> From<&'a UniversalString> for AnyRef<'a> {
+        fn from(universal_string: &'a UniversalString) -> AnyRef<'a> {
+            AnyRef::from_tag_and_value(
+                Tag::UniversalString,
+                BytesRef::new(universal_string.as_bytes()).expect("Invalid UniversalString"),
+            )
+        }
+    }
+
+    impl<'a> From<&'a UniversalString> for UniversalStringRef<'a> {
+        fn from(universal_string: &'a UniversalString) -> UniversalStringRef<'a> {
+            universal_string.owned_to_ref()
+        }
+    }
+
+    impl<'a> RefToOwned<'a> for UniversalStringRef<'a> {
+        type Owned = UniversalString;
+        fn ref_to_owned(&self) -> Self::Owned {
+            UniversalString {
+                bytes: self.inner.into(),
+                utf8: chars(self.as_bytes()).collect(),
+            }
+        }
+    }
+
+    impl OwnedToRef for UniversalString {
+        type Borrowed<'a> = UniversalStringRef<'a>;
+        fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+            UniversalStringRef {
+                inner: self.bytes.owned_to_ref(),
+            }
+        }
+    }
+
+    impl TryFrom<String> for UniversalString {
+        type Error = Error;
+
+        fn try_from(input: String) -> Result<Self> {
+            Self::from_utf8(&input)
+        }
+    }
+
+    impl FromStr for UniversalString {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self> {
+            Self::from_utf8(s)
+        }
+    }
+
+    impl fmt::Debug for UniversalString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "UniversalString({:?})", self.as_str())
+        }
+    }
+
+    impl fmt::Display for UniversalString {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::UniversalStringRef;
+    use crate::{Decode, Tag};
+    use hex_literal::hex;
+
+    const EXAMPLE_BYTES: &[u8] = &hex!("1C 0C 00 00 00 48 00 00 00 E9 00 01 F6 00");
+
+    #[test]
+    fn parse_bytes() {
+        let universal_string = UniversalStringRef::from_der(EXAMPLE_BYTES).unwrap();
+        assert!(universal_string.chars().eq("Hé😀".chars()));
+    }
+
+    #[test]
+    fn reject_invalid() {
+        let err = UniversalStringRef::from_der(&hex!("1C 03 00 00 48")).unwrap_err();
+        assert_eq!(err.kind(), Tag::UniversalString.length_error().kind());
+
+        // Surrogate code point
+        let err = UniversalStringRef::from_der(&hex!("1C 04 00 00 D8 00")).unwrap_err();
+        assert_eq!(err.kind(), Tag::UniversalString.value_error().kind());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use super::UniversalString;
+        use crate::Encode;
+
+        let universal_string = UniversalString::from_utf8("Hé😀").unwrap();
+        assert_eq!(universal_string.as_str(), "Hé😀");
+        assert_eq!(universal_string.to_der().unwrap(), EXAMPLE_BYTES);
+
+        let decoded = UniversalString::from_der(EXAMPLE_BYTES).unwrap();
+        assert_eq!(decoded, universal_string);
+    }
+}
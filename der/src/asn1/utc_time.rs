@@ -1,8 +1,8 @@
 //! ASN.1 `UTCTime` support.
 
 use crate::{
-    DecodeValue, EncodeValue, Error, ErrorKind, FixedTag, Header, Length, Reader, Result, Tag,
-    Writer,
+    DecodeQuirk, DecodeValue, EncodeValue, Error, ErrorKind, FixedLen, FixedTag, Header, Length,
+    Reader, Result, Tag, Writer,
     datetime::{self, DateTime},
     ord::OrdIsValueOrd,
 };
@@ -40,8 +40,11 @@ impl UtcTime {
     pub const MAX_YEAR: u16 = 2049;
 
     /// Create a [`UtcTime`] from a [`DateTime`].
+    ///
+    /// Returns `Err` if the year is after [`UtcTime::MAX_YEAR`], or if the [`DateTime`] has a
+    /// fraction of a second, which `UTCTime` can't represent.
     pub fn from_date_time(datetime: DateTime) -> Result<Self> {
-        if datetime.year() <= UtcTime::MAX_YEAR {
+        if datetime.year() <= UtcTime::MAX_YEAR && datetime.nanoseconds() == 0 {
             Ok(Self(datetime))
         } else {
             Err(Self::TAG.value_error())
@@ -85,12 +88,22 @@ impl<'a> DecodeValue<'a> for UtcTime {
     type Error = Error;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        if Self::LENGTH != usize::try_from(header.length)? {
-            return Err(Self::TAG.value_error());
-        }
+        /// Length of `YYMMDDHHMMZ`, i.e. without seconds.
+        const LENGTH_WITHOUT_SECONDS: usize = 11;
 
         let mut bytes = [0u8; Self::LENGTH];
-        reader.read_into(&mut bytes)?;
+
+        match usize::try_from(header.length)? {
+            Self::LENGTH => {
+                reader.read_into(&mut bytes)?;
+            }
+            LENGTH_WITHOUT_SECONDS if reader.allow_quirk(DecodeQuirk::UtcTimeWithoutSeconds) => {
+                reader.read_into(&mut bytes[..10])?;
+                bytes[10..12].copy_from_slice(b"00");
+                bytes[12] = reader.read_byte()?;
+            }
+            _ => return Err(Self::TAG.value_error()),
+        }
 
         match bytes {
             // RFC 5280 requires mandatory seconds and Z-normalized time zone
@@ -161,6 +174,11 @@ impl FixedTag for UtcTime {
     const TAG: Tag = Tag::UtcTime;
 }
 
+impl FixedLen for UtcTime {
+    #[allow(clippy::cast_possible_truncation)]
+    const ENCODED_LEN: Length = Length::new(Self::LENGTH as u16).for_tlv_const(Self::TAG);
+}
+
 impl OrdIsValueOrd for UtcTime {}
 
 impl From<&UtcTime> for UtcTime {
@@ -0,0 +1,207 @@
+//! ASN.1 `VisibleString` support.
+
+use crate::{FixedTag, Result, StrRef, Tag, asn1::AnyRef};
+use core::{fmt, ops::Deref};
+
+macro_rules! impl_visible_string {
+    ($type: ty) => {
+        impl_visible_string!($type,);
+    };
+    ($type: ty, $($li: lifetime)?) => {
+        impl_string_type!($type, $($li),*);
+
+        impl<$($li),*> FixedTag for $type {
+            const TAG: Tag = Tag::VisibleString;
+        }
+
+        impl<$($li),*> fmt::Debug for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "VisibleString({:?})", self.as_str())
+            }
+        }
+    };
+}
+
+/// ASN.1 `VisibleString` type.
+///
+/// Supports the printable ASCII characters, i.e. `0x20..=0x7E`, which excludes control
+/// characters.
+///
+/// For the full ASCII character set, use
+/// [`Ia5StringRef`][`crate::asn1::Ia5StringRef`] instead.
+///
+/// This is a zero-copy reference type which borrows from the input data.
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Ord)]
+pub struct VisibleStringRef<'a> {
+    /// Inner value
+    inner: StrRef<'a>,
+}
+
+impl<'a> VisibleStringRef<'a> {
+    /// Create a new ASN.1 `VisibleString`.
+    pub fn new<T>(input: &'a T) -> Result<Self>
+    where
+        T: AsRef<[u8]> + ?Sized,
+    {
+        let input = input.as_ref();
+
+        // Validate all characters are within VisibleString's allowed set
+        if input.iter().any(|&c| !(0x20..=0x7E).contains(&c)) {
+            return Err(Self::TAG.value_error());
+        }
+
+        StrRef::from_bytes(input)
+            .map(|inner| Self { inner })
+            .map_err(|_| Self::TAG.value_error())
+    }
+}
+
+impl_visible_string!(VisibleStringRef<'a>, 'a);
+
+impl<'a> Deref for VisibleStringRef<'a> {
+    type Target = StrRef<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<'a> From<&VisibleStringRef<'a>> for VisibleStringRef<'a> {
+    fn from(value: &VisibleStringRef<'a>) -> VisibleStringRef<'a> {
+        *value
+    }
+}
+
+impl<'a> From<VisibleStringRef<'a>> for AnyRef<'a> {
+    fn from(visible_string: VisibleStringRef<'a>) -> AnyRef<'a> {
+        AnyRef::from_tag_and_value(Tag::VisibleString, visible_string.inner.into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub use self::allocation::VisibleString;
+
+#[cfg(feature = "alloc")]
+mod allocation {
+    use super::VisibleStringRef;
+
+    use crate::{
+        BytesRef, Error, FixedTag, Result, StrOwned, Tag,
+        asn1::AnyRef,
+        referenced::{OwnedToRef, RefToOwned},
+    };
+    use alloc::string::String;
+    use core::{fmt, ops::Deref};
+
+    /// ASN.1 `VisibleString` type.
+    ///
+    /// Supports the printable ASCII characters, i.e. `0x20..=0x7E`, which excludes control
+    /// characters.
+    ///
+    /// For the full ASCII character set, use
+    /// [`Ia5String`][`crate::asn1::Ia5String`] instead.
+    #[derive(Clone, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct VisibleString {
+        /// Inner value
+        inner: StrOwned,
+    }
+
+    impl VisibleString {
+        /// Create a new ASN.1 `VisibleString`.
+        pub fn new<T>(input: &T) -> Result<Self>
+        where
+            T: AsRef<[u8]> + ?Sized,
+        {
+            let input = input.as_ref();
+            VisibleStringRef::new(input)?;
+
+            StrOwned::from_bytes(input)
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.value_error())
+        }
+    }
+
+    impl_visible_string!(VisibleString);
+
+    impl Deref for VisibleString {
+        type Target = StrOwned;
+
+        fn deref(&self) -> &Self::Target {
+            &self.inner
+        }
+    }
+
+    impl<'a> From<VisibleStringRef<'a>> for VisibleString {
+        fn from(value: VisibleStringRef<'a>) -> VisibleString {
+            let inner =
+                StrOwned::from_bytes(value.inner.as_bytes()).expect("Invalid VisibleString");
+            Self { inner }
+        }
+    }
+
+    impl<'a> From<&'a VisibleString> for AnyRef<'a> {
+        fn from(visible_string: &'a VisibleString) -> AnyRef<'a> {
+            AnyRef::from_tag_and_value(
+                Tag::VisibleString,
+                BytesRef::new(visible_string.inner.as_bytes()).expect("Invalid VisibleString"),
+            )
+        }
+    }
+
+    impl<'a> From<&'a VisibleString> for VisibleStringRef<'a> {
+        fn from(visible_string: &'a VisibleString) -> VisibleStringRef<'a> {
+            visible_string.owned_to_ref()
+        }
+    }
+
+    impl<'a> RefToOwned<'a> for VisibleStringRef<'a> {
+        type Owned = VisibleString;
+        fn ref_to_owned(&self) -> Self::Owned {
+            VisibleString {
+                inner: self.inner.ref_to_owned(),
+            }
+        }
+    }
+
+    impl OwnedToRef for VisibleString {
+        type Borrowed<'a> = VisibleStringRef<'a>;
+        fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+            VisibleStringRef {
+                inner: self.inner.owned_to_ref(),
+            }
+        }
+    }
+
+    impl TryFrom<String> for VisibleString {
+        type Error = Error;
+
+        fn try_from(input: String) -> Result<Self> {
+            VisibleStringRef::new(&input)?;
+
+            StrOwned::new(input)
+                .map(|inner| Self { inner })
+                .map_err(|_| Self::TAG.value_error())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::VisibleStringRef;
+    use crate::{Decode, Tag};
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_bytes() {
+        let visible_string =
+            VisibleStringRef::from_der(&hex!("1A 07 48 69 2C 20 79 6F 75")).unwrap();
+        assert_eq!(visible_string.as_str(), "Hi, you");
+    }
+
+    #[test]
+    fn reject_invalid() {
+        let err = VisibleStringRef::from_der(&hex!("1A 03 48 0A 69")).unwrap_err();
+        assert_eq!(err.kind(), Tag::VisibleString.value_error().kind());
+    }
+}
@@ -72,9 +72,16 @@ impl AsRef<[u8]> for BytesRef<'_> {
 
 impl<'a> DecodeValue<'a> for BytesRef<'a> {
     type Error = Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
-        reader.read_slice(header.length).and_then(Self::new)
+        // BER constructed string types can't be borrowed since they need to be reassembled
+        if header.is_constructed() != header.tag.is_constructed() {
+            return Err(header.tag.non_canonical_error());
+        }
+
+        let len = header.value_len(reader)?;
+        reader.read_slice(len).and_then(Self::new)
     }
 }
 
@@ -0,0 +1,123 @@
+//! Common handling for types backed by a shared [`Bytes`] buffer with enforcement of a
+//! library-level length limitation i.e. `Length::max()`.
+
+use crate::{
+    BytesOwned, BytesRef, DecodeValue, DerOrd, EncodeValue, Error, Header, Length, Reader, Result,
+    Writer, referenced::OwnedToRef,
+};
+use alloc::boxed::Box;
+use bytes::Bytes;
+use core::cmp::Ordering;
+
+/// [`Bytes`] newtype which respects the `Length::max()` limit.
+///
+/// This is the counterpart of [`BytesOwned`] whose value can share the allocation of the input
+/// it was decoded from, see [`Reader::read_bytes`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub(crate) struct BytesShared {
+    /// Precomputed `Length` (avoids possible panicking conversions)
+    length: Length,
+
+    /// Inner value
+    inner: Bytes,
+}
+
+impl BytesShared {
+    /// Create a new [`BytesShared`], ensuring that the provided `data` value
+    /// is shorter than `Length::max()`.
+    pub fn new(data: impl Into<Bytes>) -> Result<Self> {
+        let inner: Bytes = data.into();
+
+        Ok(Self {
+            length: Length::try_from(inner.len())?,
+            inner,
+        })
+    }
+
+    /// Borrow the inner byte slice
+    pub fn as_slice(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Borrow the inner [`Bytes`] buffer
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.inner
+    }
+
+    /// Get the [`Length`] of this [`BytesShared`]
+    pub fn len(&self) -> Length {
+        self.length
+    }
+
+    /// Is this [`BytesShared`] empty?
+    pub fn is_empty(&self) -> bool {
+        self.len() == Length::ZERO
+    }
+}
+
+impl AsRef<[u8]> for BytesShared {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<'a> DecodeValue<'a> for BytesShared {
+    type Error = Error;
+    const INDEFINITE_LENGTH: bool = true;
+
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+        reader
+            .read_bytes(header.value_len(reader)?)
+            .and_then(Self::new)
+    }
+}
+
+impl EncodeValue for BytesShared {
+    fn value_len(&self) -> Result<Length> {
+        Ok(self.length)
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.as_ref())
+    }
+}
+
+impl DerOrd for BytesShared {
+    fn der_cmp(&self, other: &Self) -> Result<Ordering> {
+        Ok(self.as_slice().cmp(other.as_slice()))
+    }
+}
+
+impl From<BytesShared> for Bytes {
+    fn from(bytes: BytesShared) -> Bytes {
+        bytes.inner
+    }
+}
+
+impl OwnedToRef for BytesShared {
+    type Borrowed<'a> = BytesRef<'a>;
+    fn owned_to_ref(&self) -> Self::Borrowed<'_> {
+        BytesRef {
+            length: self.length,
+            inner: self.inner.as_ref(),
+        }
+    }
+}
+
+impl From<BytesRef<'_>> for BytesShared {
+    fn from(s: BytesRef<'_>) -> BytesShared {
+        BytesShared {
+            length: s.length,
+            inner: Bytes::copy_from_slice(s.inner),
+        }
+    }
+}
+
+impl From<BytesOwned> for BytesShared {
+    fn from(s: BytesOwned) -> BytesShared {
+        BytesShared {
+            length: s.len(),
+            inner: Box::<[u8]>::from(s).into(),
+        }
+    }
+}
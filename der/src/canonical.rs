@@ -0,0 +1,212 @@
+//! Schema-less transcoding of BER into DER.
+
+use crate::{
+    Decode, DecodeLimits, DecodeValue, Encode, EncodingRules, Header, Length, Reader, Result,
+    SliceReader, Tag,
+    asn1::{Any, BitString, segments::read_segments},
+};
+use alloc::vec::Vec;
+
+/// Maximum nesting depth of the input.
+const MAX_DEPTH: u32 = 64;
+
+/// Transcode a BER-encoded value into the equivalent DER, without knowing its schema.
+///
+/// The following BER productions are rewritten into their DER form:
+/// - indefinite lengths, which become definite lengths
+/// - lengths which aren't encoded in the minimum number of octets
+/// - `BIT STRING`, `OCTET STRING` and character string values which are split into segments using
+///   the constructed form, which are reassembled into the primitive form
+/// - `BOOLEAN` values other than `0x00` and `0xFF`, which become `0xFF`
+/// - `SET` and `SET OF` elements, which are sorted by the class and number of their tags, and
+///   elements with the same tag by comparing their canonical encodings as octet strings (X.690
+///   Section 10.3)
+///
+/// Since the schema isn't known, values with an `IMPLICIT` tag are copied as-is apart from their
+/// length, and the contents of constructed values with an `IMPLICIT` tag are transcoded as if they
+/// were explicitly tagged. In particular, the elements of a `SET` or `SET OF` with an `IMPLICIT`
+/// tag, e.g. the `[0] IMPLICIT SET OF` signed attributes of a CMS `SignerInfo`, are **not** sorted,
+/// since they can't be told apart from a `SEQUENCE` with an `IMPLICIT` tag, whose order is
+/// significant. The output is only DER if the input has no such values, or if their elements are
+/// already in DER order.
+///
+/// The input must consist of exactly one value. Nesting deeper than 64 levels is rejected with
+/// [`ErrorKind::LimitExceeded`][`crate::ErrorKind::LimitExceeded`].
+pub fn canonicalize(ber: &[u8]) -> Result<Vec<u8>> {
+    let limits = DecodeLimits {
+        max_depth: Some(MAX_DEPTH),
+        ..DecodeLimits::default()
+    };
+
+    let mut reader =
+        SliceReader::new_with_encoding_rules(ber, EncodingRules::Ber)?.with_limits(limits);
+
+    let mut der = Vec::with_capacity(ber.len());
+    canonicalize_tlv(&mut reader, &mut der)?;
+    reader.finish(der)
+}
+
+/// Transcode the tag-length-value at the current position of the reader, appending it to `der`
+/// and returning its tag.
+fn canonicalize_tlv(reader: &mut SliceReader<'_>, der: &mut Vec<u8>) -> Result<Tag> {
+    let header = Header::decode(reader)?;
+
+    let value = if header.is_constructed() != header.tag.is_constructed() {
+        reader.read_value(header, |r| reassemble_string(r, header))?
+    } else if header.is_constructed() {
+        let mut elements = reader.read_value(header, canonicalize_elements)?;
+
+        // X.690 Section 10.3: the elements of a `SET` are ordered by tag, regardless of whether
+        // they're constructed, and those of a `SET OF`, which share a tag, as octet strings
+        if header.tag == Tag::Set {
+            elements.sort_by(|(a_tag, a), (b_tag, b)| {
+                (a_tag.class(), a_tag.number())
+                    .cmp(&(b_tag.class(), b_tag.number()))
+                    .then_with(|| a.cmp(b))
+            });
+        }
+
+        elements
+            .into_iter()
+            .flat_map(|(_, element)| element)
+            .collect()
+    } else {
+        let value = reader.read_value(header, |r| r.read_slice(header.length))?;
+
+        match (header.tag, value) {
+            (Tag::Boolean, [0]) => [0x00].into(),
+            (Tag::Boolean, [_]) => [0xFF].into(),
+            (Tag::Boolean, _) => return Err(Tag::Boolean.length_error()),
+            _ => value.into(),
+        }
+    };
+
+    Header::new(header.tag, Length::try_from(value.len())?)?.encode_to_vec(der)?;
+    der.extend_from_slice(&value);
+    Ok(header.tag)
+}
+
+/// Transcode each of the remaining tag-length-values in the reader, along with their tags.
+fn canonicalize_elements(reader: &mut SliceReader<'_>) -> Result<Vec<(Tag, Vec<u8>)>> {
+    let mut elements = Vec::new();
+
+    while !reader.is_finished() {
+        let mut element = Vec::new();
+        let tag = canonicalize_tlv(reader, &mut element)?;
+        elements.push((tag, element));
+    }
+
+    Ok(elements)
+}
+
+/// Reassemble the contents of a string type value which uses the constructed form.
+fn reassemble_string(reader: &mut SliceReader<'_>, header: Header) -> Result<Vec<u8>> {
+    match header.tag {
+        Tag::BitString => {
+            let bit_string = BitString::decode_value(reader, header)?;
+            Ok(Any::encode_from(&bit_string)?.value().into())
+        }
+        Tag::OctetString
+        | Tag::Utf8String
+        | Tag::NumericString
+        | Tag::PrintableString
+        | Tag::TeletexString
+        | Tag::VideotexString
+        | Tag::Ia5String
+        | Tag::UtcTime
+        | Tag::GeneralizedTime
+        | Tag::VisibleString
+        | Tag::GeneralString
+        | Tag::UniversalString
+        | Tag::BmpString => {
+            let mut value = Vec::new();
+
+            read_segments(reader, header, header.tag, |r, segment| {
+                value.extend_from_slice(r.read_slice(segment.length)?);
+                Ok(())
+            })?;
+
+            Ok(value)
+        }
+        tag => Err(tag.non_canonical_error()),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::canonicalize;
+    use crate::ErrorKind;
+    use hex_literal::hex;
+
+    #[test]
+    fn definite_lengths() {
+        // SEQUENCE (indefinite) { INTEGER 1 with a non-minimal length, [0] (indefinite) { NULL } }
+        let ber = hex!("3080 02810101 A080 0500 0000 0000");
+        assert_eq!(canonicalize(&ber).unwrap(), hex!("3007 020101 A002 0500"));
+    }
+
+    #[test]
+    fn constructed_strings() {
+        // OCTET STRING split into two segments, one of them nested
+        let ber = hex!("2480 0402AABB 2403 0401CC 0000");
+        assert_eq!(canonicalize(&ber).unwrap(), hex!("0403AABBCC"));
+
+        // BIT STRING split into two segments, with unused bits in the last one
+        let ber = hex!("2309 0302 00AA 0303 04BBC0");
+        assert_eq!(canonicalize(&ber).unwrap(), hex!("0304 04AABBC0"));
+
+        // IA5String split into two segments
+        let ber = hex!("3680 1602 6869 1601 21 0000");
+        assert_eq!(canonicalize(&ber).unwrap(), hex!("1603 686921"));
+
+        // Constructed INTEGER
+        let err = canonicalize(&hex!("2203 020101")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TagUnknown { byte: 0x22 });
+    }
+
+    #[test]
+    fn booleans() {
+        assert_eq!(canonicalize(&hex!("010101")).unwrap(), hex!("0101FF"));
+        assert_eq!(canonicalize(&hex!("010100")).unwrap(), hex!("010100"));
+        assert!(canonicalize(&hex!("01020000")).is_err());
+    }
+
+    #[test]
+    fn set_ordering() {
+        // SET OF { OCTET STRING 02, OCTET STRING (constructed) 01, BOOLEAN 1 }
+        let ber = hex!("3180 040102 2403 040101 010101 0000");
+        assert_eq!(
+            canonicalize(&ber).unwrap(),
+            hex!("3109 0101FF 040101 040102")
+        );
+
+        // SET { [0] { NULL }, [1] 01 }: elements are ordered by tag number, regardless of the
+        // constructed bit of the tag
+        let ber = hex!("3180 810101 A0020500 0000");
+        assert_eq!(canonicalize(&ber).unwrap(), hex!("3107 A0020500 810101"));
+
+        // Elements of values with an IMPLICIT tag are left in their original order
+        let ber = hex!("A080 040102 040101 0000");
+        assert_eq!(canonicalize(&ber).unwrap(), hex!("A006 040102 040101"));
+    }
+
+    #[test]
+    fn trailing_data() {
+        let err = canonicalize(&hex!("0500 0500")).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+    }
+
+    #[test]
+    fn depth_limit() {
+        let mut ber = [0x30; 130];
+        ber[1..].iter_mut().step_by(2).for_each(|byte| *byte = 0x80);
+
+        let mut ber = ber.to_vec();
+        ber.extend_from_slice(&[0; 130]);
+        assert!(matches!(
+            canonicalize(&ber).unwrap_err().kind(),
+            ErrorKind::LimitExceeded { .. }
+        ));
+    }
+}
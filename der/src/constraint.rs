@@ -0,0 +1,185 @@
+//! ASN.1 subtype constraints (X.680 Section 51), checked by the `Sequence` and `Set` custom
+//! derives for fields with the `size` and `range` attributes.
+
+use crate::{
+    ErrorKind, Result,
+    asn1::{BitStringRef, OctetStringRef, SequenceOf, SetOf},
+    ord::DerOrd,
+};
+use core::{fmt, ops::RangeBounds};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::asn1::{BitString, OctetString, SetOfVec},
+    alloc::{string::String, vec::Vec},
+};
+
+/// Kinds of constraints which a value can violate.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Constraint {
+    /// `SIZE` constraint on the number of elements, characters, octets or bits of a value.
+    Size,
+
+    /// Value range constraint, e.g. `INTEGER (1..65535)`.
+    ValueRange,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Constraint::Size => "SIZE",
+            Constraint::ValueRange => "value range",
+        })
+    }
+}
+
+/// Types which can be subject to a `SIZE` constraint.
+pub trait Size {
+    /// Size of the value as measured by a `SIZE` constraint: the number of elements of a
+    /// `SEQUENCE OF` or `SET OF`, characters of a string, octets of an `OCTET STRING`, or bits
+    /// of a `BIT STRING`.
+    fn size(&self) -> usize;
+}
+
+/// Check that the [`Size`] of a value is within the given bounds.
+pub fn check_size<T>(value: &T, bounds: impl RangeBounds<usize>) -> Result<()>
+where
+    T: Size + ?Sized,
+{
+    if bounds.contains(&value.size()) {
+        Ok(())
+    } else {
+        Err(violation(Constraint::Size))
+    }
+}
+
+/// Check that a value is within the given bounds.
+pub fn check_range<T>(value: &T, bounds: impl RangeBounds<T>) -> Result<()>
+where
+    T: PartialOrd + ?Sized,
+{
+    if bounds.contains(value) {
+        Ok(())
+    } else {
+        Err(violation(Constraint::ValueRange))
+    }
+}
+
+/// Error for a violation of the given constraint.
+fn violation(constraint: Constraint) -> crate::Error {
+    ErrorKind::ConstraintViolation { constraint }.into()
+}
+
+impl<T: Size + ?Sized> Size for &T {
+    fn size(&self) -> usize {
+        T::size(self)
+    }
+}
+
+impl<T> Size for [T] {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> Size for SequenceOf<T, N> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: DerOrd, const N: usize> Size for SetOf<T, N> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Size for str {
+    fn size(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Size for OctetStringRef<'_> {
+    fn size(&self) -> usize {
+        self.as_bytes().len()
+    }
+}
+
+impl Size for BitStringRef<'_> {
+    fn size(&self) -> usize {
+        self.bit_len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Size for Vec<T> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: DerOrd> Size for SetOfVec<T> {
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Size for String {
+    fn size(&self) -> usize {
+        self.as_str().size()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Size for OctetString {
+    fn size(&self) -> usize {
+        self.as_bytes().len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Size for BitString {
+    fn size(&self) -> usize {
+        self.bit_len()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Constraint, check_range, check_size};
+    use crate::{ErrorKind, asn1::BitStringRef};
+
+    #[test]
+    fn size() {
+        assert!(check_size(&[1u8, 2, 3][..], 1..).is_ok());
+        assert!(check_size(&"héllo", ..=5).is_ok());
+        assert!(check_size(&BitStringRef::new(3, &[0xF8]).unwrap(), 5..=5).is_ok());
+
+        let err = check_size(&[0u8; 0][..], 1..).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ConstraintViolation {
+                constraint: Constraint::Size
+            }
+        );
+    }
+
+    #[test]
+    fn range() {
+        assert!(check_range(&1u32, 1..=65535).is_ok());
+        assert!(check_range(&-5i8, ..0).is_ok());
+
+        let err = check_range(&0u32, 1..=65535).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange
+            }
+        );
+    }
+}
@@ -15,6 +15,9 @@ use const_range::const_contains_u8;
 #[cfg(feature = "time")]
 use time::PrimitiveDateTime;
 
+/// Number of nanoseconds in a second.
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+
 /// Minimum year allowed in [`DateTime`] values.
 const MIN_YEAR: u16 = 1970;
 
@@ -30,6 +33,9 @@ const MAX_UNIX_DURATION: Duration = Duration::from_secs(253_402_300_799);
 /// Following conventions from RFC 5280, this type is always Z-normalized
 /// (i.e. represents a UTC time). However, it isn't named "UTC time" in order
 /// to prevent confusion with ASN.1 `UTCTime`.
+///
+/// It has nanosecond precision, though values constructed with [`DateTime::new`] or
+/// [`DateTime::from_unix_duration`] are whole seconds. See [`DateTime::with_nanoseconds`].
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub struct DateTime {
     /// Full year (e.g. 2000).
@@ -52,6 +58,9 @@ pub struct DateTime {
     /// Seconds (0-59)
     seconds: u8,
 
+    /// Fraction of a second in nanoseconds (0-999,999,999)
+    nanoseconds: u32,
+
     /// [`Duration`] since the Unix epoch.
     unix_duration: Duration,
 }
@@ -66,6 +75,7 @@ impl DateTime {
         hour: 23,
         minutes: 59,
         seconds: 59,
+        nanoseconds: 0,
         unix_duration: MAX_UNIX_DURATION,
     };
 
@@ -155,12 +165,30 @@ impl DateTime {
             hour,
             minutes,
             seconds,
+            nanoseconds: 0,
             unix_duration,
         })
     }
 
+    /// Create a new [`DateTime`] from the given UTC time components, including the fraction
+    /// of a second in nanoseconds.
+    pub fn new_with_nanoseconds(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minutes: u8,
+        seconds: u8,
+        nanoseconds: u32,
+    ) -> Result<Self> {
+        Self::new(year, month, day, hour, minutes, seconds)?.with_nanoseconds(nanoseconds)
+    }
+
     /// Compute a [`DateTime`] from the given [`Duration`] since the `UNIX_EPOCH`.
     ///
+    /// The fraction of a second is discarded, so that the result can be encoded as specified
+    /// by RFC 5280. Use [`DateTime::with_nanoseconds`] to keep it.
+    ///
     /// Returns `Err` if the value is outside the supported date range.
     // TODO(tarcieri): checked arithmetic
     #[allow(clippy::arithmetic_side_effects)]
@@ -270,6 +298,26 @@ impl DateTime {
         self.seconds
     }
 
+    /// Get the fraction of a second in nanoseconds.
+    pub fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Get a copy of this [`DateTime`] with the given fraction of a second in nanoseconds.
+    ///
+    /// Returns `Err` if `nanoseconds` isn't less than one billion.
+    pub fn with_nanoseconds(&self, nanoseconds: u32) -> Result<Self> {
+        if nanoseconds >= NANOS_PER_SECOND {
+            return Err(ErrorKind::DateTime.into());
+        }
+
+        Ok(Self {
+            nanoseconds,
+            unix_duration: Duration::new(self.unix_duration.as_secs(), nanoseconds),
+            ..*self
+        })
+    }
+
     /// Compute [`Duration`] since `UNIX_EPOCH` from the given calendar date.
     pub fn unix_duration(&self) -> Duration {
         self.unix_duration
@@ -315,7 +363,7 @@ impl FromStr for DateTime {
                 b':',
                 sec1,
                 sec2,
-                b'Z',
+                ref zone @ ..,
             ] => {
                 let tag = Tag::GeneralizedTime;
                 let year = decode_year(&[year1, year2, year3, year4])?;
@@ -324,7 +372,14 @@ impl FromStr for DateTime {
                 let hour = decode_decimal(tag, hour1, hour2).map_err(|_| ErrorKind::DateTime)?;
                 let minutes = decode_decimal(tag, min1, min2).map_err(|_| ErrorKind::DateTime)?;
                 let seconds = decode_decimal(tag, sec1, sec2).map_err(|_| ErrorKind::DateTime)?;
-                Self::new(year, month, day, hour, minutes, seconds)
+                let nanoseconds = match zone {
+                    [b'Z'] => 0,
+                    [b'.', digits @ .., b'Z'] => {
+                        decode_nanoseconds(digits).ok_or(ErrorKind::DateTime)?
+                    }
+                    _ => return Err(ErrorKind::DateTime.into()),
+                };
+                Self::new_with_nanoseconds(year, month, day, hour, minutes, seconds, nanoseconds)
             }
             _ => Err(ErrorKind::DateTime.into()),
         }
@@ -335,9 +390,20 @@ impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{:02}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            "{:02}-{:02}-{:02}T{:02}:{:02}:{:02}",
             self.year, self.month, self.day, self.hour, self.minutes, self.seconds
-        )
+        )?;
+
+        if self.nanoseconds != 0 {
+            let (digits, len) = encode_nanoseconds(self.nanoseconds);
+            f.write_str(".")?;
+
+            for &digit in &digits[..len] {
+                write!(f, "{}", char::from(digit))?;
+            }
+        }
+
+        f.write_str("Z")
     }
 }
 
@@ -380,7 +446,12 @@ impl TryFrom<DateTime> for PrimitiveDateTime {
     fn try_from(time: DateTime) -> Result<PrimitiveDateTime> {
         let month = time.month().try_into()?;
         let date = time::Date::from_calendar_date(i32::from(time.year()), month, time.day())?;
-        let time = time::Time::from_hms(time.hour(), time.minutes(), time.seconds())?;
+        let time = time::Time::from_hms_nano(
+            time.hour(),
+            time.minutes(),
+            time.seconds(),
+            time.nanoseconds(),
+        )?;
 
         Ok(PrimitiveDateTime::new(date, time))
     }
@@ -391,13 +462,14 @@ impl TryFrom<PrimitiveDateTime> for DateTime {
     type Error = Error;
 
     fn try_from(time: PrimitiveDateTime) -> Result<DateTime> {
-        DateTime::new(
+        DateTime::new_with_nanoseconds(
             time.year().try_into().map_err(|_| ErrorKind::DateTime)?,
             time.month().into(),
             time.day(),
             time.hour(),
             time.minute(),
             time.second(),
+            time.nanosecond(),
         )
     }
 }
@@ -445,6 +517,41 @@ where
     writer.write_byte(b'0'.checked_add(value % 10).ok_or(ErrorKind::Overflow)?)
 }
 
+/// Decode the digits of a fraction of a second as nanoseconds, e.g. `5` as 500,000,000.
+///
+/// Returns `None` unless there are 1 to 9 digits.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn decode_nanoseconds(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() || digits.len() > 9 || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    let nanoseconds = digits
+        .iter()
+        .fold(0, |acc, &digit| acc * 10 + u32::from(digit - b'0'));
+
+    Some(nanoseconds * 10u32.pow(9 - digits.len() as u32))
+}
+
+/// Encode nanoseconds as the digits of a fraction of a second without trailing zeros, returned
+/// as a buffer along with the length of its used prefix.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn encode_nanoseconds(nanoseconds: u32) -> ([u8; 9], usize) {
+    let mut digits = [b'0'; 9];
+    let mut n = nanoseconds;
+
+    for digit in digits.iter_mut().rev() {
+        *digit = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+
+    let len = digits
+        .iter()
+        .rposition(|&digit| digit != b'0')
+        .map_or(0, |pos| pos + 1);
+    (digits, len)
+}
+
 /// Decode 4-digit year.
 // TODO(tarcieri): checked arithmetic
 #[allow(clippy::arithmetic_side_effects)]
@@ -516,11 +623,39 @@ mod tests {
         assert_eq!(datetime.seconds(), 14);
     }
 
+    #[test]
+    fn from_str_nanoseconds() {
+        let datetime = "2001-01-02T12:13:14.05Z".parse::<DateTime>().unwrap();
+        assert_eq!(datetime.seconds(), 14);
+        assert_eq!(datetime.nanoseconds(), 50_000_000);
+        assert_eq!(datetime.unix_duration().subsec_nanos(), 50_000_000);
+
+        assert!("2001-01-02T12:13:14.Z".parse::<DateTime>().is_err());
+        assert!(
+            "2001-01-02T12:13:14.0123456789Z"
+                .parse::<DateTime>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn with_nanoseconds() {
+        let datetime = DateTime::new(2001, 1, 2, 12, 13, 14).unwrap();
+        let precise = datetime.with_nanoseconds(123).unwrap();
+        assert_eq!(precise.nanoseconds(), 123);
+        assert!(precise > datetime);
+        assert_eq!(precise.with_nanoseconds(0).unwrap(), datetime);
+        assert!(datetime.with_nanoseconds(1_000_000_000).is_err());
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn display() {
         use alloc::string::ToString;
         let datetime = DateTime::new(2001, 1, 2, 12, 13, 14).unwrap();
         assert_eq!(&datetime.to_string(), "2001-01-02T12:13:14Z");
+
+        let datetime = datetime.with_nanoseconds(120_000_000).unwrap();
+        assert_eq!(&datetime.to_string(), "2001-01-02T12:13:14.12Z");
     }
 }
@@ -1,13 +1,16 @@
 //! Trait definition for [`Decode`].
 
-use crate::{EncodingRules, Error, FixedTag, Header, Reader, SliceReader};
+use crate::{EncodingRules, Error, ErrorKind, FixedTag, Header, Reader, SliceReader};
 use core::marker::PhantomData;
 
+#[cfg(feature = "bytes")]
+use crate::BytesReader;
+
 #[cfg(feature = "pem")]
 use crate::{PemReader, pem::PemLabel};
 
 #[cfg(doc)]
-use crate::{ErrorKind, Length, Tag};
+use crate::{Length, Tag};
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
@@ -42,6 +45,19 @@ pub trait Decode<'a>: Sized + 'a {
         Ok(reader.finish(result)?)
     }
 
+    /// Parse `Self` from the provided DER-encoded [`Bytes`][`bytes::Bytes`] buffer.
+    ///
+    /// Values backed by [`Bytes`][`bytes::Bytes`], such as [`AnyBytes`][`crate::AnyBytes`], share
+    /// the allocation of the buffer rather than copying out of it.
+    ///
+    /// Returns [`ErrorKind::TrailingData`] if message is incomplete.
+    #[cfg(feature = "bytes")]
+    fn from_der_bytes(bytes: &'a bytes::Bytes) -> Result<Self, Self::Error> {
+        let mut reader = BytesReader::new(bytes)?;
+        let result = Self::decode(&mut reader)?;
+        Ok(reader.finish(result)?)
+    }
+
     /// Parse `Self` from the provided DER-encoded byte slice.
     ///
     /// Returns remaining byte slice, without checking for incomplete message.
@@ -63,7 +79,12 @@ where
     fn decode<R: Reader<'a>>(reader: &mut R) -> Result<T, <T as DecodeValue<'a>>::Error> {
         let header = Header::decode(reader)?;
         header.tag.assert_eq(T::TAG)?;
-        T::decode_value(reader, header)
+
+        if header.is_indefinite() && !T::INDEFINITE_LENGTH {
+            return Err(reader.error(ErrorKind::IndefiniteLength).into());
+        }
+
+        reader.read_value(header, |r| T::decode_value(r, header))
     }
 }
 
@@ -117,6 +138,14 @@ pub trait DecodeValue<'a>: Sized {
     /// Type returned in the event of a decoding error.
     type Error: From<Error> + 'static;
 
+    /// Can this type be decoded from a value using the BER indefinite length form?
+    ///
+    /// The [`Header::length`] of such values is zero, so only types which read their contents
+    /// with [`Reader::read_contents`], or otherwise until [`Reader::is_finished`], can set this.
+    /// Decoding a value using the indefinite length form as a type which doesn't set it fails with
+    /// [`ErrorKind::IndefiniteLength`].
+    const INDEFINITE_LENGTH: bool = false;
+
     /// Attempt to decode this message using the provided [`Reader`].
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Self::Error>;
 }
@@ -127,6 +156,7 @@ where
     T: DecodeValue<'a>,
 {
     type Error = T::Error;
+    const INDEFINITE_LENGTH: bool = T::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Self::Error> {
         Ok(Box::new(T::decode_value(reader, header)?))
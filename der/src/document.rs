@@ -148,8 +148,7 @@ impl<'a> Decode<'a> for Document {
     type Error = Error;
 
     fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Document, Error> {
-        let header = Header::peek(reader)?;
-        let length = (header.encoded_len()? + header.length)?;
+        let length = Header::peek_tlv_len(reader)?;
         let bytes = reader.read_slice(length)?;
 
         Ok(Self {
@@ -326,8 +325,7 @@ fn decode_sequence<'a>(decoder: &mut SliceReader<'a>) -> Result<&'a [u8], Error>
     let header = Header::peek(decoder)?;
     header.tag.assert_eq(Tag::Sequence)?;
 
-    let len = (header.encoded_len()? + header.length)?;
-    decoder.read_slice(len)
+    decoder.tlv_bytes()
 }
 
 /// Write a file containing secret data to the filesystem, restricting the
@@ -0,0 +1,496 @@
+//! Pretty printer for arbitrary DER/BER encodings, in the style of `dumpasn1` and
+//! `openssl asn1parse`.
+//!
+//! Useful when debugging malformed or unexpected documents, as it doesn't require knowing the
+//! ASN.1 schema of the input: every tag-length-value is walked generically.
+//!
+//! # Example
+//!
+//! ```
+//! use der::dump::Dump;
+//!
+//! // SEQUENCE { INTEGER 42, OCTET STRING { BOOLEAN TRUE } }
+//! let bytes = [0x30, 0x08, 0x02, 0x01, 0x2A, 0x04, 0x03, 0x01, 0x01, 0xFF];
+//! print!("{}", Dump::new(&bytes));
+//! ```
+//!
+//! Prints:
+//!
+//! ```text
+//!     0:d=0  hl=2 l=    8 cons: SEQUENCE
+//!     2:d=1  hl=2 l=    1 prim:   INTEGER 42
+//!     5:d=1  hl=2 l=    3 prim:   OCTET STRING, encapsulates:
+//!     7:d=2  hl=2 l=    1 prim:     BOOLEAN TRUE
+//! ```
+//!
+//! Each line shows the offset of the value in the input, its nesting depth, the length of its
+//! header and contents, and whether it uses the primitive or constructed form, followed by its tag
+//! and decoded value. Indefinite lengths are shown as `l=  inf`.
+//!
+//! When the `dump-oid-names` feature is enabled, object identifiers are shown along with their
+//! names from the `const-oid` database.
+
+use crate::{Class, Decode, EncodingRules, Error, Header, Reader, Result, SliceReader, Tag};
+use core::{fmt, str};
+
+#[cfg(feature = "oid")]
+use const_oid::{ObjectIdentifier, RelativeOid};
+
+#[cfg(feature = "dump-oid-names")]
+use const_oid::db::DB;
+
+/// Maximum nesting depth which will be descended into.
+const MAX_DEPTH: usize = 32;
+
+/// Maximum number of octets shown on a single line of a hex dump.
+const HEX_LINE_LEN: usize = 16;
+
+/// Width of the prefix of each line which precedes the indented tag.
+const PREFIX_WIDTH: usize = 30;
+
+/// Indented listing of every tag-length-value in an encoding, which is produced by its
+/// [`fmt::Display`] impl.
+///
+/// `OCTET STRING` and `BIT STRING` values whose contents consist of complete tag-length-values
+/// are descended into and listed as "encapsulating" those contents.
+///
+/// Values nested more deeply than a fixed limit, including encapsulated ones, are shown as a hex
+/// dump rather than descended into.
+///
+/// Input which can't be parsed doesn't cause the [`fmt::Display`] impl to fail: instead an error
+/// line is printed, and listing continues after the enclosing constructed value if possible.
+#[derive(Clone, Copy, Debug)]
+pub struct Dump<'a> {
+    /// Input to be listed.
+    bytes: &'a [u8],
+
+    /// Encoding rules used to parse the input.
+    encoding_rules: EncodingRules,
+}
+
+impl<'a> Dump<'a> {
+    /// Create a listing of the given input.
+    ///
+    /// The input is parsed using [`EncodingRules::Ber`], which is a superset of DER, so that
+    /// indefinite-length and segmented string encodings can be listed too.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::new_with_encoding_rules(bytes, EncodingRules::Ber)
+    }
+
+    /// Create a listing of the given input, which is parsed using the given encoding rules.
+    pub fn new_with_encoding_rules(bytes: &'a [u8], encoding_rules: EncodingRules) -> Self {
+        Self {
+            bytes,
+            encoding_rules,
+        }
+    }
+
+    /// Write a line for each tag-length-value in `bytes`, which begins at `offset` in the input.
+    fn write_tlvs(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        bytes: &[u8],
+        offset: usize,
+        depth: usize,
+    ) -> fmt::Result {
+        let mut reader = match SliceReader::new_with_encoding_rules(bytes, self.encoding_rules) {
+            Ok(reader) => reader,
+            Err(err) => return write_error(f, offset, err),
+        };
+
+        while !reader.is_finished() {
+            let position = offset.saturating_add(usize::try_from(reader.position()).unwrap_or(0));
+
+            let (header, header_len, value) = match read_tlv(&mut reader) {
+                Ok(tlv) => tlv,
+                Err(err) => return write_error(f, position, err),
+            };
+
+            let form = if header.is_constructed() {
+                "cons"
+            } else {
+                "prim"
+            };
+            write!(f, "{position:>5}:d={depth:<2} hl={header_len} ")?;
+
+            if header.is_indefinite() {
+                write!(f, "l=  inf {form}: ")?;
+            } else {
+                write!(f, "l={:>5} {form}: ", header.length)?;
+            }
+
+            write_indent(f, depth)?;
+            write_tag(f, header.tag)?;
+
+            let value_offset = position.saturating_add(header_len);
+
+            if header.is_constructed() {
+                writeln!(f)?;
+
+                if depth < MAX_DEPTH {
+                    self.write_tlvs(f, value, value_offset, depth + 1)?;
+                } else {
+                    write_hex_lines(f, value, depth + 1)?;
+                }
+            } else {
+                self.write_primitive(f, header.tag, value, value_offset, depth)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write the decoded value of a primitive encoding, completing the current line.
+    fn write_primitive(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        tag: Tag,
+        value: &[u8],
+        offset: usize,
+        depth: usize,
+    ) -> fmt::Result {
+        match tag {
+            Tag::Boolean => match value {
+                [0] => writeln!(f, " FALSE"),
+                [_] => writeln!(f, " TRUE"),
+                _ => write_hex(f, value, depth),
+            },
+            Tag::Integer | Tag::Enumerated => match int_value(value) {
+                Some(n) => writeln!(f, " {n}"),
+                None => write_hex(f, value, depth),
+            },
+            Tag::Null => writeln!(f),
+            #[cfg(feature = "oid")]
+            Tag::ObjectIdentifier => match ObjectIdentifier::from_bytes(value) {
+                #[cfg(feature = "dump-oid-names")]
+                Ok(oid) => match DB.by_oid(&oid) {
+                    Some(name) => writeln!(f, " {name} ({oid})"),
+                    None => writeln!(f, " {oid}"),
+                },
+                #[cfg(not(feature = "dump-oid-names"))]
+                Ok(oid) => writeln!(f, " {oid}"),
+                Err(_) => write_hex(f, value, depth),
+            },
+            #[cfg(feature = "oid")]
+            Tag::RelativeOid => match RelativeOid::from_bytes(value) {
+                Ok(oid) => writeln!(f, " {oid}"),
+                Err(_) => write_hex(f, value, depth),
+            },
+            Tag::OctetString if depth < MAX_DEPTH && self.is_encapsulated(value) => {
+                writeln!(f, ", encapsulates:")?;
+                self.write_tlvs(f, value, offset, depth + 1)
+            }
+            Tag::BitString => match value.split_first() {
+                Some((0, contents)) if depth < MAX_DEPTH && self.is_encapsulated(contents) => {
+                    writeln!(f, ", encapsulates:")?;
+                    self.write_tlvs(f, contents, offset.saturating_add(1), depth + 1)
+                }
+                Some((unused_bits, contents)) => {
+                    write!(f, " ({unused_bits} unused bits)")?;
+                    write_hex(f, contents, depth)
+                }
+                None => write_hex(f, value, depth),
+            },
+            Tag::BmpString => write_utf16(f, value, depth),
+            Tag::UniversalString => write_ucs4(f, value, depth),
+            Tag::Date
+            | Tag::DateTime
+            | Tag::Duration
+            | Tag::GeneralizedTime
+            | Tag::GeneralString
+            | Tag::Ia5String
+            | Tag::NumericString
+            | Tag::PrintableString
+            | Tag::TeletexString
+            | Tag::TimeOfDay
+            | Tag::UtcTime
+            | Tag::Utf8String
+            | Tag::VideotexString
+            | Tag::VisibleString => match str::from_utf8(value) {
+                Ok(s) => writeln!(f, " {s:?}"),
+                Err(_) => write_hex(f, value, depth),
+            },
+            _ => write_hex(f, value, depth),
+        }
+    }
+
+    /// Do the given contents consist entirely of complete tag-length-values?
+    ///
+    /// Only the outermost tag-length-values are checked, so checking the contents of nested
+    /// encapsulating values doesn't parse the same input repeatedly. Errors within the contents of
+    /// constructed values are reported when they're listed.
+    fn is_encapsulated(&self, bytes: &[u8]) -> bool {
+        let Ok(mut reader) = SliceReader::new_with_encoding_rules(bytes, self.encoding_rules)
+        else {
+            return false;
+        };
+
+        while !reader.is_finished() {
+            if read_tlv(&mut reader).is_err() {
+                return false;
+            }
+        }
+
+        !bytes.is_empty()
+    }
+}
+
+impl fmt::Display for Dump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_tlvs(f, self.bytes, 0, 0)
+    }
+}
+
+/// Read a tag-length-value, returning its header, the length of the header, and its contents.
+fn read_tlv<'a>(reader: &mut SliceReader<'a>) -> Result<(Header, usize, &'a [u8])> {
+    let start = reader.position();
+    let header = Header::decode(reader)?;
+    let header_len = usize::try_from((reader.position() - start)?)?;
+    let value = reader.read_value(header, |r| {
+        let len = header.value_len(r)?;
+        r.read_slice(len)
+    })?;
+    Ok((header, header_len, value))
+}
+
+/// Write a line describing an error at the given offset in the input.
+fn write_error(f: &mut fmt::Formatter<'_>, offset: usize, err: Error) -> fmt::Result {
+    writeln!(f, "{offset:>5}: error: {}", err.kind())
+}
+
+/// Write the indentation for the given nesting depth.
+fn write_indent(f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+    for _ in 0..depth {
+        f.write_str("  ")?;
+    }
+
+    Ok(())
+}
+
+/// Write a tag using ASN.1 notation for its class.
+fn write_tag(f: &mut fmt::Formatter<'_>, tag: Tag) -> fmt::Result {
+    match tag.class() {
+        Class::Universal => write!(f, "{tag}"),
+        Class::Application => write!(f, "[APPLICATION {}]", tag.number()),
+        Class::ContextSpecific => write!(f, "[{}]", tag.number()),
+        Class::Private => write!(f, "[PRIVATE {}]", tag.number()),
+    }
+}
+
+/// Write a hex dump of the given contents, completing the current line.
+///
+/// Short contents are written inline, while longer contents are written on subsequent lines.
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8], depth: usize) -> fmt::Result {
+    if bytes.len() <= HEX_LINE_LEN {
+        for byte in bytes {
+            write!(f, " {byte:02X}")?;
+        }
+
+        writeln!(f)
+    } else {
+        writeln!(f)?;
+        write_hex_lines(f, bytes, depth + 1)
+    }
+}
+
+/// Write a hex dump of the given contents on lines of their own, aligned with values at the given
+/// nesting depth.
+fn write_hex_lines(f: &mut fmt::Formatter<'_>, bytes: &[u8], depth: usize) -> fmt::Result {
+    for line in bytes.chunks(HEX_LINE_LEN) {
+        write!(f, "{:PREFIX_WIDTH$}", "")?;
+        write_indent(f, depth)?;
+
+        for (i, byte) in line.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+
+            write!(f, "{byte:02X}")?;
+        }
+
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+/// Write big endian UTF-16 (i.e. `BMPString`) contents as a quoted string, falling back to a hex
+/// dump if they're invalid.
+fn write_utf16(f: &mut fmt::Formatter<'_>, bytes: &[u8], depth: usize) -> fmt::Result {
+    let code_units = || {
+        bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+    };
+
+    if bytes.len() % 2 != 0 || char::decode_utf16(code_units()).any(|c| c.is_err()) {
+        return write_hex(f, bytes, depth);
+    }
+
+    f.write_str(" \"")?;
+
+    for c in char::decode_utf16(code_units()).flatten() {
+        write!(f, "{}", c.escape_debug())?;
+    }
+
+    writeln!(f, "\"")
+}
+
+/// Write UCS-4 (i.e. `UniversalString`) contents as a quoted string, falling back to a hex dump
+/// if they're invalid.
+fn write_ucs4(f: &mut fmt::Formatter<'_>, bytes: &[u8], depth: usize) -> fmt::Result {
+    let chars = || {
+        bytes.chunks_exact(4).map(|chunk| {
+            char::from_u32(u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        })
+    };
+
+    if bytes.len() % 4 != 0 || chars().any(|c| c.is_none()) {
+        return write_hex(f, bytes, depth);
+    }
+
+    f.write_str(" \"")?;
+
+    for c in chars().flatten() {
+        write!(f, "{}", c.escape_debug())?;
+    }
+
+    writeln!(f, "\"")
+}
+
+/// Decode the contents of an `INTEGER` or `ENUMERATED` if they fit in an [`i128`].
+fn int_value(bytes: &[u8]) -> Option<i128> {
+    let (&first, _) = bytes.split_first()?;
+
+    if bytes.len() > 16 {
+        return None;
+    }
+
+    let sign = if first & 0x80 != 0 { -1 } else { 0 };
+    Some(
+        bytes
+            .iter()
+            .fold(sign, |acc: i128, &byte| (acc << 8) | i128::from(byte)),
+    )
+}
+
+#[cfg(all(test, feature = "alloc"))]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::Dump;
+    use crate::EncodingRules;
+    use alloc::{string::ToString, vec, vec::Vec};
+    use hex_literal::hex;
+
+    #[test]
+    fn primitives() {
+        let bytes = hex!(
+            "3029 0101FF 020180 0A0101 0500 0C026869 1E0400680069 170D3235303130313030303030305A
+             A003 020100"
+        );
+
+        assert_eq!(
+            Dump::new(&bytes).to_string(),
+            "    0:d=0  hl=2 l=   41 cons: SEQUENCE
+    2:d=1  hl=2 l=    1 prim:   BOOLEAN TRUE
+    5:d=1  hl=2 l=    1 prim:   INTEGER -128
+    8:d=1  hl=2 l=    1 prim:   ENUMERATED 1
+   11:d=1  hl=2 l=    0 prim:   NULL
+   13:d=1  hl=2 l=    2 prim:   UTF8String \"hi\"
+   17:d=1  hl=2 l=    4 prim:   BMPString \"hi\"
+   23:d=1  hl=2 l=   13 prim:   UTCTime \"250101000000Z\"
+   38:d=1  hl=2 l=    3 cons:   [0]
+   40:d=2  hl=2 l=    1 prim:     INTEGER 0
+"
+        );
+    }
+
+    #[cfg(feature = "dump-oid-names")]
+    #[test]
+    fn oid_names() {
+        let bytes = hex!("300D 06092A864886F70D010101 0500");
+
+        assert_eq!(
+            Dump::new(&bytes).to_string(),
+            "    0:d=0  hl=2 l=   13 cons: SEQUENCE
+    2:d=1  hl=2 l=    9 prim:   OBJECT IDENTIFIER rsaEncryption (1.2.840.113549.1.1.1)
+   13:d=1  hl=2 l=    0 prim:   NULL
+"
+        );
+    }
+
+    #[test]
+    fn encapsulated() {
+        let bytes = hex!("300F 0403020105 0304000101FF 0402AABB");
+
+        assert_eq!(
+            Dump::new(&bytes).to_string(),
+            "    0:d=0  hl=2 l=   15 cons: SEQUENCE
+    2:d=1  hl=2 l=    3 prim:   OCTET STRING, encapsulates:
+    4:d=2  hl=2 l=    1 prim:     INTEGER 5
+    7:d=1  hl=2 l=    4 prim:   BIT STRING, encapsulates:
+   10:d=2  hl=2 l=    1 prim:     BOOLEAN TRUE
+   13:d=1  hl=2 l=    2 prim:   OCTET STRING AA BB
+"
+        );
+    }
+
+    #[test]
+    fn nested_encapsulation() {
+        // 40 levels of OCTET STRING, each encapsulating the next, around a NULL
+        let mut bytes = vec![0x05, 0x00];
+        for _ in 0..40 {
+            let len = u8::try_from(bytes.len()).unwrap();
+            bytes.splice(0..0, [0x04, len]);
+        }
+
+        let dump = Dump::new(&bytes).to_string();
+        let lines = dump.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 33);
+        assert!(lines[31].ends_with("OCTET STRING, encapsulates:"));
+        assert!(
+            lines[32].ends_with("OCTET STRING 04 0E 04 0C 04 0A 04 08 04 06 04 04 04 02 05 00")
+        );
+    }
+
+    #[test]
+    fn hex_lines() {
+        let mut bytes = [0x11; 20];
+        bytes[..3].copy_from_slice(&hex!("0312 04"));
+
+        assert_eq!(
+            Dump::new(&bytes).to_string(),
+            "    0:d=0  hl=2 l=   18 prim: BIT STRING (4 unused bits)
+                                11 11 11 11 11 11 11 11 11 11 11 11 11 11 11 11
+                                11
+"
+        );
+    }
+
+    #[test]
+    fn indefinite_length() {
+        let bytes = hex!("3080 2480 040161 040162 0000 0000");
+
+        assert_eq!(
+            Dump::new(&bytes).to_string(),
+            "    0:d=0  hl=2 l=  inf cons: SEQUENCE
+    2:d=1  hl=2 l=  inf cons:   OCTET STRING
+    4:d=2  hl=2 l=    1 prim:     OCTET STRING 61
+    7:d=2  hl=2 l=    1 prim:     OCTET STRING 62
+"
+        );
+
+        let err = Dump::new_with_encoding_rules(&bytes, EncodingRules::Der).to_string();
+        assert!(err.starts_with("    0: error: "));
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = hex!("3006 020101 0205");
+
+        assert_eq!(
+            Dump::new(&bytes).to_string(),
+            "    0: error: ASN.1 DER message is incomplete: expected 8, actual 7\n"
+        );
+    }
+}
@@ -1,10 +1,19 @@
 //! Trait definition for [`Encode`].
 
-use crate::{Header, Length, Result, SliceWriter, Tagged, Writer};
+use crate::{
+    FixedTag, Header, Length, Result, SliceWriter, Tag, Tagged, Writer,
+    writer::{array::ArrayWriter, cer},
+};
 use core::marker::PhantomData;
 
 #[cfg(feature = "alloc")]
-use {alloc::boxed::Box, alloc::vec::Vec};
+use {crate::ErrorKind, alloc::boxed::Box, alloc::vec::Vec};
+
+#[cfg(feature = "digest")]
+use {
+    crate::writer::digest::DigestAdapter,
+    digest::{Digest, Output},
+};
 
 #[cfg(feature = "pem")]
 use {
@@ -13,11 +22,8 @@ use {
     pem_rfc7468::{self as pem, LineEnding, PemLabel},
 };
 
-#[cfg(any(feature = "alloc", feature = "pem"))]
-use crate::ErrorKind;
-
 #[cfg(doc)]
-use crate::Tag;
+use crate::EncodingRules;
 
 /// Encoding trait.
 pub trait Encode {
@@ -35,6 +41,39 @@ pub trait Encode {
         writer.finish()
     }
 
+    /// Encode this value into an array whose length is that of its encoding, which must be known
+    /// at compile time:
+    ///
+    /// ```
+    /// use der::Encode;
+    ///
+    /// let der: [u8; 3] = true.encode_to_array()?;
+    /// assert_eq!(der, [0x01, 0x01, 0xFF]);
+    /// # Ok::<(), der::Error>(())
+    /// ```
+    ///
+    /// Using an array whose length `N` differs from [`FixedLen::ENCODED_LEN`] fails
+    /// compilation. The header is encoded from [`FixedLen::ENCODED_LEN`], so the length of the
+    /// value isn't computed at runtime.
+    fn encode_to_array<const N: usize>(&self) -> Result<[u8; N]>
+    where
+        Self: FixedLen,
+    {
+        let value_len = const {
+            assert!(
+                Self::ENCODED_LEN.as_usize() == N,
+                "array length differs from `FixedLen::ENCODED_LEN`"
+            );
+
+            Self::ENCODED_LEN.value_len_const(Self::TAG)
+        };
+
+        let mut writer = ArrayWriter::<N>::new();
+        Header::new(Self::TAG, value_len)?.encode(&mut writer)?;
+        self.encode_value(&mut writer)?;
+        writer.finish()
+    }
+
     /// Encode this message as ASN.1 DER, appending it to the provided
     /// byte vector.
     #[cfg(feature = "alloc")]
@@ -64,6 +103,15 @@ pub trait Encode {
         self.encode_to_vec(&mut buf)?;
         Ok(buf)
     }
+
+    /// Compute the digest of the DER encoding of this value using the hash function `D`, without
+    /// buffering the encoding.
+    #[cfg(feature = "digest")]
+    fn digest<D: Digest>(&self) -> Result<Output<D>> {
+        let mut writer = DigestAdapter(D::new());
+        self.encode(&mut writer)?;
+        Ok(writer.0.finalize())
+    }
 }
 
 impl<T> Encode for T
@@ -76,7 +124,13 @@ where
     }
 
     /// Encode this value as ASN.1 DER using the provided [`Writer`].
+    ///
+    /// If the writer uses [`EncodingRules::Cer`], the value is encoded as CER instead.
     fn encode(&self, writer: &mut impl Writer) -> Result<()> {
+        if writer.encoding_rules().is_cer() {
+            return cer::encode(self, writer);
+        }
+
         self.header()?.encode(writer)?;
         self.encode_value(writer)
     }
@@ -97,6 +151,16 @@ where
     }
 }
 
+/// Types whose DER encoding has the same length for every value.
+///
+/// This allows sizing buffers at compile time, e.g. with [`Encode::encode_to_array`], and
+/// can be derived for `SEQUENCE`s whose fields all impl it: see the `fixed_len` attribute of
+/// the [`Sequence`][`crate::Sequence`] derive macro.
+pub trait FixedLen: EncodeValue + FixedTag {
+    /// Length of the DER encoding of any value of this type, including its header.
+    const ENCODED_LEN: Length;
+}
+
 /// PEM encoding trait.
 ///
 /// This trait is automatically impl'd for any type which impls both
@@ -144,6 +208,17 @@ pub trait EncodeValue {
     /// Encode value (sans [`Tag`]+[`Length`] header) as ASN.1 DER using the
     /// provided [`Writer`].
     fn encode_value(&self, encoder: &mut impl Writer) -> Result<()>;
+
+    /// Get the [`Tag`] of the string type this value is encoded as, if it's `IMPLICIT`ly tagged.
+    ///
+    /// CER splits the contents of string types into segments when they're longer than 1000 octets
+    /// (X.690 Section 9.2), which it recognizes by their tag unless this returns the tag of the
+    /// underlying string type. Types which encode the contents of another value under their own
+    /// tag, like [`ContextSpecific`][`crate::asn1::ContextSpecific`] in [`TagMode::Implicit`][`crate::TagMode::Implicit`],
+    /// need to override it.
+    fn string_tag(&self) -> Option<Tag> {
+        None
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -157,4 +232,50 @@ where
     fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
         T::encode_value(self, writer)
     }
+    fn string_tag(&self) -> Option<Tag> {
+        T::string_tag(self)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Encode, FixedLen};
+    use crate::asn1::{Date, LocalDateTime, Null, TimeOfDay, UtcTime};
+    use hex_literal::hex;
+
+    /// Check that [`FixedLen::ENCODED_LEN`] matches the length of the encoding of `value`.
+    fn check<T: FixedLen>(value: T) {
+        assert_eq!(T::ENCODED_LEN, value.encoded_len().unwrap());
+    }
+
+    #[test]
+    fn fixed_len() {
+        check(true);
+        check(());
+        check(Null);
+        let date = Date::new(2024, 2, 29).unwrap();
+        let time = TimeOfDay::new(23, 59, 59).unwrap();
+        check(date);
+        check(time);
+        check(LocalDateTime::new(date, time));
+        check(UtcTime::from_unix_duration(Default::default()).unwrap());
+        check([[false; 2]; 100]);
+    }
+
+    #[test]
+    fn encode_to_array() {
+        assert_eq!([(); 2].encode_to_array().unwrap(), hex!("3004 0500 0500"));
+
+        let der: [u8; 8] = [true, false].encode_to_array().unwrap();
+        assert_eq!(der, hex!("3006 0101FF 010100"));
+
+        // Long form length, whose header is also encoded from `ENCODED_LEN`
+        let value = [[false; 2]; 100];
+        let der: [u8; 804] = value.encode_to_array().unwrap();
+        assert_eq!(der[..4], hex!("3082 0320"));
+
+        let mut buf = [0u8; 804];
+        assert_eq!(der[..], *value.encode_to_slice(&mut buf).unwrap());
+    }
 }
@@ -50,6 +50,10 @@ where
     fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
         self.0.encode_value(writer)
     }
+
+    fn string_tag(&self) -> Option<Tag> {
+        self.0.string_tag()
+    }
 }
 
 impl<T> Tagged for EncodeValueRef<'_, T>
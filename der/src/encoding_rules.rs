@@ -6,13 +6,43 @@
 /// In addition to the Distinguished Encoding Rules (DER), this crate also supports a strict subset
 /// of the Basic Encoding Rules (BER) which supports the minimum amount of additional productions
 /// beyond DER needed to interoperate with other implementations of cryptography-oriented formats
-/// which utilize BER, e.g. CMS, PKCS#8.
+/// which utilize BER, e.g. CMS, PKCS#8, PKCS#12.
+///
+/// Under BER, constructed values may be encoded using indefinite lengths which are terminated by
+/// end-of-contents octets.
+///
+/// The Canonical Encoding Rules (CER) are supported as an output format via
+/// [`CerWriter`][`crate::CerWriter`].
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
 pub enum EncodingRules {
     /// Basic Encoding Rules.
     Ber,
 
+    /// Canonical Encoding Rules.
+    ///
+    /// Only supported for encoding: readers reject it. CER is a subset of BER, so use
+    /// [`EncodingRules::Ber`] to decode it.
+    Cer,
+
     /// Distinguished Encoding Rules.
     #[default]
     Der,
 }
+
+impl EncodingRules {
+    /// Are we using Basic Encoding Rules?
+    pub const fn is_ber(self) -> bool {
+        matches!(self, EncodingRules::Ber)
+    }
+
+    /// Are we using Canonical Encoding Rules?
+    pub const fn is_cer(self) -> bool {
+        matches!(self, EncodingRules::Cer)
+    }
+
+    /// Are we using Distinguished Encoding Rules?
+    pub const fn is_der(self) -> bool {
+        matches!(self, EncodingRules::Der)
+    }
+}
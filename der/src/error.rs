@@ -1,8 +1,11 @@
 //! Error types.
 
+mod path;
+
+pub use self::path::FieldPath;
 pub use core::str::Utf8Error;
 
-use crate::{Length, Tag};
+use crate::{DecodeLimit, Length, Tag, constraint::Constraint};
 use core::{convert::Infallible, fmt, num::TryFromIntError};
 
 #[cfg(feature = "oid")]
@@ -15,13 +18,20 @@ use crate::pem;
 pub type Result<T> = core::result::Result<T, Error>;
 
 /// Error type.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+///
+/// Errors compare equal if their [`ErrorKind`] and position match, regardless of their
+/// [`FieldPath`].
+#[derive(Copy, Clone, Debug)]
 pub struct Error {
     /// Kind of error.
     kind: ErrorKind,
 
     /// Position inside of message where error occurred.
     position: Option<Length>,
+
+    /// Path to the field in which the error occurred.
+    #[cfg(feature = "error-path")]
+    path: FieldPath,
 }
 
 impl Error {
@@ -30,6 +40,8 @@ impl Error {
         Error {
             kind,
             position: Some(position),
+            #[cfg(feature = "error-path")]
+            path: FieldPath::EMPTY,
         }
     }
     /// Create a new [`Error`], without known position.
@@ -37,6 +49,8 @@ impl Error {
         Error {
             kind,
             position: None,
+            #[cfg(feature = "error-path")]
+            path: FieldPath::EMPTY,
         }
     }
 
@@ -64,21 +78,98 @@ impl Error {
         self.position
     }
 
+    /// Get the path to the field in which the error occurred.
+    ///
+    /// Paths are only recorded when the `error-path` feature is enabled, and are always empty
+    /// otherwise.
+    pub fn path(&self) -> &FieldPath {
+        #[cfg(feature = "error-path")]
+        return &self.path;
+
+        #[cfg(not(feature = "error-path"))]
+        return &FieldPath::EMPTY;
+    }
+
+    /// Record that this error occurred while decoding the element at the given index of a
+    /// `SEQUENCE OF` or `SET OF`.
+    ///
+    /// This is a no-op unless the `error-path` feature is enabled.
+    #[allow(unused_mut, unused_variables)]
+    pub fn in_element(mut self, index: usize) -> Self {
+        #[cfg(feature = "error-path")]
+        self.path.push_index(index);
+        self
+    }
+
+    /// Apply `f` to an error of type `E` if it's an [`Error`], otherwise return it unchanged.
+    ///
+    /// This allows annotating errors of types which are generic over their error type, e.g. the
+    /// [`Decode::Error`][`crate::Decode::Error`] of a type parameter, which is usually an
+    /// [`Error`] but may be a custom error type.
+    pub fn annotate<E: 'static>(mut err: E, f: impl FnOnce(Self) -> Self) -> E {
+        if let Some(err) = (&mut err as &mut dyn core::any::Any).downcast_mut::<Self>() {
+            *err = f(*err);
+        }
+
+        err
+    }
+
+    /// Record that this error occurred while decoding the given field of the given type.
+    ///
+    /// Used by the code generated by the custom derive macros, which annotate errors from the
+    /// innermost field outwards, so that the outermost type becomes the root of the [`FieldPath`].
+    /// Names are passed by reference so that paths only store thin pointers to them, which keeps
+    /// [`Error`] small.
+    ///
+    /// This is a no-op unless the `error-path` feature is enabled.
+    #[doc(hidden)]
+    #[allow(unused_mut, unused_variables)]
+    pub fn in_field(
+        mut self,
+        type_name: &'static &'static str,
+        field_name: &'static &'static str,
+    ) -> Self {
+        #[cfg(feature = "error-path")]
+        self.path.push_field(type_name, field_name);
+        self
+    }
+
+    /// Record that an error of type `E` occurred while decoding the given field, if any.
+    ///
+    /// Used by the code generated by the custom derive macros, which track the field currently
+    /// being decoded and annotate errors with it once they propagate out of the type.
+    #[doc(hidden)]
+    pub fn annotate_field<E: 'static>(
+        err: E,
+        type_name: &'static &'static str,
+        field_name: Option<&'static &'static str>,
+    ) -> E {
+        match field_name {
+            Some(field_name) => Self::annotate(err, |err| err.in_field(type_name, field_name)),
+            None => err,
+        }
+    }
+
     /// For errors occurring inside of a nested message, extend the position
     /// count by the location where the nested message occurs.
     pub(crate) fn nested(self, nested_position: Length) -> Self {
         // TODO(tarcieri): better handle length overflows occurring in this calculation?
         let position = (nested_position + self.position.unwrap_or_default()).ok();
 
-        Self {
-            kind: self.kind,
-            position,
-        }
+        Self { position, ..self }
     }
 }
 
 impl core::error::Error for Error {}
 
+impl Eq for Error {}
+
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.position == other.position
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.kind)?;
@@ -87,6 +178,10 @@ impl fmt::Display for Error {
             write!(f, " at DER byte {}", pos)?;
         }
 
+        if !self.path().is_empty() {
+            write!(f, " in {}", self.path())?;
+        }
+
         Ok(())
     }
 }
@@ -105,19 +200,13 @@ impl From<Infallible> for Error {
 
 impl From<TryFromIntError> for Error {
     fn from(_: TryFromIntError) -> Error {
-        Error {
-            kind: ErrorKind::Overflow,
-            position: None,
-        }
+        ErrorKind::Overflow.into()
     }
 }
 
 impl From<Utf8Error> for Error {
     fn from(err: Utf8Error) -> Error {
-        Error {
-            kind: ErrorKind::Utf8(err),
-            position: None,
-        }
+        ErrorKind::Utf8(err).into()
     }
 }
 
@@ -165,6 +254,12 @@ impl From<time::error::ComponentRange> for Error {
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum ErrorKind {
+    /// Value violates an ASN.1 subtype constraint.
+    ConstraintViolation {
+        /// Kind of constraint which was violated.
+        constraint: Constraint,
+    },
+
     /// Date-and-time related errors.
     DateTime,
 
@@ -201,6 +296,16 @@ pub enum ErrorKind {
     /// Indefinite length disallowed.
     IndefiniteLength,
 
+    /// Malformed JSON, or a JSON value which doesn't match the JER encoding of the type being
+    /// decoded.
+    Json,
+
+    /// A [`DecodeLimits`][`crate::DecodeLimits`] limit was exceeded.
+    LimitExceeded {
+        /// Limit which was exceeded.
+        limit: DecodeLimit,
+    },
+
     /// Incorrect length for a given field.
     Length {
         /// Tag of the value being decoded.
@@ -311,6 +416,9 @@ impl ErrorKind {
 impl fmt::Display for ErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            ErrorKind::ConstraintViolation { constraint } => {
+                write!(f, "value violates ASN.1 {} constraint", constraint)
+            }
             ErrorKind::DateTime => write!(f, "date/time error"),
             ErrorKind::Failed => write!(f, "operation failed"),
             #[cfg(feature = "std")]
@@ -326,6 +434,8 @@ impl fmt::Display for ErrorKind {
             #[cfg(feature = "std")]
             ErrorKind::Io(err) => write!(f, "I/O error: {:?}", err),
             ErrorKind::IndefiniteLength => write!(f, "indefinite length disallowed"),
+            ErrorKind::Json => write!(f, "malformed or unexpected JSON value"),
+            ErrorKind::LimitExceeded { limit } => write!(f, "decoding limit exceeded: {}", limit),
             ErrorKind::Length { tag } => write!(f, "incorrect length for {}", tag),
             ErrorKind::Noncanonical { tag } => {
                 write!(f, "ASN.1 {} not canonically encoded as DER", tag)
@@ -0,0 +1,190 @@
+//! Paths to the fields in which errors occurred.
+
+use core::fmt;
+
+/// Path to the field in which an [`Error`][`crate::Error`] occurred, e.g.
+/// `Certificate.tbs_certificate.extensions[3].extn_value`.
+///
+/// Paths are recorded automatically as errors propagate outwards, by the code generated by the
+/// custom derive macros and by `SEQUENCE OF`/`SET OF` types via
+/// [`Error::in_element`][`crate::Error::in_element`].
+///
+/// Recording paths requires the `error-path` feature, since it grows [`Error`][`crate::Error`]
+/// considerably. Without it, paths are always empty.
+///
+/// Since [`Error`][`crate::Error`] is `Copy` and usable in heapless environments, paths have a
+/// fixed maximum depth of [`FieldPath::MAX_DEPTH`] segments. When this is exceeded, the innermost
+/// segments are retained and the omitted ones are displayed as `...`.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct FieldPath {
+    /// Name of the outermost type.
+    root: Option<&'static &'static str>,
+
+    /// Names of the fields in each segment, innermost first.
+    names: [Option<&'static &'static str>; Self::MAX_DEPTH],
+
+    /// Indices of the elements in each segment, innermost first, or [`NO_INDEX`] if none.
+    indices: [u16; Self::MAX_DEPTH],
+
+    /// Number of segments.
+    len: u8,
+
+    /// Were outer segments omitted due to exceeding the maximum depth?
+    truncated: bool,
+}
+
+/// Placeholder for segments which don't have an index.
+const NO_INDEX: u16 = u16::MAX;
+
+impl FieldPath {
+    /// Maximum number of segments which are recorded.
+    pub const MAX_DEPTH: usize = 5;
+
+    /// Empty path.
+    pub(super) const EMPTY: Self = Self {
+        root: None,
+        names: [None; Self::MAX_DEPTH],
+        indices: [NO_INDEX; Self::MAX_DEPTH],
+        len: 0,
+        truncated: false,
+    };
+
+    /// Is this path empty, i.e. was the error not annotated with any fields?
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none() && self.len == 0
+    }
+
+    /// Record that the error occurred in the given field of the given type.
+    #[cfg_attr(not(feature = "error-path"), allow(dead_code))]
+    pub(super) fn push_field(
+        &mut self,
+        type_name: &'static &'static str,
+        field_name: &'static &'static str,
+    ) {
+        self.root = Some(type_name);
+
+        // An index without a name belongs to the field which contains the element
+        if let Some(last) = usize::from(self.len).checked_sub(1) {
+            if self.names[last].is_none() {
+                self.names[last] = Some(field_name);
+                return;
+            }
+        }
+
+        self.push(Some(field_name), NO_INDEX);
+    }
+
+    /// Record that the error occurred in the element at the given index of a collection.
+    #[cfg_attr(not(feature = "error-path"), allow(dead_code))]
+    pub(super) fn push_index(&mut self, index: usize) {
+        // Indices which don't fit are clamped, which is unlikely to matter in practice
+        let index = u16::try_from(index).unwrap_or(NO_INDEX).min(NO_INDEX - 1);
+        self.push(None, index);
+    }
+
+    /// Add a new outermost segment.
+    #[cfg_attr(not(feature = "error-path"), allow(dead_code))]
+    fn push(&mut self, name: Option<&'static &'static str>, index: u16) {
+        let len = usize::from(self.len);
+
+        if len < Self::MAX_DEPTH {
+            self.names[len] = name;
+            self.indices[len] = index;
+            self.len += 1;
+        } else {
+            self.truncated = true;
+        }
+    }
+}
+
+impl Default for FieldPath {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+
+        if let Some(root) = self.root {
+            f.write_str(root)?;
+            first = false;
+        }
+
+        if self.truncated {
+            f.write_str(if first { "..." } else { ".." })?;
+        }
+
+        let len = usize::from(self.len);
+
+        for (name, &index) in self.names[..len].iter().zip(&self.indices[..len]).rev() {
+            if let Some(name) = name {
+                if !first {
+                    f.write_str(".")?;
+                }
+
+                f.write_str(name)?;
+            }
+
+            if index != NO_INDEX {
+                write!(f, "[{}]", index)?;
+            }
+
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FieldPath({})", self)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::FieldPath;
+    use alloc::string::ToString;
+
+    #[test]
+    fn display() {
+        let mut path = FieldPath::default();
+        assert!(path.is_empty());
+        assert_eq!(path.to_string(), "");
+
+        path.push_field(&"Extension", &"extn_value");
+        path.push_index(3);
+        path.push_field(&"TbsCertificate", &"extensions");
+        path.push_field(&"Certificate", &"tbs_certificate");
+        assert!(!path.is_empty());
+        assert_eq!(
+            path.to_string(),
+            "Certificate.tbs_certificate.extensions[3].extn_value"
+        );
+    }
+
+    #[test]
+    fn nested_indices() {
+        let mut path = FieldPath::default();
+        path.push_index(1);
+        assert_eq!(path.to_string(), "[1]");
+
+        path.push_index(2);
+        path.push_field(&"Matrix", &"rows");
+        assert_eq!(path.to_string(), "Matrix.rows[2][1]");
+    }
+
+    #[test]
+    fn truncated() {
+        let mut path = FieldPath::default();
+
+        for _ in 0..FieldPath::MAX_DEPTH + 2 {
+            path.push_field(&"Node", &"child");
+        }
+
+        assert_eq!(path.to_string(), "Node...child.child.child.child.child");
+    }
+}
@@ -1,6 +1,9 @@
 //! ASN.1 DER headers.
 
-use crate::{Decode, DerOrd, Encode, Error, ErrorKind, Length, Reader, Result, Tag, Writer};
+use crate::{
+    Decode, DerOrd, Encode, EncodingRules, Error, ErrorKind, Length, Reader, Result, SliceReader,
+    Tag, Writer, length::INDEFINITE_LENGTH_OCTET, tag::CONSTRUCTED_FLAG,
+};
 use core::cmp::Ordering;
 
 /// ASN.1 DER headers: tag + length component of TLV-encoded values
@@ -10,7 +13,17 @@ pub struct Header {
     pub tag: Tag,
 
     /// Length of the encoded value
+    ///
+    /// For values using the BER indefinite length form (see [`Header::is_indefinite`]) the length
+    /// isn't encoded, so this is zero: their contents need to be read with
+    /// [`Reader::read_contents`] instead.
     pub length: Length,
+
+    /// Is the value encoded using the constructed form?
+    constructed: bool,
+
+    /// Is the value encoded using the BER indefinite length form?
+    indefinite: bool,
 }
 
 impl Header {
@@ -22,25 +35,140 @@ impl Header {
     /// Returns an error if the length exceeds the limits of [`Length`].
     pub fn new(tag: Tag, length: impl TryInto<Length>) -> Result<Self> {
         let length = length.try_into().map_err(|_| ErrorKind::Overflow)?;
-        Ok(Self { tag, length })
+
+        Ok(Self {
+            tag,
+            length,
+            constructed: tag.is_constructed(),
+            indefinite: false,
+        })
+    }
+
+    /// Create a new [`Header`] for a value encoded using the constructed form, regardless of
+    /// whether its [`Tag`] is constructed.
+    ///
+    /// BER allows values of universal string types, e.g. `OCTET STRING`, to be split into
+    /// segments using the constructed form.
+    ///
+    /// Returns an error if the length exceeds the limits of [`Length`].
+    pub fn new_constructed(tag: Tag, length: impl TryInto<Length>) -> Result<Self> {
+        let mut header = Self::new(tag, length)?;
+        header.constructed = true;
+        Ok(header)
+    }
+
+    /// Create a new [`Header`] for a constructed value using the BER indefinite length form.
+    fn new_indefinite(tag: Tag) -> Self {
+        Self {
+            tag,
+            length: Length::ZERO,
+            constructed: true,
+            indefinite: true,
+        }
+    }
+
+    /// Is the value encoded using the constructed form?
+    ///
+    /// This is the same as [`Tag::is_constructed`] except for universal string types, which BER
+    /// allows to be split into segments using the constructed form.
+    pub fn is_constructed(&self) -> bool {
+        self.constructed
+    }
+
+    /// Is the value encoded using the BER indefinite length form?
+    ///
+    /// Such values are terminated by end-of-contents octets, which [`Reader::read_value`] consumes.
+    pub fn is_indefinite(&self) -> bool {
+        self.indefinite
     }
 
     /// Peek forward in the reader, attempting to decode a [`Header`] at the current position.
     ///
     /// Does not modify the reader's state.
     pub fn peek<'a>(reader: &impl Reader<'a>) -> Result<Self> {
+        Self::peek_with_len(reader).map(|(header, _)| header)
+    }
+
+    /// Peek forward in the reader like [`Header::peek`], additionally returning the number of
+    /// octets the header is encoded with.
+    ///
+    /// This can't be derived from the [`Header`] itself, since BER permits lengths which aren't
+    /// encoded using the minimum number of octets.
+    pub(crate) fn peek_with_len<'a>(reader: &impl Reader<'a>) -> Result<(Self, Length)> {
         let mut buf = [0u8; Self::MAX_SIZE];
 
+        let encoding_rules = reader.encoding_rules();
+
+        if encoding_rules.is_ber() {
+            let tag = Tag::peek(reader)?;
+            let tag_len = usize::try_from(tag.encoded_len()?)?;
+            let slice = &mut buf[..=tag_len];
+
+            if reader.peek_into(slice).is_ok()
+                && is_constructed(slice[0])
+                && slice[tag_len] == INDEFINITE_LENGTH_OCTET
+            {
+                let header_len = (tag.encoded_len()? + Length::ONE)?;
+                return Ok((Self::new_indefinite(tag), header_len));
+            }
+        }
+
         for i in 2..Self::MAX_SIZE {
             let slice = &mut buf[0..i];
             if reader.peek_into(slice).is_ok() {
-                if let Ok(header) = Self::from_der(slice) {
-                    return Ok(header);
+                if let Ok(header) = Self::decode_peeked(slice, encoding_rules) {
+                    return Ok((header, Length::try_from(i)?));
                 }
             }
         }
 
-        Self::from_der(&buf)
+        let header = Self::decode_peeked(&buf, encoding_rules)?;
+        Ok((header, Length::try_from(Self::MAX_SIZE)?))
+    }
+
+    /// Decode a [`Header`] from bytes peeked from a reader using the given [`EncodingRules`].
+    fn decode_peeked(bytes: &[u8], encoding_rules: EncodingRules) -> Result<Self> {
+        let mut reader = SliceReader::new_with_encoding_rules(bytes, encoding_rules)?;
+        let header = Self::decode(&mut reader)?;
+        reader.finish(header)
+    }
+
+    /// Peek forward in the reader like [`Header::peek`], returning the length of the complete
+    /// tag-length-value at the current position.
+    ///
+    /// For values using the indefinite length form, this scans ahead for the end-of-contents
+    /// octets which terminate them.
+    pub(crate) fn peek_tlv_len<'a>(reader: &impl Reader<'a>) -> Result<Length> {
+        let (header, header_len) = Self::peek_with_len(reader)?;
+
+        if header.indefinite {
+            let value_len = reader.peek_indefinite_length(header_len)?;
+            reader
+                .limits()
+                .check_value_len(value_len)
+                .map_err(|kind| kind.at(reader.position()))?;
+            (header_len + value_len)? + Length::EOC_LEN
+        } else {
+            header_len + header.length
+        }
+    }
+
+    /// Get the length of the contents of the value described by this header, which the reader
+    /// is positioned at the start of.
+    ///
+    /// For values using the indefinite length form, this scans ahead for the end-of-contents
+    /// octets which terminate them.
+    pub(crate) fn value_len<'a>(&self, reader: &impl Reader<'a>) -> Result<Length> {
+        if self.indefinite {
+            let value_len = reader.peek_indefinite_length(Length::ZERO)?;
+            reader
+                .limits()
+                .check_value_len(value_len)
+                .map_err(|kind| kind.at(reader.position()))?;
+            Ok(value_len)
+        } else {
+            Ok(self.length)
+        }
     }
 }
 
@@ -48,31 +176,65 @@ impl<'a> Decode<'a> for Header {
     type Error = Error;
 
     fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Header> {
+        let constructed = reader.peek_byte().is_some_and(is_constructed);
         let tag = Tag::decode(reader)?;
 
-        let length = Length::decode(reader).map_err(|e| {
-            if e.kind() == ErrorKind::Overlength {
-                ErrorKind::Length { tag }.into()
-            } else {
-                e
+        let header = if reader.encoding_rules().is_ber()
+            && constructed
+            && reader.peek_byte() == Some(INDEFINITE_LENGTH_OCTET)
+        {
+            reader.read_byte()?;
+            Self::new_indefinite(tag)
+        } else {
+            let length = Length::decode(reader).map_err(|e| {
+                if e.kind() == ErrorKind::Overlength {
+                    ErrorKind::Length { tag }.into()
+                } else {
+                    e
+                }
+            })?;
+
+            Self {
+                tag,
+                length,
+                constructed,
+                indefinite: false,
             }
-        })?;
+        };
+
+        if let Err(kind) = reader.limits().check_header(&header, reader.position()) {
+            return Err(reader.error(kind));
+        }
 
-        Ok(Self { tag, length })
+        Ok(header)
     }
 }
 
 impl Encode for Header {
     fn encoded_len(&self) -> Result<Length> {
-        self.tag.encoded_len()? + self.length.encoded_len()?
+        if self.indefinite {
+            self.tag.encoded_len()? + Length::ONE
+        } else {
+            self.tag.encoded_len()? + self.length.encoded_len()?
+        }
     }
 
     fn encode(&self, writer: &mut impl Writer) -> Result<()> {
-        self.tag.encode(writer)?;
-        self.length.encode(writer)
+        self.tag.encode_identifier(self.constructed, writer)?;
+
+        if self.indefinite {
+            writer.write_byte(INDEFINITE_LENGTH_OCTET)
+        } else {
+            self.length.encode(writer)
+        }
     }
 }
 
+/// Is the constructed bit set in the given leading identifier octet?
+fn is_constructed(first_byte: u8) -> bool {
+    first_byte & CONSTRUCTED_FLAG != 0
+}
+
 impl DerOrd for Header {
     fn der_cmp(&self, other: &Self) -> Result<Ordering> {
         match self.tag.der_cmp(&other.tag)? {
@@ -85,7 +247,7 @@ impl DerOrd for Header {
 #[cfg(test)]
 mod tests {
     use super::Header;
-    use crate::{Length, Reader, SliceReader, Tag};
+    use crate::{Decode, Encode, EncodingRules, Length, Reader, SliceReader, Tag};
     use hex_literal::hex;
 
     #[test]
@@ -102,4 +264,46 @@ mod tests {
         assert_eq!(header.length, Length::ONE);
         assert_eq!(reader.position(), Length::ZERO); // Position unchanged
     }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn peek_indefinite() {
+        // SEQUENCE (indefinite) { INTEGER: 42 }
+        const EXAMPLE_MSG: &[u8] = &hex!("308002012A0000");
+
+        let reader = SliceReader::new_with_encoding_rules(EXAMPLE_MSG, EncodingRules::Ber).unwrap();
+        let header = Header::peek(&reader).unwrap();
+        assert_eq!(header.tag, Tag::Sequence);
+        assert!(header.is_indefinite());
+        assert_eq!(header.length, Length::ZERO);
+        assert_eq!(reader.position(), Length::ZERO);
+
+        assert_eq!(Header::peek_tlv_len(&reader).unwrap(), Length::new(7));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn decode_indefinite_der() {
+        const EXAMPLE_MSG: &[u8] = &hex!("308002012A0000");
+        assert!(Header::from_der(EXAMPLE_MSG).is_err());
+    }
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn constructed_string() {
+        // OCTET STRING (constructed) { OCTET STRING: AA }
+        const EXAMPLE_MSG: &[u8] = &hex!("24030401AA");
+
+        let header = Header::from_ber(&EXAMPLE_MSG[..2]).unwrap();
+        assert_eq!(header.tag, Tag::OctetString);
+        assert!(header.is_constructed());
+        assert_eq!(
+            header,
+            Header::new_constructed(Tag::OctetString, 3u8).unwrap()
+        );
+
+        let mut buf = [0u8; 2];
+        assert_eq!(header.encode_to_slice(&mut buf).unwrap(), &EXAMPLE_MSG[..2]);
+
+        assert!(!Header::new(Tag::OctetString, 3u8).unwrap().is_constructed());
+    }
 }
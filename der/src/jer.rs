@@ -0,0 +1,144 @@
+//! JSON Encoding Rules (JER) as described in ITU-T X.697.
+//!
+//! JER maps each ASN.1 type to JSON: `SEQUENCE` and `SET` types become objects whose members
+//! are named after their fields, `CHOICE` types become objects with a single member named
+//! after the chosen alternative, `ENUMERATED` values become strings, `INTEGER` values become
+//! numbers, `OCTET STRING` values become hex strings, and so on.
+//!
+//! The [`JerEncode`] and [`JerDecode`] traits are implemented for the ASN.1 types in this
+//! crate, for types deriving `BitString`, and for `SEQUENCE`, `CHOICE` and `ENUMERATED` types
+//! by the `Jer` custom derive, which names members after the lowerCamelCase form of the
+//! Rust field and variant names, e.g. `tbs_certificate` becomes `tbsCertificate`.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use der::{
+//!     asn1::OctetString,
+//!     jer::{JerDecode, JerEncode},
+//! };
+//!
+//! /// `Entry ::= SEQUENCE { key-id OCTET STRING, version INTEGER DEFAULT 1 }`
+//! #[derive(der::Jer, Debug, Eq, PartialEq)]
+//! struct Entry {
+//!     key_id: OctetString,
+//!
+//!     #[asn1(default = "default_version")]
+//!     version: u8,
+//! }
+//!
+//! fn default_version() -> u8 {
+//!     1
+//! }
+//!
+//! let entry = Entry {
+//!     key_id: OctetString::new([0xAB, 0xCD]).unwrap(),
+//!     version: 2,
+//! };
+//!
+//! let json = entry.to_jer().unwrap();
+//! assert_eq!(json, r#"{"keyId":"ABCD","version":2}"#);
+//! assert_eq!(Entry::from_jer(&json).unwrap(), entry);
+//! # }
+//! ```
+//!
+//! # Open types
+//!
+//! X.697 encodes open types, e.g. [`Any`][`crate::asn1::Any`], as the JER encoding of their
+//! actual type, which requires knowing that type. This crate instead encodes them as a hex
+//! string containing their DER encoding, so that they round-trip.
+
+mod types;
+mod value;
+
+pub use self::value::Value;
+
+use crate::{ErrorKind, Result};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Encoding trait for the JSON Encoding Rules.
+pub trait JerEncode {
+    /// Encode this value as a JSON [`Value`].
+    fn to_jer_value(&self) -> Result<Value>;
+
+    /// Encode this value as JSON text.
+    fn to_jer(&self) -> Result<String> {
+        Ok(self.to_jer_value()?.to_string())
+    }
+}
+
+/// Decoding trait for the JSON Encoding Rules.
+pub trait JerDecode: Sized {
+    /// Decode a value from a JSON [`Value`].
+    fn from_jer_value(value: &Value) -> Result<Self>;
+
+    /// Decode a value from JSON text.
+    fn from_jer(json: &str) -> Result<Self> {
+        Self::from_jer_value(&json.parse()?)
+    }
+}
+
+/// Build a JSON object from the given members, omitting the absent ones.
+pub fn object<const N: usize>(members: [(&str, Option<Value>); N]) -> Value {
+    Value::Object(
+        members
+            .into_iter()
+            .filter_map(|(name, value)| Some((name.into(), value?)))
+            .collect(),
+    )
+}
+
+/// Get the value of a required member of a JSON object.
+pub fn member<'a>(value: &'a Value, name: &str) -> Result<&'a Value> {
+    value.get(name).ok_or_else(|| ErrorKind::Json.into())
+}
+
+/// Check that a JSON object only contains members with the given names.
+pub fn check_members(value: &Value, names: &[&str]) -> Result<()> {
+    if value
+        .as_object()?
+        .iter()
+        .all(|(name, _)| names.contains(&name.as_str()))
+    {
+        Ok(())
+    } else {
+        Err(ErrorKind::Json.into())
+    }
+}
+
+/// Encode bytes as uppercase hex.
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+    bytes
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0xF])
+        .map(|digit| char::from(DIGITS[usize::from(digit)]))
+        .collect()
+}
+
+/// Decode hex of either case.
+pub(crate) fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return Err(ErrorKind::Json.into());
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let digit = |c: u8| {
+                char::from(c)
+                    .to_digit(16)
+                    .and_then(|digit| u8::try_from(digit).ok())
+                    .ok_or(ErrorKind::Json)
+            };
+
+            Ok((digit(pair[0])? << 4) | digit(pair[1])?)
+        })
+        .collect()
+}
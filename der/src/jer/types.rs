@@ -0,0 +1,470 @@
+//! JER encodings of ASN.1 types (X.697 Sections 19–40).
+
+use super::{JerDecode, JerEncode, Value, check_members, decode_hex, encode_hex, member};
+use crate::{
+    AllowedLenBitString, Decode, Encode, EncodeValue, Error, ErrorKind, FixedTag, Result, Tagged,
+    asn1::{
+        Any, AnyRef, BitString, BmpString, GeneralizedTime, Ia5String, Int, Null, NumericString,
+        OctetString, PrintableString, SetOfVec, TeletexString, Uint, UniversalString, UtcTime,
+        VisibleString,
+    },
+    ord::DerOrd,
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+#[cfg(feature = "oid")]
+use crate::asn1::ObjectIdentifier;
+
+impl JerEncode for bool {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::Bool(*self))
+    }
+}
+
+impl JerDecode for bool {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        value.as_bool()
+    }
+}
+
+impl JerEncode for Null {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::Null)
+    }
+}
+
+impl JerDecode for Null {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        match value {
+            Value::Null => Ok(Null),
+            _ => Err(ErrorKind::Json.into()),
+        }
+    }
+}
+
+macro_rules! impl_jer_integer {
+    ($($int:ty),+) => {
+        $(
+            impl JerEncode for $int {
+                fn to_jer_value(&self) -> Result<Value> {
+                    Ok(Value::Number(self.to_string()))
+                }
+            }
+
+            impl JerDecode for $int {
+                fn from_jer_value(value: &Value) -> Result<Self> {
+                    value.as_number()?.parse().map_err(|_| ErrorKind::Json.into())
+                }
+            }
+        )+
+    };
+}
+
+impl_jer_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
+
+impl JerEncode for Int {
+    fn to_jer_value(&self) -> Result<Value> {
+        let bytes = self.as_bytes();
+
+        if bytes.first().is_some_and(|byte| byte & 0x80 != 0) {
+            let mut magnitude = bytes.to_vec();
+            negate(&mut magnitude);
+            Ok(Value::Number(["-", &to_decimal(&magnitude)].concat()))
+        } else {
+            Ok(Value::Number(to_decimal(bytes)))
+        }
+    }
+}
+
+impl JerDecode for Int {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        let number = value.as_number()?;
+        let (negative, digits) = match number.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, number),
+        };
+
+        let mut bytes = from_decimal(digits)?;
+
+        // Leading zero so that the magnitude is a non-negative two's complement integer
+        if bytes[0] & 0x80 != 0 {
+            bytes.insert(0, 0);
+        }
+
+        if negative {
+            negate(&mut bytes);
+        }
+
+        Int::new(&bytes)
+    }
+}
+
+impl JerEncode for Uint {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::Number(to_decimal(self.as_bytes())))
+    }
+}
+
+impl JerDecode for Uint {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        Uint::new(&from_decimal(value.as_number()?)?)
+    }
+}
+
+/// Negate a big endian two's complement integer in place.
+fn negate(bytes: &mut [u8]) {
+    let mut carry = true;
+
+    for byte in bytes.iter_mut().rev() {
+        let (negated, overflow) = (!*byte).overflowing_add(u8::from(carry));
+        *byte = negated;
+        carry = overflow;
+    }
+}
+
+/// Format a big endian unsigned integer in decimal.
+#[allow(clippy::cast_possible_truncation)]
+fn to_decimal(bytes: &[u8]) -> String {
+    let mut magnitude = bytes.to_vec();
+    let mut digits = Vec::new();
+
+    loop {
+        // Divide by 10, keeping the remainder as the next least significant digit
+        let mut remainder = 0u16;
+
+        for byte in &mut magnitude {
+            let value = (remainder << 8) | u16::from(*byte);
+            *byte = (value / 10) as u8;
+            remainder = value % 10;
+        }
+
+        digits.push(char::from(b'0' + remainder as u8));
+
+        if magnitude.iter().all(|&byte| byte == 0) {
+            break;
+        }
+    }
+
+    digits.iter().rev().collect()
+}
+
+/// Parse a non-negative decimal integer as a big endian unsigned integer, which is at least
+/// one octet.
+#[allow(clippy::cast_possible_truncation)]
+fn from_decimal(digits: &str) -> Result<Vec<u8>> {
+    if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+        return Err(ErrorKind::Json.into());
+    }
+
+    let mut bytes = vec![0u8];
+
+    for c in digits.chars() {
+        let mut carry = c.to_digit(10).ok_or(ErrorKind::Json)?;
+
+        // Multiply by 10 and add the digit
+        for byte in bytes.iter_mut().rev() {
+            let value = u32::from(*byte) * 10 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+
+        if carry > 0 {
+            bytes.insert(0, carry as u8);
+        }
+    }
+
+    Ok(bytes)
+}
+
+impl JerEncode for String {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::String(self.clone()))
+    }
+}
+
+impl JerDecode for String {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        value.as_str().map(Into::into)
+    }
+}
+
+macro_rules! impl_jer_string {
+    ($($string:ty),+) => {
+        $(
+            impl JerEncode for $string {
+                fn to_jer_value(&self) -> Result<Value> {
+                    Ok(Value::String(self.as_str().into()))
+                }
+            }
+
+            impl JerDecode for $string {
+                fn from_jer_value(value: &Value) -> Result<Self> {
+                    Self::new(value.as_str()?)
+                }
+            }
+        )+
+    };
+}
+
+impl_jer_string!(
+    Ia5String,
+    NumericString,
+    PrintableString,
+    TeletexString,
+    VisibleString
+);
+
+impl JerEncode for BmpString {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::String(self.chars().collect()))
+    }
+}
+
+impl JerDecode for BmpString {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        Self::from_utf8(value.as_str()?)
+    }
+}
+
+impl JerEncode for UniversalString {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::String(self.as_str().into()))
+    }
+}
+
+impl JerDecode for UniversalString {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        Self::from_utf8(value.as_str()?)
+    }
+}
+
+impl JerEncode for OctetString {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::String(encode_hex(self.as_bytes())))
+    }
+}
+
+impl JerDecode for OctetString {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        OctetString::new(decode_hex(value.as_str()?)?)
+    }
+}
+
+/// `BIT STRING` values are objects containing the bits as hex, padded to a whole number of
+/// octets, and the number of bits (X.697 Section 22.3).
+impl JerEncode for BitString {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::Object(vec![
+            ("value".into(), Value::String(encode_hex(self.raw_bytes()))),
+            ("length".into(), self.bit_len().to_jer_value()?),
+        ]))
+    }
+}
+
+impl JerDecode for BitString {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        check_members(value, &["value", "length"])?;
+
+        let bytes = decode_hex(member(value, "value")?.as_str()?)?;
+        let bit_len = usize::from_jer_value(member(value, "length")?)?;
+
+        let unused_bits = (bytes.len() * 8)
+            .checked_sub(bit_len)
+            .and_then(|unused_bits| u8::try_from(unused_bits).ok())
+            .filter(|&unused_bits| unused_bits < 8)
+            .ok_or(ErrorKind::Json)?;
+
+        BitString::new(unused_bits, bytes)
+    }
+}
+
+/// Types deriving `BitString` are encoded as a `BIT STRING`.
+impl<T> JerEncode for T
+where
+    T: AllowedLenBitString + Encode,
+{
+    fn to_jer_value(&self) -> Result<Value> {
+        BitString::from_der(&self.to_der()?)?.to_jer_value()
+    }
+}
+
+impl<T> JerDecode for T
+where
+    T: AllowedLenBitString + for<'a> Decode<'a, Error = Error>,
+{
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        T::from_der(&BitString::from_jer_value(value)?.to_der()?)
+    }
+}
+
+/// Named bit lists are encoded as a `BIT STRING`.
+#[cfg(feature = "flagset")]
+impl<T> JerEncode for flagset::FlagSet<T>
+where
+    T: flagset::Flags,
+    Self: Encode,
+{
+    fn to_jer_value(&self) -> Result<Value> {
+        BitString::from_der(&self.to_der()?)?.to_jer_value()
+    }
+}
+
+#[cfg(feature = "flagset")]
+impl<T> JerDecode for flagset::FlagSet<T>
+where
+    T: flagset::Flags,
+    Self: for<'a> Decode<'a, Error = Error>,
+{
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        Self::from_der(&BitString::from_jer_value(value)?.to_der()?)
+    }
+}
+
+#[cfg(feature = "oid")]
+impl JerEncode for ObjectIdentifier {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(feature = "oid")]
+impl JerDecode for ObjectIdentifier {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        Ok(ObjectIdentifier::new(value.as_str()?)?)
+    }
+}
+
+/// Time types are strings containing their ASN.1 value notation, which is their DER value
+/// (X.697 Section 34).
+macro_rules! impl_jer_time {
+    ($($time:ty),+) => {
+        $(
+            impl JerEncode for $time {
+                fn to_jer_value(&self) -> Result<Value> {
+                    to_value_string(self)
+                }
+            }
+
+            impl JerDecode for $time {
+                fn from_jer_value(value: &Value) -> Result<Self> {
+                    AnyRef::new(Self::TAG, value.as_str()?.as_bytes())?.decode_as()
+                }
+            }
+        )+
+    };
+}
+
+impl_jer_time!(UtcTime, GeneralizedTime);
+
+/// Encode the DER value of a type whose value is a string as a JSON string.
+fn to_value_string<T: Tagged + EncodeValue>(value: &T) -> Result<Value> {
+    let any = Any::encode_from(value)?;
+    let s = core::str::from_utf8(any.value()).map_err(|_| any.tag().value_error())?;
+    Ok(Value::String(s.into()))
+}
+
+/// Open types are hex strings containing their DER encoding, since their actual type isn't
+/// known.
+impl JerEncode for Any {
+    fn to_jer_value(&self) -> Result<Value> {
+        Ok(Value::String(encode_hex(&self.to_der()?)))
+    }
+}
+
+impl JerDecode for Any {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        Any::from_der(&decode_hex(value.as_str()?)?)
+    }
+}
+
+impl<T: JerEncode> JerEncode for Vec<T> {
+    fn to_jer_value(&self) -> Result<Value> {
+        self.iter()
+            .map(JerEncode::to_jer_value)
+            .collect::<Result<_>>()
+            .map(Value::Array)
+    }
+}
+
+impl<T: JerDecode> JerDecode for Vec<T> {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        value.as_array()?.iter().map(T::from_jer_value).collect()
+    }
+}
+
+impl<T: JerEncode + DerOrd> JerEncode for SetOfVec<T> {
+    fn to_jer_value(&self) -> Result<Value> {
+        self.iter()
+            .map(JerEncode::to_jer_value)
+            .collect::<Result<_>>()
+            .map(Value::Array)
+    }
+}
+
+impl<T: JerDecode + DerOrd> JerDecode for SetOfVec<T> {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        Vec::from_jer_value(value)?.try_into()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{JerDecode, JerEncode};
+    use crate::asn1::{BitString, GeneralizedTime, Int, OctetString, Uint};
+    use alloc::string::String;
+    use hex_literal::hex;
+
+    fn round_trip<T: JerEncode + JerDecode + PartialEq + core::fmt::Debug>(value: T, json: &str) {
+        assert_eq!(value.to_jer().unwrap(), json);
+        assert_eq!(T::from_jer(json).unwrap(), value);
+    }
+
+    #[test]
+    fn integers() {
+        round_trip(-5i32, "-5");
+        round_trip(Int::new(&hex!("FF7F")).unwrap(), "-129");
+        round_trip(Int::new(&hex!("0080")).unwrap(), "128");
+        round_trip(Int::new(&hex!("00")).unwrap(), "0");
+        round_trip(
+            Uint::new(&hex!("0123456789ABCDEF0123456789ABCDEF")).unwrap(),
+            "1512366075204170929049582354406559215",
+        );
+
+        assert!(u8::from_jer("256").is_err());
+        assert!(Uint::from_jer("1.5").is_err());
+        assert!(Uint::from_jer("012").is_err());
+    }
+
+    #[test]
+    fn strings() {
+        round_trip(String::from("héllo"), r#""héllo""#);
+        round_trip(OctetString::new(hex!("0AFF")).unwrap(), r#""0AFF""#);
+        assert_eq!(
+            OctetString::from_jer(r#""0aff""#).unwrap(),
+            OctetString::new(hex!("0AFF")).unwrap()
+        );
+    }
+
+    #[test]
+    fn bit_string() {
+        round_trip(
+            BitString::new(4, hex!("A0")).unwrap(),
+            r#"{"value":"A0","length":4}"#,
+        );
+
+        assert!(BitString::from_jer(r#"{"value":"A0","length":9}"#).is_err());
+    }
+
+    #[test]
+    fn time() {
+        round_trip(
+            GeneralizedTime::from_unix_duration(core::time::Duration::from_secs(0)).unwrap(),
+            r#""19700101000000Z""#,
+        );
+    }
+}
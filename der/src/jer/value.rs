@@ -0,0 +1,470 @@
+//! JSON values.
+
+use crate::{Error, ErrorKind, Length, Result};
+use alloc::{string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+/// Maximum nesting depth of arrays and objects when parsing JSON.
+const MAX_DEPTH: usize = 128;
+
+/// JSON value, as produced and consumed by the JSON Encoding Rules.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    /// `null`.
+    Null,
+
+    /// `true` or `false`.
+    Bool(bool),
+
+    /// Number, kept as its JSON text so that integers of any size round-trip.
+    Number(String),
+
+    /// String.
+    String(String),
+
+    /// Array.
+    Array(Vec<Value>),
+
+    /// Object, whose members are kept in order.
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Get the value of a `true` or `false` literal.
+    pub fn as_bool(&self) -> Result<bool> {
+        match self {
+            Value::Bool(value) => Ok(*value),
+            _ => Err(ErrorKind::Json.into()),
+        }
+    }
+
+    /// Get the text of a number.
+    pub fn as_number(&self) -> Result<&str> {
+        match self {
+            Value::Number(number) => Ok(number),
+            _ => Err(ErrorKind::Json.into()),
+        }
+    }
+
+    /// Get the contents of a string.
+    pub fn as_str(&self) -> Result<&str> {
+        match self {
+            Value::String(s) => Ok(s),
+            _ => Err(ErrorKind::Json.into()),
+        }
+    }
+
+    /// Get the elements of an array.
+    pub fn as_array(&self) -> Result<&[Value]> {
+        match self {
+            Value::Array(elements) => Ok(elements),
+            _ => Err(ErrorKind::Json.into()),
+        }
+    }
+
+    /// Get the members of an object.
+    pub fn as_object(&self) -> Result<&[(String, Value)]> {
+        match self {
+            Value::Object(members) => Ok(members),
+            _ => Err(ErrorKind::Json.into()),
+        }
+    }
+
+    /// Get the value of the member of an object with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members
+                .iter()
+                .find(|(member, _)| member == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Number(number) => f.write_str(number),
+            Value::String(s) => write_string(f, s),
+            Value::Array(elements) => {
+                f.write_str("[")?;
+
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write!(f, "{element}")?;
+                }
+
+                f.write_str("]")
+            }
+            Value::Object(members) => {
+                f.write_str("{")?;
+
+                for (i, (name, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write_string(f, name)?;
+                    write!(f, ":{value}")?;
+                }
+
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = Error;
+
+    fn from_str(json: &str) -> Result<Self> {
+        let mut parser = Parser {
+            input: json.as_bytes(),
+            position: 0,
+        };
+
+        let value = parser.parse_value(0)?;
+        parser.skip_whitespace();
+
+        if parser.position < parser.input.len() {
+            return Err(parser.error());
+        }
+
+        Ok(value)
+    }
+}
+
+/// Write a string as JSON, escaping the characters which JSON requires to be escaped.
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    f.write_str("\"")?;
+
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c < ' ' => write!(f, "\\u{:04x}", u32::from(c))?,
+            c => write!(f, "{c}")?,
+        }
+    }
+
+    f.write_str("\"")
+}
+
+/// Parser for JSON text (RFC 8259).
+struct Parser<'a> {
+    /// JSON text being parsed.
+    input: &'a [u8],
+
+    /// Position of the next byte to parse.
+    position: usize,
+}
+
+impl Parser<'_> {
+    /// Error at the current position.
+    fn error(&self) -> Error {
+        ErrorKind::Json.at(Length::try_from(self.position).unwrap_or(Length::MAX))
+    }
+
+    /// Peek at the next byte, if any.
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    /// Consume the next byte.
+    fn next(&mut self) -> Result<u8> {
+        let byte = self.peek().ok_or_else(|| self.error())?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    /// Consume the given bytes, which must come next.
+    fn expect(&mut self, expected: &[u8]) -> Result<()> {
+        for &byte in expected {
+            if self.next()? != byte {
+                self.position -= 1;
+                return Err(self.error());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Result<Value> {
+        if depth > MAX_DEPTH {
+            return Err(self.error());
+        }
+
+        self.skip_whitespace();
+
+        match self.peek().ok_or_else(|| self.error())? {
+            b'n' => self.expect(b"null").map(|_| Value::Null),
+            b't' => self.expect(b"true").map(|_| Value::Bool(true)),
+            b'f' => self.expect(b"false").map(|_| Value::Bool(false)),
+            b'"' => self.parse_string().map(Value::String),
+            b'-' | b'0'..=b'9' => self.parse_number().map(Value::Number),
+            b'[' => {
+                self.position += 1;
+                let mut elements = Vec::new();
+
+                self.skip_whitespace();
+                if self.peek() == Some(b']') {
+                    self.position += 1;
+                    return Ok(Value::Array(elements));
+                }
+
+                loop {
+                    elements.push(self.parse_value(depth + 1)?);
+                    self.skip_whitespace();
+
+                    match self.next()? {
+                        b',' => continue,
+                        b']' => return Ok(Value::Array(elements)),
+                        _ => {
+                            self.position -= 1;
+                            return Err(self.error());
+                        }
+                    }
+                }
+            }
+            b'{' => {
+                self.position += 1;
+                let mut members = Vec::new();
+
+                self.skip_whitespace();
+                if self.peek() == Some(b'}') {
+                    self.position += 1;
+                    return Ok(Value::Object(members));
+                }
+
+                loop {
+                    self.skip_whitespace();
+
+                    if self.peek() != Some(b'"') {
+                        return Err(self.error());
+                    }
+
+                    let name = self.parse_string()?;
+                    self.skip_whitespace();
+                    self.expect(b":")?;
+                    members.push((name, self.parse_value(depth + 1)?));
+                    self.skip_whitespace();
+
+                    match self.next()? {
+                        b',' => continue,
+                        b'}' => return Ok(Value::Object(members)),
+                        _ => {
+                            self.position -= 1;
+                            return Err(self.error());
+                        }
+                    }
+                }
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_string(&mut self) -> Result<String> {
+        self.expect(b"\"")?;
+        let mut bytes = Vec::new();
+
+        loop {
+            match self.next()? {
+                b'"' => break,
+                b'\\' => {
+                    let c = match self.next()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.parse_unicode_escape()?,
+                        _ => {
+                            self.position -= 1;
+                            return Err(self.error());
+                        }
+                    };
+
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte if byte < 0x20 => {
+                    self.position -= 1;
+                    return Err(self.error());
+                }
+                byte => bytes.push(byte),
+            }
+        }
+
+        // The input is a `str`, so unescaped bytes are valid UTF-8
+        String::from_utf8(bytes).map_err(|_| self.error())
+    }
+
+    /// Parse the hex digits of a `\u` escape, including a following low surrogate escape if
+    /// the first is a high surrogate.
+    fn parse_unicode_escape(&mut self) -> Result<char> {
+        let high = self.parse_hex4()?;
+
+        let code_point = if (0xD800..0xDC00).contains(&high) {
+            self.expect(b"\\u")?;
+            let low = self.parse_hex4()?;
+
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error());
+            }
+
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        char::from_u32(code_point).ok_or_else(|| self.error())
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32> {
+        let mut value = 0;
+
+        for _ in 0..4 {
+            let digit = char::from(self.next()?)
+                .to_digit(16)
+                .ok_or_else(|| self.error())?;
+
+            value = (value << 4) | digit;
+        }
+
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> Result<String> {
+        let start = self.position;
+
+        if self.peek() == Some(b'-') {
+            self.position += 1;
+        }
+
+        match self.next()? {
+            b'0' => {}
+            b'1'..=b'9' => self.skip_digits(),
+            _ => {
+                self.position -= 1;
+                return Err(self.error());
+            }
+        }
+
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            self.expect_digits()?;
+        }
+
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.position += 1;
+
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.position += 1;
+            }
+
+            self.expect_digits()?;
+        }
+
+        let number =
+            core::str::from_utf8(&self.input[start..self.position]).map_err(|_| self.error())?;
+
+        Ok(number.into())
+    }
+
+    fn skip_digits(&mut self) {
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.position += 1;
+        }
+    }
+
+    /// Consume one or more digits.
+    fn expect_digits(&mut self) -> Result<()> {
+        if !matches!(self.peek(), Some(b'0'..=b'9')) {
+            return Err(self.error());
+        }
+
+        self.skip_digits();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::Value;
+    use crate::{ErrorKind, Length};
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn parse() {
+        let value: Value = r#" {"a": [1, -2.5e3, true, null], "b\u00e9\ud83d\ude00": "x\"\n"} "#
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            value,
+            Value::Object(vec![
+                (
+                    "a".into(),
+                    Value::Array(vec![
+                        Value::Number("1".into()),
+                        Value::Number("-2.5e3".into()),
+                        Value::Bool(true),
+                        Value::Null,
+                    ])
+                ),
+                ("bé😀".into(), Value::String("x\"\n".into())),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (json, position) in [
+            ("[1,]", 3),
+            ("{\"a\" 1}", 5),
+            ("01", 1),
+            ("\"\\x\"", 2),
+            ("[1] 2", 4),
+            ("", 0),
+        ] {
+            let err = json.parse::<Value>().unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Json, "{json}");
+            assert_eq!(err.position(), Some(Length::new(position)), "{json}");
+        }
+
+        let deep = "[".repeat(1000);
+        assert!(deep.parse::<Value>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        let value = Value::Object(vec![
+            (
+                "a".into(),
+                Value::Array(vec![Value::Number("1".into()), Value::Null]),
+            ),
+            ("b".into(), Value::String("x\"\u{1}".into())),
+        ]);
+
+        assert_eq!(value.to_string(), r#"{"a":[1,null],"b":"x\"\u0001"}"#);
+        assert_eq!(value.to_string().parse::<Value>().unwrap(), value);
+    }
+}
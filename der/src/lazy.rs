@@ -0,0 +1,485 @@
+//! Lazily decoded values.
+
+use crate::{
+    Choice, Decode, DecodeValue, DerOrd, Encode, EncodeValue, EncodingRules, Error, Header, Length,
+    Reader, Result, SliceReader, Tag, TagMode, Tagged, Writer,
+    asn1::{AnyRef, ContextSpecific, ContextSpecificRef},
+};
+use core::{cell::OnceCell, cmp::Ordering, fmt};
+
+#[cfg(feature = "alloc")]
+pub use self::allocating::LazyOwned;
+
+/// Value of type `T` which is decoded on demand.
+///
+/// When decoded, only the raw tag-length-value is captured and checked to be well-formed and have a
+/// tag which `T` can decode: `T` itself is parsed on the first call to [`Lazy::get`], and the
+/// result cached. This avoids the
+/// cost of parsing large structures, e.g. a `TBSCertList`, when only a few of their fields are
+/// ever accessed.
+///
+/// The original encoding is retained and available via [`Lazy::as_bytes`], e.g. for use in
+/// signature verification. It is also what gets written when this value is encoded, which means
+/// inputs round-trip byte-for-byte, including BER encodings which aren't valid DER.
+///
+/// This type can be used as a field in `#[derive(Sequence)]`, including `OPTIONAL` and `EXPLICIT`
+/// context-specific fields, but not `IMPLICIT` ones since their original tag is unknown.
+#[derive(Clone)]
+pub struct Lazy<'a, T> {
+    /// Complete tag-length-value encoding of the value.
+    bytes: &'a [u8],
+
+    /// Tag and contents of the value.
+    any: AnyRef<'a>,
+
+    /// Length of the header preceding the contents in `bytes`.
+    #[cfg(feature = "alloc")]
+    header_len: usize,
+
+    /// Encoding rules which the value was decoded with.
+    encoding_rules: EncodingRules,
+
+    /// Decoded value, once it's been accessed.
+    value: OnceCell<T>,
+}
+
+impl<'a, T> Lazy<'a, T> {
+    /// Get the tag and contents of this value, for use by `impl_context_specific`.
+    fn as_any_ref(&self) -> Result<AnyRef<'a>> {
+        Ok(self.any)
+    }
+
+    /// Get the complete tag-length-value encoding of this value, exactly as it was decoded.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get the tag and contents of this value as an [`AnyRef`].
+    pub fn as_any(&self) -> AnyRef<'a> {
+        self.any
+    }
+
+    /// Get the decoded value, decoding it first if this is the first time it's been accessed.
+    pub fn get(&self) -> core::result::Result<&T, T::Error>
+    where
+        T: Decode<'a>,
+    {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+
+        let value = decode_tlv::<T>(self.bytes, self.encoding_rules)?;
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    /// Take the decoded value, decoding it first if it hasn't already been accessed.
+    pub fn into_value(self) -> core::result::Result<T, T::Error>
+    where
+        T: Decode<'a>,
+    {
+        match self.value.into_inner() {
+            Some(value) => Ok(value),
+            None => decode_tlv(self.bytes, self.encoding_rules),
+        }
+    }
+}
+
+impl<'a, T> Choice<'a> for Lazy<'a, T>
+where
+    T: Choice<'a>,
+{
+    fn can_decode(tag: Tag) -> bool {
+        T::can_decode(tag)
+    }
+}
+
+impl<'a, T> Decode<'a> for Lazy<'a, T>
+where
+    T: Choice<'a>,
+{
+    type Error = Error;
+
+    fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
+        check_tag::<T>(reader)?;
+        let encoding_rules = reader.encoding_rules();
+        let bytes = reader.tlv_bytes()?;
+        let (any, _header_len) = parse_tlv(bytes, encoding_rules)?;
+
+        Ok(Self {
+            bytes,
+            any,
+            #[cfg(feature = "alloc")]
+            header_len: _header_len,
+            encoding_rules,
+            value: OnceCell::new(),
+        })
+    }
+}
+
+impl<T> Encode for Lazy<'_, T> {
+    fn encoded_len(&self) -> Result<Length> {
+        Length::try_from(self.bytes.len())
+    }
+
+    /// Write the original encoding of this value.
+    fn encode(&self, writer: &mut impl Writer) -> Result<()> {
+        writer.write(self.bytes)
+    }
+}
+
+/// Implement [`EncodeValue`] for context-specific fields containing a lazily decoded value.
+///
+/// When `EXPLICIT` the original encoding is written. When `IMPLICIT` only the original contents
+/// are written, since the original tag is replaced anyway.
+macro_rules! impl_context_specific {
+    ($([$($generics:tt)*] $lazy:ty),+) => {
+        $(
+            impl<$($generics)*> EncodeValue for ContextSpecific<$lazy> {
+                fn value_len(&self) -> Result<Length> {
+                    match self.tag_mode {
+                        TagMode::Explicit => self.value.encoded_len(),
+                        TagMode::Implicit => self.value.as_any_ref()?.value_len(),
+                    }
+                }
+
+                fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+                    match self.tag_mode {
+                        TagMode::Explicit => self.value.encode(writer),
+                        TagMode::Implicit => self.value.as_any_ref()?.encode_value(writer),
+                    }
+                }
+            }
+
+            impl<$($generics)*> EncodeValue for ContextSpecificRef<'_, $lazy> {
+                fn value_len(&self) -> Result<Length> {
+                    match self.tag_mode {
+                        TagMode::Explicit => self.value.encoded_len(),
+                        TagMode::Implicit => self.value.as_any_ref()?.value_len(),
+                    }
+                }
+
+                fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+                    match self.tag_mode {
+                        TagMode::Explicit => self.value.encode(writer),
+                        TagMode::Implicit => self.value.as_any_ref()?.encode_value(writer),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_context_specific!([T] Lazy<'_, T>);
+
+#[cfg(feature = "alloc")]
+impl_context_specific!([T] LazyOwned<T>);
+
+impl<T> Tagged for Lazy<'_, T> {
+    fn tag(&self) -> Tag {
+        self.any.tag()
+    }
+}
+
+impl<T> DerOrd for Lazy<'_, T> {
+    fn der_cmp(&self, other: &Self) -> Result<Ordering> {
+        Ok(self.bytes.cmp(other.bytes))
+    }
+}
+
+impl<T> Eq for Lazy<'_, T> {}
+
+impl<T> PartialEq for Lazy<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl<T> fmt::Debug for Lazy<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lazy").field("any", &self.any).finish()
+    }
+}
+
+/// Check the value at the current position of the reader has a tag which `T` can decode.
+fn check_tag<'a, T: Choice<'a>>(reader: &impl Reader<'a>) -> Result<()> {
+    let tag = Tag::peek(reader)?;
+
+    if T::can_decode(tag) {
+        Ok(())
+    } else {
+        Err(tag.unexpected_error(None).kind().at(reader.position()))
+    }
+}
+
+/// Parse a complete tag-length-value, returning its tag and contents along with the length of its
+/// header.
+fn parse_tlv(bytes: &[u8], encoding_rules: EncodingRules) -> Result<(AnyRef<'_>, usize)> {
+    let mut reader = SliceReader::new_with_encoding_rules(bytes, encoding_rules)?;
+    let header = Header::decode(&mut reader)?;
+    let header_len = usize::try_from(reader.position())?;
+    let any = reader.read_value(header, |r| AnyRef::decode_value(r, header))?;
+    Ok((reader.finish(any)?, header_len))
+}
+
+/// Decode a value of type `T` from a complete tag-length-value.
+fn decode_tlv<'a, T>(
+    bytes: &'a [u8],
+    encoding_rules: EncodingRules,
+) -> core::result::Result<T, T::Error>
+where
+    T: Decode<'a>,
+{
+    let mut reader = SliceReader::new_with_encoding_rules(bytes, encoding_rules)?;
+    let value = T::decode(&mut reader)?;
+    Ok(reader.finish(value)?)
+}
+
+#[cfg(feature = "alloc")]
+mod allocating {
+    use super::*;
+    use crate::{DecodeOwned, asn1::AnyRef};
+    use alloc::boxed::Box;
+    use core::ops::Range;
+
+    /// Value of type `T` which is decoded on demand.
+    ///
+    /// This type provides the same functionality as [`Lazy`] but owns the backing data, so it can
+    /// be decoded from any [`Reader`], including ones which can't be borrowed from.
+    #[derive(Clone)]
+    pub struct LazyOwned<T> {
+        /// Complete tag-length-value encoding of the value.
+        bytes: Box<[u8]>,
+
+        /// Tag of the value.
+        tag: Tag,
+
+        /// Range of `bytes` containing the contents of the value.
+        contents: Range<usize>,
+
+        /// Encoding rules which the value was decoded with.
+        encoding_rules: EncodingRules,
+
+        /// Decoded value, once it's been accessed.
+        value: OnceCell<T>,
+    }
+
+    impl<T> LazyOwned<T> {
+        /// Get the complete tag-length-value encoding of this value, exactly as it was decoded.
+        pub fn as_bytes(&self) -> &[u8] {
+            &self.bytes
+        }
+
+        /// Get the tag and contents of this value as an [`AnyRef`].
+        pub fn as_any(&self) -> Result<AnyRef<'_>> {
+            AnyRef::new(self.tag, &self.bytes[self.contents.clone()])
+        }
+
+        /// Get the tag and contents of this value, for use by `impl_context_specific`.
+        pub(super) fn as_any_ref(&self) -> Result<AnyRef<'_>> {
+            self.as_any()
+        }
+    }
+
+    impl<T: DecodeOwned> LazyOwned<T> {
+        /// Get the decoded value, decoding it first if this is the first time it's been accessed.
+        pub fn get(&self) -> core::result::Result<&T, <T as Decode<'_>>::Error> {
+            if let Some(value) = self.value.get() {
+                return Ok(value);
+            }
+
+            let value = decode_tlv::<T>(&self.bytes, self.encoding_rules)?;
+            Ok(self.value.get_or_init(|| value))
+        }
+    }
+
+    impl<'a, T> Choice<'a> for LazyOwned<T>
+    where
+        T: Choice<'a>,
+    {
+        fn can_decode(tag: Tag) -> bool {
+            T::can_decode(tag)
+        }
+    }
+
+    impl<'a, T> Decode<'a> for LazyOwned<T>
+    where
+        T: Choice<'a>,
+    {
+        type Error = Error;
+
+        fn decode<R: Reader<'a>>(reader: &mut R) -> Result<Self> {
+            check_tag::<T>(reader)?;
+            let encoding_rules = reader.encoding_rules();
+            let bytes = reader.read_vec(Header::peek_tlv_len(reader)?)?;
+            let (any, header_len) = parse_tlv(&bytes, encoding_rules)?;
+            let tag = any.tag();
+            let contents = header_len..header_len + any.value().len();
+
+            Ok(Self {
+                bytes: bytes.into_boxed_slice(),
+                tag,
+                contents,
+                encoding_rules,
+                value: OnceCell::new(),
+            })
+        }
+    }
+
+    impl<T> Encode for LazyOwned<T> {
+        fn encoded_len(&self) -> Result<Length> {
+            Length::try_from(self.bytes.len())
+        }
+
+        /// Write the original encoding of this value.
+        fn encode(&self, writer: &mut impl Writer) -> Result<()> {
+            writer.write(&self.bytes)
+        }
+    }
+
+    impl<T> Tagged for LazyOwned<T> {
+        fn tag(&self) -> Tag {
+            self.tag
+        }
+    }
+
+    impl<T> DerOrd for LazyOwned<T> {
+        fn der_cmp(&self, other: &Self) -> Result<Ordering> {
+            Ok(self.bytes.cmp(&other.bytes))
+        }
+    }
+
+    impl<T> Eq for LazyOwned<T> {}
+
+    impl<T> PartialEq for LazyOwned<T> {
+        fn eq(&self, other: &Self) -> bool {
+            self.bytes == other.bytes
+        }
+    }
+
+    impl<T> fmt::Debug for LazyOwned<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("LazyOwned")
+                .field("tag", &self.tag)
+                .field("value", &&self.bytes[self.contents.clone()])
+                .finish()
+        }
+    }
+
+    impl<'a, T> From<&Lazy<'a, T>> for LazyOwned<T> {
+        fn from(lazy: &Lazy<'a, T>) -> Self {
+            Self {
+                bytes: lazy.bytes.into(),
+                tag: lazy.any.tag(),
+                contents: lazy.header_len..lazy.header_len + lazy.any.value().len(),
+                encoding_rules: lazy.encoding_rules,
+                value: OnceCell::new(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::Lazy;
+    use crate::{
+        Decode, Encode, ErrorKind, SliceWriter, Tag, TagMode, TagNumber, Tagged,
+        asn1::{ContextSpecificRef, OctetStringRef, SequenceOf},
+    };
+    use hex_literal::hex;
+
+    #[cfg(feature = "alloc")]
+    use super::LazyOwned;
+
+    /// `SEQUENCE OF INTEGER` encoded with a non-minimal integer.
+    const NON_MINIMAL: &[u8] = &hex!("3007 020101 02020001");
+
+    #[test]
+    fn decode_on_demand() {
+        let lazy = Lazy::<SequenceOf<u8, 2>>::from_der(&hex!("3006 020101 020102")).unwrap();
+        assert_eq!(lazy.tag(), Tag::Sequence);
+        assert_eq!(lazy.as_any().value(), &hex!("020101 020102"));
+
+        let value = lazy.get().unwrap();
+        assert_eq!(value.get(0), Some(&1));
+        assert_eq!(value.get(1), Some(&2));
+
+        // Invalid contents aren't detected until the value is accessed
+        let lazy = Lazy::<SequenceOf<u8, 2>>::from_der(NON_MINIMAL).unwrap();
+        assert_eq!(lazy.as_bytes(), NON_MINIMAL);
+        assert_eq!(
+            lazy.get().unwrap_err().kind(),
+            ErrorKind::Noncanonical { tag: Tag::Integer }
+        );
+    }
+
+    #[test]
+    fn encode_verbatim() {
+        let lazy = Lazy::<SequenceOf<u8, 2>>::from_der(NON_MINIMAL).unwrap();
+
+        let mut buf = [0u8; 16];
+        let mut writer = SliceWriter::new(&mut buf);
+        lazy.encode(&mut writer).unwrap();
+        assert_eq!(writer.finish().unwrap(), NON_MINIMAL);
+    }
+
+    #[test]
+    fn decode_tag_mismatch() {
+        let err = Lazy::<bool>::from_der(&hex!("020101")).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::TagUnexpected {
+                expected: None,
+                actual: Tag::Integer
+            }
+        );
+    }
+
+    #[test]
+    fn decode_ber() {
+        let bytes = hex!("3080 04026869 0000");
+        let lazy = Lazy::<SequenceOf<OctetStringRef<'_>, 1>>::from_ber(&bytes).unwrap();
+        assert_eq!(lazy.as_bytes(), &bytes);
+        assert_eq!(lazy.as_any().value(), &hex!("04026869"));
+        assert_eq!(lazy.get().unwrap().get(0).unwrap().as_bytes(), b"hi");
+
+        assert!(Lazy::<SequenceOf<OctetStringRef<'_>, 1>>::from_der(&bytes).is_err());
+
+        // Non-minimal length
+        let bytes = hex!("3081 03 020101");
+        let lazy = Lazy::<SequenceOf<u8, 1>>::from_ber(&bytes).unwrap();
+        assert_eq!(lazy.as_bytes(), &bytes);
+        assert_eq!(lazy.get().unwrap().get(0), Some(&1));
+
+        // Re-encoding writes the original encoding, including within `EXPLICIT` fields
+        let mut buf = [0u8; 8];
+        assert_eq!(lazy.encode_to_slice(&mut buf).unwrap(), &bytes);
+
+        let field = ContextSpecificRef {
+            tag_number: TagNumber(0),
+            tag_mode: TagMode::Explicit,
+            value: &lazy,
+        };
+        assert_eq!(
+            field.encode_to_slice(&mut buf).unwrap(),
+            &hex!("A006 3081 03 020101")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned() {
+        let lazy = LazyOwned::<SequenceOf<u8, 2>>::from_der(NON_MINIMAL).unwrap();
+        assert_eq!(lazy.as_bytes(), NON_MINIMAL);
+        assert_eq!(lazy.as_any().unwrap().value(), &hex!("020101 02020001"));
+        assert!(lazy.get().is_err());
+
+        let bytes = hex!("3080 020101 0000");
+        let lazy = Lazy::<SequenceOf<u8, 2>>::from_ber(&bytes).unwrap();
+        let owned = LazyOwned::from(&lazy);
+        assert_eq!(owned.as_bytes(), &bytes);
+        assert_eq!(owned.as_any().unwrap(), lazy.as_any());
+        assert_eq!(owned.get().unwrap().get(0), Some(&1));
+        assert_eq!(owned.to_der().unwrap(), bytes);
+    }
+}
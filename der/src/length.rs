@@ -1,5 +1,7 @@
 //! Length calculations for encoded ASN.1 DER values
 
+pub(crate) mod indefinite;
+
 use crate::{Decode, DerOrd, Encode, Error, ErrorKind, Reader, Result, SliceWriter, Tag, Writer};
 use core::{
     cmp::Ordering,
@@ -12,7 +14,7 @@ use core::{
 ///
 /// > The single octet shall have bit 8 set to one, and bits 7 to
 /// > 1 set to zero.
-const INDEFINITE_LENGTH_OCTET: u8 = 0b10000000; // 0x80
+pub(crate) const INDEFINITE_LENGTH_OCTET: u8 = 0b10000000; // 0x80
 
 /// ASN.1-encoded length.
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -28,6 +30,10 @@ impl Length {
     /// Maximum length (`u32::MAX`).
     pub const MAX: Self = Self(u32::MAX);
 
+    /// Length of the end-of-contents octets which terminate an indefinite
+    /// length value.
+    pub(crate) const EOC_LEN: Self = Self(2);
+
     /// Maximum number of octets in a DER encoding of a [`Length`] using the
     /// rules implemented by this crate.
     pub(crate) const MAX_SIZE: usize = 5;
@@ -72,6 +78,107 @@ impl Length {
         Self(self.0.saturating_sub(rhs.0))
     }
 
+    /// Compute the sum of the given lengths.
+    ///
+    /// This function is const-safe and therefore useful for computing
+    /// [`FixedLen::ENCODED_LEN`][`crate::FixedLen::ENCODED_LEN`].
+    ///
+    /// # Panics
+    ///
+    /// If the sum overflows, which fails compilation when evaluated in a constant.
+    pub const fn sum(lengths: &[Self]) -> Self {
+        let mut sum = 0u32;
+        let mut i = 0;
+
+        while i < lengths.len() {
+            sum = sum.checked_add(lengths[i].0).expect("length overflow");
+            i += 1;
+        }
+
+        Self(sum)
+    }
+
+    /// Const-safe equivalent of [`Length::for_tlv`] for definite lengths.
+    ///
+    /// # Panics
+    ///
+    /// If the length of the TLV overflows, which fails compilation when evaluated in a constant.
+    pub const fn for_tlv_const(self, tag: Tag) -> Self {
+        Self::sum(&[self.header_len_const(tag), self])
+    }
+
+    /// Inverse of [`Length::for_tlv_const`]: get the length of the value of a TLV whose total
+    /// length is `self`.
+    ///
+    /// # Panics
+    ///
+    /// If no value length gives a TLV of this length, which fails compilation when evaluated in a
+    /// constant.
+    pub(crate) const fn value_len_const(self, tag: Tag) -> Self {
+        // The TLV length strictly increases with the value length, so at most one value length
+        // can match, and its length octets are between 1 and 5 long
+        let mut header_len = Self::ZERO.header_len_const(tag).0;
+        let mut value_len = None;
+
+        while header_len <= self.0 {
+            let candidate = Self(self.0 - header_len);
+            let actual_header_len = candidate.header_len_const(tag).0;
+
+            if actual_header_len == header_len {
+                value_len = Some(candidate);
+                break;
+            } else if actual_header_len < header_len {
+                break;
+            }
+
+            header_len = actual_header_len;
+        }
+
+        value_len.expect("no value length gives a TLV of this length")
+    }
+
+    /// Length of the header of a TLV with the given tag whose value has this length.
+    const fn header_len_const(self, tag: Tag) -> Self {
+        let number = tag.number().0;
+
+        let tag_len = if number <= 30 {
+            1
+        } else {
+            number.ilog2() / 7 + 2
+        };
+
+        let length_len = match self.0 {
+            0..=0x7F => 1,
+            0x80..=0xFF => 2,
+            0x100..=0xFFFF => 3,
+            0x10000..=0xFFFFFF => 4,
+            0x1000000..=0xFFFFFFFF => 5,
+        };
+
+        Self(tag_len + length_len)
+    }
+
+    /// Multiply this length by `n` in a `const` context.
+    ///
+    /// # Panics
+    ///
+    /// If the product overflows.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn mul_const(self, n: usize) -> Self {
+        let product = if n > (u32::MAX as usize) {
+            None
+        } else {
+            self.0.checked_mul(n as u32)
+        };
+
+        Self(product.expect("length overflow"))
+    }
+
+    /// Get the number of octets as a [`usize`] in a `const` context.
+    pub(crate) const fn as_usize(self) -> usize {
+        self.0 as usize
+    }
+
     /// Get initial octet of the encoded length (if one is required).
     ///
     /// From X.690 Section 8.1.3.5:
@@ -228,8 +335,8 @@ impl<'a> Decode<'a> for Length {
                 let length = Length::from(decoded_len);
 
                 // X.690 Section 10.1: DER lengths must be encoded with a minimum
-                // number of octets
-                if length.initial_octet() == Some(tag) {
+                // number of octets, whereas BER allows any number (Section 8.1.3.5)
+                if length.initial_octet() == Some(tag) || reader.encoding_rules().is_ber() {
                     Ok(length)
                 } else {
                     Err(ErrorKind::Overlength.into())
@@ -417,7 +524,7 @@ impl TryFrom<IndefiniteLength> for Length {
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::{IndefiniteLength, Length};
-    use crate::{Decode, DerOrd, Encode, ErrorKind};
+    use crate::{Decode, DerOrd, Encode, ErrorKind, Tag, TagNumber};
     use core::cmp::Ordering;
 
     #[test]
@@ -506,6 +613,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn for_tlv_const() {
+        let tags = [
+            Tag::Boolean,
+            Tag::Date,
+            Tag::ContextSpecific {
+                constructed: true,
+                number: TagNumber(200),
+            },
+        ];
+
+        for tag in tags {
+            for len in [0u32, 0x7F, 0x80, 0x100, 0x10000, 0x1000000] {
+                let len = Length::from(len);
+                assert_eq!(len.for_tlv_const(tag), len.for_tlv(tag).unwrap());
+                assert_eq!(len.for_tlv_const(tag).value_len_const(tag), len);
+            }
+        }
+
+        const SUM: Length = Length::sum(&[Length::ONE, Length::new(2)]);
+        assert_eq!(SUM, Length::new(3));
+    }
+
     #[test]
     fn der_ord() {
         assert_eq!(Length::ONE.der_cmp(&Length::MAX).unwrap(), Ordering::Less);
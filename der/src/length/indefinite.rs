@@ -0,0 +1,213 @@
+//! Support for decoding BER indefinite lengths as described in X.690 Section
+//! 8.1.3.6.
+//!
+//! Constructed values are decoded as they're read, with the [`Reader`] stopping
+//! at the end-of-contents octets which terminate them (see
+//! [`Reader::is_indefinite`]). Values whose contents are captured as a whole,
+//! e.g. [`AnyRef`][`crate::asn1::AnyRef`], need their length up front, which
+//! is computed by scanning ahead for the end-of-contents octets.
+
+use super::INDEFINITE_LENGTH_OCTET;
+use crate::{
+    Decode, Error, ErrorKind, Length, Reader, Result, Tag, TagNumber, tag::CONSTRUCTED_FLAG,
+};
+
+/// End-of-contents octets as described in X.690 Section 8.1.5:
+///
+/// > The end-of-contents octets shall consist of two zero octets.
+const EOC_OCTETS: [u8; 2] = [0, 0];
+
+/// Compute the length of the contents of an indefinite-length value.
+///
+/// The reader is expected to be positioned at the first contents octet, i.e.
+/// immediately after the `0x80` length octet, and will be advanced past the
+/// terminating end-of-contents octets.
+///
+/// Nested indefinite-length values are handled iteratively rather than
+/// recursively so the nesting depth is bounded only by the input length.
+pub(crate) fn decode_indefinite_length<'a, R: Reader<'a>>(reader: &mut R) -> Result<Length> {
+    let start_pos = reader.position();
+    let mut depth = 0usize;
+
+    loop {
+        if peek_eoc(reader) {
+            read_eoc(reader)?;
+
+            match depth.checked_sub(1) {
+                Some(d) => depth = d,
+                None => {
+                    return (reader.position() - start_pos)? - Length::EOC_LEN;
+                }
+            }
+
+            continue;
+        }
+
+        let constructed = read_identifier(reader)?;
+
+        if reader.peek_byte() == Some(INDEFINITE_LENGTH_OCTET) {
+            if !constructed {
+                return Err(reader.error(ErrorKind::IndefiniteLength));
+            }
+
+            reader.read_byte()?;
+            depth = depth.checked_add(1).ok_or(ErrorKind::Overflow)?;
+        } else {
+            let length = Length::decode(reader)?;
+            reader.drain(length)?;
+        }
+    }
+}
+
+/// Skip over the identifier octets of a value, returning whether or not it
+/// uses the constructed form.
+///
+/// This intentionally doesn't go through [`Tag`] so that BER productions
+/// which [`Tag`] can't represent (e.g. constructed `OCTET STRING`) can still
+/// be skipped over.
+fn read_identifier<'a, R: Reader<'a>>(reader: &mut R) -> Result<bool> {
+    let first = reader.read_byte()?;
+    let constructed = first & CONSTRUCTED_FLAG != 0;
+
+    if first & TagNumber::MASK == TagNumber::MASK {
+        // High tag number form: subsequent octets have bit 8 set until the last
+        for _ in 1..Tag::MAX_SIZE {
+            if reader.read_byte()? & 0x80 == 0 {
+                return Ok(constructed);
+            }
+        }
+
+        return Err(reader.error(ErrorKind::TagNumberInvalid));
+    }
+
+    Ok(constructed)
+}
+
+/// Is the reader positioned at end-of-contents octets?
+pub(crate) fn peek_eoc<'a, R: Reader<'a>>(reader: &R) -> bool {
+    let mut buf = [0xFF; 2];
+    reader.peek_into(&mut buf).is_ok() && buf == EOC_OCTETS
+}
+
+/// Read the end-of-contents octets which terminate an indefinite-length value.
+pub(crate) fn read_eoc<'a, R: Reader<'a>>(reader: &mut R) -> Result<()> {
+    let mut buf = [0xFF; 2];
+    reader.read_into(&mut buf)?;
+
+    if buf == EOC_OCTETS {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::IndefiniteLength, reader.position()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::decode_indefinite_length;
+    use crate::{
+        Decode, DecodeValue, EncodingRules, ErrorKind, FixedTag, Header, Length, Reader, Result,
+        SliceReader, Tag, asn1::Null,
+    };
+    use hex_literal::hex;
+
+    /// `SEQUENCE { INTEGER }` whose contents are read with [`Reader::read_contents`] if it opts into
+    /// the indefinite length form, or with `read_nested(header.length, ..)` otherwise.
+    struct Wrapper<const INDEFINITE: bool>(u8);
+
+    impl<'a, const INDEFINITE: bool> DecodeValue<'a> for Wrapper<INDEFINITE> {
+        type Error = crate::Error;
+        const INDEFINITE_LENGTH: bool = INDEFINITE;
+
+        fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
+            if INDEFINITE {
+                reader.read_contents(header, |r| r.decode().map(Self))
+            } else {
+                reader.read_nested(header.length, |r| r.decode().map(Self))
+            }
+        }
+    }
+
+    impl<const INDEFINITE: bool> FixedTag for Wrapper<INDEFINITE> {
+        const TAG: Tag = Tag::Sequence;
+    }
+
+    #[test]
+    fn flat() {
+        // Contents of `SEQUENCE { INTEGER 1, NULL }` followed by EOC
+        let mut reader =
+            SliceReader::new_with_encoding_rules(&hex!("020101 0500 0000"), EncodingRules::Ber)
+                .unwrap();
+
+        let length = decode_indefinite_length(&mut reader).unwrap();
+        assert_eq!(length, Length::new(5));
+        assert!(reader.is_finished());
+    }
+
+    #[test]
+    fn nested() {
+        // Contents of `SEQUENCE { [0] { INTEGER 1 } }` with indefinite lengths
+        let mut reader = SliceReader::new_with_encoding_rules(
+            &hex!("3080 A080 020101 0000 0000 0000"),
+            EncodingRules::Ber,
+        )
+        .unwrap();
+
+        let length = decode_indefinite_length(&mut reader).unwrap();
+        assert_eq!(length, Length::new(11));
+        assert!(reader.is_finished());
+    }
+
+    #[test]
+    fn constructed_octet_string() {
+        // Contents of a constructed `OCTET STRING` with two segments followed by EOC
+        let mut reader =
+            SliceReader::new_with_encoding_rules(&hex!("0401AA 0402BBCC 0000"), EncodingRules::Ber)
+                .unwrap();
+
+        let length = decode_indefinite_length(&mut reader).unwrap();
+        assert_eq!(length, Length::new(7));
+        assert!(reader.is_finished());
+    }
+
+    #[test]
+    fn missing_eoc() {
+        let mut reader =
+            SliceReader::new_with_encoding_rules(&hex!("020101"), EncodingRules::Ber).unwrap();
+
+        let err = decode_indefinite_length(&mut reader).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+    }
+
+    #[test]
+    fn primitive_indefinite() {
+        let mut reader =
+            SliceReader::new_with_encoding_rules(&hex!("0480 0000 0000"), EncodingRules::Ber)
+                .unwrap();
+
+        let err = decode_indefinite_length(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+    }
+
+    #[test]
+    fn opt_in() {
+        // `SEQUENCE { INTEGER 1 }` with an indefinite length, followed by `NULL`
+        let ber = hex!("3080 020101 0000 0500");
+
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        assert_eq!(Wrapper::<true>::decode(&mut reader).unwrap().0, 1);
+        Null::decode(&mut reader).unwrap();
+        assert!(reader.is_finished());
+
+        // Types which don't opt in are rejected rather than reading past the end-of-contents
+        let mut reader = SliceReader::new_with_encoding_rules(&ber, EncodingRules::Ber).unwrap();
+        let err = Wrapper::<false>::decode(&mut reader).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::IndefiniteLength);
+
+        // Definite lengths are unaffected
+        assert_eq!(
+            Wrapper::<false>::from_ber(&hex!("3003 020101")).unwrap().0,
+            1
+        );
+    }
+}
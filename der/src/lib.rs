@@ -35,9 +35,12 @@
 //! The traits are impl'd for the following Rust core types:
 //! - `()`: ASN.1 `NULL`. See also [`Null`].
 //! - [`bool`]: ASN.1 `BOOLEAN`.
+//! - [`Bytes`][`::bytes::Bytes`]: ASN.1 `OCTET STRING`. Requires `bytes` feature. Decoding with
+//!   a [`BytesReader`] shares the allocation of the input. See also [`AnyBytes`].
 //! - [`i8`], [`i16`], [`i32`], [`i64`], [`i128`]: ASN.1 `INTEGER`.
 //! - [`u8`], [`u16`], [`u32`], [`u64`], [`u128`]: ASN.1 `INTEGER`.
-//! - [`f64`]: ASN.1 `REAL` (gated on `real` crate feature)
+//! - [`f32`], [`f64`]: ASN.1 `REAL` (gated on `real` crate feature). See also
+//!   [`RealDecimalRef`][`asn1::RealDecimalRef`].
 //! - [`str`], [`String`][`alloc::string::String`]: ASN.1 `UTF8String`.
 //!   `String` requires `alloc` feature. See also [`Utf8StringRef`].
 //! - [`Option`]: ASN.1 `OPTIONAL`.
@@ -48,14 +51,23 @@
 //! The following ASN.1 types provided by this crate also impl these traits:
 //! - [`Any`], [`AnyRef`]: ASN.1 `ANY`.
 //! - [`BitString`], [`BitStringRef`]: ASN.1 `BIT STRING`
+//! - [`Date`]: ASN.1 `DATE`.
+//! - [`Duration`]: ASN.1 `DURATION`.
 //! - [`GeneralizedTime`]: ASN.1 `GeneralizedTime`.
 //! - [`Ia5StringRef`]: ASN.1 `IA5String`.
+//! - [`LocalDateTime`]: ASN.1 `DATE-TIME`.
 //! - [`Null`]: ASN.1 `NULL`.
+//! - [`NumericStringRef`]: ASN.1 `NumericString` (digits and space).
 //! - [`ObjectIdentifier`]: ASN.1 `OBJECT IDENTIFIER`.
-//! - [`OctetString`], [`OctetStringRef`]: ASN.1 `OCTET STRING`.
+//! - [`OctetString`], [`OctetStringRef`]: ASN.1 `OCTET STRING`. See also [`OctetStringArray`]
+//!   for fixed-size octet strings.
 //! - [`PrintableStringRef`]: ASN.1 `PrintableString` (ASCII subset).
+//! - [`RelativeOid`]: ASN.1 `RELATIVE-OID`.
 //! - [`TeletexStringRef`]: ASN.1 `TeletexString`.
+//! - [`TimeOfDay`]: ASN.1 `TIME-OF-DAY`.
+//! - [`UniversalStringRef`]: ASN.1 `UniversalString` (UCS-4).
 //! - [`VideotexStringRef`]: ASN.1 `VideotexString`.
+//! - [`VisibleStringRef`]: ASN.1 `VisibleString` (printable ASCII).
 //! - [`SequenceOf`]: ASN.1 `SEQUENCE OF`.
 //! - [`SetOf`], [`SetOfVec`]: ASN.1 `SET OF`.
 //! - [`UintRef`]: ASN.1 unsigned `INTEGER` with raw access to encoded bytes.
@@ -212,7 +224,10 @@
 //!
 //! - [`Choice`]: derive for `CHOICE` enum (see [`der_derive::Choice`])
 //! - [`Enumerated`]: derive for `ENUMERATED` enum (see [`der_derive::Enumerated`])
+//! - [`Jer`]: derive for JER encoding of the above (see [`der_derive::Jer`] and [`jer`])
+//! - [`Per`]: derive for PER encoding of the above (see [`der_derive::Per`] and [`per`])
 //! - [`Sequence`]: derive for `SEQUENCE` struct (see [`der_derive::Sequence`])
+//! - [`Set`]: derive for `SET` struct (see [`der_derive::Set`])
 //!
 //! ### Derive [`Sequence`] for struct
 //! The following is a code example of how to use the [`Sequence`] custom derive:
@@ -313,15 +328,24 @@
 //! [`ContextSpecificRef`]: asn1::ContextSpecificRef
 //! [`BitString`]: asn1::BitString
 //! [`BitStringRef`]: asn1::BitStringRef
+//! [`Date`]: asn1::Date
+//! [`Duration`]: asn1::Duration
 //! [`GeneralizedTime`]: asn1::GeneralizedTime
 //! [`Ia5StringRef`]: asn1::Ia5StringRef
+//! [`LocalDateTime`]: asn1::LocalDateTime
 //! [`Null`]: asn1::Null
+//! [`NumericStringRef`]: asn1::NumericStringRef
 //! [`ObjectIdentifier`]: asn1::ObjectIdentifier
 //! [`OctetString`]: asn1::OctetString
+//! [`OctetStringArray`]: asn1::OctetStringArray
 //! [`OctetStringRef`]: asn1::OctetStringRef
 //! [`PrintableStringRef`]: asn1::PrintableStringRef
+//! [`RelativeOid`]: asn1::RelativeOid
 //! [`TeletexStringRef`]: asn1::TeletexStringRef
+//! [`TimeOfDay`]: asn1::TimeOfDay
+//! [`UniversalStringRef`]: asn1::UniversalStringRef
 //! [`VideotexStringRef`]: asn1::VideotexStringRef
+//! [`VisibleStringRef`]: asn1::VisibleStringRef
 //! [`SequenceOf`]: asn1::SequenceOf
 //! [`SetOf`]: asn1::SetOf
 //! [`SetOfVec`]: asn1::SetOfVec
@@ -339,8 +363,15 @@ extern crate alloc;
 extern crate std;
 
 pub mod asn1;
+pub mod constraint;
+pub mod dump;
 pub mod referenced;
 
+#[cfg(feature = "alloc")]
+pub mod jer;
+#[cfg(feature = "alloc")]
+pub mod per;
+
 pub(crate) mod arrayvec;
 mod bytes_ref;
 mod datetime;
@@ -350,15 +381,21 @@ mod encode_ref;
 mod encoding_rules;
 mod error;
 mod header;
+mod lazy;
 mod length;
 mod ord;
 mod reader;
+mod spanned;
 mod str_ref;
 mod tag;
 mod writer;
 
 #[cfg(feature = "alloc")]
 mod bytes_owned;
+#[cfg(feature = "bytes")]
+mod bytes_shared;
+#[cfg(feature = "alloc")]
+mod canonical;
 #[cfg(feature = "alloc")]
 mod document;
 #[cfg(feature = "alloc")]
@@ -369,23 +406,41 @@ pub use crate::{
     asn1::{AnyRef, Choice, Sequence},
     datetime::DateTime,
     decode::{Decode, DecodeOwned, DecodeValue},
-    encode::{Encode, EncodeValue},
+    encode::{Encode, EncodeValue, FixedLen},
     encode_ref::{EncodeRef, EncodeValueRef},
     encoding_rules::EncodingRules,
-    error::{Error, ErrorKind, Result},
+    error::{Error, ErrorKind, FieldPath, Result},
     header::Header,
+    lazy::Lazy,
     length::{IndefiniteLength, Length},
     ord::{DerOrd, ValueOrd},
-    reader::{Reader, slice::SliceReader},
+    reader::{
+        Reader,
+        limits::{DecodeLimit, DecodeLimits},
+        quirks::{DecodeQuirk, DecodeQuirks, DecodeWarning},
+        slice::SliceReader,
+    },
+    spanned::{FieldSpan, Span, Spanned},
     tag::{Class, FixedTag, Tag, TagMode, TagNumber, Tagged},
-    writer::{Writer, slice::SliceWriter},
+    writer::{Writer, cer::CerWriter, slice::SliceWriter},
 };
 
 #[cfg(feature = "alloc")]
-pub use crate::{asn1::Any, document::Document};
+pub use crate::{asn1::Any, canonical::canonicalize, document::Document, lazy::LazyOwned};
+
+#[cfg(feature = "bytes")]
+pub use crate::{asn1::AnyBytes, reader::bytes::BytesReader};
+
+#[cfg(feature = "std")]
+pub use crate::reader::io::IoReader;
 
 #[cfg(feature = "derive")]
-pub use der_derive::{BitString, Choice, DecodeValue, EncodeValue, Enumerated, Sequence, ValueOrd};
+pub use der_derive::{
+    BitString, Choice, DecodeValue, EncodeValue, Enumerated, Jer, Per, Sequence, Set, ValueOrd,
+};
+
+#[cfg(feature = "digest")]
+pub use {crate::writer::digest::DigestWriter, digest};
 
 #[cfg(feature = "flagset")]
 pub use flagset;
@@ -408,6 +463,8 @@ pub use zeroize;
 #[cfg(all(feature = "alloc", feature = "zeroize"))]
 pub use crate::document::SecretDocument;
 
+#[cfg(feature = "bytes")]
+pub(crate) use crate::bytes_shared::BytesShared;
 pub(crate) use crate::{arrayvec::ArrayVec, bytes_ref::BytesRef, str_ref::StrRef};
 #[cfg(feature = "alloc")]
 pub(crate) use crate::{bytes_owned::BytesOwned, str_owned::StrOwned};
@@ -0,0 +1,138 @@
+//! Packed Encoding Rules (PER) as described in ITU-T X.691, in both the `ALIGNED` and
+//! `UNALIGNED` variants of `BASIC-PER`.
+//!
+//! Unlike DER, PER doesn't encode tags or lengths of values whose size is fixed by their type,
+//! and encodes values using as few bits as the `SIZE` and value range constraints of their type
+//! allow. The [`PerEncode`] and [`PerDecode`] traits therefore take the [`Constraints`] of the
+//! value being encoded, which the `Per` custom derive provides from the `size` and `range`
+//! attributes of each field.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use der::per::{Alignment, PerDecode, PerEncode};
+//!
+//! /// `Point ::= SEQUENCE { x INTEGER (0..255), y INTEGER (0..255) OPTIONAL }`
+//! #[derive(der::Per, Debug, Eq, PartialEq)]
+//! struct Point {
+//!     #[asn1(range = "0..=255")]
+//!     x: u8,
+//!
+//!     #[asn1(range = "0..=255", optional = "true")]
+//!     y: Option<u8>,
+//! }
+//!
+//! let point = Point { x: 7, y: None };
+//! let uper = point.to_per(Alignment::Unaligned).unwrap();
+//!
+//! // Presence bit of `y`, followed by `x` in 8 bits
+//! assert_eq!(uper, [0b0000_0011, 0b1000_0000]);
+//! assert_eq!(Point::from_per(&uper, Alignment::Unaligned).unwrap(), point);
+//! # }
+//! ```
+//!
+//! # Limitations
+//!
+//! - Lengths of 16K or more which aren't constrained to less than 64K, which require
+//!   fragmentation, result in an [`ErrorKind::Overlength`] error.
+//! - Extensible constraints, and extension additions of `CHOICE` types, aren't supported.
+//!   Extension additions of `SEQUENCE` types are skipped when decoding.
+//!
+//! [`ErrorKind::Overlength`]: crate::ErrorKind::Overlength
+
+mod reader;
+mod types;
+mod writer;
+
+pub use self::{reader::PerReader, writer::PerWriter};
+
+use crate::{Result, Tag};
+use alloc::vec::Vec;
+
+/// Variant of the Packed Encoding Rules.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Alignment {
+    /// `ALIGNED` variant (APER), which pads some fields to octet boundaries.
+    Aligned,
+
+    /// `UNALIGNED` variant (UPER), which never pads fields.
+    Unaligned,
+}
+
+/// Bounds of a constraint, where `None` means unbounded.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bounds {
+    /// Lower bound (inclusive).
+    pub lower: Option<i128>,
+
+    /// Upper bound (inclusive).
+    pub upper: Option<i128>,
+}
+
+impl Bounds {
+    /// No bounds.
+    pub const UNBOUNDED: Self = Self {
+        lower: None,
+        upper: None,
+    };
+
+    /// Does `value` lie within these bounds?
+    pub fn contains(&self, value: i128) -> bool {
+        self.lower.is_none_or(|lower| value >= lower)
+            && self.upper.is_none_or(|upper| value <= upper)
+    }
+}
+
+/// PER-visible constraints of a value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Constraints {
+    /// Value range constraint of an `INTEGER`.
+    pub value: Bounds,
+
+    /// `SIZE` constraint of a string or of a `SEQUENCE OF` or `SET OF`.
+    pub size: Bounds,
+}
+
+impl Constraints {
+    /// No constraints.
+    pub const NONE: Self = Self {
+        value: Bounds::UNBOUNDED,
+        size: Bounds::UNBOUNDED,
+    };
+}
+
+/// Encoding trait for the Packed Encoding Rules.
+pub trait PerEncode {
+    /// Encode this value, which is subject to the given constraints.
+    fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()>;
+
+    /// Encode this value as a complete PER encoding using the given [`Alignment`].
+    fn to_per(&self, alignment: Alignment) -> Result<Vec<u8>> {
+        let mut writer = PerWriter::new(alignment);
+        self.per_encode(&mut writer, &Constraints::NONE)?;
+        Ok(writer.finish())
+    }
+}
+
+/// Decoding trait for the Packed Encoding Rules.
+pub trait PerDecode: Sized {
+    /// Decode a value, which is subject to the given constraints.
+    fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self>;
+
+    /// Decode a value from a complete PER encoding using the given [`Alignment`].
+    fn from_per(bytes: &[u8], alignment: Alignment) -> Result<Self> {
+        let mut reader = PerReader::new(bytes, alignment);
+        let value = Self::per_decode(&mut reader, &Constraints::NONE)?;
+        reader.finish()?;
+        Ok(value)
+    }
+}
+
+/// Get the index of the alternative with the given tag within a `CHOICE` whose alternatives
+/// have the given `tags`, which in PER is the position of the tag in canonical order.
+pub fn choice_index(tags: &[Tag], tag: Tag) -> usize {
+    let key = |tag: &Tag| (tag.class(), tag.number());
+    tags.iter().filter(|other| key(other) < key(&tag)).count()
+}
@@ -0,0 +1,263 @@
+//! PER reader.
+
+use super::{
+    Alignment, Bounds,
+    writer::{CONSTRAINED_LENGTH_LIMIT, bit_len, octet_len},
+};
+use crate::{Error, ErrorKind, Length, Result, constraint::Constraint};
+use alloc::vec::Vec;
+
+/// Reader for PER encodings, which are bit rather than octet oriented.
+#[derive(Clone, Debug)]
+pub struct PerReader<'a> {
+    /// Variant being decoded.
+    alignment: Alignment,
+
+    /// Complete encoding being decoded.
+    bytes: &'a [u8],
+
+    /// Number of bits read.
+    position: usize,
+}
+
+impl<'a> PerReader<'a> {
+    /// Create a new reader for a complete encoding of the given variant.
+    pub fn new(bytes: &'a [u8], alignment: Alignment) -> Self {
+        Self {
+            alignment,
+            bytes,
+            position: 0,
+        }
+    }
+
+    /// Get the variant being decoded.
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Create an error of the given kind at the current position, in octets.
+    pub fn error(&self, kind: ErrorKind) -> Error {
+        kind.at(octet_position(self.position))
+    }
+
+    /// Read a single bit.
+    pub fn read_bit(&mut self) -> Result<bool> {
+        let byte = self
+            .bytes
+            .get(self.position / 8)
+            .ok_or_else(|| Error::incomplete(octet_position(self.bytes.len() * 8)))?;
+
+        let bit = byte & (0x80 >> (self.position % 8)) != 0;
+        self.position += 1;
+        Ok(bit)
+    }
+
+    /// Read `count` bits as a number, most significant bit first.
+    pub fn read_bits(&mut self, count: u32) -> Result<u128> {
+        let mut value = 0;
+
+        for _ in 0..count {
+            value = (value << 1) | u128::from(self.read_bit()?);
+        }
+
+        Ok(value)
+    }
+
+    /// Skip padding bits to the next octet boundary when decoding the `ALIGNED` variant.
+    pub fn align(&mut self) {
+        if self.alignment == Alignment::Aligned {
+            self.position = self.position.next_multiple_of(8);
+        }
+    }
+
+    /// Read `len` octets, which are only aligned if [`PerReader::align`] was called first.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn read_octets(&mut self, len: usize) -> Result<Vec<u8>> {
+        if self.position % 8 == 0 {
+            let start = self.position / 8;
+            let octets = start
+                .checked_add(len)
+                .and_then(|end| self.bytes.get(start..end))
+                .ok_or_else(|| Error::incomplete(octet_position(self.bytes.len() * 8)))?;
+
+            self.position += len * 8;
+            return Ok(octets.to_vec());
+        }
+
+        (0..len).map(|_| Ok(self.read_bits(8)? as u8)).collect()
+    }
+
+    /// Read a constrained whole number within a range of `range` values, returning its offset
+    /// from the lower bound of the range (X.691 Section 11.5).
+    pub fn read_constrained_whole_number(&mut self, range: u128) -> Result<u128> {
+        let value = match (self.alignment, range) {
+            (_, 0..=1) => 0,
+            (Alignment::Unaligned, _) | (Alignment::Aligned, 2..=255) => {
+                self.read_bits(bit_len(range - 1))?
+            }
+            (Alignment::Aligned, 256) => {
+                self.align();
+                self.read_bits(8)?
+            }
+            (Alignment::Aligned, 257..=65536) => {
+                self.align();
+                self.read_bits(16)?
+            }
+            (Alignment::Aligned, _) => {
+                let max_octets = octet_len(range - 1) as u128;
+                let len = self.read_constrained_whole_number(max_octets)? as usize + 1;
+                self.align();
+                self.read_unsigned(len)?
+            }
+        };
+
+        if value >= range.max(1) {
+            return Err(self.error(ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange,
+            }));
+        }
+
+        Ok(value)
+    }
+
+    /// Read a semi-constrained whole number, returning its offset from the lower bound of its
+    /// range (X.691 Section 11.7).
+    pub fn read_semi_constrained_whole_number(&mut self) -> Result<u128> {
+        let len = self.read_length(Bounds::UNBOUNDED)?;
+        self.align();
+        self.read_unsigned(len)
+    }
+
+    /// Read an unconstrained whole number (X.691 Section 11.8).
+    pub fn read_unconstrained_whole_number(&mut self) -> Result<i128> {
+        let len = self.read_length(Bounds::UNBOUNDED)?;
+
+        if len == 0 || len > 16 {
+            return Err(self.error(ErrorKind::Overflow));
+        }
+
+        self.align();
+        let octets = self.read_octets(len)?;
+
+        // Sign extend
+        let fill = if octets[0] & 0x80 != 0 { 0xFF } else { 0 };
+        let mut bytes = [fill; 16];
+        bytes[16 - len..].copy_from_slice(&octets);
+        Ok(i128::from_be_bytes(bytes))
+    }
+
+    /// Read a normally small non-negative whole number (X.691 Section 11.6).
+    pub fn read_normally_small_number(&mut self) -> Result<u128> {
+        if self.read_bit()? {
+            self.read_semi_constrained_whole_number()
+        } else {
+            self.read_bits(6)
+        }
+    }
+
+    /// Read the index of the alternative of a `CHOICE` with `count` alternatives in its root,
+    /// preceded by the extension bit if the `CHOICE` is extensible (X.691 Section 23).
+    ///
+    /// Extension additions are unsupported, since they can't be decoded as any known
+    /// alternative, and result in a [`Constraint::ValueRange`] violation.
+    pub fn read_choice_index(&mut self, count: usize, extensible: bool) -> Result<usize> {
+        if extensible && self.read_bit()? {
+            return Err(self.error(ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange,
+            }));
+        }
+
+        Ok(self.read_constrained_whole_number(count as u128)? as usize)
+    }
+
+    /// Read a length determinant for a value subject to the given `SIZE` constraint
+    /// (X.691 Section 11.9).
+    pub fn read_length(&mut self, size: Bounds) -> Result<usize> {
+        let lower = size.lower.unwrap_or(0);
+
+        let len = match size.upper {
+            Some(upper) if upper == lower && upper < CONSTRAINED_LENGTH_LIMIT => Some(lower),
+            Some(upper) if upper < CONSTRAINED_LENGTH_LIMIT => {
+                let range = upper.abs_diff(lower).saturating_add(1);
+                lower.checked_add_unsigned(self.read_constrained_whole_number(range)?)
+            }
+            _ => {
+                self.align();
+
+                let first = self.read_bits(8)?;
+
+                let len = match first >> 6 {
+                    0b00 | 0b01 => first,
+                    0b10 => ((first & 0x3F) << 8) | self.read_bits(8)?,
+                    _ => return Err(self.error(ErrorKind::Overlength)),
+                };
+
+                i128::try_from(len).ok()
+            }
+        }
+        .ok_or_else(|| self.error(ErrorKind::Overflow))?;
+
+        if !size.contains(len) {
+            return Err(self.error(ErrorKind::ConstraintViolation {
+                constraint: Constraint::Size,
+            }));
+        }
+
+        usize::try_from(len).map_err(|_| self.error(ErrorKind::Overflow))
+    }
+
+    /// Skip the extension additions of an extensible `SEQUENCE` whose extension bit was set
+    /// (X.691 Section 19.7), which are encoded as open types.
+    pub fn skip_extension_additions(&mut self) -> Result<()> {
+        let count = self.read_normally_small_number()? + 1;
+        let mut present = 0;
+
+        for _ in 0..count {
+            present += usize::from(self.read_bit()?);
+        }
+
+        for _ in 0..present {
+            let len = self.read_length(Bounds::UNBOUNDED)?;
+            self.align();
+            self.read_octets(len)?;
+        }
+
+        Ok(())
+    }
+
+    /// Finish decoding, checking that only the padding of the final octet remains.
+    pub fn finish(self) -> Result<()> {
+        let decoded = self.position.div_ceil(8);
+
+        // An empty complete encoding is a single zero octet (X.691 Section 11.1)
+        if decoded == 0 && self.bytes == [0] {
+            return Ok(());
+        }
+
+        if decoded < self.bytes.len() {
+            return Err(self.error(ErrorKind::TrailingData {
+                decoded: octet_position(decoded * 8),
+                remaining: octet_position((self.bytes.len() - decoded) * 8),
+            }));
+        }
+
+        Ok(())
+    }
+
+    /// Read a big endian unsigned number of `len` octets.
+    fn read_unsigned(&mut self, len: usize) -> Result<u128> {
+        if len > 16 {
+            return Err(self.error(ErrorKind::Overflow));
+        }
+
+        let octets = self.read_octets(len)?;
+        Ok(octets
+            .iter()
+            .fold(0, |value, &octet| (value << 8) | u128::from(octet)))
+    }
+}
+
+/// Convert a position in bits to a [`Length`] in octets, saturating if too large.
+fn octet_position(bits: usize) -> Length {
+    Length::try_from(bits / 8).unwrap_or(Length::MAX)
+}
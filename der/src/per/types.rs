@@ -0,0 +1,597 @@
+//! PER encodings of ASN.1 types (X.691 Sections 12–30).
+
+use super::{Alignment, Bounds, Constraints, PerDecode, PerEncode, PerReader, PerWriter};
+use crate::{
+    DerOrd, ErrorKind, Result, Tag,
+    asn1::{
+        BitString, Ia5String, Null, NumericString, OctetString, PrintableString, SetOfVec,
+        VisibleString,
+    },
+    constraint::Constraint,
+};
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "oid")]
+use crate::asn1::ObjectIdentifier;
+
+impl PerEncode for bool {
+    fn per_encode(&self, writer: &mut PerWriter, _constraints: &Constraints) -> Result<()> {
+        writer.write_bit(*self);
+        Ok(())
+    }
+}
+
+impl PerDecode for bool {
+    fn per_decode(reader: &mut PerReader<'_>, _constraints: &Constraints) -> Result<Self> {
+        reader.read_bit()
+    }
+}
+
+macro_rules! impl_per_integer {
+    ($($int:ty),+) => {
+        $(
+            impl PerEncode for $int {
+                fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()> {
+                    encode_integer(writer, (*self).into(), constraints.value)
+                }
+            }
+
+            impl PerDecode for $int {
+                fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self> {
+                    let value = decode_integer(reader, constraints.value)?;
+                    Self::try_from(value).map_err(|_| reader.error(ErrorKind::Overflow))
+                }
+            }
+        )+
+    };
+}
+
+impl_per_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Encode an `INTEGER` subject to the given value range constraint (X.691 Section 13).
+fn encode_integer(writer: &mut PerWriter, value: i128, range: Bounds) -> Result<()> {
+    if !range.contains(value) {
+        return Err(ErrorKind::ConstraintViolation {
+            constraint: Constraint::ValueRange,
+        }
+        .into());
+    }
+
+    match (range.lower, range.upper) {
+        (Some(lower), Some(upper)) => writer.write_constrained_whole_number(
+            value.abs_diff(lower),
+            upper.abs_diff(lower).saturating_add(1),
+        ),
+        (Some(lower), None) => writer.write_semi_constrained_whole_number(value.abs_diff(lower)),
+        _ => writer.write_unconstrained_whole_number(value),
+    }
+}
+
+/// Decode an `INTEGER` subject to the given value range constraint (X.691 Section 13).
+fn decode_integer(reader: &mut PerReader<'_>, range: Bounds) -> Result<i128> {
+    let value = match (range.lower, range.upper) {
+        (Some(lower), Some(upper)) => {
+            let offset =
+                reader.read_constrained_whole_number(upper.abs_diff(lower).saturating_add(1))?;
+            lower.checked_add_unsigned(offset)
+        }
+        (Some(lower), None) => {
+            let offset = reader.read_semi_constrained_whole_number()?;
+            lower.checked_add_unsigned(offset)
+        }
+        _ => Some(reader.read_unconstrained_whole_number()?),
+    }
+    .ok_or_else(|| reader.error(ErrorKind::Overflow))?;
+
+    if !range.contains(value) {
+        return Err(reader.error(ErrorKind::ConstraintViolation {
+            constraint: Constraint::ValueRange,
+        }));
+    }
+
+    Ok(value)
+}
+
+impl PerEncode for Null {
+    fn per_encode(&self, _writer: &mut PerWriter, _constraints: &Constraints) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl PerDecode for Null {
+    fn per_decode(_reader: &mut PerReader<'_>, _constraints: &Constraints) -> Result<Self> {
+        Ok(Null)
+    }
+}
+
+/// Does a `SIZE` constraint fix the size of a value to at most 16 bits, in which case its
+/// contents aren't octet-aligned (X.691 Sections 16.9 and 17.6)?
+fn is_small_fixed_size(size: Bounds, unit_bits: i128) -> bool {
+    size.upper
+        .is_some_and(|upper| size.lower.unwrap_or(0) == upper && upper * unit_bits <= 16)
+}
+
+impl PerEncode for OctetString {
+    fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()> {
+        let bytes = self.as_bytes();
+        writer.write_length(bytes.len(), constraints.size)?;
+
+        if !is_small_fixed_size(constraints.size, 8) {
+            writer.align();
+        }
+
+        writer.write_octets(bytes);
+        Ok(())
+    }
+}
+
+impl PerDecode for OctetString {
+    fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self> {
+        let len = reader.read_length(constraints.size)?;
+
+        if !is_small_fixed_size(constraints.size, 8) {
+            reader.align();
+        }
+
+        OctetString::new(reader.read_octets(len)?)
+    }
+}
+
+impl PerEncode for BitString {
+    fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()> {
+        writer.write_length(self.bit_len(), constraints.size)?;
+
+        if !is_small_fixed_size(constraints.size, 1) {
+            writer.align();
+        }
+
+        for bit in self.bits() {
+            writer.write_bit(bit);
+        }
+
+        Ok(())
+    }
+}
+
+impl PerDecode for BitString {
+    fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self> {
+        let bit_len = reader.read_length(constraints.size)?;
+
+        if !is_small_fixed_size(constraints.size, 1) {
+            reader.align();
+        }
+
+        let mut bytes = Vec::with_capacity(bit_len.div_ceil(8));
+
+        for i in 0..bit_len {
+            if i % 8 == 0 {
+                bytes.push(0);
+            }
+
+            if reader.read_bit()? {
+                if let Some(byte) = bytes.last_mut() {
+                    *byte |= 0x80 >> (i % 8);
+                }
+            }
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let unused_bits = (bytes.len() * 8 - bit_len) as u8;
+        BitString::new(unused_bits, bytes)
+    }
+}
+
+impl PerEncode for String {
+    fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()> {
+        // The `SIZE` constraint of a `UTF8String` counts characters rather than octets, so it
+        // isn't PER-visible, but is still checked
+        check_char_count(self, constraints.size)?;
+        writer.write_length(self.len(), Bounds::UNBOUNDED)?;
+        writer.align();
+        writer.write_octets(self.as_bytes());
+        Ok(())
+    }
+}
+
+impl PerDecode for String {
+    fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self> {
+        let len = reader.read_length(Bounds::UNBOUNDED)?;
+        reader.align();
+
+        let s = String::from_utf8(reader.read_octets(len)?)
+            .map_err(|_| reader.error(Tag::Utf8String.value_error().kind()))?;
+
+        check_char_count(&s, constraints.size)?;
+        Ok(s)
+    }
+}
+
+/// Check the `SIZE` constraint of a string whose size isn't PER-visible.
+fn check_char_count(s: &str, size: Bounds) -> Result<()> {
+    if size.contains(s.chars().count() as i128) {
+        Ok(())
+    } else {
+        Err(ErrorKind::ConstraintViolation {
+            constraint: Constraint::Size,
+        }
+        .into())
+    }
+}
+
+/// Alphabet of a known-multiplier character string type (X.691 Section 30).
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Alphabet {
+    /// Tag of the string type.
+    tag: Tag,
+
+    /// Number of bits of each character in the `UNALIGNED` variant, which the `ALIGNED`
+    /// variant rounds up to a power of two.
+    bits: u32,
+
+    /// Characters of the alphabet in order, if characters are encoded as their index within
+    /// the alphabet rather than their value, because the largest value doesn't fit in `bits`.
+    indexed: Option<&'static [u8]>,
+}
+
+impl Alphabet {
+    /// Alphabet of `IA5String`.
+    pub(crate) const IA5: Self = Self {
+        tag: Tag::Ia5String,
+        bits: 7,
+        indexed: None,
+    };
+
+    /// Alphabet of `PrintableString`, whose largest character `z` fits in 7 bits.
+    pub(crate) const PRINTABLE: Self = Self {
+        tag: Tag::PrintableString,
+        bits: 7,
+        indexed: None,
+    };
+
+    /// Alphabet of `NumericString`, whose 11 characters are encoded as their index in 4 bits.
+    pub(crate) const NUMERIC: Self = Self {
+        tag: Tag::NumericString,
+        bits: 4,
+        indexed: Some(b" 0123456789"),
+    };
+
+    /// Alphabet of `VisibleString`, whose largest character `~` fits in 7 bits.
+    pub(crate) const VISIBLE: Self = Self {
+        tag: Tag::VisibleString,
+        bits: 7,
+        indexed: None,
+    };
+
+    /// Number of bits of each character in the given variant.
+    fn char_bits(self, alignment: Alignment) -> u32 {
+        match alignment {
+            Alignment::Aligned => self.bits.next_power_of_two(),
+            Alignment::Unaligned => self.bits,
+        }
+    }
+
+    /// Encode a string of this alphabet subject to the given `SIZE` constraint.
+    pub(crate) fn encode(self, writer: &mut PerWriter, s: &str, size: Bounds) -> Result<()> {
+        let bits = self.char_bits(writer.alignment());
+        writer.write_length(s.len(), size)?;
+
+        if !self.is_unaligned(size, bits) {
+            writer.align();
+        }
+
+        for &c in s.as_bytes() {
+            let value = match self.indexed {
+                Some(chars) => chars
+                    .iter()
+                    .position(|&other| other == c)
+                    .ok_or_else(|| self.tag.value_error())?,
+                None => usize::from(c),
+            };
+
+            writer.write_bits(value as u128, bits);
+        }
+
+        Ok(())
+    }
+
+    /// Decode the characters of a string of this alphabet subject to the given `SIZE`
+    /// constraint, which must still be validated by the string type.
+    pub(crate) fn decode(self, reader: &mut PerReader<'_>, size: Bounds) -> Result<Vec<u8>> {
+        let bits = self.char_bits(reader.alignment());
+        let len = reader.read_length(size)?;
+
+        if !self.is_unaligned(size, bits) {
+            reader.align();
+        }
+
+        (0..len)
+            .map(|_| {
+                let value = reader.read_bits(bits)?;
+
+                match self.indexed {
+                    Some(chars) => usize::try_from(value)
+                        .ok()
+                        .and_then(|i| chars.get(i).copied())
+                        .ok_or_else(|| reader.error(ErrorKind::Value { tag: self.tag })),
+                    None => u8::try_from(value)
+                        .map_err(|_| reader.error(ErrorKind::Value { tag: self.tag })),
+                }
+            })
+            .collect()
+    }
+
+    /// Are the characters of a string subject to the given `SIZE` constraint left unaligned,
+    /// because the string is at most 16 bits long (X.691 Section 30.5.7)?
+    fn is_unaligned(self, size: Bounds, bits: u32) -> bool {
+        size.upper
+            .is_some_and(|upper| upper * i128::from(bits) <= 16)
+    }
+}
+
+macro_rules! impl_per_known_multiplier_string {
+    ($($string:ty => $alphabet:expr),+) => {
+        $(
+            impl PerEncode for $string {
+                fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()> {
+                    $alphabet.encode(writer, self.as_str(), constraints.size)
+                }
+            }
+
+            impl PerDecode for $string {
+                fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self> {
+                    let bytes = $alphabet.decode(reader, constraints.size)?;
+                    Self::new(&bytes)
+                }
+            }
+        )+
+    };
+}
+
+impl_per_known_multiplier_string!(
+    Ia5String => Alphabet::IA5,
+    NumericString => Alphabet::NUMERIC,
+    PrintableString => Alphabet::PRINTABLE,
+    VisibleString => Alphabet::VISIBLE
+);
+
+#[cfg(feature = "oid")]
+impl PerEncode for ObjectIdentifier {
+    fn per_encode(&self, writer: &mut PerWriter, _constraints: &Constraints) -> Result<()> {
+        let bytes = self.as_bytes();
+        writer.write_length(bytes.len(), Bounds::UNBOUNDED)?;
+        writer.align();
+        writer.write_octets(bytes);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "oid")]
+impl PerDecode for ObjectIdentifier {
+    fn per_decode(reader: &mut PerReader<'_>, _constraints: &Constraints) -> Result<Self> {
+        let len = reader.read_length(Bounds::UNBOUNDED)?;
+        reader.align();
+        Ok(Self::from_bytes(&reader.read_octets(len)?)?)
+    }
+}
+
+impl<T: PerEncode> PerEncode for Vec<T> {
+    fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()> {
+        encode_elements(writer, self, constraints.size)
+    }
+}
+
+impl<T: PerDecode> PerDecode for Vec<T> {
+    fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self> {
+        let len = reader.read_length(constraints.size)?;
+        (0..len)
+            .map(|_| T::per_decode(reader, &Constraints::NONE))
+            .collect()
+    }
+}
+
+impl<T: PerEncode + DerOrd> PerEncode for SetOfVec<T> {
+    fn per_encode(&self, writer: &mut PerWriter, constraints: &Constraints) -> Result<()> {
+        encode_elements(writer, self.as_ref(), constraints.size)
+    }
+}
+
+impl<T: PerDecode + DerOrd> PerDecode for SetOfVec<T> {
+    fn per_decode(reader: &mut PerReader<'_>, constraints: &Constraints) -> Result<Self> {
+        Vec::per_decode(reader, constraints)?.try_into()
+    }
+}
+
+/// Encode the elements of a `SEQUENCE OF` or `SET OF` subject to the given `SIZE` constraint
+/// (X.691 Section 20).
+fn encode_elements<T: PerEncode>(
+    writer: &mut PerWriter,
+    elements: &[T],
+    size: Bounds,
+) -> Result<()> {
+    writer.write_length(elements.len(), size)?;
+
+    for element in elements {
+        element.per_encode(writer, &Constraints::NONE)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Alignment, Bounds, Constraints, PerDecode, PerEncode, PerReader, PerWriter};
+    use crate::{
+        ErrorKind,
+        asn1::{BitString, Ia5String, NumericString, OctetString},
+        constraint::Constraint,
+    };
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    fn encode<T: PerEncode>(value: &T, alignment: Alignment, constraints: Constraints) -> Vec<u8> {
+        let mut writer = PerWriter::new(alignment);
+        value.per_encode(&mut writer, &constraints).unwrap();
+        writer.finish()
+    }
+
+    fn decode<T: PerDecode>(bytes: &[u8], alignment: Alignment, constraints: Constraints) -> T {
+        let mut reader = PerReader::new(bytes, alignment);
+        let value = T::per_decode(&mut reader, &constraints).unwrap();
+        reader.finish().unwrap();
+        value
+    }
+
+    fn range(lower: i128, upper: i128) -> Constraints {
+        Constraints {
+            value: Bounds {
+                lower: Some(lower),
+                upper: Some(upper),
+            },
+            ..Constraints::NONE
+        }
+    }
+
+    fn size(lower: i128, upper: i128) -> Constraints {
+        Constraints {
+            size: Bounds {
+                lower: Some(lower),
+                upper: Some(upper),
+            },
+            ..Constraints::NONE
+        }
+    }
+
+    #[test]
+    fn integer() {
+        // INTEGER (-1..6): offset 7 in 3 bits
+        assert_eq!(
+            encode(&6i8, Alignment::Unaligned, range(-1, 6)),
+            [0b1110_0000]
+        );
+        assert_eq!(
+            decode::<i8>(&[0b1110_0000], Alignment::Unaligned, range(-1, 6)),
+            6
+        );
+
+        // INTEGER (0..65535) is two aligned octets in APER
+        assert_eq!(
+            encode(&0x1234u16, Alignment::Aligned, range(0, 65535)),
+            [0x12, 0x34]
+        );
+
+        // Unconstrained
+        assert_eq!(
+            encode(&-1i32, Alignment::Aligned, Constraints::NONE),
+            [0x01, 0xFF]
+        );
+        assert_eq!(
+            decode::<i32>(&[0x01, 0xFF], Alignment::Aligned, Constraints::NONE),
+            -1
+        );
+
+        let mut writer = PerWriter::new(Alignment::Unaligned);
+        let err = 7u8.per_encode(&mut writer, &range(0, 6)).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange
+            }
+        );
+
+        // Unconstrained value too large for the type
+        let mut reader = PerReader::new(&[0x02, 0x01, 0x00], Alignment::Unaligned);
+        let err = u8::per_decode(&mut reader, &Constraints::NONE).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overflow);
+    }
+
+    #[test]
+    fn octet_string() {
+        let os = OctetString::new(hex!("AABB")).unwrap();
+
+        // Fixed size of at most two octets: neither length nor alignment
+        let mut writer = PerWriter::new(Alignment::Aligned);
+        writer.write_bit(true);
+        os.per_encode(&mut writer, &size(2, 2)).unwrap();
+        assert_eq!(writer.finish(), hex!("D5 5D 80"));
+
+        // Constrained length, then aligned octets
+        assert_eq!(
+            encode(&os, Alignment::Aligned, size(0, 7)),
+            hex!("40 AA BB")
+        );
+        assert_eq!(
+            encode(&os, Alignment::Unaligned, size(0, 7)),
+            hex!("55 57 60")
+        );
+        assert_eq!(
+            decode::<OctetString>(&hex!("55 57 60"), Alignment::Unaligned, size(0, 7)),
+            os
+        );
+    }
+
+    #[test]
+    fn bit_string() {
+        let bits = BitString::new(4, hex!("A0")).unwrap();
+        assert_eq!(encode(&bits, Alignment::Aligned, size(4, 4)), [0xA0]);
+        assert_eq!(
+            encode(&bits, Alignment::Unaligned, Constraints::NONE),
+            [0x04, 0xA0]
+        );
+        assert_eq!(
+            decode::<BitString>(&[0x04, 0xA0], Alignment::Unaligned, Constraints::NONE),
+            bits
+        );
+    }
+
+    #[test]
+    fn ia5_string() {
+        let s = Ia5String::new("hi").unwrap();
+
+        // 7 bits per character in UPER, 8 in APER
+        assert_eq!(
+            encode(&s, Alignment::Unaligned, Constraints::NONE),
+            hex!("02 D1 A4")
+        );
+        assert_eq!(
+            encode(&s, Alignment::Aligned, Constraints::NONE),
+            hex!("02 68 69")
+        );
+        assert_eq!(
+            decode::<Ia5String>(&hex!("02 D1 A4"), Alignment::Unaligned, Constraints::NONE),
+            s
+        );
+    }
+
+    #[test]
+    fn numeric_string() {
+        let s = NumericString::new("1 9").unwrap();
+
+        // Characters are encoded as their index in the alphabet, in 4 bits
+        assert_eq!(
+            encode(&s, Alignment::Unaligned, Constraints::NONE),
+            hex!("03 20 A0")
+        );
+        assert_eq!(
+            decode::<NumericString>(&hex!("03 20 A0"), Alignment::Aligned, Constraints::NONE),
+            s
+        );
+
+        // Index outside the alphabet
+        let mut reader = PerReader::new(&hex!("01 F0"), Alignment::Unaligned);
+        assert!(NumericString::per_decode(&mut reader, &Constraints::NONE).is_err());
+    }
+
+    #[test]
+    fn sequence_of() {
+        let values: Vec<bool> = [true, false, true].into();
+        assert_eq!(
+            encode(&values, Alignment::Unaligned, size(1, 4)),
+            [0b1010_1000]
+        );
+        assert_eq!(
+            decode::<Vec<bool>>(&[0b1010_1000], Alignment::Unaligned, size(1, 4)),
+            values
+        );
+    }
+}
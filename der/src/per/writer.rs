@@ -0,0 +1,323 @@
+//! PER writer.
+
+use super::{Alignment, Bounds};
+use crate::{ErrorKind, Result, constraint::Constraint};
+use alloc::vec::Vec;
+
+/// Largest length which can be encoded without fragmentation (X.691 Section 11.9.3.8).
+pub(super) const MAX_UNFRAGMENTED_LEN: usize = 16384;
+
+/// Upper bound below which constrained lengths are encoded as constrained whole numbers
+/// (X.691 Section 11.9.3.3).
+pub(super) const CONSTRAINED_LENGTH_LIMIT: i128 = 65536;
+
+/// Writer for PER encodings, which are bit rather than octet oriented.
+#[derive(Clone, Debug)]
+pub struct PerWriter {
+    /// Variant being encoded.
+    alignment: Alignment,
+
+    /// Octets written so far, where the last one may be partially written.
+    bytes: Vec<u8>,
+
+    /// Number of bits written.
+    bit_len: usize,
+}
+
+impl PerWriter {
+    /// Create a new writer for the given variant.
+    pub fn new(alignment: Alignment) -> Self {
+        Self {
+            alignment,
+            bytes: Vec::new(),
+            bit_len: 0,
+        }
+    }
+
+    /// Get the variant being encoded.
+    pub fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Write a single bit.
+    pub fn write_bit(&mut self, bit: bool) {
+        if self.bit_len % 8 == 0 {
+            self.bytes.push(0);
+        }
+
+        if bit {
+            if let Some(byte) = self.bytes.last_mut() {
+                *byte |= 0x80 >> (self.bit_len % 8);
+            }
+        }
+
+        self.bit_len += 1;
+    }
+
+    /// Write the `count` least significant bits of `value`, most significant bit first.
+    pub fn write_bits(&mut self, value: u128, count: u32) {
+        for i in (0..count).rev() {
+            self.write_bit((value >> i) & 1 == 1);
+        }
+    }
+
+    /// Pad with zero bits to the next octet boundary when encoding the `ALIGNED` variant.
+    pub fn align(&mut self) {
+        if self.alignment == Alignment::Aligned {
+            while self.bit_len % 8 != 0 {
+                self.write_bit(false);
+            }
+        }
+    }
+
+    /// Write octets, which are only aligned if [`PerWriter::align`] was called first.
+    pub fn write_octets(&mut self, octets: &[u8]) {
+        if self.bit_len % 8 == 0 {
+            self.bytes.extend_from_slice(octets);
+            self.bit_len += octets.len() * 8;
+        } else {
+            for &octet in octets {
+                self.write_bits(octet.into(), 8);
+            }
+        }
+    }
+
+    /// Write a constrained whole number, i.e. the offset `value` of a number from the lower
+    /// bound of a range of `range` values (X.691 Section 11.5).
+    pub fn write_constrained_whole_number(&mut self, value: u128, range: u128) -> Result<()> {
+        if value >= range {
+            return Err(ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange,
+            }
+            .into());
+        }
+
+        match (self.alignment, range) {
+            (_, 0..=1) => {}
+            (Alignment::Unaligned, _) | (Alignment::Aligned, 2..=255) => {
+                self.write_bits(value, bit_len(range - 1));
+            }
+            (Alignment::Aligned, 256) => {
+                self.align();
+                self.write_bits(value, 8);
+            }
+            (Alignment::Aligned, 257..=65536) => {
+                self.align();
+                self.write_bits(value, 16);
+            }
+            (Alignment::Aligned, _) => {
+                // Length of the value in octets, followed by the octets
+                let octets = minimal_octets(value);
+                let max_octets = octet_len(range - 1);
+                self.write_constrained_whole_number(octets.len() as u128 - 1, max_octets as u128)?;
+                self.align();
+                self.write_octets(&octets);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Write a semi-constrained whole number, i.e. the offset `value` of a number from the lower
+    /// bound of a range without an upper bound (X.691 Section 11.7).
+    pub fn write_semi_constrained_whole_number(&mut self, value: u128) -> Result<()> {
+        let octets = minimal_octets(value);
+        self.write_length(octets.len(), Bounds::UNBOUNDED)?;
+        self.align();
+        self.write_octets(&octets);
+        Ok(())
+    }
+
+    /// Write an unconstrained whole number (X.691 Section 11.8).
+    pub fn write_unconstrained_whole_number(&mut self, value: i128) -> Result<()> {
+        let bytes = value.to_be_bytes();
+
+        // Minimal two's complement encoding: skip redundant leading sign octets
+        let mut start = 0;
+        while start < bytes.len() - 1
+            && ((bytes[start] == 0 && bytes[start + 1] & 0x80 == 0)
+                || (bytes[start] == 0xFF && bytes[start + 1] & 0x80 != 0))
+        {
+            start += 1;
+        }
+
+        self.write_length(bytes.len() - start, Bounds::UNBOUNDED)?;
+        self.align();
+        self.write_octets(&bytes[start..]);
+        Ok(())
+    }
+
+    /// Write a normally small non-negative whole number (X.691 Section 11.6).
+    pub fn write_normally_small_number(&mut self, value: u128) -> Result<()> {
+        if value < 64 {
+            self.write_bit(false);
+            self.write_bits(value, 6);
+            Ok(())
+        } else {
+            self.write_bit(true);
+            self.write_semi_constrained_whole_number(value)
+        }
+    }
+
+    /// Write the index of the alternative of a `CHOICE` with `count` alternatives in its root,
+    /// preceded by the extension bit if the `CHOICE` is extensible (X.691 Section 23).
+    pub fn write_choice_index(
+        &mut self,
+        index: usize,
+        count: usize,
+        extensible: bool,
+    ) -> Result<()> {
+        if extensible {
+            self.write_bit(false);
+        }
+
+        self.write_constrained_whole_number(index as u128, count as u128)
+    }
+
+    /// Write a length determinant for a value of `len` units subject to the given `SIZE`
+    /// constraint (X.691 Section 11.9).
+    ///
+    /// Nothing is written when the constraint fixes the length.
+    pub fn write_length(&mut self, len: usize, size: Bounds) -> Result<()> {
+        let value = i128::try_from(len)?;
+
+        if !size.contains(value) {
+            return Err(ErrorKind::ConstraintViolation {
+                constraint: Constraint::Size,
+            }
+            .into());
+        }
+
+        let lower = size.lower.unwrap_or(0);
+
+        match size.upper {
+            Some(upper) if upper == lower && upper < CONSTRAINED_LENGTH_LIMIT => Ok(()),
+            Some(upper) if upper < CONSTRAINED_LENGTH_LIMIT => self.write_constrained_whole_number(
+                value.abs_diff(lower),
+                upper.abs_diff(lower).saturating_add(1),
+            ),
+            _ => {
+                self.align();
+
+                match len {
+                    len if len < 128 => self.write_bits(len as u128, 8),
+                    len if len < MAX_UNFRAGMENTED_LEN => self.write_bits(0x8000 | len as u128, 16),
+                    _ => return Err(ErrorKind::Overlength.into()),
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Finish encoding, returning the complete encoding padded to a whole number of octets.
+    pub fn finish(mut self) -> Vec<u8> {
+        // An empty complete encoding is replaced by a single zero octet (X.691 Section 11.1)
+        if self.bytes.is_empty() {
+            self.bytes.push(0);
+        }
+
+        self.bytes
+    }
+}
+
+/// Number of bits needed to represent `value`.
+pub(super) fn bit_len(value: u128) -> u32 {
+    u128::BITS - value.leading_zeros()
+}
+
+/// Number of octets needed to represent `value`, which is at least one.
+pub(super) fn octet_len(value: u128) -> usize {
+    (bit_len(value).max(1) as usize).div_ceil(8)
+}
+
+/// Minimal big endian encoding of a non-negative number, which is at least one octet.
+fn minimal_octets(value: u128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    bytes[bytes.len() - octet_len(value)..].to_vec()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Alignment, Bounds, PerWriter};
+    use crate::ErrorKind;
+
+    fn encode(alignment: Alignment, f: impl FnOnce(&mut PerWriter)) -> alloc::vec::Vec<u8> {
+        let mut writer = PerWriter::new(alignment);
+        f(&mut writer);
+        writer.finish()
+    }
+
+    #[test]
+    fn constrained_whole_number() {
+        // Range of 5 values: 3 bits, unaligned in both variants
+        for alignment in [Alignment::Aligned, Alignment::Unaligned] {
+            let bytes = encode(alignment, |w| {
+                w.write_bit(true);
+                w.write_constrained_whole_number(4, 5).unwrap();
+            });
+            assert_eq!(bytes, [0b1100_0000]);
+        }
+
+        // Range of 256 values: octet-aligned in APER
+        let bytes = encode(Alignment::Aligned, |w| {
+            w.write_bit(true);
+            w.write_constrained_whole_number(0xAB, 256).unwrap();
+        });
+        assert_eq!(bytes, [0x80, 0xAB]);
+
+        let bytes = encode(Alignment::Unaligned, |w| {
+            w.write_bit(true);
+            w.write_constrained_whole_number(0xAB, 256).unwrap();
+        });
+        assert_eq!(bytes, [0xD5, 0x80]);
+
+        // Range above 64K: length of the value in octets, then the aligned octets
+        let bytes = encode(Alignment::Aligned, |w| {
+            w.write_constrained_whole_number(0x1234, 1 << 32).unwrap();
+        });
+        assert_eq!(bytes, [0x40, 0x12, 0x34]);
+    }
+
+    #[test]
+    fn whole_numbers() {
+        let bytes = encode(Alignment::Unaligned, |w| {
+            w.write_unconstrained_whole_number(-129).unwrap();
+        });
+        assert_eq!(bytes, [0x02, 0xFF, 0x7F]);
+
+        let bytes = encode(Alignment::Unaligned, |w| {
+            w.write_unconstrained_whole_number(128).unwrap();
+        });
+        assert_eq!(bytes, [0x02, 0x00, 0x80]);
+
+        let bytes = encode(Alignment::Aligned, |w| {
+            w.write_bit(true);
+            w.write_semi_constrained_whole_number(0).unwrap();
+        });
+        assert_eq!(bytes, [0x80, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn length() {
+        let bytes = encode(Alignment::Unaligned, |w| {
+            w.write_length(200, Bounds::UNBOUNDED).unwrap()
+        });
+        assert_eq!(bytes, [0x80, 0xC8]);
+
+        let mut writer = PerWriter::new(Alignment::Unaligned);
+        let err = writer.write_length(16384, Bounds::UNBOUNDED).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Overlength);
+
+        // Fixed size: nothing is written
+        let fixed = Bounds {
+            lower: Some(4),
+            upper: Some(4),
+        };
+        assert_eq!(
+            encode(Alignment::Aligned, |w| w.write_length(4, fixed).unwrap()),
+            [0]
+        );
+    }
+}
@@ -1,12 +1,20 @@
 //! Reader trait.
 
+#[cfg(feature = "bytes")]
+pub(crate) mod bytes;
+#[cfg(feature = "std")]
+pub(crate) mod io;
+pub(crate) mod limits;
 #[cfg(feature = "pem")]
 pub(crate) mod pem;
+pub(crate) mod quirks;
 pub(crate) mod slice;
 
 use crate::{
-    Decode, DecodeValue, Encode, EncodingRules, Error, ErrorKind, FixedTag, Header, Length, Tag,
-    TagMode, TagNumber, asn1::ContextSpecific,
+    Decode, DecodeLimits, DecodeQuirk, DecodeQuirks, DecodeValue, EncodingRules, Error, ErrorKind,
+    FieldSpan, FixedTag, Header, Length, Tag, TagMode, TagNumber,
+    asn1::ContextSpecific,
+    length::indefinite::{decode_indefinite_length, peek_eoc, read_eoc},
 };
 
 #[cfg(feature = "alloc")]
@@ -20,6 +28,82 @@ pub trait Reader<'r>: Sized {
     /// Get the length of the input.
     fn input_len(&self) -> Length;
 
+    /// Get the [`DecodeLimits`] which should be enforced when decoding the input.
+    ///
+    /// Readers which don't support limits enforce none by default.
+    fn limits(&self) -> DecodeLimits {
+        DecodeLimits::default()
+    }
+
+    /// Get the nesting depth of the value currently being read, which is tracked by
+    /// [`Reader::read_value`] to enforce [`DecodeLimits::max_depth`].
+    ///
+    /// Readers which support limits need to store the depth and override this method along with
+    /// [`Reader::set_depth`].
+    fn depth(&self) -> u32 {
+        0
+    }
+
+    /// Set the nesting depth of the value currently being read.
+    fn set_depth(&mut self, _depth: u32) {}
+
+    /// Is the value currently being read encoded using the BER indefinite length form?
+    ///
+    /// Such values are terminated by end-of-contents octets rather than the end of the input,
+    /// which [`Reader::is_finished`] checks for. [`Reader::read_value`] sets this flag when
+    /// reading the contents of a value which uses the indefinite length form.
+    ///
+    /// Readers which support indefinite lengths need to store the flag and override this method
+    /// along with [`Reader::set_indefinite`]. [`Reader::read_nested`] needs to clear it unless
+    /// the nested input extends to the end of the current input, in which case it's still
+    /// terminated by the same end-of-contents octets.
+    fn is_indefinite(&self) -> bool {
+        false
+    }
+
+    /// Set whether the value currently being read is encoded using the indefinite length form.
+    fn set_indefinite(&mut self, _indefinite: bool) {}
+
+    /// Get the [`DecodeQuirks`] which are tolerated when decoding the input.
+    ///
+    /// Readers which don't support quirks tolerate none by default.
+    fn quirks(&self) -> DecodeQuirks {
+        DecodeQuirks::NONE
+    }
+
+    /// Record that the input relies on the given quirk.
+    ///
+    /// Readers which support quirks need to store the recorded quirks and override this method
+    /// along with [`Reader::quirks`].
+    fn record_quirk(&mut self, _quirk: DecodeQuirk) {}
+
+    /// Does this reader record the [`FieldSpan`] of every field it decodes?
+    ///
+    /// Readers which don't support recording spans don't by default.
+    fn records_spans(&self) -> bool {
+        false
+    }
+
+    /// Record the [`FieldSpan`] of a decoded field.
+    ///
+    /// Readers which support recording spans need to store them and override this method along
+    /// with [`Reader::records_spans`].
+    fn record_span(&mut self, _span: FieldSpan) {}
+
+    /// Check whether the given quirk is tolerated, recording it if so.
+    ///
+    /// Decoders call this when they encounter input which relies on a quirk, and fail as they
+    /// would otherwise if it returns `false`.
+    fn allow_quirk(&mut self, quirk: DecodeQuirk) -> bool {
+        let allowed = self.quirks().contains(quirk);
+
+        if allowed {
+            self.record_quirk(quirk);
+        }
+
+        allowed
+    }
+
     /// Peek at the decoded PEM without updating the internal state, writing into the provided
     /// output buffer.
     ///
@@ -66,6 +150,35 @@ pub trait Reader<'r>: Sized {
         T::decode(self)
     }
 
+    /// Drain the given amount of data from the reader, discarding it.
+    fn drain(&mut self, amount: Length) -> Result<(), Error> {
+        let mut remaining = usize::try_from(amount)?;
+        let mut buf = [0u8; 32];
+
+        while remaining > 0 {
+            let n = remaining.min(buf.len());
+            self.read_into(&mut buf[..n])?;
+            remaining -= n;
+        }
+
+        Ok(())
+    }
+
+    /// Skip over the contents of the value described by the given [`Header`], which the reader is
+    /// positioned at the start of, discarding them.
+    ///
+    /// The contents of values using the BER indefinite length form are skipped up to and including
+    /// their end-of-contents octets. Unlike decoding the value as [`AnyRef`][`crate::asn1::AnyRef`],
+    /// this only drains the input, so it works with readers which can't borrow from it.
+    fn skip_value(&mut self, header: Header) -> Result<(), Error> {
+        if header.is_indefinite() {
+            decode_indefinite_length(self)?;
+            Ok(())
+        } else {
+            self.drain(header.length)
+        }
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     fn error(&mut self, kind: ErrorKind) -> Error {
@@ -87,8 +200,15 @@ pub trait Reader<'r>: Sized {
     }
 
     /// Have we read all of the input data?
+    ///
+    /// When reading the contents of a value which uses the indefinite length form, this checks
+    /// whether the reader is positioned at its end-of-contents octets instead.
     fn is_finished(&self) -> bool {
-        self.remaining_len().is_zero()
+        if self.is_indefinite() {
+            peek_eoc(self)
+        } else {
+            self.remaining_len().is_zero()
+        }
     }
 
     /// Offset within the original input stream.
@@ -100,6 +220,27 @@ pub trait Reader<'r>: Sized {
         self.position()
     }
 
+    /// Compute the length of the contents of a BER indefinite-length value
+    /// which begins `offset` bytes past the current position, by scanning
+    /// ahead for its end-of-contents octets.
+    ///
+    /// The offset identifies the first contents octet, i.e. the octet after
+    /// the `0x80` length octet. The returned [`Length`] doesn't include the
+    /// end-of-contents octets.
+    ///
+    /// This is only needed to capture the contents of such a value as a
+    /// whole, e.g. when decoding [`AnyRef`][`crate::asn1::AnyRef`]: other
+    /// values are decoded as they're read (see [`Reader::is_indefinite`]).
+    ///
+    /// Does not modify the reader's state.
+    ///
+    /// # Returns
+    /// - `Ok(length)` on success
+    /// - `Err(ErrorKind::Reader)` if the reader can't look ahead in the input
+    fn peek_indefinite_length(&self, _offset: Length) -> Result<Length, Error> {
+        Err(ErrorKind::Reader.at(self.position()))
+    }
+
     /// Peek at the next byte of input without modifying the cursor.
     fn peek_byte(&self) -> Option<u8> {
         let mut byte = [0];
@@ -148,12 +289,93 @@ pub trait Reader<'r>: Sized {
         Ok(bytes)
     }
 
+    /// Read a [`Bytes`][`::bytes::Bytes`] buffer of the given length.
+    ///
+    /// [`BytesReader`][`crate::BytesReader`] returns a slice which shares the allocation of its
+    /// input, whereas other readers copy the data.
+    #[cfg(feature = "bytes")]
+    fn read_bytes(&mut self, len: Length) -> Result<::bytes::Bytes, Error> {
+        self.read_vec(len).map(Into::into)
+    }
+
     /// Get the number of bytes still remaining in the buffer.
     fn remaining_len(&self) -> Length {
         debug_assert!(self.position() <= self.input_len());
         self.input_len().saturating_sub(self.position())
     }
 
+    /// Read the value (i.e. the "V" part of a "TLV" field) described by the
+    /// given [`Header`], creating a nested [`Reader`] for it and calling the
+    /// provided closure with it.
+    ///
+    /// If the header uses the indefinite length form, the contents are read
+    /// up to their end-of-contents octets, which are consumed as well.
+    fn read_value<F, T, E>(&mut self, header: Header, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let depth = self.depth().saturating_add(1);
+        if let Err(kind) = self.limits().check_depth(depth) {
+            return Err(self.error(kind).into());
+        }
+
+        let orig_depth = self.depth();
+        self.set_depth(depth);
+
+        let ret = if header.is_indefinite() {
+            let orig_indefinite = self.is_indefinite();
+
+            // The contents are bounded by the remaining input until their end-of-contents octets
+            let ret = self.read_nested(self.remaining_len(), |reader| {
+                reader.set_indefinite(true);
+
+                if !reader.is_indefinite() {
+                    return Err(reader.error(ErrorKind::IndefiniteLength).into());
+                }
+
+                f(reader)
+            });
+
+            self.set_indefinite(orig_indefinite);
+            ret
+        } else {
+            self.read_nested(header.length, f)
+        };
+
+        self.set_depth(orig_depth);
+        let ret = ret?;
+
+        if header.is_indefinite() {
+            read_eoc(self)?;
+        }
+
+        Ok(ret)
+    }
+
+    /// Read the contents of the value described by the given [`Header`], which the reader is
+    /// positioned at the start of, creating a nested [`Reader`] for them and calling the provided
+    /// closure with it.
+    ///
+    /// Unlike `read_nested(header.length, f)`, this supports values using the BER indefinite length
+    /// form, whose contents are read up to their end-of-contents octets. Those are consumed by
+    /// [`Reader::read_value`], which the reader must be reading such a value with.
+    fn read_contents<F, T, E>(&mut self, header: Header, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        if !header.is_indefinite() {
+            return self.read_nested(header.length, f);
+        }
+
+        if !self.is_indefinite() {
+            return Err(self.error(ErrorKind::IndefiniteLength).into());
+        }
+
+        self.read_nested(self.remaining_len(), f)
+    }
+
     /// Read an ASN.1 `SEQUENCE`, creating a nested [`Reader`] for the body and
     /// calling the provided closure with it.
     fn sequence<F, T, E>(&mut self, f: F) -> Result<T, E>
@@ -163,13 +385,12 @@ pub trait Reader<'r>: Sized {
     {
         let header = Header::decode(self)?;
         header.tag.assert_eq(Tag::Sequence)?;
-        self.read_nested(header.length, f)
+        self.read_value(header, f)
     }
 
     /// Obtain a slice of bytes contain a complete TLV production suitable for parsing later.
     fn tlv_bytes(&mut self) -> Result<&'r [u8], Error> {
-        let header = Header::peek(self)?;
-        let header_len = header.encoded_len()?;
-        self.read_slice((header_len + header.length)?)
+        let tlv_len = Header::peek_tlv_len(self)?;
+        self.read_slice(tlv_len)
     }
 }
@@ -0,0 +1,244 @@
+//! Reader for `bytes::Bytes` buffers.
+
+use crate::{
+    DecodeLimits, DecodeQuirk, DecodeQuirks, DecodeWarning, EncodingRules, Error, ErrorKind,
+    FieldSpan, Length, Reader, SliceReader, Tag,
+};
+use bytes::Bytes;
+
+/// [`Reader`] which consumes a [`Bytes`] buffer.
+///
+/// Behaves like a [`SliceReader`], except that [`Reader::read_bytes`] returns slices of the
+/// input which share its allocation rather than copying out of it. This makes decoding owned
+/// values backed by [`Bytes`], i.e. [`AnyBytes`][`crate::asn1::AnyBytes`],
+/// [`OctetStringBytes`][`crate::asn1::OctetStringBytes`],
+/// [`BitStringBytes`][`crate::asn1::BitStringBytes`] and `OCTET STRING`s decoded as [`Bytes`],
+/// zero-copy.
+#[derive(Clone, Debug)]
+pub struct BytesReader<'a> {
+    /// Buffer being decoded.
+    bytes: &'a Bytes,
+
+    /// Reader over the contents of the buffer.
+    inner: SliceReader<'a>,
+}
+
+impl<'a> BytesReader<'a> {
+    /// Create a new reader for the given buffer.
+    pub fn new(bytes: &'a Bytes) -> Result<Self, Error> {
+        Self::new_with_encoding_rules(bytes, EncodingRules::default())
+    }
+
+    /// Create a new reader with the given encoding rules.
+    pub fn new_with_encoding_rules(
+        bytes: &'a Bytes,
+        encoding_rules: EncodingRules,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            bytes,
+            inner: SliceReader::new_with_encoding_rules(bytes, encoding_rules)?,
+        })
+    }
+
+    /// Enforce the given [`DecodeLimits`] when decoding the input.
+    pub fn with_limits(mut self, limits: DecodeLimits) -> Self {
+        self.inner = self.inner.with_limits(limits);
+        self
+    }
+
+    /// Tolerate the given [`DecodeQuirks`] when decoding the input.
+    pub fn with_quirks(mut self, quirks: DecodeQuirks) -> Self {
+        self.inner = self.inner.with_quirks(quirks);
+        self
+    }
+
+    /// Get a [`DecodeWarning`] for every time the input decoded so far relied on a quirk.
+    pub fn warnings(&self) -> &[DecodeWarning] {
+        self.inner.warnings()
+    }
+
+    /// Record the [`FieldSpan`] of every field of a type with derived `Sequence` or `Set` impls
+    /// which is decoded from the input.
+    pub fn with_spans(mut self) -> Self {
+        self.inner = self.inner.with_spans();
+        self
+    }
+
+    /// Get the spans of the fields decoded so far, if they're being recorded.
+    pub fn spans(&self) -> &[FieldSpan] {
+        self.inner.spans()
+    }
+
+    /// Return an error for an invalid value with the given tag.
+    pub fn value_error(&mut self, tag: Tag) -> Error {
+        self.inner.value_error(tag)
+    }
+
+    /// Did the decoding operation fail due to an error?
+    pub fn is_failed(&self) -> bool {
+        self.inner.is_failed()
+    }
+}
+
+impl<'a> Reader<'a> for BytesReader<'a> {
+    fn encoding_rules(&self) -> EncodingRules {
+        self.inner.encoding_rules()
+    }
+
+    fn input_len(&self) -> Length {
+        self.inner.input_len()
+    }
+
+    fn limits(&self) -> DecodeLimits {
+        self.inner.limits()
+    }
+
+    fn quirks(&self) -> DecodeQuirks {
+        self.inner.quirks()
+    }
+
+    fn record_quirk(&mut self, quirk: DecodeQuirk) {
+        self.inner.record_quirk(quirk);
+    }
+
+    fn records_spans(&self) -> bool {
+        self.inner.records_spans()
+    }
+
+    fn record_span(&mut self, span: FieldSpan) {
+        self.inner.record_span(span);
+    }
+
+    fn depth(&self) -> u32 {
+        self.inner.depth()
+    }
+
+    fn set_depth(&mut self, depth: u32) {
+        self.inner.set_depth(depth);
+    }
+
+    fn is_indefinite(&self) -> bool {
+        self.inner.is_indefinite()
+    }
+
+    fn set_indefinite(&mut self, indefinite: bool) {
+        self.inner.set_indefinite(indefinite);
+    }
+
+    fn peek_into(&self, buf: &mut [u8]) -> crate::Result<()> {
+        self.inner.peek_into(buf)
+    }
+
+    fn peek_indefinite_length(&self, offset: Length) -> Result<Length, Error> {
+        self.inner.peek_indefinite_length(offset)
+    }
+
+    fn drain(&mut self, amount: Length) -> Result<(), Error> {
+        self.inner.drain(amount)
+    }
+
+    fn position(&self) -> Length {
+        self.inner.position()
+    }
+
+    fn read_nested<T, F, E>(&mut self, len: Length, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let bytes = self.bytes;
+
+        self.inner.read_nested(len, |inner| {
+            let fork = inner.fork();
+            let mut nested = Self {
+                bytes,
+                inner: core::mem::replace(inner, fork),
+            };
+
+            let ret = f(&mut nested);
+            *inner = nested.inner;
+            ret
+        })
+    }
+
+    fn read_slice(&mut self, len: Length) -> Result<&'a [u8], Error> {
+        self.inner.read_slice(len)
+    }
+
+    fn read_bytes(&mut self, len: Length) -> Result<Bytes, Error> {
+        let slice = self.inner.read_slice(len)?;
+        Ok(self.bytes.slice_ref(slice))
+    }
+
+    fn error(&mut self, kind: ErrorKind) -> Error {
+        self.inner.error(kind)
+    }
+
+    fn finish<T>(self, value: T) -> Result<T, Error> {
+        self.inner.finish(value)
+    }
+
+    fn remaining_len(&self) -> Length {
+        self.inner.remaining_len()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::BytesReader;
+    use crate::{
+        Decode, Encode, Error, Reader,
+        asn1::{AnyBytes, BitStringBytes, OctetStringBytes},
+    };
+    use bytes::Bytes;
+    use hex_literal::hex;
+
+    #[test]
+    fn read_bytes_shares_allocation() {
+        // SEQUENCE { OCTET STRING AABBCC, NULL }
+        let input = Bytes::from_static(&hex!("3007 0403AABBCC 0500"));
+        let mut reader = BytesReader::new(&input).unwrap();
+
+        let (octets, null) = reader
+            .sequence(|reader| Ok::<_, Error>((Bytes::decode(reader)?, AnyBytes::decode(reader)?)))
+            .unwrap();
+
+        reader.finish(()).unwrap();
+        assert_eq!(octets.as_ref(), &hex!("AABBCC"));
+        assert_eq!(octets.as_ptr(), input[4..].as_ptr());
+        assert!(null.is_null());
+    }
+
+    #[test]
+    fn string_types_share_allocation() {
+        // SEQUENCE { OCTET STRING AABBCC, BIT STRING (4 unused bits) DDE0 }
+        let input = Bytes::from_static(&hex!("300A 0403AABBCC 030304DDE0"));
+        let mut reader = BytesReader::new(&input).unwrap();
+
+        let (octets, bits) = reader
+            .sequence(|reader| {
+                Ok::<_, Error>((
+                    OctetStringBytes::decode(reader)?,
+                    BitStringBytes::decode(reader)?,
+                ))
+            })
+            .unwrap();
+
+        reader.finish(()).unwrap();
+        assert_eq!(octets.to_der().unwrap(), &input[2..7]);
+        assert_eq!(octets.into_bytes().as_ptr(), input[4..].as_ptr());
+        assert_eq!(bits.bit_len(), 12);
+        assert_eq!(bits.to_der().unwrap(), &input[7..]);
+        assert_eq!(bits.raw_bytes_shared().as_ptr(), input[10..].as_ptr());
+    }
+
+    #[test]
+    fn nested_errors() {
+        let input = Bytes::from_static(&hex!("3003 0403AABB"));
+        let mut reader = BytesReader::new(&input).unwrap();
+
+        assert!(reader.sequence(Bytes::decode).is_err());
+        assert!(reader.is_failed());
+    }
+}
@@ -0,0 +1,436 @@
+//! Streaming reader over [`std::io::Read`].
+
+use super::Reader;
+use crate::{
+    DecodeLimits, DecodeQuirk, DecodeQuirks, DecodeWarning, EncodingRules, Error, ErrorKind,
+    FieldSpan, Header, Length,
+};
+use core::cell::RefCell;
+use std::{io, vec::Vec};
+
+/// Size of the lookahead buffer, which is large enough to peek at any [`Header`].
+const LOOKAHEAD_SIZE: usize = Header::MAX_SIZE;
+
+/// Maximum number of bytes [`Reader::read_vec`] reads at a time.
+///
+/// Reading in chunks means memory is only allocated for data which is actually present in the
+/// input, rather than up front for a length which may have been chosen by an attacker.
+const READ_CHUNK_SIZE: usize = 0x10000;
+
+/// [`Reader`] which decodes from any [`io::Read`] source, e.g. a file or socket.
+///
+/// Only a small, fixed-size lookahead buffer is kept in memory, which is used to peek at headers.
+/// Values are read directly from the underlying source, so wrapping it in an [`io::BufReader`]
+/// is recommended when it performs poorly with small reads.
+///
+/// Since the input can't be borrowed, zero-copy types such as
+/// [`OctetStringRef`][`crate::asn1::OctetStringRef`] can't be decoded using this reader: use the
+/// corresponding owned types such as [`OctetString`][`crate::asn1::OctetString`] instead.
+///
+/// Looking arbitrarily far ahead in the input isn't possible either, which means the contents of
+/// BER indefinite-length values can't be captured as a whole, e.g. as an
+/// [`Any`][`crate::asn1::Any`]: they can only be decoded as they're read.
+///
+/// The input as a whole may be larger than [`Length::MAX`], e.g. a stream of many values, but
+/// each top-level value must fit within it. Since [`Reader::position`] is a [`Length`], positions
+/// are relative to the start of the input until it exceeds [`Length::MAX`], after which they're
+/// relative to a later top-level value: use [`IoReader::stream_position`] to get the absolute
+/// position in the input.
+#[derive(Debug)]
+pub struct IoReader<R> {
+    /// Inner reader and the lookahead buffer used for peeking.
+    inner: RefCell<Lookahead<R>>,
+
+    /// Encoding rules to apply when decoding the input.
+    encoding_rules: EncodingRules,
+
+    /// Limits to enforce when decoding the input.
+    limits: DecodeLimits,
+
+    /// Quirks to tolerate when decoding the input.
+    quirks: DecodeQuirks,
+
+    /// Quirks which the input relied on.
+    warnings: Vec<DecodeWarning>,
+
+    /// Spans of the decoded fields, if they're being recorded.
+    spans: Option<Vec<FieldSpan>>,
+
+    /// Nesting depth of the value currently being read.
+    depth: u32,
+
+    /// Is the value currently being read encoded using the indefinite length form?
+    indefinite: bool,
+
+    /// Input length, which is unbounded unless constrained by [`Reader::read_nested`].
+    input_len: Option<Length>,
+
+    /// Number of bytes read before the origin of `position`.
+    offset: u64,
+
+    /// Position in the input, relative to `offset`.
+    position: Length,
+}
+
+impl<R: io::Read> IoReader<R> {
+    /// Create a new streaming reader for the given [`io::Read`] source.
+    pub fn new(reader: R) -> Self {
+        Self::new_unchecked(reader, EncodingRules::default())
+    }
+
+    /// Create a new streaming reader with the given encoding rules.
+    ///
+    /// Returns an error for [`EncodingRules::Cer`], which is only supported for encoding.
+    pub fn new_with_encoding_rules(
+        reader: R,
+        encoding_rules: EncodingRules,
+    ) -> crate::Result<Self> {
+        if encoding_rules.is_cer() {
+            return Err(ErrorKind::Reader.into());
+        }
+
+        Ok(Self::new_unchecked(reader, encoding_rules))
+    }
+
+    /// Create a new streaming reader without checking the encoding rules are supported.
+    fn new_unchecked(reader: R, encoding_rules: EncodingRules) -> Self {
+        Self {
+            inner: RefCell::new(Lookahead {
+                reader,
+                buf: [0; LOOKAHEAD_SIZE],
+                len: 0,
+            }),
+            encoding_rules,
+            limits: DecodeLimits::default(),
+            quirks: DecodeQuirks::NONE,
+            warnings: Vec::new(),
+            spans: None,
+            depth: 0,
+            indefinite: false,
+            input_len: None,
+            offset: 0,
+            position: Length::ZERO,
+        }
+    }
+
+    /// Enforce the given [`DecodeLimits`] when decoding the input.
+    pub fn with_limits(mut self, limits: DecodeLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Tolerate the given [`DecodeQuirks`] when decoding the input.
+    pub fn with_quirks(mut self, quirks: DecodeQuirks) -> Self {
+        self.quirks = quirks;
+        self
+    }
+
+    /// Get a [`DecodeWarning`] for every time the input decoded so far relied on a quirk.
+    pub fn warnings(&self) -> &[DecodeWarning] {
+        &self.warnings
+    }
+
+    /// Record the [`FieldSpan`] of every field of a type with derived `Sequence` or `Set` impls
+    /// which is decoded from the input.
+    ///
+    /// Like [`Reader::position`], the recorded positions are relative to a later top-level value
+    /// once the input exceeds [`Length::MAX`].
+    pub fn with_spans(mut self) -> Self {
+        self.spans = Some(Vec::new());
+        self
+    }
+
+    /// Get the spans of the fields decoded so far, if they're being recorded.
+    pub fn spans(&self) -> &[FieldSpan] {
+        self.spans.as_deref().unwrap_or_default()
+    }
+
+    /// Get the absolute position in the input, i.e. the total number of bytes read from it.
+    pub fn stream_position(&self) -> u64 {
+        self.offset + u64::from(u32::from(self.position))
+    }
+
+    /// Check `len` bytes can be read at the current position, moving the origin of
+    /// [`Reader::position`] to it first if the input is unbounded and the position would otherwise
+    /// exceed [`Length::MAX`].
+    fn check_len(&mut self, len: Length) -> crate::Result<Length> {
+        let Some(input_len) = self.input_len else {
+            if (self.position + len).is_err() {
+                self.offset = self.stream_position();
+                self.position = Length::ZERO;
+            }
+
+            return self.position + len;
+        };
+
+        let new_position = (self.position + len)?;
+        if new_position > input_len {
+            return Err(ErrorKind::Incomplete {
+                expected_len: new_position,
+                actual_len: input_len,
+            }
+            .at(self.position));
+        }
+
+        Ok(new_position)
+    }
+
+    /// Convert an I/O error into an [`Error`] at the current position.
+    fn io_error(&self, err: io::Error) -> Error {
+        if err.kind() == io::ErrorKind::UnexpectedEof {
+            Error::incomplete(self.position)
+        } else {
+            Error::from(err).kind().at(self.position)
+        }
+    }
+}
+
+impl<'r, R: io::Read> Reader<'r> for IoReader<R> {
+    fn encoding_rules(&self) -> EncodingRules {
+        self.encoding_rules
+    }
+
+    fn input_len(&self) -> Length {
+        self.input_len.unwrap_or(Length::MAX)
+    }
+
+    fn limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    fn quirks(&self) -> DecodeQuirks {
+        self.quirks
+    }
+
+    fn record_quirk(&mut self, quirk: DecodeQuirk) {
+        self.warnings.push(DecodeWarning {
+            quirk,
+            position: self.position,
+        });
+    }
+
+    fn records_spans(&self) -> bool {
+        self.spans.is_some()
+    }
+
+    fn record_span(&mut self, span: FieldSpan) {
+        if let Some(spans) = &mut self.spans {
+            spans.push(span);
+        }
+    }
+
+    fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: u32) {
+        self.depth = depth;
+    }
+
+    fn is_indefinite(&self) -> bool {
+        self.indefinite
+    }
+
+    fn set_indefinite(&mut self, indefinite: bool) {
+        self.indefinite = indefinite;
+    }
+
+    fn peek_into(&self, buf: &mut [u8]) -> crate::Result<()> {
+        if let Some(input_len) = self.input_len {
+            let new_position = (self.position + buf.len())?;
+            if new_position > input_len {
+                return Err(ErrorKind::Incomplete {
+                    expected_len: new_position,
+                    actual_len: input_len,
+                }
+                .at(self.position));
+            }
+        }
+
+        if buf.len() > LOOKAHEAD_SIZE {
+            return Err(ErrorKind::Reader.at(self.position));
+        }
+
+        let mut inner = self.inner.borrow_mut();
+        inner.fill(buf.len()).map_err(|e| self.io_error(e))?;
+        buf.copy_from_slice(&inner.buf[..buf.len()]);
+        Ok(())
+    }
+
+    fn position(&self) -> Length {
+        self.position
+    }
+
+    fn read_nested<T, F, E>(&mut self, len: Length, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let nested_input_len = self
+            .check_len(len)
+            .map_err(|_| Error::incomplete(self.input_len()))?;
+
+        let orig_input_len = self.input_len;
+        let orig_indefinite = self.indefinite;
+        self.indefinite = orig_indefinite && Some(nested_input_len) == orig_input_len;
+        self.input_len = Some(nested_input_len);
+        let ret = f(self);
+        self.input_len = orig_input_len;
+        self.indefinite = orig_indefinite;
+        ret
+    }
+
+    fn read_slice(&mut self, _len: Length) -> crate::Result<&'r [u8]> {
+        // Can't borrow from a stream
+        Err(ErrorKind::Reader.at(self.position))
+    }
+
+    fn read_into<'o>(&mut self, buf: &'o mut [u8]) -> crate::Result<&'o [u8]> {
+        let new_position = self.check_len(buf.len().try_into()?)?;
+
+        self.inner
+            .get_mut()
+            .read(buf)
+            .map_err(|e| self.io_error(e))?;
+
+        self.position = new_position;
+        Ok(buf)
+    }
+
+    fn read_vec(&mut self, len: Length) -> crate::Result<Vec<u8>> {
+        self.read_nested(len, |reader| {
+            let mut bytes = Vec::new();
+
+            while !reader.remaining_len().is_zero() {
+                let chunk_len = usize::try_from(reader.remaining_len())?.min(READ_CHUNK_SIZE);
+                let start = bytes.len();
+                bytes.resize(start + chunk_len, 0);
+                reader.read_into(&mut bytes[start..])?;
+            }
+
+            Ok(bytes)
+        })
+    }
+}
+
+/// Inner reader along with a buffer of data which has been peeked at but not yet read.
+#[derive(Debug)]
+struct Lookahead<R> {
+    /// Inner reader.
+    reader: R,
+
+    /// Lookahead buffer.
+    buf: [u8; LOOKAHEAD_SIZE],
+
+    /// Number of bytes in the lookahead buffer.
+    len: usize,
+}
+
+impl<R: io::Read> Lookahead<R> {
+    /// Ensure at least `n` bytes are present in the lookahead buffer.
+    fn fill(&mut self, n: usize) -> io::Result<()> {
+        if self.len < n {
+            self.reader.read_exact(&mut self.buf[self.len..n])?;
+            self.len = n;
+        }
+
+        Ok(())
+    }
+
+    /// Read into the given buffer, consuming data from the lookahead buffer first.
+    fn read(&mut self, out: &mut [u8]) -> io::Result<()> {
+        let n = out.len().min(self.len);
+        out[..n].copy_from_slice(&self.buf[..n]);
+        self.buf.copy_within(n..self.len, 0);
+        self.len -= n;
+        self.reader.read_exact(&mut out[n..])
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::IoReader;
+    use crate::{
+        Decode, ErrorKind, Length, Reader,
+        asn1::{Any, OctetString, OctetStringRef},
+    };
+    use hex_literal::hex;
+    use std::{io, vec::Vec};
+
+    /// `SEQUENCE OF OCTET STRING` followed by a `NULL`.
+    const EXAMPLE_MSG: &[u8] = &hex!("300A 0403010203 0403040506 0500");
+
+    /// [`io::Read`] source which returns a single byte at a time.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl io::Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((&byte, rest)), Some(out)) => {
+                    *out = byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn decode_owned() {
+        let mut reader = IoReader::new(Trickle(EXAMPLE_MSG));
+
+        let sequence_of: Vec<OctetString> = reader.decode().unwrap();
+        assert_eq!(sequence_of.len(), 2);
+        assert_eq!(sequence_of[0].as_bytes(), &[1, 2, 3]);
+        assert_eq!(sequence_of[1].as_bytes(), &[4, 5, 6]);
+
+        let null: Any = reader.decode().unwrap();
+        assert!(null.is_null());
+        assert_eq!(reader.position(), EXAMPLE_MSG.len().try_into().unwrap());
+    }
+
+    #[test]
+    fn decode_borrowed() {
+        let mut reader = IoReader::new(EXAMPLE_MSG);
+        reader.read_byte().unwrap();
+        reader.read_byte().unwrap();
+
+        let err = OctetStringRef::decode(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Reader);
+    }
+
+    #[test]
+    fn truncated() {
+        let mut reader = IoReader::new(&EXAMPLE_MSG[..6]);
+        let err = Vec::<OctetString>::decode(&mut reader).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+    }
+
+    #[test]
+    fn truncated_large_value() {
+        // OCTET STRING claiming to be 2 GiB long
+        let mut reader = IoReader::new(&hex!("04847FFFFFFF 0102")[..]);
+        let err = OctetString::decode(&mut reader).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::Incomplete { .. }));
+    }
+
+    #[test]
+    fn position_past_length_max() {
+        let mut reader = IoReader::new(EXAMPLE_MSG);
+        let start = (Length::MAX - Length::new(4)).unwrap();
+        reader.position = start;
+
+        let sequence_of: Vec<OctetString> = reader.decode().unwrap();
+        assert_eq!(sequence_of.len(), 2);
+        assert_eq!(reader.position(), Length::new(10));
+
+        let null: Any = reader.decode().unwrap();
+        assert!(null.is_null());
+        assert_eq!(
+            reader.stream_position(),
+            u64::from(u32::from(start)) + EXAMPLE_MSG.len() as u64
+        );
+    }
+}
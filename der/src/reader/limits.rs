@@ -0,0 +1,215 @@
+//! Limits on the resources consumed when decoding.
+
+use crate::{ErrorKind, Header, Length};
+use core::fmt;
+
+/// Limits which a [`Reader`][`crate::Reader`] enforces when decoding, protecting against resource
+/// exhaustion when decoding untrusted input.
+///
+/// By default no limits are enforced, apart from those implied by the size of the input. Limits
+/// are configured using struct update syntax, e.g.:
+///
+/// ```
+/// use der::{DecodeLimits, Length, SliceReader};
+///
+/// let limits = DecodeLimits {
+///     max_depth: Some(16),
+///     max_value_len: Some(Length::new(16384)),
+///     ..DecodeLimits::default()
+/// };
+///
+/// let reader = SliceReader::new(&[0x05, 0x00])?.with_limits(limits);
+/// # Ok::<(), der::Error>(())
+/// ```
+///
+/// When a limit is exceeded, decoding fails with [`ErrorKind::LimitExceeded`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodeLimits {
+    /// Maximum nesting depth of values, where a top-level value has a depth of 1.
+    pub max_depth: Option<u32>,
+
+    /// Maximum length of the input, i.e. the maximum position at which a value may end.
+    pub max_input_len: Option<Length>,
+
+    /// Maximum length of the contents of any single value.
+    pub max_value_len: Option<Length>,
+
+    /// Maximum number of elements in a `SEQUENCE OF` or `SET OF`.
+    pub max_elements: Option<usize>,
+}
+
+impl DecodeLimits {
+    /// Check that a value may be nested at the given depth.
+    pub(crate) fn check_depth(&self, depth: u32) -> Result<(), ErrorKind> {
+        check(self.max_depth, depth, DecodeLimit::Depth)
+    }
+
+    /// Check the given header, which was decoded with the reader at the given position.
+    ///
+    /// The length of values using the indefinite length form isn't known up front, so it's only
+    /// checked with [`DecodeLimits::check_value_len`] when their contents are captured as a whole.
+    pub(crate) fn check_header(&self, header: &Header, position: Length) -> Result<(), ErrorKind> {
+        if !header.is_indefinite() {
+            self.check_value_len(header.length)?;
+        }
+
+        // A length too large to represent exceeds any limit
+        let end = (position + header.length).unwrap_or(Length::MAX);
+        check(self.max_input_len, end, DecodeLimit::InputLength)
+    }
+
+    /// Check that a value may have contents of the given length.
+    pub(crate) fn check_value_len(&self, len: Length) -> Result<(), ErrorKind> {
+        check(self.max_value_len, len, DecodeLimit::ValueLength)
+    }
+
+    /// Check that a `SEQUENCE OF` or `SET OF` may contain the given number of elements.
+    pub(crate) fn check_elements(&self, count: usize) -> Result<(), ErrorKind> {
+        check(self.max_elements, count, DecodeLimit::Elements)
+    }
+}
+
+/// Check the given value doesn't exceed the given maximum, if any.
+fn check<T: PartialOrd>(max: Option<T>, value: T, limit: DecodeLimit) -> Result<(), ErrorKind> {
+    match max {
+        Some(max) if value > max => Err(ErrorKind::LimitExceeded { limit }),
+        _ => Ok(()),
+    }
+}
+
+/// Individual limits within [`DecodeLimits`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DecodeLimit {
+    /// [`DecodeLimits::max_depth`].
+    Depth,
+
+    /// [`DecodeLimits::max_input_len`].
+    InputLength,
+
+    /// [`DecodeLimits::max_value_len`].
+    ValueLength,
+
+    /// [`DecodeLimits::max_elements`].
+    Elements,
+}
+
+impl fmt::Display for DecodeLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DecodeLimit::Depth => "nesting depth",
+            DecodeLimit::InputLength => "input length",
+            DecodeLimit::ValueLength => "value length",
+            DecodeLimit::Elements => "number of elements",
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{DecodeLimit, DecodeLimits};
+    use crate::{
+        Decode, ErrorKind, Length, Reader, SliceReader,
+        asn1::{AnyRef, Null, SequenceOf},
+    };
+    use hex_literal::hex;
+
+    /// `SEQUENCE { SEQUENCE { SEQUENCE { NULL } } }`
+    const NESTED: &[u8] = &hex!("3006 3004 3002 0500");
+
+    /// `SEQUENCE OF INTEGER` containing three elements.
+    const INTEGERS: &[u8] = &hex!("3009 020101 020102 020103");
+
+    /// Decode the given message as `T` with the given limits.
+    fn decode<'a, T: Decode<'a, Error = crate::Error>>(
+        bytes: &'a [u8],
+        limits: DecodeLimits,
+    ) -> crate::Result<T> {
+        let mut reader = SliceReader::new(bytes).unwrap().with_limits(limits);
+        let value = T::decode(&mut reader)?;
+        reader.finish(value)
+    }
+
+    /// Get the limit which was exceeded from an error, if any.
+    fn exceeded<T>(result: crate::Result<T>) -> Option<DecodeLimit> {
+        match result.err()?.kind() {
+            ErrorKind::LimitExceeded { limit } => Some(limit),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn unlimited() {
+        assert!(decode::<AnyRef<'_>>(NESTED, DecodeLimits::default()).is_ok());
+        assert!(decode::<SequenceOf<u8, 3>>(INTEGERS, DecodeLimits::default()).is_ok());
+    }
+
+    #[test]
+    fn max_depth() {
+        let limits = DecodeLimits {
+            max_depth: Some(3),
+            ..Default::default()
+        };
+
+        // `AnyRef` doesn't descend into the value
+        assert!(decode::<AnyRef<'_>>(NESTED, limits).is_ok());
+
+        let mut reader = SliceReader::new(NESTED).unwrap().with_limits(limits);
+        let result = reader.sequence(|r| r.sequence(|r| r.sequence(|r| r.decode::<Null>())));
+        assert_eq!(exceeded(result), Some(DecodeLimit::Depth));
+
+        let mut reader = SliceReader::new(NESTED).unwrap().with_limits(limits);
+        let result = reader.sequence(|r| r.sequence(|r| r.decode::<AnyRef<'_>>()));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn max_input_len() {
+        let limits = DecodeLimits {
+            max_input_len: Some(Length::new(7)),
+            ..Default::default()
+        };
+        assert_eq!(
+            exceeded(decode::<AnyRef<'_>>(NESTED, limits)),
+            Some(DecodeLimit::InputLength)
+        );
+
+        let limits = DecodeLimits {
+            max_input_len: Some(Length::new(8)),
+            ..Default::default()
+        };
+        assert!(decode::<AnyRef<'_>>(NESTED, limits).is_ok());
+    }
+
+    #[test]
+    fn max_value_len() {
+        let limits = DecodeLimits {
+            max_value_len: Some(Length::new(4)),
+            ..Default::default()
+        };
+        assert_eq!(
+            exceeded(decode::<AnyRef<'_>>(NESTED, limits)),
+            Some(DecodeLimit::ValueLength)
+        );
+        assert!(decode::<AnyRef<'_>>(&NESTED[4..], limits).is_ok());
+    }
+
+    #[test]
+    fn max_elements() {
+        let limits = DecodeLimits {
+            max_elements: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            exceeded(decode::<SequenceOf<u8, 3>>(INTEGERS, limits)),
+            Some(DecodeLimit::Elements)
+        );
+
+        let limits = DecodeLimits {
+            max_elements: Some(3),
+            ..Default::default()
+        };
+        assert!(decode::<SequenceOf<u8, 3>>(INTEGERS, limits).is_ok());
+    }
+}
@@ -1,7 +1,11 @@
 //! Streaming PEM reader.
 
 use super::Reader;
-use crate::{EncodingRules, Error, ErrorKind, Length};
+use crate::{
+    DecodeLimits, DecodeQuirk, DecodeQuirks, DecodeWarning, EncodingRules, Error, ErrorKind,
+    Length, length::indefinite,
+};
+use alloc::vec::Vec;
 use pem_rfc7468::Decoder;
 
 /// `Reader` type which decodes PEM on-the-fly.
@@ -14,6 +18,21 @@ pub struct PemReader<'i> {
     /// Encoding rules to apply when decoding the input.
     encoding_rules: EncodingRules,
 
+    /// Limits to enforce when decoding the input.
+    limits: DecodeLimits,
+
+    /// Quirks to tolerate when decoding the input.
+    quirks: DecodeQuirks,
+
+    /// Quirks which the input relied on.
+    warnings: Vec<DecodeWarning>,
+
+    /// Nesting depth of the value currently being read.
+    depth: u32,
+
+    /// Is the value currently being read encoded using the indefinite length form?
+    indefinite: bool,
+
     /// Input length (in bytes after Base64 decoding).
     input_len: Length,
 
@@ -33,11 +52,33 @@ impl<'i> PemReader<'i> {
         Ok(Self {
             decoder,
             encoding_rules: EncodingRules::default(),
+            limits: DecodeLimits::default(),
+            quirks: DecodeQuirks::NONE,
+            warnings: Vec::new(),
+            depth: 0,
+            indefinite: false,
             input_len,
             position: Length::ZERO,
         })
     }
 
+    /// Enforce the given [`DecodeLimits`] when decoding the input.
+    pub fn with_limits(mut self, limits: DecodeLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Tolerate the given [`DecodeQuirks`] when decoding the input.
+    pub fn with_quirks(mut self, quirks: DecodeQuirks) -> Self {
+        self.quirks = quirks;
+        self
+    }
+
+    /// Get a [`DecodeWarning`] for every time the input decoded so far relied on a quirk.
+    pub fn warnings(&self) -> &[DecodeWarning] {
+        &self.warnings
+    }
+
     /// Get the PEM label which will be used in the encapsulation boundaries
     /// for this document.
     pub fn type_label(&self) -> &'i str {
@@ -55,11 +96,48 @@ impl<'i> Reader<'i> for PemReader<'i> {
         self.input_len
     }
 
+    fn limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    fn quirks(&self) -> DecodeQuirks {
+        self.quirks
+    }
+
+    fn record_quirk(&mut self, quirk: DecodeQuirk) {
+        self.warnings.push(DecodeWarning {
+            quirk,
+            position: self.position,
+        });
+    }
+
+    fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: u32) {
+        self.depth = depth;
+    }
+
+    fn is_indefinite(&self) -> bool {
+        self.indefinite
+    }
+
+    fn set_indefinite(&mut self, indefinite: bool) {
+        self.indefinite = indefinite;
+    }
+
     fn peek_into(&self, buf: &mut [u8]) -> crate::Result<()> {
         self.clone().read_into(buf)?;
         Ok(())
     }
 
+    fn peek_indefinite_length(&self, offset: Length) -> crate::Result<Length> {
+        let mut reader = self.clone();
+        reader.drain(offset)?;
+        indefinite::decode_indefinite_length(&mut reader)
+    }
+
     fn position(&self) -> Length {
         self.position
     }
@@ -75,9 +153,12 @@ impl<'i> Reader<'i> for PemReader<'i> {
         }
 
         let orig_input_len = self.input_len;
+        let orig_indefinite = self.indefinite;
+        self.indefinite = orig_indefinite && nested_input_len == orig_input_len;
         self.input_len = nested_input_len;
         let ret = f(self);
         self.input_len = orig_input_len;
+        self.indefinite = orig_indefinite;
         ret
     }
 
@@ -0,0 +1,332 @@
+//! Quirks which may be tolerated when decoding.
+
+use crate::Length;
+use core::fmt;
+
+/// Set of [`DecodeQuirk`]s, i.e. deviations from DER which are commonly found in real-world
+/// encodings.
+///
+/// By default a [`Reader`][`crate::Reader`] tolerates none of them. Readers which support quirks
+/// can be configured to tolerate some or all of them, in which case they record a
+/// [`DecodeWarning`] every time the input relies on one, so that the caller can report them:
+///
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # {
+/// use der::{Decode, DecodeQuirk, DecodeQuirks, DecodeWarning, Length, SliceReader};
+///
+/// // BOOLEAN encoded as 0x01 rather than 0xFF
+/// let mut reader = SliceReader::new(&[0x01, 0x01, 0x01])?.with_quirks(DecodeQuirks::ALL);
+/// assert!(bool::decode(&mut reader)?);
+///
+/// assert_eq!(
+///     reader.warnings(),
+///     [DecodeWarning {
+///         quirk: DecodeQuirk::NonCanonicalBoolean,
+///         position: Length::new(3),
+///     }]
+/// );
+/// # }
+/// # Ok::<(), der::Error>(())
+/// ```
+///
+/// Recording warnings requires the `alloc` feature. Without it, quirks are still tolerated but
+/// aren't reported.
+///
+/// Tolerating a quirk doesn't change how the decoded value is re-encoded, which is always DER:
+/// signatures over the original encoding need to be verified using the original bytes.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DecodeQuirks(u8);
+
+impl DecodeQuirks {
+    /// No quirks.
+    pub const NONE: Self = Self(0);
+
+    /// All quirks.
+    pub const ALL: Self = Self((1 << DecodeQuirk::VARIANTS.len()) - 1);
+
+    /// Add the given quirk to this set.
+    pub const fn with(self, quirk: DecodeQuirk) -> Self {
+        Self(self.0 | quirk.bit())
+    }
+
+    /// Does this set contain the given quirk?
+    pub const fn contains(self, quirk: DecodeQuirk) -> bool {
+        self.0 & quirk.bit() != 0
+    }
+
+    /// Is this set empty?
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterate over the quirks in this set.
+    pub fn iter(self) -> impl Iterator<Item = DecodeQuirk> {
+        DecodeQuirk::VARIANTS
+            .into_iter()
+            .filter(move |&quirk| self.contains(quirk))
+    }
+}
+
+impl From<DecodeQuirk> for DecodeQuirks {
+    fn from(quirk: DecodeQuirk) -> DecodeQuirks {
+        DecodeQuirks::NONE.with(quirk)
+    }
+}
+
+/// Individual quirks within [`DecodeQuirks`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum DecodeQuirk {
+    /// `INTEGER` encoded with redundant leading `0x00` or `0xFF` octets.
+    NonMinimalInteger,
+
+    /// `BOOLEAN` `TRUE` encoded as a value other than `0xFF`.
+    NonCanonicalBoolean,
+
+    /// `BIT STRING` with named bits, i.e. a `flagset::FlagSet`, encoded with trailing zero
+    /// bits.
+    ///
+    /// Like [`DecodeQuirk::UnsortedSetOf`], this one is always tolerated for compatibility, but
+    /// it's reported nonetheless.
+    BitStringTrailingZeros,
+
+    /// `UTCTime` without seconds, i.e. `YYMMDDHHMMZ`, which is decoded as zero seconds.
+    UtcTimeWithoutSeconds,
+
+    /// `SET OF` whose elements aren't sorted, which are sorted when decoded.
+    ///
+    /// Unlike the other quirks, this one is always tolerated since it's so common in the wild,
+    /// but it's reported nonetheless.
+    UnsortedSetOf,
+
+    /// Negative X.509 certificate serial number, which RFC 5280 forbids.
+    ///
+    /// Like [`DecodeQuirk::UnsortedSetOf`], this one is always tolerated since non-conforming CAs
+    /// have issued such certificates, but it's reported nonetheless. It's recorded by the
+    /// `SerialNumber` type of the `x509-cert` crate.
+    NegativeSerial,
+}
+
+impl DecodeQuirk {
+    /// All quirks, in the order they're iterated over by [`DecodeQuirks::iter`].
+    const VARIANTS: [Self; 6] = [
+        Self::NonMinimalInteger,
+        Self::NonCanonicalBoolean,
+        Self::BitStringTrailingZeros,
+        Self::UtcTimeWithoutSeconds,
+        Self::UnsortedSetOf,
+        Self::NegativeSerial,
+    ];
+
+    /// Bit representing this quirk within [`DecodeQuirks`].
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl fmt::Display for DecodeQuirk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DecodeQuirk::NonMinimalInteger => "non-minimal INTEGER",
+            DecodeQuirk::NonCanonicalBoolean => "non-canonical BOOLEAN",
+            DecodeQuirk::BitStringTrailingZeros => "trailing zero bits in named BIT STRING",
+            DecodeQuirk::UtcTimeWithoutSeconds => "UTCTime without seconds",
+            DecodeQuirk::UnsortedSetOf => "unsorted SET OF",
+            DecodeQuirk::NegativeSerial => "negative serial number",
+        })
+    }
+}
+
+/// Occurrence of a [`DecodeQuirk`] which the input relied on, as recorded by a
+/// [`Reader`][`crate::Reader`] which tolerates quirks.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DecodeWarning {
+    /// Quirk which the input relied on.
+    pub quirk: DecodeQuirk,
+
+    /// Position of the reader when the quirk was encountered, which is within or just past the
+    /// contents of the offending value.
+    pub position: Length,
+}
+
+impl fmt::Display for DecodeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at DER byte {}", self.quirk, self.position)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{DecodeQuirk, DecodeQuirks, DecodeWarning};
+    use crate::{
+        Decode, ErrorKind, Length, Reader, SliceReader, Tag,
+        asn1::{IntRef, SetOf, UintRef, UtcTime},
+    };
+    use alloc::vec::Vec;
+    use hex_literal::hex;
+
+    /// Decode the given message as `T` tolerating the given quirks, returning the quirks the
+    /// message relied on along with the value.
+    fn decode<'a, T: Decode<'a, Error = crate::Error>>(
+        bytes: &'a [u8],
+        quirks: DecodeQuirks,
+    ) -> crate::Result<(T, Vec<DecodeQuirk>)> {
+        let mut reader = SliceReader::new(bytes).unwrap().with_quirks(quirks);
+        let value = T::decode(&mut reader)?;
+        let warnings = reader.warnings().iter().map(|w| w.quirk).collect();
+        reader.finish((value, warnings))
+    }
+
+    #[test]
+    fn set_operations() {
+        let quirks = DecodeQuirks::NONE.with(DecodeQuirk::UnsortedSetOf);
+        assert!(quirks.contains(DecodeQuirk::UnsortedSetOf));
+        assert!(!quirks.contains(DecodeQuirk::NonMinimalInteger));
+        assert!(DecodeQuirks::NONE.is_empty());
+        assert_eq!(DecodeQuirks::ALL.iter().count(), 6);
+    }
+
+    #[test]
+    fn strict_by_default() {
+        let err = decode::<bool>(&hex!("010101"), DecodeQuirks::NONE).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Noncanonical { tag: Tag::Boolean });
+
+        // Tolerating other quirks doesn't help
+        let quirks = DecodeQuirks::from(DecodeQuirk::NonMinimalInteger);
+        assert!(decode::<bool>(&hex!("010101"), quirks).is_err());
+    }
+
+    #[test]
+    fn non_canonical_boolean() {
+        let (value, warnings) = decode::<bool>(&hex!("010101"), DecodeQuirks::ALL).unwrap();
+        assert!(value);
+        assert_eq!(warnings, [DecodeQuirk::NonCanonicalBoolean]);
+
+        // Canonical encodings don't produce warnings
+        let (_, warnings) = decode::<bool>(&hex!("0101FF"), DecodeQuirks::ALL).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn non_minimal_integer() {
+        let quirks = DecodeQuirks::from(DecodeQuirk::NonMinimalInteger);
+        let expected_warnings = [DecodeQuirk::NonMinimalInteger];
+
+        for (der, expected) in [
+            (&hex!("02020005")[..], 5i64),
+            (&hex!("0203FFFF80"), -128),
+            (&hex!("020A00000000000000000001"), 1),
+        ] {
+            assert!(decode::<i64>(der, DecodeQuirks::NONE).is_err());
+            assert_eq!(
+                decode::<i64>(der, quirks).unwrap(),
+                (expected, expected_warnings.into())
+            );
+        }
+
+        assert_eq!(
+            decode::<u8>(&hex!("0203000080"), quirks).unwrap(),
+            (128, expected_warnings.into())
+        );
+
+        // Leading octets which aren't redundant are still rejected
+        assert!(decode::<u8>(&hex!("0203000100"), quirks).is_err());
+        assert!(decode::<i8>(&hex!("02020080"), quirks).is_err());
+
+        let (value, warnings) = decode::<UintRef<'_>>(&hex!("0204000000FF"), quirks).unwrap();
+        assert_eq!(value.as_bytes(), &[0xFF]);
+        assert_eq!(warnings, expected_warnings);
+
+        let (value, warnings) = decode::<IntRef<'_>>(&hex!("0203FFFF80"), quirks).unwrap();
+        assert_eq!(value.as_bytes(), &[0x80]);
+        assert_eq!(warnings, expected_warnings);
+    }
+
+    #[test]
+    fn utc_time_without_seconds() {
+        let der = hex!("170B 393130353036323334355A");
+        assert!(decode::<UtcTime>(&der, DecodeQuirks::NONE).is_err());
+
+        let (time, warnings) = decode::<UtcTime>(&der, DecodeQuirks::ALL).unwrap();
+        assert_eq!(time.to_date_time().minutes(), 45);
+        assert_eq!(time.to_date_time().seconds(), 0);
+        assert_eq!(warnings, [DecodeQuirk::UtcTimeWithoutSeconds]);
+    }
+
+    #[cfg(feature = "flagset")]
+    #[test]
+    fn bit_string_trailing_zeros() {
+        flagset::flags! {
+            enum Flags: u8 {
+                A,
+                B,
+            }
+        }
+
+        // Bits `10`, i.e. with a trailing zero bit: always tolerated, but reported
+        let der = hex!("03020680");
+        let (flags, warnings) =
+            decode::<flagset::FlagSet<Flags>>(&der, DecodeQuirks::NONE).unwrap();
+        assert_eq!(flags, flagset::FlagSet::from(Flags::A));
+        assert_eq!(warnings, [DecodeQuirk::BitStringTrailingZeros]);
+
+        let (_, warnings) =
+            decode::<flagset::FlagSet<Flags>>(&hex!("03020780"), DecodeQuirks::NONE).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn unsorted_set_of() {
+        let der = hex!("3106 020102 020101");
+
+        // Always tolerated, but reported
+        let (set, warnings) = decode::<SetOf<u8, 2>>(&der, DecodeQuirks::NONE).unwrap();
+        assert_eq!((set.get(0), set.get(1)), (Some(&1), Some(&2)));
+        assert_eq!(warnings, [DecodeQuirk::UnsortedSetOf]);
+
+        let (_, warnings) =
+            decode::<SetOf<u8, 2>>(&hex!("3106 020101 020102"), DecodeQuirks::NONE).unwrap();
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn warnings_from_nested_values() {
+        // SEQUENCE { BOOLEAN 0x01 }
+        let mut reader = SliceReader::new(&hex!("3003 010101"))
+            .unwrap()
+            .with_quirks(DecodeQuirks::ALL);
+
+        assert!(reader.sequence(|r| r.decode::<bool>()).unwrap());
+        assert_eq!(
+            reader.warnings(),
+            [DecodeWarning {
+                quirk: DecodeQuirk::NonCanonicalBoolean,
+                position: Length::new(5),
+            }]
+        );
+    }
+
+    #[test]
+    fn warning_per_occurrence() {
+        // SEQUENCE { BOOLEAN 0x01, BOOLEAN 0xFF, BOOLEAN 0x02 }
+        let mut reader = SliceReader::new(&hex!("3009 010101 0101FF 010102"))
+            .unwrap()
+            .with_quirks(DecodeQuirks::ALL);
+
+        let values = reader
+            .sequence(|r| Ok::<_, crate::Error>([r.decode::<bool>()?, r.decode()?, r.decode()?]))
+            .unwrap();
+        assert_eq!(values, [true; 3]);
+
+        let positions = reader.warnings().iter().map(|w| w.position);
+        assert_eq!(positions.collect::<Vec<_>>(), [5u8.into(), 11u8.into()]);
+        assert!(
+            reader
+                .warnings()
+                .iter()
+                .all(|w| w.quirk == DecodeQuirk::NonCanonicalBoolean)
+        );
+    }
+}
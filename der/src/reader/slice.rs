@@ -1,6 +1,15 @@
 //! Slice reader.
 
-use crate::{BytesRef, Decode, EncodingRules, Error, ErrorKind, Length, Reader, Tag};
+use crate::{
+    BytesRef, Decode, DecodeLimits, DecodeQuirks, EncodingRules, Error, ErrorKind, Length, Reader,
+    Tag, length::indefinite,
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{DecodeQuirk, DecodeWarning, FieldSpan},
+    alloc::vec::Vec,
+};
 
 /// [`Reader`] which consumes an input byte slice.
 #[derive(Clone, Debug)]
@@ -11,6 +20,26 @@ pub struct SliceReader<'a> {
     /// Encoding rules to apply when decoding the input.
     encoding_rules: EncodingRules,
 
+    /// Limits to enforce when decoding the input.
+    limits: DecodeLimits,
+
+    /// Quirks to tolerate when decoding the input.
+    quirks: DecodeQuirks,
+
+    /// Quirks which the input relied on.
+    #[cfg(feature = "alloc")]
+    warnings: Vec<DecodeWarning>,
+
+    /// Spans of the decoded fields, if they're being recorded.
+    #[cfg(feature = "alloc")]
+    spans: Option<Vec<FieldSpan>>,
+
+    /// Nesting depth of the value currently being read.
+    depth: u32,
+
+    /// Is the value currently being read encoded using the indefinite length form?
+    indefinite: bool,
+
     /// Did the decoding operation fail?
     failed: bool,
 
@@ -25,18 +54,87 @@ impl<'a> SliceReader<'a> {
     }
 
     /// Create a new slice reader with the given encoding rules.
+    ///
+    /// Returns an error for [`EncodingRules::Cer`], which is only supported for encoding.
     pub fn new_with_encoding_rules(
         bytes: &'a [u8],
         encoding_rules: EncodingRules,
     ) -> Result<Self, Error> {
+        if encoding_rules.is_cer() {
+            return Err(ErrorKind::Reader.into());
+        }
+
         Ok(Self {
             bytes: BytesRef::new(bytes)?,
             encoding_rules,
+            limits: DecodeLimits::default(),
+            quirks: DecodeQuirks::NONE,
+            #[cfg(feature = "alloc")]
+            warnings: Vec::new(),
+            #[cfg(feature = "alloc")]
+            spans: None,
+            depth: 0,
+            indefinite: false,
             failed: false,
             position: Length::ZERO,
         })
     }
 
+    /// Enforce the given [`DecodeLimits`] when decoding the input.
+    pub fn with_limits(mut self, limits: DecodeLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Tolerate the given [`DecodeQuirks`] when decoding the input.
+    pub fn with_quirks(mut self, quirks: DecodeQuirks) -> Self {
+        self.quirks = quirks;
+        self
+    }
+
+    /// Get a [`DecodeWarning`] for every time the input decoded so far relied on a quirk.
+    #[cfg(feature = "alloc")]
+    pub fn warnings(&self) -> &[DecodeWarning] {
+        &self.warnings
+    }
+
+    /// Record the [`FieldSpan`] of every field of a type with derived `Sequence` or `Set` impls
+    /// which is decoded from the input.
+    ///
+    /// Values decoded from a separate reader, e.g. the contents of an `OCTET STRING` decoded with
+    /// [`OctetStringRef::decode_into`][`crate::asn1::OctetStringRef::decode_into`], aren't
+    /// recorded.
+    #[cfg(feature = "alloc")]
+    pub fn with_spans(mut self) -> Self {
+        self.spans = Some(Vec::new());
+        self
+    }
+
+    /// Get the spans of the fields decoded so far, if they're being recorded.
+    #[cfg(feature = "alloc")]
+    pub fn spans(&self) -> &[FieldSpan] {
+        self.spans.as_deref().unwrap_or_default()
+    }
+
+    /// Copy of this reader's state which doesn't record spans or warnings, for peeking and reading
+    /// nested values without copying the ones recorded so far.
+    pub(crate) fn fork(&self) -> Self {
+        Self {
+            bytes: self.bytes,
+            encoding_rules: self.encoding_rules,
+            limits: self.limits,
+            quirks: self.quirks,
+            #[cfg(feature = "alloc")]
+            warnings: Vec::new(),
+            #[cfg(feature = "alloc")]
+            spans: None,
+            depth: self.depth,
+            indefinite: self.indefinite,
+            failed: self.failed,
+            position: self.position,
+        }
+    }
+
     /// Return an error with the given [`ErrorKind`], annotating it with
     /// context about where the error occurred.
     pub fn error(&mut self, kind: ErrorKind) -> Error {
@@ -77,8 +175,63 @@ impl<'a> Reader<'a> for SliceReader<'a> {
         self.bytes.len()
     }
 
+    fn limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
+    fn quirks(&self) -> DecodeQuirks {
+        self.quirks
+    }
+
+    #[cfg(feature = "alloc")]
+    fn record_quirk(&mut self, quirk: DecodeQuirk) {
+        self.warnings.push(DecodeWarning {
+            quirk,
+            position: self.position,
+        });
+    }
+
+    #[cfg(feature = "alloc")]
+    fn records_spans(&self) -> bool {
+        self.spans.is_some()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn record_span(&mut self, span: FieldSpan) {
+        if let Some(spans) = &mut self.spans {
+            spans.push(span);
+        }
+    }
+
+    fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: u32) {
+        self.depth = depth;
+    }
+
+    fn is_indefinite(&self) -> bool {
+        self.indefinite
+    }
+
+    fn set_indefinite(&mut self, indefinite: bool) {
+        self.indefinite = indefinite;
+    }
+
     fn peek_into(&self, buf: &mut [u8]) -> crate::Result<()> {
-        self.clone().read_into(buf)?;
+        self.fork().read_into(buf)?;
+        Ok(())
+    }
+
+    fn peek_indefinite_length(&self, offset: Length) -> Result<Length, Error> {
+        let mut reader = self.fork();
+        reader.drain(offset)?;
+        indefinite::decode_indefinite_length(&mut reader)
+    }
+
+    fn drain(&mut self, amount: Length) -> Result<(), Error> {
+        self.read_slice(amount)?;
         Ok(())
     }
 
@@ -93,13 +246,36 @@ impl<'a> Reader<'a> for SliceReader<'a> {
         E: From<Error>,
     {
         let prefix_len = (self.position + len)?;
-        let mut nested_reader = self.clone();
+        if prefix_len > self.input_len() {
+            self.failed = true;
+            return Err(ErrorKind::Incomplete {
+                expected_len: prefix_len,
+                actual_len: self.input_len(),
+            }
+            .at(self.input_len())
+            .into());
+        }
+
+        let mut nested_reader = self.fork();
         nested_reader.bytes = self.bytes.prefix(prefix_len)?;
+        nested_reader.indefinite = self.indefinite && prefix_len == self.input_len();
+
+        #[cfg(feature = "alloc")]
+        {
+            nested_reader.warnings = core::mem::take(&mut self.warnings);
+            nested_reader.spans = self.spans.take();
+        }
 
         let ret = f(&mut nested_reader);
         self.position = nested_reader.position;
         self.failed = nested_reader.failed;
 
+        #[cfg(feature = "alloc")]
+        {
+            self.warnings = core::mem::take(&mut nested_reader.warnings);
+            self.spans = nested_reader.spans.take();
+        }
+
         ret.and_then(|value| {
             nested_reader.finish(value).map_err(|e| {
                 self.failed = true;
@@ -164,12 +340,20 @@ impl<'a> Reader<'a> for SliceReader<'a> {
 #[allow(clippy::unwrap_used, clippy::panic)]
 mod tests {
     use super::SliceReader;
-    use crate::{Decode, ErrorKind, Length, Reader};
+    use crate::{Decode, EncodingRules, ErrorKind, Length, Reader};
     use hex_literal::hex;
 
     // INTEGER: 42
     const EXAMPLE_MSG: &[u8] = &hex!("02012A00");
 
+    #[test]
+    fn cer_unsupported() {
+        let err = SliceReader::new_with_encoding_rules(EXAMPLE_MSG, EncodingRules::Cer)
+            .err()
+            .unwrap();
+        assert_eq!(ErrorKind::Reader, err.kind());
+    }
+
     #[test]
     fn empty_message() {
         let mut reader = SliceReader::new(&[]).unwrap();
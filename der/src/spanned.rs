@@ -0,0 +1,266 @@
+//! Values annotated with their location in the input.
+//!
+//! There are two ways of obtaining the location of decoded values:
+//! - wrapping the type of individual fields in [`Spanned`]
+//! - having a reader record the [`FieldSpan`] of every field of a type with derived `Sequence` or
+//!   `Set` impls it decodes, e.g. with [`SliceReader::with_spans`][`crate::SliceReader::with_spans`]
+
+use crate::{
+    Choice, Decode, EncodeValue, Header, Length, Reader, Result, Tag, Tagged, ValueOrd, Writer,
+};
+use core::cmp::Ordering;
+
+/// Location of an encoded value within the input it was decoded from.
+///
+/// Positions are offsets from the start of the input of the [`Reader`] the value was decoded
+/// with, as returned by [`Reader::position`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Span {
+    /// Position of the first octet of the header, i.e. of the tag.
+    pub header_start: Length,
+
+    /// Position of the first octet of the contents.
+    pub value_start: Length,
+
+    /// Position just past the last octet of the value, including the end-of-contents octets if
+    /// the value uses the BER indefinite length form.
+    pub end: Length,
+}
+
+impl Span {
+    /// Start recording the span of the field at the current position of the reader, if it
+    /// records spans.
+    ///
+    /// Used by the custom derive along with [`FieldSpan::record`].
+    #[doc(hidden)]
+    pub fn start<'a, R: Reader<'a>>(reader: &R) -> Option<Self> {
+        if !reader.records_spans() {
+            return None;
+        }
+
+        // Absent `OPTIONAL` fields may be at the end of the input
+        let header_start = reader.position();
+        let (_, header_len) = Header::peek_with_len(reader).ok()?;
+
+        Some(Self {
+            header_start,
+            value_start: (header_start + header_len).ok()?,
+            end: header_start,
+        })
+    }
+}
+
+/// [`Span`] of a field of a type with derived `Sequence` or `Set` impls, as recorded by readers
+/// configured to do so, e.g. with [`SliceReader::with_spans`][`crate::SliceReader::with_spans`].
+///
+/// Fields are recorded once they've been decoded, so the fields of a nested value are recorded
+/// before the field containing it. Absent `OPTIONAL` and `DEFAULT` fields aren't recorded.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FieldSpan {
+    /// Name of the type the field belongs to.
+    pub type_name: &'static str,
+
+    /// Name of the field.
+    pub field_name: &'static str,
+
+    /// Nesting depth of the value containing the field, where `1` is the outermost value.
+    pub depth: u32,
+
+    /// Location of the field in the input.
+    pub span: Span,
+}
+
+impl FieldSpan {
+    /// Finish recording the span of a field started with [`Span::start`], once it's been
+    /// decoded.
+    #[doc(hidden)]
+    pub fn record<'a, R: Reader<'a>>(
+        reader: &mut R,
+        span: Option<Span>,
+        type_name: &'static str,
+        field_name: &'static str,
+    ) {
+        let Some(mut span) = span else {
+            return;
+        };
+
+        span.end = reader.position();
+
+        // Absent `OPTIONAL` and `DEFAULT` fields don't consume any input
+        if span.end > span.header_start {
+            let depth = reader.depth();
+
+            reader.record_span(Self {
+                type_name,
+                field_name,
+                depth,
+                span,
+            });
+        }
+    }
+}
+
+/// Value of type `T` along with the [`Span`] of input it was decoded from.
+///
+/// This is useful for tooling which needs to relate decoded values back to the bytes which
+/// produced them, e.g. to highlight the encoding of a particular field of a certificate. Since it
+/// decodes a complete tag-length-value, it can be used as a field in `#[derive(Sequence)]`,
+/// including `OPTIONAL` and `EXPLICIT` context-specific fields, but not `IMPLICIT` ones.
+///
+/// Values which weren't decoded, e.g. ones created with [`Spanned::new`], have an empty span at
+/// position zero. Only `T` is written when this value is encoded.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Spanned<T> {
+    /// Decoded value.
+    value: T,
+
+    /// Location of the value in the input.
+    span: Span,
+}
+
+impl<T> Spanned<T> {
+    /// Wrap a value which wasn't decoded, giving it an empty span.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            span: Span {
+                header_start: Length::ZERO,
+                value_start: Length::ZERO,
+                end: Length::ZERO,
+            },
+        }
+    }
+
+    /// Borrow the decoded value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Get the location of the value in the input.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Take the decoded value.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<'a, T> Choice<'a> for Spanned<T>
+where
+    T: Choice<'a>,
+{
+    fn can_decode(tag: Tag) -> bool {
+        T::can_decode(tag)
+    }
+}
+
+impl<'a, T> Decode<'a> for Spanned<T>
+where
+    T: Decode<'a>,
+{
+    type Error = T::Error;
+
+    fn decode<R: Reader<'a>>(reader: &mut R) -> core::result::Result<Self, T::Error> {
+        let header_start = reader.position();
+        let (_, header_len) = Header::peek_with_len(reader)?;
+        let value_start = (header_start + header_len)?;
+        let value = T::decode(reader)?;
+        let end = reader.position();
+
+        Ok(Self {
+            value,
+            span: Span {
+                header_start,
+                value_start,
+                end,
+            },
+        })
+    }
+}
+
+impl<T: EncodeValue> EncodeValue for Spanned<T> {
+    fn value_len(&self) -> Result<Length> {
+        self.value.value_len()
+    }
+
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<()> {
+        self.value.encode_value(writer)
+    }
+}
+
+impl<T: Tagged> Tagged for Spanned<T> {
+    fn tag(&self) -> Tag {
+        self.value.tag()
+    }
+}
+
+impl<T: ValueOrd> ValueOrd for Spanned<T> {
+    fn value_cmp(&self, other: &Self) -> Result<Ordering> {
+        self.value.value_cmp(&other.value)
+    }
+}
+
+impl<T> From<T> for Spanned<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Span, Spanned};
+    use crate::{
+        Decode, Encode, Error, Length, Reader, SliceReader,
+        asn1::{OctetStringRef, SequenceOf},
+    };
+    use hex_literal::hex;
+
+    fn span(header_start: u16, value_start: u16, end: u16) -> Span {
+        Span {
+            header_start: Length::new(header_start),
+            value_start: Length::new(value_start),
+            end: Length::new(end),
+        }
+    }
+
+    #[test]
+    fn nested_fields() {
+        // SEQUENCE { INTEGER 1, OCTET STRING AABB, BOOLEAN (absent) }
+        let der = hex!("3007 020101 0402AABB");
+        let mut reader = SliceReader::new(&der).unwrap();
+
+        let (int, octets, boolean) = reader
+            .sequence(|r| {
+                Ok::<_, Error>((
+                    r.decode::<Spanned<u8>>()?,
+                    r.decode::<Spanned<OctetStringRef<'_>>>()?,
+                    r.decode::<Option<Spanned<bool>>>()?,
+                ))
+            })
+            .unwrap();
+
+        assert_eq!(*int.value(), 1);
+        assert_eq!(int.span(), span(2, 4, 5));
+        assert_eq!(octets.value().as_bytes(), &hex!("AABB"));
+        assert_eq!(octets.span(), span(5, 7, 9));
+        assert!(boolean.is_none());
+
+        // Only the value is encoded
+        let mut buf = [0u8; 4];
+        assert_eq!(octets.encode_to_slice(&mut buf).unwrap(), &hex!("0402AABB"));
+    }
+
+    #[test]
+    fn ber_lengths() {
+        // INTEGER 1 with a non-minimal length
+        let value = Spanned::<u8>::from_ber(&hex!("02810101")).unwrap();
+        assert_eq!(value.span(), span(0, 3, 4));
+
+        // SEQUENCE (indefinite) { NULL }
+        let value = Spanned::<SequenceOf<(), 1>>::from_ber(&hex!("3080 0500 0000")).unwrap();
+        assert_eq!(value.span(), span(0, 2, 6));
+    }
+}
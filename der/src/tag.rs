@@ -7,11 +7,14 @@ mod number;
 
 pub use self::{class::Class, mode::TagMode, number::TagNumber};
 
-use crate::{Decode, DerOrd, Encode, Error, ErrorKind, Length, Reader, Result, Writer};
+use crate::{
+    Decode, DerOrd, Encode, EncodingRules, Error, ErrorKind, Length, Reader, Result, SliceReader,
+    Writer,
+};
 use core::{cmp::Ordering, fmt};
 
 /// Indicator bit for constructed form encoding (i.e. vs primitive form)
-const CONSTRUCTED_FLAG: u8 = 0b100000;
+pub(crate) const CONSTRUCTED_FLAG: u8 = 0b100000;
 
 /// Types which have a constant ASN.1 [`Tag`].
 pub trait FixedTag {
@@ -78,6 +81,9 @@ pub enum Tag {
     /// `UTF8String` tag: `12`.
     Utf8String,
 
+    /// `RELATIVE-OID` tag: `13`.
+    RelativeOid,
+
     /// `SEQUENCE` tag: `16`.
     Sequence,
 
@@ -111,9 +117,24 @@ pub enum Tag {
     /// `GeneralString` tag: `27`.
     GeneralString,
 
+    /// `UniversalString` tag: `28`.
+    UniversalString,
+
     /// `BMPString` tag: `30`.
     BmpString,
 
+    /// `DATE` tag: `31`.
+    Date,
+
+    /// `TIME-OF-DAY` tag: `32`.
+    TimeOfDay,
+
+    /// `DATE-TIME` tag: `33`.
+    DateTime,
+
+    /// `DURATION` tag: `34`.
+    Duration,
+
     /// Application tag.
     Application {
         /// Is this tag constructed? (vs primitive).
@@ -157,24 +178,34 @@ impl Tag {
     pub(crate) fn peek_optional<'a>(reader: &impl Reader<'a>) -> Result<Option<Self>> {
         let mut buf = [0u8; Self::MAX_SIZE];
 
-        if reader.peek_into(&mut buf[0..1]).is_err() {
+        // Values using the indefinite length form end at their end-of-contents octets
+        if reader.is_finished() || reader.peek_into(&mut buf[0..1]).is_err() {
             return Ok(None);
         }
 
-        if let Ok(tag) = Self::from_der(&buf[0..1]) {
+        let encoding_rules = reader.encoding_rules();
+
+        if let Ok(tag) = Self::decode_peeked(&buf[0..1], encoding_rules) {
             return Ok(Some(tag));
         }
 
         for i in 2..Self::MAX_SIZE {
             let slice = &mut buf[0..i];
             if reader.peek_into(slice).is_ok() {
-                if let Ok(tag) = Self::from_der(slice) {
+                if let Ok(tag) = Self::decode_peeked(slice, encoding_rules) {
                     return Ok(Some(tag));
                 }
             }
         }
 
-        Some(Self::from_der(&buf)).transpose()
+        Some(Self::decode_peeked(&buf, encoding_rules)).transpose()
+    }
+
+    /// Decode a [`Tag`] from bytes peeked from a reader using the given [`EncodingRules`].
+    fn decode_peeked(bytes: &[u8], encoding_rules: EncodingRules) -> Result<Self> {
+        let mut reader = SliceReader::new_with_encoding_rules(bytes, encoding_rules)?;
+        let tag = Self::decode(&mut reader)?;
+        reader.finish(tag)
     }
 
     /// Assert that this [`Tag`] matches the provided expected tag.
@@ -210,6 +241,7 @@ impl Tag {
             Tag::Real => TagNumber(9),
             Tag::Enumerated => TagNumber(10),
             Tag::Utf8String => TagNumber(12),
+            Tag::RelativeOid => TagNumber(13),
             Tag::Sequence => TagNumber(16),
             Tag::Set => TagNumber(17),
             Tag::NumericString => TagNumber(18),
@@ -221,7 +253,12 @@ impl Tag {
             Tag::GeneralizedTime => TagNumber(24),
             Tag::VisibleString => TagNumber(26),
             Tag::GeneralString => TagNumber(27),
+            Tag::UniversalString => TagNumber(28),
             Tag::BmpString => TagNumber(30),
+            Tag::Date => TagNumber(31),
+            Tag::TimeOfDay => TagNumber(32),
+            Tag::DateTime => TagNumber(33),
+            Tag::Duration => TagNumber(34),
             Tag::Application { number, .. } => number,
             Tag::ContextSpecific { number, .. } => number,
             Tag::Private { number, .. } => number,
@@ -239,6 +276,26 @@ impl Tag {
         }
     }
 
+    /// Is this the tag of a string type, i.e. `BIT STRING`, `OCTET STRING` or one of the restricted
+    /// character string types?
+    pub(crate) fn is_string(self) -> bool {
+        matches!(
+            self,
+            Tag::BitString
+                | Tag::OctetString
+                | Tag::Utf8String
+                | Tag::NumericString
+                | Tag::PrintableString
+                | Tag::TeletexString
+                | Tag::VideotexString
+                | Tag::Ia5String
+                | Tag::VisibleString
+                | Tag::GeneralString
+                | Tag::UniversalString
+                | Tag::BmpString
+        )
+    }
+
     /// Is this an application tag?
     pub fn is_application(self) -> bool {
         self.class() == Class::Application
@@ -303,6 +360,7 @@ impl<'a> Decode<'a> for Tag {
             0x09 => Tag::Real,
             0x0A => Tag::Enumerated,
             0x0C => Tag::Utf8String,
+            0x0D => Tag::RelativeOid,
             0x12 => Tag::NumericString,
             0x13 => Tag::PrintableString,
             0x14 => Tag::TeletexString,
@@ -312,9 +370,25 @@ impl<'a> Decode<'a> for Tag {
             0x18 => Tag::GeneralizedTime,
             0x1A => Tag::VisibleString,
             0x1B => Tag::GeneralString,
+            0x1C => Tag::UniversalString,
             0x1E => Tag::BmpString,
             0x30 => Tag::Sequence, // constructed
             0x31 => Tag::Set,      // constructed
+            // BER allows string types to use the constructed form (X.690 8.6.3, 8.7.3, 8.23.6)
+            0x23 if reader.encoding_rules().is_ber() => Tag::BitString,
+            0x24 if reader.encoding_rules().is_ber() => Tag::OctetString,
+            0x2C if reader.encoding_rules().is_ber() => Tag::Utf8String,
+            0x32 if reader.encoding_rules().is_ber() => Tag::NumericString,
+            0x33 if reader.encoding_rules().is_ber() => Tag::PrintableString,
+            0x34 if reader.encoding_rules().is_ber() => Tag::TeletexString,
+            0x35 if reader.encoding_rules().is_ber() => Tag::VideotexString,
+            0x36 if reader.encoding_rules().is_ber() => Tag::Ia5String,
+            0x37 if reader.encoding_rules().is_ber() => Tag::UtcTime,
+            0x38 if reader.encoding_rules().is_ber() => Tag::GeneralizedTime,
+            0x3A if reader.encoding_rules().is_ber() => Tag::VisibleString,
+            0x3B if reader.encoding_rules().is_ber() => Tag::GeneralString,
+            0x3C if reader.encoding_rules().is_ber() => Tag::UniversalString,
+            0x3E if reader.encoding_rules().is_ber() => Tag::BmpString,
             0x40..=0x7F => {
                 let (constructed, number) = parse_parts(first_byte, reader)?;
 
@@ -339,8 +413,15 @@ impl<'a> Decode<'a> for Tag {
                     number,
                 }
             }
-            // universal tag in long form
-            0x1F => return Err(ErrorKind::TagNumberInvalid.into()),
+            // universal tag in long form, used by the X.680 time types, whose numbers all fit
+            // in a single subsequent octet
+            0x1F => match reader.read_byte()? {
+                31 => Tag::Date,
+                32 => Tag::TimeOfDay,
+                33 => Tag::DateTime,
+                34 => Tag::Duration,
+                _ => return Err(ErrorKind::TagNumberInvalid.into()),
+            },
             byte => return Err(ErrorKind::TagUnknown { byte }.into()),
         };
 
@@ -399,7 +480,20 @@ impl Encode for Tag {
     }
 
     fn encode(&self, writer: &mut impl Writer) -> Result<()> {
-        let mut first_byte = (self.class() as u8) | (u8::from(self.is_constructed()) << 5);
+        self.encode_identifier(self.is_constructed(), writer)
+    }
+}
+
+impl Tag {
+    /// Encode the identifier octets for this tag, using the given primitive/constructed form.
+    ///
+    /// This allows encoding the constructed form of universal string types as permitted by BER.
+    pub(crate) fn encode_identifier(
+        &self,
+        constructed: bool,
+        writer: &mut impl Writer,
+    ) -> Result<()> {
+        let mut first_byte = (self.class() as u8) | (u8::from(constructed) << 5);
 
         let number = self.number().value();
 
@@ -451,6 +545,7 @@ impl fmt::Display for Tag {
             Tag::Real => f.write_str("REAL"),
             Tag::Enumerated => f.write_str("ENUMERATED"),
             Tag::Utf8String => f.write_str("UTF8String"),
+            Tag::RelativeOid => f.write_str("RELATIVE-OID"),
             Tag::Set => f.write_str("SET"),
             Tag::NumericString => f.write_str("NumericString"),
             Tag::PrintableString => f.write_str("PrintableString"),
@@ -461,7 +556,12 @@ impl fmt::Display for Tag {
             Tag::GeneralizedTime => f.write_str("GeneralizedTime"),
             Tag::VisibleString => f.write_str("VisibleString"),
             Tag::GeneralString => f.write_str("GeneralString"),
+            Tag::UniversalString => f.write_str("UniversalString"),
             Tag::BmpString => f.write_str("BMPString"),
+            Tag::Date => f.write_str("DATE"),
+            Tag::TimeOfDay => f.write_str("TIME-OF-DAY"),
+            Tag::DateTime => f.write_str("DATE-TIME"),
+            Tag::Duration => f.write_str("DURATION"),
             Tag::Sequence => f.write_str("SEQUENCE"),
             Tag::Application {
                 constructed,
@@ -505,7 +605,7 @@ mod tests {
     use hex_literal::hex;
 
     use super::{Class, Tag, TagNumber};
-    use crate::{Decode, ErrorKind, Length, Reader, SliceReader};
+    use crate::{Decode, Encode, ErrorKind, Length, Reader, SliceReader};
 
     #[test]
     fn tag_class() {
@@ -518,6 +618,7 @@ mod tests {
         assert_eq!(Tag::Real.class(), Class::Universal);
         assert_eq!(Tag::Enumerated.class(), Class::Universal);
         assert_eq!(Tag::Utf8String.class(), Class::Universal);
+        assert_eq!(Tag::RelativeOid.class(), Class::Universal);
         assert_eq!(Tag::Set.class(), Class::Universal);
         assert_eq!(Tag::NumericString.class(), Class::Universal);
         assert_eq!(Tag::PrintableString.class(), Class::Universal);
@@ -526,6 +627,10 @@ mod tests {
         assert_eq!(Tag::Ia5String.class(), Class::Universal);
         assert_eq!(Tag::UtcTime.class(), Class::Universal);
         assert_eq!(Tag::GeneralizedTime.class(), Class::Universal);
+        assert_eq!(Tag::VisibleString.class(), Class::Universal);
+        assert_eq!(Tag::UniversalString.class(), Class::Universal);
+        assert_eq!(Tag::Date.class(), Class::Universal);
+        assert_eq!(Tag::Duration.class(), Class::Universal);
         assert_eq!(Tag::Sequence.class(), Class::Universal);
 
         for num in 0..=30 {
@@ -591,12 +696,32 @@ mod tests {
                 .expect_err("valid tag number but must be in short form")
                 .kind()
         );
+        assert_eq!(
+            Tag::Date,
+            Tag::from_der(&hex!("1F1F")).expect("universal tag 31")
+        );
+        assert_eq!(
+            Tag::Duration,
+            Tag::from_der(&hex!("1F22")).expect("universal tag 34")
+        );
+        assert_eq!(
+            ErrorKind::TagNumberInvalid,
+            Tag::from_der(&hex!("1F23"))
+                .expect_err("unsupported universal tag with long form")
+                .kind()
+        );
         assert_eq!(
             ErrorKind::TagNumberInvalid,
             Tag::from_der(&hex!("1FFF"))
                 .expect_err("universal tag with long form")
                 .kind()
         );
+        assert_eq!(
+            ErrorKind::TagNumberInvalid,
+            Tag::from_der(&hex!("1F1E"))
+                .expect_err("universal tag with long form but must be in short form")
+                .kind()
+        );
         assert_eq!(
             ErrorKind::TagNumberInvalid,
             Tag::from_der(&hex!("5F8020"))
@@ -620,6 +745,18 @@ mod tests {
         );
     }
 
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn encoding() {
+        let mut buf = [0u8; Tag::MAX_SIZE];
+        assert_eq!(Tag::RelativeOid.encode_to_slice(&mut buf).unwrap(), [0x0D]);
+        assert_eq!(Tag::DateTime.encoded_len().unwrap(), Length::new(2));
+        assert_eq!(
+            Tag::DateTime.encode_to_slice(&mut buf).unwrap(),
+            hex!("1F21")
+        );
+    }
+
     #[test]
     #[allow(clippy::unwrap_used)]
     fn peek() {
@@ -627,5 +764,9 @@ mod tests {
         assert_eq!(reader.position(), Length::ZERO);
         assert_eq!(Tag::peek(&reader).unwrap(), Tag::Integer);
         assert_eq!(reader.position(), Length::ZERO); // Position unchanged
+
+        let reader = SliceReader::new(&hex!("1F20 00")).unwrap();
+        assert_eq!(Tag::peek(&reader).unwrap(), Tag::TimeOfDay);
+        assert_eq!(reader.position(), Length::ZERO);
     }
 }
@@ -21,7 +21,7 @@ pub struct TagNumber(pub u32);
 
 impl TagNumber {
     /// Mask value used to obtain the tag number from a tag octet.
-    pub(super) const MASK: u8 = 0b11111;
+    pub(crate) const MASK: u8 = 0b11111;
 
     /// Create a new tag number.
     #[deprecated(
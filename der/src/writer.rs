@@ -1,10 +1,14 @@
 //! Writer trait.
 
+pub(crate) mod array;
+pub(crate) mod cer;
+#[cfg(feature = "digest")]
+pub(crate) mod digest;
 #[cfg(feature = "pem")]
 pub(crate) mod pem;
 pub(crate) mod slice;
 
-use crate::Result;
+use crate::{EncodingRules, Result};
 
 #[cfg(feature = "std")]
 use std::io;
@@ -18,6 +22,13 @@ pub trait Writer {
     fn write_byte(&mut self, byte: u8) -> Result<()> {
         self.write(&[byte])
     }
+
+    /// Get the [`EncodingRules`] which values written to this writer should be encoded with.
+    ///
+    /// Defaults to [`EncodingRules::Der`].
+    fn encoding_rules(&self) -> EncodingRules {
+        EncodingRules::Der
+    }
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,64 @@
+//! Array writer.
+
+use crate::{ErrorKind, Result, Writer};
+
+/// [`Writer`] which encodes DER into an array whose length is that of the encoding, used by
+/// [`Encode::encode_to_array`][`crate::Encode::encode_to_array`].
+///
+/// Unlike [`SliceWriter`][`crate::SliceWriter`], it doesn't compute its position as a
+/// [`Length`][`crate::Length`] nor track failures, as the length of the encoding is known at
+/// compile time.
+pub(crate) struct ArrayWriter<const N: usize> {
+    /// Buffer into which DER-encoded message is written
+    bytes: [u8; N],
+
+    /// Total number of bytes written to buffer so far
+    position: usize,
+}
+
+impl<const N: usize> ArrayWriter<N> {
+    /// Create a new writer into a zeroed array.
+    pub(crate) fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            position: 0,
+        }
+    }
+
+    /// Finish encoding, returning the array if it has been completely written.
+    pub(crate) fn finish(self) -> Result<[u8; N]> {
+        if self.position == N {
+            Ok(self.bytes)
+        } else {
+            Err(ErrorKind::Incomplete {
+                expected_len: N.try_into()?,
+                actual_len: self.position.try_into()?,
+            }
+            .into())
+        }
+    }
+}
+
+impl<const N: usize> Writer for ArrayWriter<N> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        let end = self.position.saturating_add(slice.len());
+
+        self.bytes
+            .get_mut(self.position..end)
+            .ok_or(ErrorKind::Overlength)?
+            .copy_from_slice(slice);
+
+        self.position = end;
+        Ok(())
+    }
+
+    fn write_byte(&mut self, byte: u8) -> Result<()> {
+        *self
+            .bytes
+            .get_mut(self.position)
+            .ok_or(ErrorKind::Overlength)? = byte;
+
+        self.position += 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,354 @@
+//! Canonical Encoding Rules (CER) writer.
+
+use crate::{
+    Encode, EncodeValue, EncodingRules, Header, Length, Result, Tag, Tagged, Writer,
+    length::INDEFINITE_LENGTH_OCTET,
+};
+
+/// End-of-contents octets which terminate indefinite-length encodings.
+const EOC_OCTETS: [u8; 2] = [0, 0];
+
+/// Maximum number of contents octets in a primitive string encoding (X.690 Section 9.2).
+const MAX_SEGMENT_LEN: u16 = 1000;
+
+/// [`Writer`] which emits the Canonical Encoding Rules (CER) as described in X.690 Section 9.
+///
+/// Wraps another [`Writer`], e.g. a [`SliceWriter`][`crate::SliceWriter`] or any
+/// `std::io::Write` type when the `std` feature is enabled, which receives the encoded output.
+///
+/// Under CER, constructed values are encoded using the indefinite length form, so they can be
+/// written without computing their length up front. Values of string types, i.e. `OCTET STRING`,
+/// `BIT STRING` and the restricted character string types such as `UTF8String`, with more than
+/// 1000 contents octets are split into 1000-octet segments using the constructed form.
+///
+/// Note that only the value currently being encoded is affected: pre-encoded values such as
+/// [`Any`][`crate::asn1::Any`] are written as-is.
+#[derive(Debug)]
+pub struct CerWriter<W> {
+    /// Inner writer which receives the encoded output.
+    inner: W,
+}
+
+impl<W: Writer> CerWriter<W> {
+    /// Create a new CER writer which writes to the given inner [`Writer`].
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Borrow the inner writer.
+    pub fn inner(&self) -> &W {
+        &self.inner
+    }
+
+    /// Take ownership of the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Writer> Writer for CerWriter<W> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        self.inner.write(slice)
+    }
+
+    fn write_byte(&mut self, byte: u8) -> Result<()> {
+        self.inner.write_byte(byte)
+    }
+
+    fn encoding_rules(&self) -> EncodingRules {
+        EncodingRules::Cer
+    }
+}
+
+/// Encode the given value as a complete CER tag-length-value.
+pub(crate) fn encode<T>(value: &T, writer: &mut impl Writer) -> Result<()>
+where
+    T: EncodeValue + Tagged + ?Sized,
+{
+    let tag = value.tag();
+
+    if tag.is_constructed() {
+        tag.encode_identifier(true, writer)?;
+        writer.write_byte(INDEFINITE_LENGTH_OCTET)?;
+        value.encode_value(writer)?;
+        return writer.write(&EOC_OCTETS);
+    }
+
+    let length = value.value_len()?;
+
+    // `IMPLICIT`ly tagged strings are segmented according to their underlying string type
+    let string_tag = value
+        .string_tag()
+        .or_else(|| tag.is_string().then_some(tag));
+
+    if let Some(string_tag) = string_tag.filter(|_| length > Length::from(MAX_SEGMENT_LEN)) {
+        tag.encode_identifier(true, writer)?;
+        writer.write_byte(INDEFINITE_LENGTH_OCTET)?;
+
+        let mut segments = SegmentWriter::new(writer, string_tag, length)?;
+        value.encode_value(&mut segments)?;
+        segments.finish()?;
+
+        return writer.write(&EOC_OCTETS);
+    }
+
+    Header::new(tag, length)?.encode(writer)?;
+    value.encode_value(writer)
+}
+
+/// [`Writer`] which splits the contents of a string type into primitive segments.
+struct SegmentWriter<'w, W: Writer> {
+    /// Writer which receives the segments.
+    inner: &'w mut W,
+
+    /// Tag of the string type.
+    tag: Tag,
+
+    /// Number of data octets (i.e. excluding any `BIT STRING` unused bits octet) which have yet to
+    /// be written.
+    remaining: usize,
+
+    /// Number of data octets left in the current segment.
+    segment_remaining: usize,
+
+    /// Number of unused bits in the final octet of a `BIT STRING`, which is the first octet of its
+    /// contents but needs to be written at the start of the final segment.
+    unused_bits: Option<u8>,
+}
+
+impl<'w, W: Writer> SegmentWriter<'w, W> {
+    /// Create a new segment writer for a value of the given string type and contents length.
+    fn new(inner: &'w mut W, tag: Tag, length: Length) -> Result<Self> {
+        let length = usize::try_from(length)?;
+
+        // `BIT STRING` contents begin with the unused bits octet, which is handled separately
+        let (remaining, unused_bits) = match tag {
+            Tag::BitString => (length.saturating_sub(1), None),
+            _ => (length, Some(0)),
+        };
+
+        Ok(Self {
+            inner,
+            tag,
+            remaining,
+            segment_remaining: 0,
+            unused_bits,
+        })
+    }
+
+    /// Maximum number of data octets in a segment.
+    fn max_data_len(&self) -> usize {
+        match self.tag {
+            Tag::BitString => usize::from(MAX_SEGMENT_LEN - 1),
+            _ => usize::from(MAX_SEGMENT_LEN),
+        }
+    }
+
+    /// Tag of each segment.
+    ///
+    /// Restricted character string types are encoded as if they were `IMPLICIT OCTET STRING`
+    /// (X.690 Section 8.23.5), so their segments are `OCTET STRING`s.
+    fn segment_tag(&self) -> Tag {
+        match self.tag {
+            Tag::BitString => Tag::BitString,
+            _ => Tag::OctetString,
+        }
+    }
+
+    /// Begin a new segment.
+    fn begin_segment(&mut self) -> Result<()> {
+        let data_len = self.remaining.min(self.max_data_len());
+        let is_final = data_len == self.remaining;
+
+        match self.tag {
+            Tag::BitString => {
+                Header::new(self.tag, data_len + 1)?.encode(self.inner)?;
+
+                // Only the final segment may have unused bits
+                let unused_bits = if is_final {
+                    self.unused_bits.unwrap_or(0)
+                } else {
+                    0
+                };
+                self.inner.write_byte(unused_bits)?;
+            }
+            _ => Header::new(self.segment_tag(), data_len)?.encode(self.inner)?,
+        }
+
+        self.segment_remaining = data_len;
+        Ok(())
+    }
+
+    /// Finish writing segments, checking the expected amount of data was written.
+    fn finish(self) -> Result<()> {
+        if self.remaining == 0 && self.segment_remaining == 0 {
+            Ok(())
+        } else {
+            Err(self.tag.length_error())
+        }
+    }
+}
+
+impl<W: Writer> Writer for SegmentWriter<'_, W> {
+    fn write(&mut self, mut slice: &[u8]) -> Result<()> {
+        if self.unused_bits.is_none() {
+            if let Some((&unused_bits, rest)) = slice.split_first() {
+                self.unused_bits = Some(unused_bits);
+                slice = rest;
+            }
+        }
+
+        while !slice.is_empty() {
+            if self.segment_remaining == 0 {
+                if self.remaining == 0 {
+                    return Err(self.tag.length_error());
+                }
+
+                self.begin_segment()?;
+            }
+
+            let n = slice.len().min(self.segment_remaining);
+            let (chunk, rest) = slice.split_at(n);
+            self.inner.write(chunk)?;
+
+            self.segment_remaining -= n;
+            self.remaining -= n;
+            slice = rest;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::CerWriter;
+    use crate::{
+        Encode, SliceWriter, TagMode, TagNumber,
+        asn1::{BitStringRef, ContextSpecificRef, Ia5StringRef, OctetStringRef},
+    };
+    use hex_literal::hex;
+
+    /// Encode the given value as CER into the provided buffer.
+    fn encode_cer<'a>(value: &impl Encode, buf: &'a mut [u8]) -> &'a [u8] {
+        let mut writer = CerWriter::new(SliceWriter::new(buf));
+        value.encode(&mut writer).unwrap();
+        writer.into_inner().finish().unwrap()
+    }
+
+    #[test]
+    fn constructed_indefinite_length() {
+        let mut buf = [0u8; 32];
+        assert_eq!(encode_cer(&[true], &mut buf), &hex!("3080 0101FF 0000"));
+
+        let field = ContextSpecificRef {
+            tag_number: TagNumber(0),
+            tag_mode: TagMode::Explicit,
+            value: &[1u8],
+        };
+        assert_eq!(
+            encode_cer(&field, &mut buf),
+            &hex!("A080 3080 020101 0000 0000")
+        );
+    }
+
+    #[test]
+    fn primitive_octet_string() {
+        let data = [0xAB; 1000];
+        let value = OctetStringRef::new(&data).unwrap();
+
+        let mut buf = [0u8; 1100];
+        let encoded = encode_cer(&value, &mut buf);
+        assert_eq!(&encoded[..4], &hex!("048203E8"));
+        assert_eq!(encoded.len(), 1004);
+    }
+
+    #[test]
+    fn segmented_octet_string() {
+        let data = [0xAB; 2500];
+        let value = OctetStringRef::new(&data).unwrap();
+
+        let mut buf = [0u8; 3000];
+        let encoded = encode_cer(&value, &mut buf);
+        assert_eq!(encoded.len(), 2 + 3 * 4 + 2500 + 2);
+        assert_eq!(&encoded[..6], &hex!("2480 048203E8"));
+        assert_eq!(&encoded[1006..1010], &hex!("048203E8"));
+        assert_eq!(&encoded[2010..2014], &hex!("048201F4"));
+        assert_eq!(&encoded[encoded.len() - 2..], &hex!("0000"));
+
+        #[cfg(feature = "alloc")]
+        {
+            use crate::{Decode, asn1::OctetString};
+            let decoded = OctetString::from_ber(encoded).unwrap();
+            assert_eq!(decoded.as_bytes(), &data);
+        }
+    }
+
+    #[test]
+    fn segmented_implicit_octet_string() {
+        let data = [0xAB; 1500];
+        let value = OctetStringRef::new(&data).unwrap();
+        let field = ContextSpecificRef {
+            tag_number: TagNumber(0),
+            tag_mode: TagMode::Implicit,
+            value: &value,
+        };
+
+        let mut buf = [0u8; 2000];
+        let encoded = encode_cer(&field, &mut buf);
+        assert_eq!(encoded.len(), 2 + 2 * 4 + 1500 + 2);
+        assert_eq!(&encoded[..6], &hex!("A080 048203E8"));
+        assert_eq!(&encoded[1006..1010], &hex!("048201F4"));
+        assert_eq!(&encoded[encoded.len() - 2..], &hex!("0000"));
+
+        #[cfg(feature = "alloc")]
+        {
+            use crate::{
+                EncodingRules, SliceReader,
+                asn1::{ContextSpecific, OctetString},
+            };
+            let mut reader =
+                SliceReader::new_with_encoding_rules(encoded, EncodingRules::Ber).unwrap();
+            let decoded =
+                ContextSpecific::<OctetString>::decode_implicit(&mut reader, TagNumber(0))
+                    .unwrap()
+                    .unwrap();
+            assert_eq!(decoded.value.as_bytes(), &data);
+        }
+    }
+
+    #[test]
+    fn segmented_bit_string() {
+        let mut data = [0xFF; 1500];
+        data[1499] = 0xF0;
+        let value = BitStringRef::new(4, &data).unwrap();
+
+        let mut buf = [0u8; 2000];
+        let encoded = encode_cer(&value, &mut buf);
+        assert_eq!(&encoded[..7], &hex!("2380 038203E8 00"));
+        assert_eq!(&encoded[1006..1011], &hex!("038201F6 04"));
+        assert_eq!(&encoded[encoded.len() - 2..], &hex!("0000"));
+
+        #[cfg(feature = "alloc")]
+        {
+            use crate::{Decode, asn1::BitString};
+            let decoded = BitString::from_ber(encoded).unwrap();
+            assert_eq!(decoded.unused_bits(), 4);
+            assert_eq!(decoded.raw_bytes(), &data);
+        }
+    }
+
+    #[test]
+    fn segmented_character_string() {
+        let data = [b'a'; 1500];
+        let value = Ia5StringRef::new(&data).unwrap();
+
+        let mut buf = [0u8; 2000];
+        let encoded = encode_cer(&value, &mut buf);
+        assert_eq!(encoded.len(), 2 + 2 * 4 + 1500 + 2);
+        assert_eq!(&encoded[..6], &hex!("3680 048203E8"));
+        assert_eq!(&encoded[1006..1010], &hex!("048201F4"));
+        assert_eq!(&encoded[encoded.len() - 2..], &hex!("0000"));
+    }
+}
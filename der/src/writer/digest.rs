@@ -0,0 +1,77 @@
+//! Digest writer.
+
+use crate::{Result, Writer};
+use digest::{Digest, Update};
+
+/// [`Writer`] which feeds the encoded DER into a hash function, rather than buffering it.
+///
+/// This allows computing the digest of a message, e.g. to sign or fingerprint it, without
+/// allocating memory for its encoding. See also [`Encode::digest`][`crate::Encode::digest`].
+#[derive(Clone, Debug, Default)]
+pub struct DigestWriter<D> {
+    /// Hash function into which DER-encoded message is fed.
+    digest: D,
+}
+
+impl<D: Update> DigestWriter<D> {
+    /// Create a new writer which feeds the encoded DER into the given hash function.
+    pub fn new(digest: D) -> Self {
+        Self { digest }
+    }
+
+    /// Finish encoding, returning the hash function fed with everything written so far.
+    pub fn finish(self) -> D {
+        self.digest
+    }
+}
+
+impl<D: Update> Writer for DigestWriter<D> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        self.digest.update(slice);
+        Ok(())
+    }
+}
+
+/// [`Writer`] which feeds the encoded DER into a [`Digest`], used by
+/// [`Encode::digest`][`crate::Encode::digest`].
+///
+/// A generic `D: Digest` bound doesn't imply [`Update`], so [`DigestWriter`] can't be used there.
+pub(crate) struct DigestAdapter<D>(pub(crate) D);
+
+impl<D: Digest> Writer for DigestAdapter<D> {
+    fn write(&mut self, slice: &[u8]) -> Result<()> {
+        Digest::update(&mut self.0, slice);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::DigestWriter;
+    use crate::{Encode, asn1::OctetStringRef};
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn digest_matches_encoding() {
+        let value = OctetStringRef::new(&[0xAB; 300]).unwrap();
+
+        let mut writer = DigestWriter::new(Sha256::new());
+        value.encode(&mut writer).unwrap();
+
+        let mut buf = [0u8; 304];
+        let expected = Sha256::digest(value.encode_to_slice(&mut buf).unwrap());
+        assert_eq!(writer.finish().finalize(), expected);
+        assert_eq!(value.digest::<Sha256>().unwrap(), expected);
+    }
+
+    #[test]
+    fn known_answer() {
+        // SHA-256 of `NULL`, i.e. `0500`
+        assert_eq!(
+            ().digest::<Sha256>().unwrap()[..],
+            hex!("2921a11f25dadaa24aa79a548e4e81508c2e5e56af2d833d65e2bcce448ce2f5")
+        );
+    }
+}
@@ -163,6 +163,12 @@ mod choice {
                 cs_time.time().unwrap().to_unix_duration().as_secs(),
                 673573540
             );
+
+            #[cfg(feature = "error-path")]
+            {
+                let err = ImplicitChoice::from_der(&hex!("81 01 31")).unwrap_err();
+                assert_eq!(err.path().to_string(), "ImplicitChoice.Time");
+            }
         }
 
         #[test]
@@ -180,12 +186,90 @@ mod choice {
             assert_eq!(TIME_DER, encoder.finish().unwrap());
         }
     }
+
+    /// `Choice` with a catch-all `other` variant.
+    mod other {
+        use der::{
+            Choice, Decode, Encode, Sequence, Tag, TagNumber, Tagged,
+            asn1::{AnyRef, Ia5StringRef},
+        };
+        use hex_literal::hex;
+
+        /// Subset of `GeneralName` as defined in RFC 5280, capturing the other alternatives.
+        #[derive(Choice, Debug, Eq, PartialEq)]
+        #[asn1(tag_mode = "IMPLICIT")]
+        pub enum GeneralName<'a> {
+            #[asn1(context_specific = "1", type = "IA5String")]
+            Rfc822Name(Ia5StringRef<'a>),
+
+            #[asn1(context_specific = "2", type = "IA5String")]
+            DnsName(Ia5StringRef<'a>),
+
+            #[asn1(other = "true")]
+            Other(AnyRef<'a>),
+        }
+
+        #[test]
+        fn known_alternative() {
+            let der = hex!("82 0b 6578616d706c652e636f6d");
+            let name = GeneralName::from_der(&der).unwrap();
+            assert_eq!(
+                name,
+                GeneralName::DnsName(Ia5StringRef::new("example.com").unwrap())
+            );
+            assert_eq!(name.to_der().unwrap(), der);
+        }
+
+        #[test]
+        fn unknown_alternatives_round_trip() {
+            // `[4]` (`directoryName`) is constructed, `[PRIVATE 5]` is primitive
+            for der in [&hex!("a4 03 020105")[..], &hex!("c5 02 abcd")[..]] {
+                let name = GeneralName::from_der(der).unwrap();
+                assert!(matches!(name, GeneralName::Other(_)));
+                assert_eq!(name.to_der().unwrap(), der);
+            }
+
+            let name = GeneralName::from_der(&hex!("a4 03 020105")).unwrap();
+            assert_eq!(
+                name.tag(),
+                Tag::ContextSpecific {
+                    constructed: true,
+                    number: TagNumber(4)
+                }
+            );
+            assert!(!GeneralName::can_decode(Tag::Utf8String));
+        }
+
+        /// `SEQUENCE` with an `OPTIONAL` field of a `CHOICE` with an `other` variant.
+        #[derive(Sequence, Debug, Eq, PartialEq)]
+        pub struct OptionalName<'a> {
+            #[asn1(optional = "true")]
+            pub name: Option<GeneralName<'a>>,
+            pub version: u8,
+        }
+
+        #[test]
+        fn optional_field_with_unknown_tag() {
+            let absent = OptionalName::from_der(&hex!("30 03 020101")).unwrap();
+            assert_eq!(absent.name, None);
+            assert_eq!(absent.version, 1);
+
+            let present = OptionalName::from_der(&hex!("30 08 8203 636f6d 020101")).unwrap();
+            assert_eq!(
+                present.name,
+                Some(GeneralName::DnsName(Ia5StringRef::new("com").unwrap()))
+            );
+
+            // Unknown alternatives can't be told apart from the following field
+            assert!(OptionalName::from_der(&hex!("30 07 c502 abcd 020101")).is_err());
+        }
+    }
 }
 
 /// Custom derive test cases for the `Enumerated` macro.
 mod enumerated {
     use super::CustomError;
-    use der::{Decode, Encode, Enumerated, SliceWriter};
+    use der::{Decode, Encode, Enumerated, FixedLen, Length, SliceWriter};
     use hex_literal::hex;
 
     /// X.509 `CRLReason`.
@@ -239,6 +323,14 @@ mod enumerated {
         CrlReason::KeyCompromise.encode(&mut encoder).unwrap();
         assert_eq!(KEY_COMPROMISE_DER, encoder.finish().unwrap());
     }
+
+    #[test]
+    fn encode_to_array() {
+        assert_eq!(CrlReason::ENCODED_LEN, Length::new(3));
+
+        let der: [u8; 3] = CrlReason::KeyCompromise.encode_to_array().unwrap();
+        assert_eq!(KEY_COMPROMISE_DER, der);
+    }
 }
 
 /// Custom derive test cases for the `Sequence` macro.
@@ -247,8 +339,8 @@ mod sequence {
     use super::CustomError;
     use core::marker::PhantomData;
     use der::{
-        Decode, Encode, Sequence, ValueOrd,
-        asn1::{AnyRef, ObjectIdentifier, SetOf},
+        Decode, Encode, Lazy, Length, Sequence, SliceReader, Spanned, ValueOrd,
+        asn1::{AnyRef, ObjectIdentifier, SequenceOf, SetOf},
     };
     use hex_literal::hex;
 
@@ -354,6 +446,30 @@ mod sequence {
         pub parameters: Option<AnyRef<'a>>,
     }
 
+    /// List of `AlgorithmIdentifier`s, used to test error paths.
+    #[derive(Sequence, Debug)]
+    pub struct AlgorithmList<'a> {
+        pub algorithms: SequenceOf<AlgorithmIdentifier<'a>, 2>,
+    }
+
+    /// `AlgorithmIdentifier` whose fields are decoded on demand.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct LazyAlgorithmIdentifier<'a> {
+        pub algorithm: Lazy<'a, ObjectIdentifier>,
+        #[asn1(context_specific = "0", optional = "true")]
+        pub tagged: Option<Lazy<'a, bool>>,
+        pub parameters: Option<Lazy<'a, AnyRef<'a>>>,
+    }
+
+    /// `AlgorithmIdentifier` whose fields record where they were decoded from.
+    #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
+    pub struct SpannedAlgorithmIdentifier<'a> {
+        pub algorithm: Spanned<ObjectIdentifier>,
+        #[asn1(context_specific = "0", optional = "true")]
+        pub tagged: Option<Spanned<bool>>,
+        pub parameters: Option<Spanned<AnyRef<'a>>>,
+    }
+
     /// X.509 `SubjectPublicKeyInfo` (SPKI)
     #[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
     pub struct SubjectPublicKeyInfo<'a> {
@@ -677,6 +793,203 @@ mod sequence {
         assert!(t.simple);
     }
 
+    #[test]
+    fn decode_ber_indefinite_length() {
+        const ALGORITHM_IDENTIFIER_BER: &[u8] =
+            &hex!("30 80 06 07 2a 86 48 ce 3d 02 01 06 08 2a 86 48 ce 3d 03 01 07 00 00");
+
+        let algorithm_identifier = AlgorithmIdentifier::from_ber(ALGORITHM_IDENTIFIER_BER).unwrap();
+        assert_eq!(ID_EC_PUBLIC_KEY_OID, algorithm_identifier.algorithm);
+        assert_eq!(
+            PRIME256V1_OID,
+            ObjectIdentifier::try_from(algorithm_identifier.parameters.unwrap()).unwrap()
+        );
+
+        // Re-encoding produces DER
+        assert_eq!(
+            ALGORITHM_IDENTIFIER_DER,
+            algorithm_identifier.to_der().unwrap()
+        );
+
+        // Indefinite lengths are not allowed in DER
+        assert!(AlgorithmIdentifier::from_der(ALGORITHM_IDENTIFIER_BER).is_err());
+    }
+
+    #[test]
+    fn decode_ber_nested_indefinite_length() {
+        // Absent OPTIONAL field followed by the end-of-contents octets
+        let list = AlgorithmList::from_ber(&hex!(
+            "30 80 30 80 30 80 06 02 2a 03 00 00 30 06 06 02 2a 04 05 00 00 00 00 00"
+        ))
+        .unwrap();
+
+        let algorithms = list.algorithms.iter().collect::<Vec<_>>();
+        assert_eq!(algorithms.len(), 2);
+        assert!(algorithms[0].parameters.is_none());
+        assert!(algorithms[1].parameters.unwrap().is_null());
+
+        // Missing end-of-contents octets
+        assert!(AlgorithmList::from_ber(&hex!("30 80 30 80 30 80 06 02 2a 03 00 00")).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "error-path")]
+    fn decode_error_path() {
+        let err = AlgorithmList::from_der(&hex!("30 0E 30 0C 30 04 06 02 2A 03 30 04 06 02 2A 80"))
+            .unwrap_err();
+
+        assert_eq!(err.kind(), der::ErrorKind::OidMalformed);
+        assert_eq!(
+            err.path().to_string(),
+            "AlgorithmList.algorithms[1].algorithm"
+        );
+        assert!(
+            err.to_string()
+                .ends_with(" in AlgorithmList.algorithms[1].algorithm")
+        );
+    }
+
+    #[test]
+    fn decode_lazy() {
+        let algorithm_identifier =
+            LazyAlgorithmIdentifier::from_der(ALGORITHM_IDENTIFIER_DER).unwrap();
+
+        assert_eq!(
+            algorithm_identifier.algorithm.as_bytes(),
+            &ALGORITHM_IDENTIFIER_DER[2..11]
+        );
+        assert_eq!(
+            ID_EC_PUBLIC_KEY_OID,
+            *algorithm_identifier.algorithm.get().unwrap()
+        );
+        assert!(algorithm_identifier.tagged.is_none());
+
+        let parameters = algorithm_identifier.parameters.as_ref().unwrap();
+        assert_eq!(
+            PRIME256V1_OID,
+            ObjectIdentifier::try_from(*parameters.get().unwrap()).unwrap()
+        );
+
+        assert_eq!(
+            ALGORITHM_IDENTIFIER_DER,
+            algorithm_identifier.to_der().unwrap()
+        );
+
+        let tagged = hex!("30 0E 06 07 2a 86 48 ce 3d 02 01 A0 03 01 01 FF");
+        let algorithm_identifier = LazyAlgorithmIdentifier::from_der(&tagged).unwrap();
+        assert!(*algorithm_identifier.tagged.as_ref().unwrap().get().unwrap());
+        assert!(algorithm_identifier.parameters.is_none());
+        assert_eq!(tagged, algorithm_identifier.to_der().unwrap().as_slice());
+    }
+
+    #[test]
+    fn decode_spanned() {
+        let algorithm_identifier =
+            SpannedAlgorithmIdentifier::from_der(ALGORITHM_IDENTIFIER_DER).unwrap();
+
+        let span = algorithm_identifier.algorithm.span();
+        assert_eq!(
+            *algorithm_identifier.algorithm.value(),
+            ID_EC_PUBLIC_KEY_OID
+        );
+        assert_eq!(
+            (span.header_start, span.value_start, span.end),
+            (Length::new(2), Length::new(4), Length::new(11))
+        );
+
+        let span = algorithm_identifier.parameters.as_ref().unwrap().span();
+        assert_eq!(
+            (span.header_start, span.value_start, span.end),
+            (Length::new(11), Length::new(13), Length::new(21))
+        );
+
+        assert_eq!(
+            ALGORITHM_IDENTIFIER_DER,
+            algorithm_identifier.to_der().unwrap()
+        );
+
+        // Spans of EXPLICIT fields cover the value inside the context-specific tag
+        let tagged = hex!("30 0E 06 07 2a 86 48 ce 3d 02 01 A0 03 01 01 FF");
+        let algorithm_identifier = SpannedAlgorithmIdentifier::from_der(&tagged).unwrap();
+        let span = algorithm_identifier.tagged.as_ref().unwrap().span();
+        assert_eq!(
+            (span.header_start, span.value_start, span.end),
+            (Length::new(13), Length::new(15), Length::new(16))
+        );
+    }
+
+    #[test]
+    fn decode_field_spans() {
+        let spki_bytes = hex!(
+            "30 1A
+                30 0D
+                    06 09 2A 86 48 86 F7 0D 01 01 01
+                    05 00
+                03 09 00 A0 A1 A2 A3 A4 A5 A6 A7"
+        );
+
+        let mut reader = SliceReader::new(&spki_bytes).unwrap().with_spans();
+        SubjectPublicKeyInfo::decode(&mut reader).unwrap();
+
+        let spans = reader
+            .spans()
+            .iter()
+            .map(|field| {
+                let span = field.span;
+                (
+                    field.type_name,
+                    field.field_name,
+                    field.depth,
+                    (span.header_start, span.value_start, span.end),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Fields are recorded as they finish decoding, innermost first
+        assert_eq!(
+            spans,
+            [
+                (
+                    "AlgorithmIdentifier",
+                    "algorithm",
+                    2,
+                    (Length::new(4), Length::new(6), Length::new(15))
+                ),
+                (
+                    "AlgorithmIdentifier",
+                    "parameters",
+                    2,
+                    (Length::new(15), Length::new(17), Length::new(17))
+                ),
+                (
+                    "SubjectPublicKeyInfo",
+                    "algorithm",
+                    1,
+                    (Length::new(2), Length::new(4), Length::new(17))
+                ),
+                (
+                    "SubjectPublicKeyInfo",
+                    "subject_public_key",
+                    1,
+                    (Length::new(17), Length::new(19), Length::new(28))
+                ),
+            ]
+        );
+
+        // Absent OPTIONAL fields aren't recorded
+        let mut reader = SliceReader::new(&hex!("30 0B 06 09 2A 86 48 86 F7 0D 01 01 01"))
+            .unwrap()
+            .with_spans();
+        AlgorithmIdentifier::decode(&mut reader).unwrap();
+        assert_eq!(reader.spans().len(), 1);
+        assert_eq!(reader.spans()[0].field_name, "algorithm");
+
+        // Readers don't record spans unless asked to
+        let mut reader = SliceReader::new(&spki_bytes).unwrap();
+        SubjectPublicKeyInfo::decode(&mut reader).unwrap();
+        assert!(reader.spans().is_empty());
+    }
+
     #[test]
     fn encode() {
         let parameters_oid = PRIME256V1_OID;
@@ -693,6 +1006,656 @@ mod sequence {
     }
 }
 
+/// Custom derive test cases for extensible `SEQUENCE` types.
+mod extensible {
+    use der::{Decode, Encode, ErrorKind, Sequence, Tag, TagNumber, Tagged, asn1::Any};
+    use hex_literal::hex;
+
+    /// Version 1 of a `SEQUENCE` which has since gained new components:
+    ///
+    /// ```text
+    /// Example ::= SEQUENCE {
+    ///     version  INTEGER,
+    ///     flag     [0] BOOLEAN OPTIONAL,
+    ///     ... }
+    /// ```
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    #[asn1(extensible = "true")]
+    pub struct Example {
+        pub version: u8,
+
+        #[asn1(context_specific = "0", optional = "true")]
+        pub flag: Option<bool>,
+    }
+
+    /// Same as [`Example`], but capturing the unknown extension additions.
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    #[asn1(extensible = "true")]
+    pub struct ExampleWithAdditions {
+        pub version: u8,
+
+        #[asn1(context_specific = "0", optional = "true")]
+        pub flag: Option<bool>,
+
+        #[asn1(extension_additions = "true")]
+        pub additions: Vec<Any>,
+    }
+
+    /// Same as [`Example`], but not extensible.
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    pub struct NonExtensible {
+        pub version: u8,
+
+        #[asn1(context_specific = "0", optional = "true")]
+        pub flag: Option<bool>,
+    }
+
+    /// Version 2 encoding with an unknown `[1] INTEGER` and `UTF8String`.
+    const V2_DER: &[u8] = &hex!("30 11 020102 A0030101FF A103020105 0C026869");
+
+    #[test]
+    fn decode_skips_additions() {
+        let example = Example::from_der(V2_DER).unwrap();
+        assert_eq!(
+            example,
+            Example {
+                version: 2,
+                flag: Some(true)
+            }
+        );
+
+        // Re-encoding only includes the known components
+        assert_eq!(example.to_der().unwrap(), hex!("30 08 020102 A0030101FF"));
+
+        // Unknown components may follow absent optional ones
+        let example = Example::from_der(&hex!("30 07 020102 0C026869")).unwrap();
+        assert_eq!(example.flag, None);
+    }
+
+    #[test]
+    fn decode_skips_ber_additions() {
+        let example = Example::from_ber(&hex!("30 80 020102 A1 80 020105 0000 0000")).unwrap();
+        assert_eq!(example.version, 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decode_skips_additions_from_io_reader() {
+        use der::{EncodingRules, IoReader};
+
+        let example = Example::decode(&mut IoReader::new(V2_DER)).unwrap();
+        assert_eq!(example.flag, Some(true));
+
+        let ber = hex!("30 80 020102 A1 80 020105 0000 0000");
+        let mut reader = IoReader::new_with_encoding_rules(&ber[..], EncodingRules::Ber).unwrap();
+        assert_eq!(Example::decode(&mut reader).unwrap().version, 2);
+    }
+
+    #[test]
+    fn additions_round_trip() {
+        let example = ExampleWithAdditions::from_der(V2_DER).unwrap();
+        assert_eq!(example.additions.len(), 2);
+        assert_eq!(
+            example.additions[0].tag(),
+            Tag::ContextSpecific {
+                constructed: true,
+                number: TagNumber(1)
+            }
+        );
+        assert_eq!(example.additions[1].tag(), Tag::Utf8String);
+        assert_eq!(example.to_der().unwrap(), V2_DER);
+
+        let example = ExampleWithAdditions::from_der(&hex!("30 03 020101")).unwrap();
+        assert!(example.additions.is_empty());
+    }
+
+    #[test]
+    fn non_extensible_rejects_additions() {
+        let err = NonExtensible::from_der(V2_DER).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TrailingData { .. }));
+    }
+}
+
+/// Custom derive test cases for the `fixed_len` attribute of the `Sequence` macro.
+mod fixed_len {
+    use der::{Decode, Encode, FixedLen, Sequence, asn1::OctetStringArray};
+    use hex_literal::hex;
+
+    /// `SEQUENCE` whose fields all have an encoding of a fixed length.
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    #[asn1(fixed_len = "true")]
+    pub struct Record {
+        pub flag: bool,
+        pub digest: OctetStringArray<4>,
+
+        #[asn1(context_specific = "0")]
+        pub pair: [bool; 2],
+    }
+
+    #[test]
+    fn encode_to_array() {
+        let record = Record {
+            flag: true,
+            digest: OctetStringArray::new([1, 2, 3, 4]),
+            pair: [false, true],
+        };
+
+        assert_eq!(Record::ENCODED_LEN, record.encoded_len().unwrap());
+
+        let der: [u8; 21] = record.encode_to_array().unwrap();
+        assert_eq!(
+            der,
+            hex!("3013 0101FF 040401020304 A008 3006 010100 0101FF")
+        );
+        assert_eq!(Record::from_der(&der).unwrap(), record);
+    }
+}
+
+/// Custom derive test cases for the `Set` macro.
+mod set {
+    use der::{Decode, Encode, ErrorKind, FixedTag, Set, Tag, asn1::Utf8StringRef};
+    use hex_literal::hex;
+
+    /// Fields are declared in a different order than their tags sort in:
+    ///
+    /// ```text
+    /// Contact ::= SET {
+    ///     age   [0] INTEGER OPTIONAL,
+    ///     name  UTF8String,
+    ///     id    INTEGER }
+    /// ```
+    #[derive(Set, Debug, Eq, PartialEq)]
+    pub struct Contact<'a> {
+        #[asn1(context_specific = "0", optional = "true")]
+        pub age: Option<u8>,
+
+        pub name: Utf8StringRef<'a>,
+
+        pub id: u32,
+    }
+
+    /// Same as [`Contact`], but skipping elements with unknown tags.
+    #[derive(Set, Debug, Eq, PartialEq)]
+    #[asn1(extensible = "true")]
+    pub struct ExtensibleContact<'a> {
+        #[asn1(context_specific = "0", optional = "true")]
+        pub age: Option<u8>,
+
+        pub name: Utf8StringRef<'a>,
+
+        pub id: u32,
+    }
+
+    /// Same as [`ExtensibleContact`], but owned so that it can be decoded with an `IoReader`.
+    #[cfg(feature = "std")]
+    #[derive(Set, Debug, Eq, PartialEq)]
+    #[asn1(extensible = "true")]
+    pub struct ExtensibleRecord {
+        #[asn1(context_specific = "0", optional = "true")]
+        pub age: Option<u8>,
+
+        pub id: u32,
+    }
+
+    const CONTACT_DER: &[u8] = &hex!("31 0C 020105 0C026869 A00302011E");
+
+    fn contact() -> Contact<'static> {
+        Contact {
+            age: Some(30),
+            name: Utf8StringRef::new("hi").unwrap(),
+            id: 5,
+        }
+    }
+
+    #[test]
+    fn encode_in_tag_order() {
+        assert_eq!(Contact::TAG, Tag::Set);
+        assert_eq!(contact().to_der().unwrap(), CONTACT_DER);
+        assert_eq!(Contact::from_der(CONTACT_DER).unwrap(), contact());
+    }
+
+    #[test]
+    fn optional_field_omitted() {
+        let der = hex!("31 07 020105 0C026869");
+        let value = Contact::from_der(&der).unwrap();
+        assert_eq!(value.age, None);
+        assert_eq!(value.to_der().unwrap(), der);
+    }
+
+    #[test]
+    fn ber_any_order() {
+        let ber = hex!("31 0C A00302011E 0C026869 020105");
+        assert_eq!(Contact::from_ber(&ber).unwrap(), contact());
+
+        let err = Contact::from_der(&ber).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetOrdering);
+    }
+
+    #[test]
+    fn duplicate_rejected() {
+        let err = Contact::from_ber(&hex!("31 0A 020105 0C026869 020106")).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SetDuplicate);
+    }
+
+    #[test]
+    fn missing_field_rejected() {
+        assert!(Contact::from_der(&hex!("31 03 020105")).is_err());
+    }
+
+    #[test]
+    fn unknown_tag() {
+        let der = hex!("31 0A 010100 020105 0C026869");
+
+        let err = Contact::from_der(&der).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::TagUnexpected { .. }));
+
+        let value = ExtensibleContact::from_der(&der).unwrap();
+        assert_eq!(value.id, 5);
+        assert_eq!(value.age, None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unknown_tag_from_io_reader() {
+        use der::{EncodingRules, IoReader};
+
+        let der = hex!("31 06 010100 020105");
+        let value = ExtensibleRecord::decode(&mut IoReader::new(&der[..])).unwrap();
+        assert_eq!(value.id, 5);
+
+        let ber = hex!("31 80 020105 A1 80 0C026869 0000 0000");
+        let mut reader = IoReader::new_with_encoding_rules(&ber[..], EncodingRules::Ber).unwrap();
+        assert_eq!(ExtensibleRecord::decode(&mut reader).unwrap().id, 5);
+    }
+}
+
+/// Custom derive test cases for `size` and `range` constraints.
+mod constraints {
+    use der::{
+        Decode, Encode, ErrorKind, Sequence, Set,
+        asn1::{OctetString, Utf8StringRef},
+        constraint::Constraint,
+    };
+    use hex_literal::hex;
+
+    /// ```text
+    /// Example ::= SEQUENCE {
+    ///     names    SEQUENCE SIZE (1..MAX) OF UTF8String,
+    ///     length   INTEGER (1..65535),
+    ///     id       [0] OCTET STRING (SIZE (4)) OPTIONAL }
+    /// ```
+    #[derive(Sequence, Debug, Eq, PartialEq)]
+    pub struct Example<'a> {
+        #[asn1(size = "1..MAX")]
+        pub names: Vec<Utf8StringRef<'a>>,
+
+        #[asn1(range = "1..=65535")]
+        pub length: u32,
+
+        #[asn1(context_specific = "0", optional = "true", size = "4")]
+        pub id: Option<OctetString>,
+    }
+
+    /// `SET` with a constrained field.
+    #[derive(Set, Debug, Eq, PartialEq)]
+    pub struct SetExample {
+        #[asn1(range = "..=255")]
+        pub value: u32,
+    }
+
+    fn kind(constraint: Constraint) -> ErrorKind {
+        ErrorKind::ConstraintViolation { constraint }
+    }
+
+    #[test]
+    fn decode() {
+        let der = hex!("30 10 3004 0C026869 020101 A0050403AABBCC");
+        let err = Example::from_der(&der).unwrap_err();
+        assert_eq!(err.kind(), kind(Constraint::Size));
+        #[cfg(feature = "error-path")]
+        assert_eq!(err.path().to_string(), "Example.id");
+
+        let example = Example::from_der(&hex!("30 09 3004 0C026869 020101")).unwrap();
+        assert_eq!(example.length, 1);
+        assert_eq!(example.id, None);
+
+        let err = Example::from_der(&hex!("30 07 3000 0203010000")).unwrap_err();
+        assert_eq!(err.kind(), kind(Constraint::Size));
+        #[cfg(feature = "error-path")]
+        assert_eq!(err.path().to_string(), "Example.names");
+
+        let err = Example::from_der(&hex!("30 0B 3004 0C026869 0203010000")).unwrap_err();
+        assert_eq!(err.kind(), kind(Constraint::ValueRange));
+
+        let err = SetExample::from_der(&hex!("31 04 02020100")).unwrap_err();
+        assert_eq!(err.kind(), kind(Constraint::ValueRange));
+    }
+
+    #[test]
+    fn encode() {
+        let mut example = Example {
+            names: vec![Utf8StringRef::new("hi").unwrap()],
+            length: 65535,
+            id: Some(OctetString::new(hex!("AABBCCDD")).unwrap()),
+        };
+        assert!(example.to_der().is_ok());
+
+        example.length = 0;
+        assert_eq!(
+            example.to_der().unwrap_err().kind(),
+            kind(Constraint::ValueRange)
+        );
+
+        example.length = 1;
+        example.names.clear();
+        assert_eq!(example.to_der().unwrap_err().kind(), kind(Constraint::Size));
+
+        let err = SetExample { value: 256 }.to_der().unwrap_err();
+        assert_eq!(err.kind(), kind(Constraint::ValueRange));
+    }
+}
+
+/// Custom derive test cases for the `Per` macro.
+mod per {
+    use der::{
+        ErrorKind, Per,
+        asn1::Ia5String,
+        constraint::Constraint,
+        per::{Alignment, PerDecode, PerEncode},
+    };
+    use hex_literal::hex;
+
+    /// ```text
+    /// Contact ::= SEQUENCE {
+    ///     name   IA5String (SIZE (1..8)),
+    ///     age    INTEGER (0..127) OPTIONAL,
+    ///     tags   SEQUENCE SIZE (0..3) OF BOOLEAN,
+    ///     ... }
+    /// ```
+    #[derive(Per, Debug, Eq, PartialEq)]
+    #[asn1(extensible = "true")]
+    pub struct Contact {
+        #[asn1(size = "1..=8")]
+        pub name: Ia5String,
+
+        #[asn1(range = "0..=127", optional = "true")]
+        pub age: Option<u8>,
+
+        #[asn1(size = "..=3")]
+        pub tags: Vec<bool>,
+    }
+
+    /// `Id ::= CHOICE { flag [0] BOOLEAN, name IA5String, ... }`
+    #[derive(Per, Debug, Eq, PartialEq)]
+    #[asn1(extensible = "true")]
+    pub enum Id {
+        #[asn1(context_specific = "0")]
+        Flag(bool),
+
+        Name(Ia5String),
+    }
+
+    /// `Color ::= ENUMERATED { red(0), green(3), blue(7) }`
+    #[derive(Per, Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u8)]
+    pub enum Color {
+        Red = 0,
+        Blue = 7,
+        Green = 3,
+    }
+
+    fn contact() -> Contact {
+        Contact {
+            name: Ia5String::new("ab").unwrap(),
+            age: Some(30),
+            tags: vec![true],
+        }
+    }
+
+    #[test]
+    fn sequence() {
+        let uper = hex!("4E 1C 47 98");
+        assert_eq!(contact().to_per(Alignment::Unaligned).unwrap(), uper);
+        assert_eq!(
+            Contact::from_per(&uper, Alignment::Unaligned).unwrap(),
+            contact()
+        );
+
+        // Characters are octet-aligned in APER
+        let aper = hex!("48 61 62 3C C0");
+        assert_eq!(contact().to_per(Alignment::Aligned).unwrap(), aper);
+        assert_eq!(
+            Contact::from_per(&aper, Alignment::Aligned).unwrap(),
+            contact()
+        );
+    }
+
+    #[test]
+    fn sequence_extension_additions_skipped() {
+        // Extension bit set, followed by one extension addition containing `FF`
+        let uper = hex!("CE 1C 47 98 08 0F F8");
+        assert_eq!(
+            Contact::from_per(&uper, Alignment::Unaligned).unwrap(),
+            contact()
+        );
+    }
+
+    #[test]
+    fn sequence_constraint_violation() {
+        let mut contact = contact();
+        contact.age = Some(128);
+
+        let err = contact.to_per(Alignment::Unaligned).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange
+            }
+        );
+        #[cfg(feature = "error-path")]
+        assert_eq!(err.path().to_string(), "Contact.age");
+    }
+
+    #[test]
+    fn choice() {
+        // Alternatives are numbered in tag order, so `name` is alternative 0
+        let flag = Id::Flag(true);
+        assert_eq!(flag.to_per(Alignment::Unaligned).unwrap(), [0b0110_0000]);
+        assert_eq!(
+            Id::from_per(&[0b0110_0000], Alignment::Unaligned).unwrap(),
+            flag
+        );
+
+        let name = Id::Name(Ia5String::new("a").unwrap());
+        assert_eq!(name.to_per(Alignment::Unaligned).unwrap(), hex!("00 70 80"));
+        assert_eq!(
+            Id::from_per(&hex!("00 70 80"), Alignment::Unaligned).unwrap(),
+            name
+        );
+
+        // Extension additions are unsupported
+        let err = Id::from_per(&[0b1000_0000], Alignment::Unaligned).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange
+            }
+        );
+    }
+
+    #[test]
+    fn enumerated() {
+        // Values are numbered in order of their discriminants
+        assert_eq!(
+            Color::Blue.to_per(Alignment::Aligned).unwrap(),
+            [0b1000_0000]
+        );
+        assert_eq!(
+            Color::Green.to_per(Alignment::Aligned).unwrap(),
+            [0b0100_0000]
+        );
+        assert_eq!(
+            Color::from_per(&[0b1000_0000], Alignment::Aligned).unwrap(),
+            Color::Blue
+        );
+
+        let err = Color::from_per(&[0b1100_0000], Alignment::Aligned).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            ErrorKind::ConstraintViolation {
+                constraint: Constraint::ValueRange
+            }
+        );
+    }
+}
+
+/// Custom derive test cases for the `Jer` macro.
+mod jer {
+    use der::{
+        BitString, ErrorKind, Jer,
+        asn1::{Ia5String, OctetString},
+        jer::{JerDecode, JerEncode},
+    };
+
+    /// ```text
+    /// Contact ::= SEQUENCE {
+    ///     name      IA5String,
+    ///     key-id    OCTET STRING OPTIONAL,
+    ///     version   INTEGER DEFAULT 1,
+    ///     id        Id,
+    ///     color     Color,
+    ///     flags     Flags }
+    /// ```
+    #[derive(Jer, Debug, Eq, PartialEq)]
+    pub struct Contact {
+        pub name: Ia5String,
+
+        #[asn1(optional = "true")]
+        pub key_id: Option<OctetString>,
+
+        #[asn1(default = "default_version")]
+        pub version: u8,
+
+        pub id: Id,
+        pub color: Color,
+        pub flags: Flags,
+    }
+
+    fn default_version() -> u8 {
+        1
+    }
+
+    /// `Id ::= CHOICE { serial-number [0] INTEGER, name IA5String }`
+    #[derive(Jer, Debug, Eq, PartialEq)]
+    pub enum Id {
+        #[asn1(context_specific = "0")]
+        SerialNumber(u64),
+
+        Name(Ia5String),
+    }
+
+    /// `Color ::= ENUMERATED { red(0), light-green(3) }`
+    #[derive(Jer, Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u8)]
+    pub enum Color {
+        Red = 0,
+        LightGreen = 3,
+    }
+
+    /// `Flags ::= BIT STRING { a(0), b(1), c(2) }`
+    #[derive(BitString, Debug, Eq, PartialEq)]
+    pub struct Flags {
+        pub a: bool,
+        pub b: bool,
+        pub c: bool,
+    }
+
+    /// `Options ::= SEQUENCE { verbose BOOLEAN, ... }`
+    #[derive(Jer, Debug, Eq, PartialEq)]
+    #[asn1(extensible = "true")]
+    pub struct Options {
+        pub verbose: bool,
+    }
+
+    const CONTACT_JSON: &str = concat!(
+        r#"{"name":"ab","keyId":"01FF","id":{"serialNumber":42},"#,
+        r#""color":"lightGreen","flags":{"value":"A0","length":3}}"#
+    );
+
+    fn contact() -> Contact {
+        Contact {
+            name: Ia5String::new("ab").unwrap(),
+            key_id: Some(OctetString::new([0x01, 0xFF]).unwrap()),
+            version: 1,
+            id: Id::SerialNumber(42),
+            color: Color::LightGreen,
+            flags: Flags {
+                a: true,
+                b: false,
+                c: true,
+            },
+        }
+    }
+
+    #[test]
+    fn sequence() {
+        assert_eq!(contact().to_jer().unwrap(), CONTACT_JSON);
+        assert_eq!(Contact::from_jer(CONTACT_JSON).unwrap(), contact());
+
+        // Absent `OPTIONAL` fields are omitted, non-default `DEFAULT` fields aren't
+        let mut contact = contact();
+        contact.key_id = None;
+        contact.version = 3;
+        contact.id = Id::Name(Ia5String::new("x").unwrap());
+
+        let json = contact.to_jer().unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"name":"ab","version":3,"id":{"name":"x"},"#,
+                r#""color":"lightGreen","flags":{"value":"A0","length":3}}"#
+            )
+        );
+        assert_eq!(Contact::from_jer(&json).unwrap(), contact);
+    }
+
+    #[test]
+    fn sequence_errors() {
+        // Missing required member
+        let err = Contact::from_jer(r#"{"name":"ab"}"#).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Json);
+        #[cfg(feature = "error-path")]
+        assert_eq!(err.path().to_string(), "Contact.id");
+
+        // Unknown member of a non-extensible `SEQUENCE`
+        let json = CONTACT_JSON.replace("\"name\"", "\"nickname\"");
+        assert_eq!(
+            Contact::from_jer(&json).unwrap_err().kind(),
+            ErrorKind::Json
+        );
+
+        // Unknown members of an extensible `SEQUENCE` are skipped
+        assert_eq!(
+            Options::from_jer(r#"{"verbose":true,"future":[1,2]}"#).unwrap(),
+            Options { verbose: true }
+        );
+    }
+
+    #[test]
+    fn choice() {
+        assert!(Id::from_jer(r#"{"other":1}"#).is_err());
+        assert!(Id::from_jer(r#"{"name":"a","serialNumber":1}"#).is_err());
+    }
+
+    #[test]
+    fn enumerated() {
+        assert_eq!(Color::Red.to_jer().unwrap(), r#""red""#);
+        assert_eq!(Color::from_jer(r#""red""#).unwrap(), Color::Red);
+        assert!(Color::from_jer(r#""blue""#).is_err());
+        assert!(Color::from_jer("0").is_err());
+    }
+}
+
 /// Custom derive test cases for the `EncodeValue` macro.
 mod encode_value {
     use der::{Encode, EncodeValue, FixedTag, Tag};
@@ -0,0 +1,192 @@
+//! Abstract syntax tree for the supported subset of ASN.1 modules.
+
+use crate::Position;
+
+/// ASN.1 module definition (X.680 Section 13).
+#[derive(Clone, Debug)]
+pub(crate) struct Module {
+    /// Module reference, e.g. `PKIX1Explicit88`.
+    pub(crate) name: String,
+
+    /// Default tagging mode for the module.
+    pub(crate) tag_default: TagDefault,
+
+    /// Type and value assignments, in the order they appear.
+    pub(crate) assignments: Vec<Assignment>,
+}
+
+/// Default tagging mode of a module (X.680 Section 13.1).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TagDefault {
+    /// `EXPLICIT TAGS`, which is the default.
+    Explicit,
+
+    /// `IMPLICIT TAGS`.
+    Implicit,
+
+    /// `AUTOMATIC TAGS`.
+    Automatic,
+}
+
+/// Type or value assignment.
+#[derive(Clone, Debug)]
+pub(crate) enum Assignment {
+    /// Type assignment, e.g. `Version ::= INTEGER { v1(0) }`.
+    Type {
+        /// Type reference.
+        name: String,
+
+        /// Assigned type.
+        ty: Type,
+
+        /// Position of the assignment.
+        position: Position,
+    },
+
+    /// Value assignment, e.g. `id-pkix OBJECT IDENTIFIER ::= { ... }`.
+    Value {
+        /// Value reference.
+        name: String,
+
+        /// Type of the value.
+        ty: Type,
+
+        /// Assigned value.
+        value: Value,
+
+        /// Position of the assignment.
+        position: Position,
+    },
+}
+
+/// ASN.1 type.
+#[derive(Clone, Debug)]
+pub(crate) enum Type {
+    /// `BOOLEAN`.
+    Boolean,
+
+    /// `INTEGER`, optionally with named numbers and a value range.
+    Integer {
+        /// Named numbers, e.g. `v1(0)`.
+        named_numbers: Vec<(String, i64)>,
+
+        /// Value range constraint, if any.
+        range: Option<Range>,
+    },
+
+    /// `ENUMERATED`.
+    Enumerated(Vec<(String, i64)>),
+
+    /// `BIT STRING`, optionally with named bits.
+    BitString(Vec<(String, u64)>),
+
+    /// `OCTET STRING`.
+    OctetString,
+
+    /// `NULL`.
+    Null,
+
+    /// `OBJECT IDENTIFIER`.
+    ObjectIdentifier,
+
+    /// Character string and time types, e.g. `UTF8String` or `UTCTime`.
+    Builtin(&'static str),
+
+    /// `ANY` or `ANY DEFINED BY`.
+    Any,
+
+    /// `SEQUENCE { ... }`.
+    Sequence {
+        /// Components of the sequence.
+        components: Vec<Component>,
+
+        /// Does the sequence contain the `...` extension marker?
+        extensible: bool,
+    },
+
+    /// `SEQUENCE OF`.
+    SequenceOf(Box<Type>),
+
+    /// `SET OF`.
+    SetOf(Box<Type>),
+
+    /// `CHOICE { ... }`.
+    Choice(Vec<Component>),
+
+    /// Prefixed type, e.g. `[0] IMPLICIT INTEGER`.
+    Tagged {
+        /// Tag number. Only context-specific tags are supported.
+        number: u64,
+
+        /// Tagging mode, if specified.
+        mode: Option<TagMode>,
+
+        /// Type being tagged.
+        inner: Box<Type>,
+    },
+
+    /// Reference to a type defined elsewhere, e.g. `AlgorithmIdentifier`.
+    Reference(String),
+}
+
+/// Lower and upper bounds of a value range constraint, where `None` means `MIN` or `MAX`
+/// respectively.
+pub(crate) type Range = (Option<Value>, Option<Value>);
+
+/// Explicit tagging mode of a prefixed type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum TagMode {
+    /// `EXPLICIT`.
+    Explicit,
+
+    /// `IMPLICIT`.
+    Implicit,
+}
+
+/// Component of a `SEQUENCE`, `SET` or `CHOICE` type.
+#[derive(Clone, Debug)]
+pub(crate) struct Component {
+    /// Identifier of the component.
+    pub(crate) name: String,
+
+    /// Type of the component.
+    pub(crate) ty: Type,
+
+    /// Is the component `OPTIONAL`?
+    pub(crate) optional: bool,
+
+    /// Default value of the component, if any.
+    pub(crate) default: Option<Value>,
+
+    /// Position of the component.
+    pub(crate) position: Position,
+}
+
+/// ASN.1 value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Value {
+    /// `TRUE` or `FALSE`.
+    Boolean(bool),
+
+    /// Integer.
+    Integer(i64),
+
+    /// Identifier, e.g. a named number or a reference to another value.
+    Identifier(String),
+
+    /// Object identifier components, e.g. `{ id-pkix 3 }`.
+    ObjectIdentifier(Vec<OidComponent>),
+
+    /// Any other value which is parsed but not supported, e.g. `{}`.
+    Unsupported,
+}
+
+/// Component of an object identifier value (X.680 Section 32.3).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum OidComponent {
+    /// Number, either bare or in the `name(number)` form.
+    Number(u64),
+
+    /// Reference to another object identifier value.
+    Reference(String),
+}
@@ -0,0 +1,827 @@
+//! Generation of Rust source code from parsed ASN.1 modules.
+
+use crate::{
+    Error, Position,
+    ast::{Assignment, Component, Module, OidComponent, Range, TagDefault, TagMode, Type, Value},
+    naming::{screaming_snake_case, snake_case, upper_camel_case},
+};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Rust types of the supported built-in character string and time types.
+const BUILTIN_TYPES: &[(&str, &str)] = &[
+    ("BMPString", "der::asn1::BmpString"),
+    ("GeneralizedTime", "der::asn1::GeneralizedTime"),
+    ("IA5String", "der::asn1::Ia5String"),
+    ("PrintableString", "der::asn1::PrintableString"),
+    ("T61String", "der::asn1::TeletexString"),
+    ("TeletexString", "der::asn1::TeletexString"),
+    ("UTCTime", "der::asn1::UtcTime"),
+    ("UTF8String", "String"),
+];
+
+/// Names of the top-level object identifier arcs (X.660 Annex A).
+const ROOT_ARCS: &[(&str, u64)] = &[
+    ("itu-t", 0),
+    ("ccitt", 0),
+    ("iso", 1),
+    ("joint-iso-itu-t", 2),
+    ("joint-iso-ccitt", 2),
+];
+
+/// Names of the arcs beneath `iso` (X.660 Annex A).
+const ISO_ARCS: &[(&str, u64)] = &[
+    ("standard", 0),
+    ("registration-authority", 1),
+    ("member-body", 2),
+    ("identified-organization", 3),
+];
+
+/// Names of the arcs beneath `itu-t` (X.660 Annex A).
+const ITU_T_ARCS: &[(&str, u64)] = &[
+    ("recommendation", 0),
+    ("question", 1),
+    ("administration", 2),
+    ("network-operator", 3),
+    ("identified-organization", 4),
+];
+
+/// Generate Rust source code for the given modules.
+pub(crate) fn generate(
+    modules: &[Module],
+    oids: &BTreeMap<String, String>,
+) -> Result<String, Error> {
+    let mut generator = Generator {
+        types: BTreeMap::new(),
+        values: BTreeMap::new(),
+        oids,
+        resolved_oids: BTreeMap::new(),
+        pending: Vec::new(),
+        nominal: BTreeSet::new(),
+        set_elements: BTreeSet::new(),
+        out: String::new(),
+    };
+
+    for module in modules {
+        for assignment in &module.assignments {
+            match assignment {
+                Assignment::Type { name, ty, .. } => {
+                    generator.types.insert(name, ty);
+                }
+                Assignment::Value {
+                    name, ty, value, ..
+                } => {
+                    generator.values.insert(name, (ty, value));
+                }
+            }
+        }
+    }
+
+    let names: Vec<&str> = modules.iter().map(|module| module.name.as_str()).collect();
+    generator.line(&format!(
+        "// This file was generated from the {} ASN.1 module{} by `der_codegen`.",
+        names
+            .iter()
+            .map(|name| format!("`{name}`"))
+            .collect::<Vec<_>>()
+            .join(", "),
+        if names.len() == 1 { "" } else { "s" }
+    ));
+    generator.line("// Do not edit it by hand.");
+
+    for module in modules {
+        for assignment in &module.assignments {
+            match assignment {
+                Assignment::Type { name, ty, position } => {
+                    generator.define(
+                        &upper_camel_case(name),
+                        name,
+                        ty,
+                        module.tag_default,
+                        *position,
+                    )?;
+
+                    while !generator.pending.is_empty() {
+                        let pending = std::mem::take(&mut generator.pending);
+
+                        for (rust_name, ty, position) in pending {
+                            generator.define(
+                                &rust_name,
+                                &rust_name,
+                                &ty,
+                                module.tag_default,
+                                position,
+                            )?;
+                        }
+                    }
+                }
+                Assignment::Value {
+                    name,
+                    ty,
+                    value,
+                    position,
+                } => generator.value(name, ty, value, *position)?,
+            }
+        }
+    }
+
+    for name in generator.set_elements.clone() {
+        if generator.nominal.contains(&name) {
+            generator.line("");
+            generator.line(&format!("impl der::ValueOrd for {name} {{"));
+            generator.line(
+                "    fn value_cmp(&self, other: &Self) -> der::Result<core::cmp::Ordering> {",
+            );
+            generator
+                .line("        Ok(der::Encode::to_der(self)?.cmp(&der::Encode::to_der(other)?))");
+            generator.line("    }");
+            generator.line("}");
+        }
+    }
+
+    Ok(generator.out)
+}
+
+/// Code generator state.
+struct Generator<'a> {
+    /// Type assignments in all modules.
+    types: BTreeMap<&'a str, &'a Type>,
+
+    /// Value assignments in all modules.
+    values: BTreeMap<&'a str, (&'a Type, &'a Value)>,
+
+    /// Externally defined object identifiers.
+    oids: &'a BTreeMap<String, String>,
+
+    /// Object identifier values which have been resolved so far.
+    resolved_oids: BTreeMap<String, String>,
+
+    /// Types defined inline within other types, which are yet to be generated.
+    pending: Vec<(String, Type, Position)>,
+
+    /// Names of the generated `struct` and `enum` types.
+    nominal: BTreeSet<String>,
+
+    /// Names of the types used as `SET OF` elements, which need to impl `ValueOrd`.
+    set_elements: BTreeSet<String>,
+
+    /// Generated code.
+    out: String,
+}
+
+impl Generator<'_> {
+    /// Append a line of generated code.
+    fn line(&mut self, line: &str) {
+        self.out.push_str(line);
+        self.out.push('\n');
+    }
+
+    /// Generate the definition of a named type.
+    fn define(
+        &mut self,
+        rust_name: &str,
+        asn1_name: &str,
+        ty: &Type,
+        tag_default: TagDefault,
+        position: Position,
+    ) -> Result<(), Error> {
+        match ty {
+            Type::Sequence {
+                components,
+                extensible,
+            } => self.sequence(rust_name, asn1_name, components, *extensible, tag_default),
+            Type::Choice(alternatives) => {
+                self.choice(rust_name, asn1_name, alternatives, tag_default)
+            }
+            Type::Enumerated(items) => {
+                self.enumerated(rust_name, asn1_name, items, false, position)
+            }
+            Type::Integer { named_numbers, .. } if !named_numbers.is_empty() => {
+                self.enumerated(rust_name, asn1_name, named_numbers, true, position)
+            }
+            Type::BitString(named_bits) if !named_bits.is_empty() => {
+                self.bit_string(rust_name, asn1_name, named_bits);
+                Ok(())
+            }
+            Type::Tagged { .. } => Err(Error::unsupported(
+                position,
+                "tagged types which aren't components of a `SEQUENCE` or `CHOICE`",
+            )),
+            _ => {
+                let rust_type = self.rust_type(ty, &format!("{rust_name}Item"), position)?;
+                self.line("");
+                self.line(&format!("/// `{asn1_name}` type."));
+                self.line(&format!("pub type {rust_name} = {rust_type};"));
+                Ok(())
+            }
+        }
+    }
+
+    /// Generate a `struct` for a `SEQUENCE` type.
+    fn sequence(
+        &mut self,
+        rust_name: &str,
+        asn1_name: &str,
+        components: &[Component],
+        extensible: bool,
+        tag_default: TagDefault,
+    ) -> Result<(), Error> {
+        let mut fields = Vec::new();
+        let mut defaults = Vec::new();
+        let automatic = self.is_automatic(components, tag_default);
+
+        for (index, component) in components.iter().enumerate() {
+            let field_name = snake_case(&component.name);
+            let (tag, inner) = self.tag(component, index, tag_default, automatic);
+            let inline_name = format!("{rust_name}{}", upper_camel_case(&component.name));
+            let mut rust_type = self.rust_type(inner, &inline_name, component.position)?;
+
+            let mut attrs = self.tag_attrs(tag, tag_default);
+
+            if component.optional {
+                rust_type = format!("Option<{rust_type}>");
+
+                if tag.is_some() {
+                    attrs.push("optional = \"true\"".into());
+                }
+            }
+
+            if let Some(default) = &component.default {
+                let fn_name = format!(
+                    "{}_{}_default",
+                    snake_case(rust_name),
+                    field_name.trim_start_matches("r#")
+                );
+                let expr = self.default_expr(inner, &rust_type, default, component.position)?;
+                attrs.push(format!("default = \"{fn_name}\""));
+                defaults.push((fn_name, component.name.as_str(), rust_type.clone(), expr));
+            }
+
+            fields.push((component.name.as_str(), field_name, rust_type, attrs));
+        }
+
+        self.nominal.insert(rust_name.into());
+        self.line("");
+        self.line(&format!("/// `{asn1_name}` type."));
+        self.line("#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]");
+        self.container_attrs(tag_default, extensible);
+        self.line(&format!("pub struct {rust_name} {{"));
+
+        for (i, (asn1_name, field_name, rust_type, attrs)) in fields.iter().enumerate() {
+            if i > 0 {
+                self.line("");
+            }
+
+            self.line(&format!("    /// `{asn1_name}` component."));
+            self.attrs("    ", attrs);
+            self.line(&format!("    pub {field_name}: {rust_type},"));
+        }
+
+        self.line("}");
+
+        for (fn_name, asn1_name, rust_type, expr) in defaults {
+            self.line("");
+            self.line(&format!(
+                "/// Default value of the `{asn1_name}` component of [`{rust_name}`]."
+            ));
+            self.line(&format!("fn {fn_name}() -> {rust_type} {{"));
+            self.line(&format!("    {expr}"));
+            self.line("}");
+        }
+
+        Ok(())
+    }
+
+    /// Generate an `enum` for a `CHOICE` type.
+    fn choice(
+        &mut self,
+        rust_name: &str,
+        asn1_name: &str,
+        alternatives: &[Component],
+        tag_default: TagDefault,
+    ) -> Result<(), Error> {
+        let mut variants = Vec::new();
+        let automatic = self.is_automatic(alternatives, tag_default);
+
+        for (index, alternative) in alternatives.iter().enumerate() {
+            let variant_name = upper_camel_case(&alternative.name);
+            let (tag, inner) = self.tag(alternative, index, tag_default, automatic);
+            let inline_name = format!("{rust_name}{variant_name}");
+            let rust_type = self.rust_type(inner, &inline_name, alternative.position)?;
+
+            let mut attrs = self.tag_attrs(tag, tag_default);
+
+            match tag {
+                Some((_, TagMode::Implicit)) if self.is_constructed(inner) => {
+                    attrs.push("constructed = \"true\"".into());
+                }
+                None if self.is_untagged(inner) => {
+                    return Err(Error::unsupported(
+                        alternative.position,
+                        "untagged `CHOICE` and `ANY` alternatives of a `CHOICE`",
+                    ));
+                }
+                _ => (),
+            }
+
+            variants.push((alternative.name.as_str(), variant_name, rust_type, attrs));
+        }
+
+        self.nominal.insert(rust_name.into());
+        self.line("");
+        self.line(&format!("/// `{asn1_name}` type."));
+        self.line("#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]");
+        self.container_attrs(tag_default, false);
+        self.line(&format!("pub enum {rust_name} {{"));
+
+        for (i, (asn1_name, variant_name, rust_type, attrs)) in variants.iter().enumerate() {
+            if i > 0 {
+                self.line("");
+            }
+
+            self.line(&format!("    /// `{asn1_name}` alternative."));
+            self.attrs("    ", attrs);
+            self.line(&format!("    {variant_name}({rust_type}),"));
+        }
+
+        self.line("}");
+        Ok(())
+    }
+
+    /// Generate an `enum` for an `ENUMERATED` type or an `INTEGER` type with named numbers.
+    fn enumerated(
+        &mut self,
+        rust_name: &str,
+        asn1_name: &str,
+        items: &[(String, i64)],
+        integer: bool,
+        position: Position,
+    ) -> Result<(), Error> {
+        let max = items.iter().map(|(_, number)| *number).max().unwrap_or(0);
+
+        let repr = if items.iter().any(|(_, number)| *number < 0) {
+            return Err(Error::unsupported(position, "negative enumerated values"));
+        } else if max <= u8::MAX.into() {
+            "u8"
+        } else if max <= u16::MAX.into() {
+            "u16"
+        } else if max <= u32::MAX.into() {
+            "u32"
+        } else {
+            return Err(Error::unsupported(
+                position,
+                "enumerated values above `u32::MAX`",
+            ));
+        };
+
+        self.nominal.insert(rust_name.into());
+        self.line("");
+        self.line(&format!("/// `{asn1_name}` type."));
+        self.line("#[derive(Clone, Copy, Debug, Eq, PartialEq, der::Enumerated)]");
+
+        if integer {
+            self.line("#[asn1(type = \"INTEGER\")]");
+        }
+
+        self.line(&format!("#[repr({repr})]"));
+        self.line(&format!("pub enum {rust_name} {{"));
+
+        for (name, number) in items {
+            self.line(&format!("    /// `{name}` value."));
+            self.line(&format!("    {} = {number},", upper_camel_case(name)));
+        }
+
+        self.line("}");
+        Ok(())
+    }
+
+    /// Generate a `struct` for a `BIT STRING` type with named bits.
+    fn bit_string(&mut self, rust_name: &str, asn1_name: &str, named_bits: &[(String, u64)]) {
+        let len = named_bits.iter().map(|(_, bit)| *bit).max().unwrap_or(0) + 1;
+
+        self.nominal.insert(rust_name.into());
+        self.line("");
+        self.line(&format!("/// `{asn1_name}` type."));
+        self.line("///");
+        self.line(
+            "/// All bits are optional, so encodings which omit trailing zero bits can be decoded.",
+        );
+        self.line("#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, der::BitString)]");
+        self.line(&format!("pub struct {rust_name} {{"));
+
+        for bit in 0..len {
+            if bit > 0 {
+                self.line("");
+            }
+
+            match named_bits.iter().find(|(_, b)| *b == bit) {
+                Some((name, _)) => {
+                    self.line(&format!("    /// `{name}` bit."));
+                    self.line("    #[asn1(optional = \"true\")]");
+                    self.line(&format!("    pub {}: bool,", snake_case(name)));
+                }
+                None => {
+                    self.line(&format!("    /// Unnamed bit {bit}."));
+                    self.line("    #[asn1(optional = \"true\")]");
+                    self.line(&format!("    pub bit_{bit}: bool,"));
+                }
+            }
+        }
+
+        self.line("}");
+    }
+
+    /// Generate a constant for a value assignment.
+    fn value(
+        &mut self,
+        name: &str,
+        ty: &Type,
+        value: &Value,
+        position: Position,
+    ) -> Result<(), Error> {
+        let const_name = screaming_snake_case(name);
+
+        match (self.resolve(ty), value) {
+            (Type::ObjectIdentifier, _) => {
+                let oid = self.resolve_oid(name, position, &mut Vec::new())?;
+                self.line("");
+                self.line(&format!("/// `{name}` object identifier: `{oid}`."));
+                self.line(&format!(
+                    "pub const {const_name}: der::asn1::ObjectIdentifier =\n    der::asn1::ObjectIdentifier::new_unwrap(\"{oid}\");"
+                ));
+            }
+            (Type::Integer { .. }, Value::Integer(n)) => {
+                self.line("");
+                self.line(&format!("/// `{name}` value."));
+                self.line(&format!("pub const {const_name}: i64 = {n};"));
+            }
+            (Type::Boolean, Value::Boolean(b)) => {
+                self.line("");
+                self.line(&format!("/// `{name}` value."));
+                self.line(&format!("pub const {const_name}: bool = {b};"));
+            }
+            _ => {
+                return Err(Error::unsupported(
+                    position,
+                    format!("value assignment `{name}`"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the Rust type for the given ASN.1 type, queueing the generation of any types which
+    /// are defined inline using the given name.
+    fn rust_type(
+        &mut self,
+        ty: &Type,
+        inline_name: &str,
+        position: Position,
+    ) -> Result<String, Error> {
+        Ok(match ty {
+            Type::Boolean => "bool".into(),
+            Type::Integer {
+                named_numbers,
+                range,
+            } => {
+                if named_numbers.is_empty() {
+                    self.integer_type(range.as_ref(), position)?
+                } else {
+                    self.inline(inline_name, ty, position)
+                }
+            }
+            Type::BitString(named_bits) => {
+                if named_bits.is_empty() {
+                    "der::asn1::BitString".into()
+                } else {
+                    self.inline(inline_name, ty, position)
+                }
+            }
+            Type::OctetString => "der::asn1::OctetString".into(),
+            Type::Null => "der::asn1::Null".into(),
+            Type::ObjectIdentifier => "der::asn1::ObjectIdentifier".into(),
+            Type::Any => "der::asn1::Any".into(),
+            Type::Builtin(name) => BUILTIN_TYPES
+                .iter()
+                .find(|(builtin, _)| builtin == name)
+                .map(|(_, rust_type)| rust_type.to_string())
+                .ok_or_else(|| Error::unsupported(position, format!("`{name}` type")))?,
+            Type::Enumerated(_) | Type::Sequence { .. } | Type::Choice(_) => {
+                self.inline(inline_name, ty, position)
+            }
+            Type::SequenceOf(element) => {
+                let element = self.rust_type(element, &format!("{inline_name}Item"), position)?;
+                format!("Vec<{element}>")
+            }
+            Type::SetOf(element) => {
+                if let Some(name) = self.nominal_name(element, &format!("{inline_name}Item")) {
+                    self.set_elements.insert(name);
+                }
+
+                let element = self.rust_type(element, &format!("{inline_name}Item"), position)?;
+                format!("der::asn1::SetOfVec<{element}>")
+            }
+            Type::Tagged { .. } => {
+                return Err(Error::unsupported(
+                    position,
+                    "tagged types which aren't components of a `SEQUENCE` or `CHOICE`",
+                ));
+            }
+            Type::Reference(name) => upper_camel_case(name),
+        })
+    }
+
+    /// Queue the generation of a type which is defined inline, returning its name.
+    fn inline(&mut self, name: &str, ty: &Type, position: Position) -> String {
+        self.pending.push((name.into(), ty.clone(), position));
+        name.into()
+    }
+
+    /// Get the Rust type for an `INTEGER` with the given value range, which is the smallest
+    /// primitive integer type that can represent it, or `Int` if it's unbounded.
+    fn integer_type(&self, range: Option<&Range>, position: Position) -> Result<String, Error> {
+        let Some((Some(lower), Some(upper))) = range else {
+            return Ok("der::asn1::Int".into());
+        };
+
+        let lower = self.integer_value(lower, position)?;
+        let upper = self.integer_value(upper, position)?;
+
+        let types: &[(&str, i128, i128)] = if lower >= 0 {
+            &[
+                ("u8", 0, u8::MAX as i128),
+                ("u16", 0, u16::MAX as i128),
+                ("u32", 0, u32::MAX as i128),
+                ("u64", 0, u64::MAX as i128),
+            ]
+        } else {
+            &[
+                ("i8", i8::MIN as i128, i8::MAX as i128),
+                ("i16", i16::MIN as i128, i16::MAX as i128),
+                ("i32", i32::MIN as i128, i32::MAX as i128),
+                ("i64", i64::MIN as i128, i64::MAX as i128),
+            ]
+        };
+
+        Ok(types
+            .iter()
+            .find(|(_, min, max)| i128::from(lower) >= *min && i128::from(upper) <= *max)
+            .map(|(name, _, _)| name.to_string())
+            .unwrap_or_else(|| "der::asn1::Int".into()))
+    }
+
+    /// Resolve an integer value, which is either a literal or a reference to an `INTEGER`
+    /// value assignment.
+    fn integer_value(&self, value: &Value, position: Position) -> Result<i64, Error> {
+        match value {
+            Value::Integer(n) => Ok(*n),
+            Value::Identifier(name) => match self.values.get(name.as_str()) {
+                Some((_, Value::Integer(n))) => Ok(*n),
+                _ => Err(Error::undefined(position, name)),
+            },
+            _ => Err(Error::unsupported(position, "non-integer value")),
+        }
+    }
+
+    /// Get the Rust expression for the default value of a component.
+    fn default_expr(
+        &self,
+        ty: &Type,
+        rust_type: &str,
+        value: &Value,
+        position: Position,
+    ) -> Result<String, Error> {
+        match (self.resolve(ty), value) {
+            (Type::Boolean, Value::Boolean(b)) => Ok(b.to_string()),
+            (Type::Integer { named_numbers, .. }, Value::Identifier(name))
+                if named_numbers.iter().any(|(n, _)| n == name) =>
+            {
+                Ok(format!("{rust_type}::{}", upper_camel_case(name)))
+            }
+            (Type::Enumerated(items), Value::Identifier(name))
+                if items.iter().any(|(n, _)| n == name) =>
+            {
+                Ok(format!("{rust_type}::{}", upper_camel_case(name)))
+            }
+            (Type::Integer { .. }, value @ (Value::Integer(_) | Value::Identifier(_))) => {
+                let n = self.integer_value(value, position)?;
+
+                if rust_type == "der::asn1::Int" {
+                    Ok(format!(
+                        "der::asn1::Int::try_from({n}i64).expect(\"valid INTEGER\")"
+                    ))
+                } else {
+                    Ok(n.to_string())
+                }
+            }
+            _ => Err(Error::unsupported(position, "`DEFAULT` value")),
+        }
+    }
+
+    /// Get the tag of a component along with the type being tagged, taking the module's
+    /// tagging default and automatic tagging into account.
+    fn tag<'t>(
+        &self,
+        component: &'t Component,
+        index: usize,
+        tag_default: TagDefault,
+        automatic: bool,
+    ) -> (Option<(u64, TagMode)>, &'t Type) {
+        let (number, mode, inner) = match &component.ty {
+            Type::Tagged {
+                number,
+                mode,
+                inner,
+            } => (*number, *mode, inner.as_ref()),
+            ty if automatic => (index as u64, None, ty),
+            ty => return (None, ty),
+        };
+
+        // Tags on `CHOICE` and `ANY` types are always explicit (X.680 Section 31.2.7)
+        let mode = if self.is_untagged(inner) {
+            TagMode::Explicit
+        } else {
+            mode.unwrap_or(default_tag_mode(tag_default))
+        };
+
+        (Some((number, mode)), inner)
+    }
+
+    /// Get the `#[asn1(...)]` attributes for a tag.
+    fn tag_attrs(&self, tag: Option<(u64, TagMode)>, tag_default: TagDefault) -> Vec<String> {
+        let Some((number, mode)) = tag else {
+            return Vec::new();
+        };
+
+        let mut attrs = vec![format!("context_specific = \"{number}\"")];
+
+        if mode != default_tag_mode(tag_default) {
+            attrs.push(match mode {
+                TagMode::Explicit => "tag_mode = \"EXPLICIT\"".into(),
+                TagMode::Implicit => "tag_mode = \"IMPLICIT\"".into(),
+            });
+        }
+
+        attrs
+    }
+
+    /// Emit the container attributes for the given module tagging default and extensibility.
+    fn container_attrs(&mut self, tag_default: TagDefault, extensible: bool) {
+        let mut attrs = Vec::new();
+
+        if tag_default != TagDefault::Explicit {
+            attrs.push("tag_mode = \"IMPLICIT\"".to_string());
+        }
+
+        if extensible {
+            attrs.push("extensible = \"true\"".to_string());
+        }
+
+        self.attrs("", &attrs);
+    }
+
+    /// Emit field or variant attributes.
+    fn attrs(&mut self, indent: &str, attrs: &[String]) {
+        if !attrs.is_empty() {
+            self.line(&format!("{indent}#[asn1({})]", attrs.join(", ")));
+        }
+    }
+
+    /// Are automatic tags applied to the given components (X.680 Section 25.3)?
+    fn is_automatic(&self, components: &[Component], tag_default: TagDefault) -> bool {
+        tag_default == TagDefault::Automatic
+            && !components
+                .iter()
+                .any(|component| matches!(component.ty, Type::Tagged { .. }))
+    }
+
+    /// Follow type references until reaching a type which isn't a reference.
+    fn resolve<'t>(&'t self, mut ty: &'t Type) -> &'t Type {
+        let mut depth = 0;
+
+        while let Type::Reference(name) = ty {
+            match self.types.get(name.as_str()) {
+                Some(referenced) if depth < self.types.len() => {
+                    ty = referenced;
+                    depth += 1;
+                }
+                _ => break,
+            }
+        }
+
+        ty
+    }
+
+    /// Is the given type a `CHOICE` or `ANY`, which don't have tags of their own?
+    fn is_untagged(&self, ty: &Type) -> bool {
+        matches!(self.resolve(ty), Type::Choice(_) | Type::Any)
+    }
+
+    /// Is the given type encoded using the constructed form?
+    fn is_constructed(&self, ty: &Type) -> bool {
+        matches!(
+            self.resolve(ty),
+            Type::Sequence { .. } | Type::SequenceOf(_) | Type::SetOf(_)
+        )
+    }
+
+    /// Get the name of the generated `struct` or `enum` for a type, if any.
+    fn nominal_name(&self, ty: &Type, inline_name: &str) -> Option<String> {
+        match ty {
+            Type::Reference(name) => match self.types.get(name.as_str()) {
+                Some(Type::Reference(_)) => {
+                    self.nominal_name(self.types[name.as_str()], inline_name)
+                }
+                Some(_) => Some(upper_camel_case(name)),
+                None => None,
+            },
+            Type::Sequence { .. } | Type::Choice(_) | Type::Enumerated(_) => {
+                Some(inline_name.into())
+            }
+            Type::Integer { named_numbers, .. } if !named_numbers.is_empty() => {
+                Some(inline_name.into())
+            }
+            Type::BitString(named_bits) if !named_bits.is_empty() => Some(inline_name.into()),
+            _ => None,
+        }
+    }
+
+    /// Resolve an object identifier value assignment into its dotted form.
+    fn resolve_oid(
+        &mut self,
+        name: &str,
+        position: Position,
+        visiting: &mut Vec<String>,
+    ) -> Result<String, Error> {
+        if let Some(oid) = self.resolved_oids.get(name).or_else(|| self.oids.get(name)) {
+            return Ok(oid.clone());
+        }
+
+        let Some((_, Value::ObjectIdentifier(components))) = self.values.get(name).copied() else {
+            return Err(Error::undefined(position, name));
+        };
+
+        if visiting.iter().any(|n| n == name) {
+            return Err(Error::syntax(
+                position,
+                format!("`{name}` is defined in terms of itself"),
+            ));
+        }
+
+        visiting.push(name.into());
+        let mut arcs: Vec<String> = Vec::new();
+
+        for (i, component) in components.iter().enumerate() {
+            match component {
+                OidComponent::Number(n) => arcs.push(n.to_string()),
+                OidComponent::Reference(reference) => {
+                    let named_arc = match (i, arcs.first().map(String::as_str)) {
+                        (0, _) => ROOT_ARCS.iter().find(|(n, _)| n == reference),
+                        (1, Some("1")) => ISO_ARCS.iter().find(|(n, _)| n == reference),
+                        (1, Some("0")) => ITU_T_ARCS.iter().find(|(n, _)| n == reference),
+                        _ => None,
+                    };
+
+                    match named_arc {
+                        Some((_, arc)) if !self.values.contains_key(reference.as_str()) => {
+                            arcs.push(arc.to_string());
+                        }
+                        _ if i == 0 => {
+                            arcs.push(self.resolve_oid(reference, position, visiting)?);
+                        }
+                        _ => return Err(Error::undefined(position, reference)),
+                    }
+                }
+            }
+        }
+
+        visiting.pop();
+
+        let oid = arcs.join(".");
+        let mut iter = oid
+            .split('.')
+            .map(|arc| arc.parse::<u64>().unwrap_or(u64::MAX));
+        let valid = match (iter.next(), iter.next()) {
+            (Some(first), Some(second)) => first <= 2 && (first == 2 || second < 40),
+            _ => false,
+        };
+
+        if !valid {
+            return Err(Error::syntax(
+                position,
+                format!("`{name}` is not a valid object identifier: `{oid}`"),
+            ));
+        }
+
+        self.resolved_oids.insert(name.into(), oid.clone());
+        Ok(oid)
+    }
+}
+
+/// Tagging mode of tags which don't specify one, under the given module tagging default.
+fn default_tag_mode(tag_default: TagDefault) -> TagMode {
+    match tag_default {
+        TagDefault::Explicit => TagMode::Explicit,
+        TagDefault::Implicit | TagDefault::Automatic => TagMode::Implicit,
+    }
+}
@@ -0,0 +1,316 @@
+//! Lexical analysis of ASN.1 module text (X.680 Section 12).
+
+use crate::{Error, Position};
+
+/// Lexical items.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Token {
+    /// Type reference, value reference, identifier or keyword.
+    Word(String),
+
+    /// Non-negative number.
+    Number(u64),
+
+    /// Quoted character string.
+    String(String),
+
+    /// Binary or hexadecimal string, e.g. `'0101'B` or `'CAFE'H`, converted to bits.
+    Bits(String),
+
+    /// Punctuation, e.g. `::=`, `{`, `..` or `...`.
+    Symbol(&'static str),
+}
+
+/// Symbols, longest first so they're matched greedily.
+const SYMBOLS: &[&str] = &[
+    "::=", "...", "[[", "]]", "..", "{", "}", "(", ")", "[", "]", ",", ";", "|", "-", "<", "@",
+    "!", "^", ":", ".", "&",
+];
+
+/// Split the given module text into tokens along with their positions.
+pub(crate) fn tokenize(input: &str) -> Result<Vec<(Token, Position)>, Error> {
+    let mut lexer = Lexer {
+        input,
+        offset: 0,
+        position: Position { line: 1, column: 1 },
+    };
+
+    let mut tokens = Vec::new();
+
+    while let Some(token) = lexer.next_token()? {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Lexer state.
+struct Lexer<'a> {
+    /// Module text.
+    input: &'a str,
+
+    /// Offset of the next character.
+    offset: usize,
+
+    /// Position of the next character.
+    position: Position,
+}
+
+impl Lexer<'_> {
+    /// Read the next token, skipping any whitespace and comments.
+    fn next_token(&mut self) -> Result<Option<(Token, Position)>, Error> {
+        self.skip_whitespace_and_comments()?;
+
+        let position = self.position;
+        let Some(c) = self.peek() else {
+            return Ok(None);
+        };
+
+        let token = if c.is_ascii_alphabetic() {
+            Token::Word(self.word())
+        } else if c.is_ascii_digit() {
+            let digits = self.take_while(|c| c.is_ascii_digit());
+            let number = digits
+                .parse()
+                .map_err(|_| Error::syntax(position, "number is too large"))?;
+            Token::Number(number)
+        } else if c == '"' {
+            Token::String(self.string(position)?)
+        } else if c == '\'' {
+            Token::Bits(self.bits(position)?)
+        } else if let Some(symbol) = SYMBOLS.iter().find(|s| self.rest().starts_with(**s)) {
+            for _ in 0..symbol.len() {
+                self.bump();
+            }
+            Token::Symbol(symbol)
+        } else {
+            return Err(Error::syntax(
+                position,
+                format!("unexpected character `{c}`"),
+            ));
+        };
+
+        Ok(Some((token, position)))
+    }
+
+    /// Remaining input.
+    fn rest(&self) -> &str {
+        &self.input[self.offset..]
+    }
+
+    /// Peek at the next character.
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Consume the next character.
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+
+        if c == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+
+        Some(c)
+    }
+
+    /// Consume characters while they match the given predicate.
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &str {
+        let start = self.offset;
+
+        while self.peek().is_some_and(&f) {
+            self.bump();
+        }
+
+        &self.input[start..self.offset]
+    }
+
+    /// Skip over whitespace, `--` comments and `/* */` comments.
+    fn skip_whitespace_and_comments(&mut self) -> Result<(), Error> {
+        loop {
+            self.take_while(char::is_whitespace);
+
+            if self.rest().starts_with("--") {
+                // Comments end at the next `--` or the end of the line
+                self.bump();
+                self.bump();
+
+                while let Some(c) = self.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+
+                    if self.rest().starts_with("--") {
+                        self.bump();
+                        self.bump();
+                        break;
+                    }
+
+                    self.bump();
+                }
+            } else if self.rest().starts_with("/*") {
+                let position = self.position;
+                let mut depth = 0usize;
+
+                loop {
+                    if self.rest().starts_with("/*") {
+                        depth += 1;
+                        self.bump();
+                        self.bump();
+                    } else if self.rest().starts_with("*/") {
+                        depth -= 1;
+                        self.bump();
+                        self.bump();
+
+                        if depth == 0 {
+                            break;
+                        }
+                    } else if self.bump().is_none() {
+                        return Err(Error::syntax(position, "unterminated comment"));
+                    }
+                }
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Read a word, which consists of letters, digits and single hyphens (X.680 Section 12.2).
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+
+        while let Some(c) = self.peek() {
+            let rest = self.rest();
+            let is_hyphen = c == '-'
+                && !rest.starts_with("--")
+                && rest[1..].starts_with(|c: char| c.is_ascii_alphanumeric());
+
+            if c.is_ascii_alphanumeric() || is_hyphen {
+                word.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+
+        word
+    }
+
+    /// Read a quoted character string, in which `""` represents a quotation mark.
+    fn string(&mut self, position: Position) -> Result<String, Error> {
+        self.bump();
+        let mut string = String::new();
+
+        loop {
+            match self.bump() {
+                Some('"') if self.peek() == Some('"') => {
+                    self.bump();
+                    string.push('"');
+                }
+                Some('"') => return Ok(string),
+                Some(c) => string.push(c),
+                None => return Err(Error::syntax(position, "unterminated string")),
+            }
+        }
+    }
+
+    /// Read a binary (`'...'B`) or hexadecimal (`'...'H`) string, returning its bits.
+    fn bits(&mut self, position: Position) -> Result<String, Error> {
+        self.bump();
+        let digits: String = self
+            .take_while(|c| c != '\'')
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+
+        if self.bump() != Some('\'') {
+            return Err(Error::syntax(position, "unterminated string"));
+        }
+
+        match self.bump() {
+            Some('B') if digits.chars().all(|c| c == '0' || c == '1') => Ok(digits),
+            Some('H') if digits.chars().all(|c| c.is_ascii_hexdigit()) => Ok(digits
+                .chars()
+                .filter_map(|c| c.to_digit(16))
+                .map(|d| format!("{d:04b}"))
+                .collect()),
+            _ => Err(Error::syntax(
+                position,
+                "invalid binary or hexadecimal string",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Token, tokenize};
+
+    fn tokens(input: &str) -> Vec<Token> {
+        tokenize(input)
+            .unwrap()
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    #[test]
+    fn words_and_symbols() {
+        assert_eq!(
+            tokens("id-pkix OBJECT IDENTIFIER ::= { 1 3 } -- comment\n(1..MAX, ...)"),
+            [
+                Token::Word("id-pkix".into()),
+                Token::Word("OBJECT".into()),
+                Token::Word("IDENTIFIER".into()),
+                Token::Symbol("::="),
+                Token::Symbol("{"),
+                Token::Number(1),
+                Token::Number(3),
+                Token::Symbol("}"),
+                Token::Symbol("("),
+                Token::Number(1),
+                Token::Symbol(".."),
+                Token::Word("MAX".into()),
+                Token::Symbol(","),
+                Token::Symbol("..."),
+                Token::Symbol(")"),
+            ]
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            tokens("a -- inline -- b /* block /* nested */ */ c--\nd"),
+            [
+                Token::Word("a".into()),
+                Token::Word("b".into()),
+                Token::Word("c".into()),
+                Token::Word("d".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(
+            tokens(r#""say ""hi""" '0A'H '101'B"#),
+            [
+                Token::String("say \"hi\"".into()),
+                Token::Bits("00001010".into()),
+                Token::Bits("101".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn positions() {
+        let tokens = tokenize("A ::=\n  B").unwrap();
+        assert_eq!((tokens[2].1.line, tokens[2].1.column), (2, 3));
+    }
+}
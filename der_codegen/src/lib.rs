@@ -0,0 +1,218 @@
+#![doc = include_str!("../README.md")]
+#![forbid(unsafe_code)]
+#![warn(
+    clippy::unwrap_used,
+    missing_docs,
+    rust_2018_idioms,
+    unused_lifetimes,
+    unused_qualifications
+)]
+
+//! ## Usage
+//!
+//! From a `build.rs` script, compile the module into `OUT_DIR` and then `include!` the output
+//! into a module of the crate:
+//!
+//! ```no_run
+//! use std::{env, path::Path};
+//!
+//! fn main() -> Result<(), der_codegen::Error> {
+//!     let out_dir = env::var("OUT_DIR").expect("OUT_DIR should be set by Cargo");
+//!
+//!     der_codegen::Compiler::new()
+//!         .oid("id-etsi-qcs", "0.4.0.1862.1")
+//!         .compile_file("asn1/ETSI-QCStatements.asn", Path::new(&out_dir).join("qcs.rs"))?;
+//!
+//!     println!("cargo:rerun-if-changed=asn1/ETSI-QCStatements.asn");
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ```ignore
+//! pub mod qcs {
+//!     include!(concat!(env!("OUT_DIR"), "/qcs.rs"));
+//! }
+//! ```
+//!
+//! The generated code requires the `alloc`, `derive` and `oid` features of the `der` crate.
+//!
+//! ## Supported ASN.1
+//!
+//! The supported subset of X.680 is the one commonly used by RFCs and similar specifications:
+//!
+//! - `EXPLICIT`, `IMPLICIT` and `AUTOMATIC` tagging defaults
+//! - `SEQUENCE` types with `OPTIONAL` and `DEFAULT` components, mapped to structs, which skip
+//!   unknown extension additions if the `SEQUENCE` contains an extension marker
+//! - `CHOICE` types, mapped to enums
+//! - `ENUMERATED` and `INTEGER` types with named numbers, mapped to C-like enums
+//! - `BIT STRING` types with named bits, mapped to structs of `bool` fields
+//! - `SEQUENCE OF` and `SET OF` types, mapped to `Vec` and `SetOfVec`
+//! - context-specific tags
+//! - `OBJECT IDENTIFIER` and `INTEGER` value assignments, mapped to constants
+//!
+//! Types defined inline within another type are given a name made from the enclosing type and
+//! the component name, e.g. a `CHOICE` for the `signer` component of `Request` is generated as
+//! `RequestSigner`.
+//!
+//! `IMPORTS` are skipped, so imported types must be in scope where the generated code is
+//! included, and imported object identifiers must be provided using [`Compiler::oid`].
+//! Constraints other than value ranges of `INTEGER` types are ignored, as are extension markers
+//! in `CHOICE` and `ENUMERATED` types.
+//!
+//! Anything else, e.g. information object classes and parameterized types, results in an
+//! [`Error::Unsupported`] pointing at the offending definition.
+
+mod ast;
+mod codegen;
+mod lexer;
+mod naming;
+mod parser;
+
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+/// ASN.1 module compiler.
+#[derive(Clone, Debug, Default)]
+pub struct Compiler {
+    /// Object identifiers defined outside the compiled modules.
+    oids: BTreeMap<String, String>,
+}
+
+impl Compiler {
+    /// Create a new compiler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Define the value of an object identifier which is referenced but not defined by the
+    /// compiled modules, e.g. because it's imported from another module.
+    ///
+    /// The value is given in dotted form, e.g. `1.3.6.1.5.5.7`.
+    pub fn oid(mut self, name: impl Into<String>, dotted: impl Into<String>) -> Self {
+        self.oids.insert(name.into(), dotted.into());
+        self
+    }
+
+    /// Compile the given ASN.1 module text, which may contain several modules, into Rust
+    /// source code.
+    pub fn compile(&self, input: &str) -> Result<String, Error> {
+        let modules = parser::parse(input)?;
+        codegen::generate(&modules, &self.oids)
+    }
+
+    /// Compile the ASN.1 modules in the file at `input`, writing the Rust source code to the
+    /// file at `output`.
+    pub fn compile_file(
+        &self,
+        input: impl AsRef<Path>,
+        output: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        let input = fs::read_to_string(input)?;
+        fs::write(output, self.compile(&input)?)?;
+        Ok(())
+    }
+}
+
+/// Position within ASN.1 module text.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Position {
+    /// Line number, starting at 1.
+    pub line: usize,
+
+    /// Column number, starting at 1.
+    pub column: usize,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Compiler errors.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// I/O error reading or writing a file.
+    Io(io::Error),
+
+    /// Module text is not valid ASN.1.
+    Syntax {
+        /// Position of the error.
+        position: Position,
+
+        /// Description of the error.
+        message: String,
+    },
+
+    /// Module uses ASN.1 features which aren't supported.
+    Unsupported {
+        /// Position of the unsupported definition.
+        position: Position,
+
+        /// Description of what isn't supported.
+        message: String,
+    },
+
+    /// Reference to a type or value which isn't defined.
+    Undefined {
+        /// Position of the reference.
+        position: Position,
+
+        /// Name of the undefined type or value.
+        name: String,
+    },
+}
+
+impl Error {
+    /// Create a new [`Error::Syntax`].
+    pub(crate) fn syntax(position: Position, message: impl Into<String>) -> Self {
+        Error::Syntax {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Create a new [`Error::Unsupported`].
+    pub(crate) fn unsupported(position: Position, message: impl Into<String>) -> Self {
+        Error::Unsupported {
+            position,
+            message: message.into(),
+        }
+    }
+
+    /// Create a new [`Error::Undefined`].
+    pub(crate) fn undefined(position: Position, name: impl Into<String>) -> Self {
+        Error::Undefined {
+            position,
+            name: name.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {err}"),
+            Error::Syntax { position, message } => write!(f, "{position}: {message}"),
+            Error::Unsupported { position, message } => {
+                write!(f, "{position}: unsupported: {message}")
+            }
+            Error::Undefined { position, name } => write!(f, "{position}: undefined: `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::Io(err)
+    }
+}
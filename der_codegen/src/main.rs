@@ -0,0 +1,55 @@
+//! Command-line interface to the ASN.1 module compiler.
+//!
+//! Usage: `der_codegen [--oid NAME=DOTTED]... FILE`
+//!
+//! Compiles the ASN.1 modules in the given file and prints the generated Rust code.
+
+use std::{env, fs, process::ExitCode};
+
+fn main() -> ExitCode {
+    let mut compiler = der_codegen::Compiler::new();
+    let mut file = None;
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        if arg == "--oid" {
+            match args.next().as_deref().and_then(|oid| oid.split_once('=')) {
+                Some((name, dotted)) => compiler = compiler.oid(name, dotted),
+                None => return usage(),
+            }
+        } else if arg.starts_with('-') || file.is_some() {
+            return usage();
+        } else {
+            file = Some(arg);
+        }
+    }
+
+    let Some(file) = file else {
+        return usage();
+    };
+
+    let input = match fs::read_to_string(&file) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {file}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match compiler.compile(&input) {
+        Ok(output) => {
+            print!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {file}:{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print usage information.
+fn usage() -> ExitCode {
+    eprintln!("usage: der_codegen [--oid NAME=DOTTED]... FILE");
+    ExitCode::FAILURE
+}
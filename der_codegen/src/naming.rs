@@ -0,0 +1,119 @@
+//! Conversion of ASN.1 names into Rust identifiers.
+
+/// Rust keywords, which can be used as identifiers in their raw form.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords which can't be used as raw identifiers.
+const RESERVED: &[&str] = &["crate", "self", "super", "Self"];
+
+/// Convert an ASN.1 name into an `UpperCamelCase` type or variant name, e.g. `TBSCertificate`
+/// becomes `TbsCertificate`.
+pub(crate) fn upper_camel_case(name: &str) -> String {
+    let name: String = words(name).iter().map(|word| capitalize(word)).collect();
+    escape(name)
+}
+
+/// Convert an ASN.1 name into a `snake_case` field or function name, e.g. `issuerUniqueID`
+/// becomes `issuer_unique_id`.
+pub(crate) fn snake_case(name: &str) -> String {
+    escape(words(name).join("_"))
+}
+
+/// Convert an ASN.1 name into a `SCREAMING_SNAKE_CASE` constant name, e.g. `id-pkix` becomes
+/// `ID_PKIX`.
+pub(crate) fn screaming_snake_case(name: &str) -> String {
+    escape(words(name).join("_").to_ascii_uppercase())
+}
+
+/// Split a name into lowercase words at hyphens and case boundaries, keeping acronyms and any
+/// trailing digits together, e.g. `UTF8String` becomes `["utf8", "string"]`.
+///
+/// A single lowercase letter followed by an acronym is treated as part of the acronym, as in
+/// `cRLSign` or `iPAddress`.
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+
+    for part in name.split('-').filter(|part| !part.is_empty()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+
+        for (i, &c) in chars.iter().enumerate() {
+            if i > 0 && c.is_ascii_uppercase() {
+                let prev = chars[i - 1];
+                let next_is_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+                let lowercase_prefix = i == 1 && !next_is_lower;
+
+                if (!prev.is_ascii_uppercase() && !lowercase_prefix) || next_is_lower {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+
+            word.push(c.to_ascii_lowercase());
+        }
+
+        words.push(word);
+    }
+
+    words
+}
+
+/// Uppercase the first character of a word.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Escape identifiers which are Rust keywords.
+fn escape(name: String) -> String {
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("r#{name}")
+    } else if RESERVED.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{screaming_snake_case, snake_case, upper_camel_case};
+
+    #[test]
+    fn type_names() {
+        assert_eq!(upper_camel_case("TBSCertificate"), "TbsCertificate");
+        assert_eq!(upper_camel_case("RDNSequence"), "RdnSequence");
+        assert_eq!(upper_camel_case("UTF8String"), "Utf8String");
+        assert_eq!(upper_camel_case("DSS-Parms"), "DssParms");
+        assert_eq!(upper_camel_case("v1"), "V1");
+        assert_eq!(upper_camel_case("self"), "Self_");
+    }
+
+    #[test]
+    fn field_names() {
+        assert_eq!(snake_case("tbsCertificate"), "tbs_certificate");
+        assert_eq!(snake_case("issuerUniqueID"), "issuer_unique_id");
+        assert_eq!(snake_case("extnID"), "extn_id");
+        assert_eq!(snake_case("cRLSign"), "crl_sign");
+        assert_eq!(snake_case("iPAddress"), "ip_address");
+        assert_eq!(snake_case("aValue"), "a_value");
+        assert_eq!(snake_case("type"), "r#type");
+        assert_eq!(snake_case("self"), "self_");
+    }
+
+    #[test]
+    fn constant_names() {
+        assert_eq!(screaming_snake_case("id-pkix"), "ID_PKIX");
+        assert_eq!(screaming_snake_case("ub-common-name"), "UB_COMMON_NAME");
+        assert_eq!(screaming_snake_case("id-ce-keyUsage"), "ID_CE_KEY_USAGE");
+    }
+}
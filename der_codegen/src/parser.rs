@@ -0,0 +1,811 @@
+//! Recursive descent parser for the supported subset of X.680.
+
+use crate::{
+    Error, Position,
+    ast::{Assignment, Component, Module, OidComponent, Range, TagDefault, TagMode, Type, Value},
+    lexer::{Token, tokenize},
+};
+
+/// Built-in character string, time and other types which are referenced by name.
+const BUILTIN_TYPES: &[&str] = &[
+    "BMPString",
+    "GeneralString",
+    "GeneralizedTime",
+    "GraphicString",
+    "IA5String",
+    "ISO646String",
+    "NumericString",
+    "PrintableString",
+    "T61String",
+    "TeletexString",
+    "UTCTime",
+    "UTF8String",
+    "UniversalString",
+    "VideotexString",
+    "VisibleString",
+];
+
+/// Built-in types which aren't supported.
+const UNSUPPORTED_TYPES: &[&str] = &[
+    "CHARACTER",
+    "DATE",
+    "DATE-TIME",
+    "DURATION",
+    "EMBEDDED",
+    "EXTERNAL",
+    "INSTANCE",
+    "OID-IRI",
+    "ObjectDescriptor",
+    "REAL",
+    "RELATIVE-OID",
+    "RELATIVE-OID-IRI",
+    "TIME",
+    "TIME-OF-DAY",
+    "TYPE-IDENTIFIER",
+];
+
+/// Parse all of the modules in the given source text.
+pub(crate) fn parse(input: &str) -> Result<Vec<Module>, Error> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        index: 0,
+    };
+
+    let mut modules = Vec::new();
+
+    while parser.peek().is_some() {
+        modules.push(parser.module()?);
+    }
+
+    Ok(modules)
+}
+
+/// Parser state.
+struct Parser {
+    /// Tokens along with their positions.
+    tokens: Vec<(Token, Position)>,
+
+    /// Index of the next token.
+    index: usize,
+}
+
+impl Parser {
+    /// Peek at the next token.
+    fn peek(&self) -> Option<&Token> {
+        self.peek_nth(0)
+    }
+
+    /// Peek at the token `n` tokens ahead of the next one.
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.index + n).map(|(token, _)| token)
+    }
+
+    /// Position of the next token, or of the last token at the end of the input.
+    fn position(&self) -> Position {
+        self.tokens
+            .get(self.index)
+            .or_else(|| self.tokens.last())
+            .map(|(_, position)| *position)
+            .unwrap_or(Position { line: 1, column: 1 })
+    }
+
+    /// Consume the next token.
+    fn next(&mut self) -> Result<Token, Error> {
+        let token = self
+            .peek()
+            .cloned()
+            .ok_or_else(|| Error::syntax(self.position(), "unexpected end of input"))?;
+
+        self.index += 1;
+        Ok(token)
+    }
+
+    /// Is the next token the given symbol?
+    fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol)
+    }
+
+    /// Is the next token the given word?
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w == word)
+    }
+
+    /// Consume the next token if it's the given symbol.
+    fn eat_symbol(&mut self, symbol: &str) -> bool {
+        let matched = self.is_symbol(symbol);
+        if matched {
+            self.index += 1;
+        }
+        matched
+    }
+
+    /// Consume the next token if it's the given word.
+    fn eat_word(&mut self, word: &str) -> bool {
+        let matched = self.is_word(word);
+        if matched {
+            self.index += 1;
+        }
+        matched
+    }
+
+    /// Consume the given symbol, returning an error if the next token is anything else.
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), Error> {
+        if self.eat_symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{symbol}`")))
+        }
+    }
+
+    /// Consume the given word, returning an error if the next token is anything else.
+    fn expect_word(&mut self, word: &str) -> Result<(), Error> {
+        if self.eat_word(word) {
+            Ok(())
+        } else {
+            Err(self.unexpected(&format!("`{word}`")))
+        }
+    }
+
+    /// Consume a word, returning an error if the next token is anything else.
+    fn word(&mut self) -> Result<String, Error> {
+        match self.peek() {
+            Some(Token::Word(word)) => {
+                let word = word.clone();
+                self.index += 1;
+                Ok(word)
+            }
+            _ => Err(self.unexpected("a name")),
+        }
+    }
+
+    /// Consume a number, which may be negative.
+    fn number(&mut self) -> Result<i64, Error> {
+        let position = self.position();
+        let negative = self.eat_symbol("-");
+
+        match self.next()? {
+            Token::Number(n) => {
+                let n = i64::try_from(n)
+                    .map_err(|_| Error::unsupported(position, "number is too large"))?;
+                Ok(if negative { -n } else { n })
+            }
+            _ => Err(Error::syntax(position, "expected a number")),
+        }
+    }
+
+    /// Error for an unexpected token.
+    fn unexpected(&self, expected: &str) -> Error {
+        let found = match self.peek() {
+            Some(Token::Word(word)) => format!("`{word}`"),
+            Some(Token::Number(n)) => format!("`{n}`"),
+            Some(Token::String(_)) => "a string".into(),
+            Some(Token::Bits(_)) => "a binary or hexadecimal string".into(),
+            Some(Token::Symbol(symbol)) => format!("`{symbol}`"),
+            None => "end of input".into(),
+        };
+
+        Error::syntax(
+            self.position(),
+            format!("expected {expected}, found {found}"),
+        )
+    }
+
+    /// Skip over a group delimited by the given symbols, e.g. `{ ... }`, including any nested
+    /// groups.
+    fn skip_group(&mut self, open: &str, close: &str) -> Result<(), Error> {
+        self.expect_symbol(open)?;
+        let mut depth = 1usize;
+
+        while depth > 0 {
+            match self.next()? {
+                Token::Symbol(s) if s == open => depth += 1,
+                Token::Symbol(s) if s == close => depth -= 1,
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Skip tokens up to and including the next `;`.
+    fn skip_statement(&mut self) -> Result<(), Error> {
+        while self.next()? != Token::Symbol(";") {}
+        Ok(())
+    }
+
+    /// `ModuleDefinition` (X.680 Section 13.1).
+    fn module(&mut self) -> Result<Module, Error> {
+        let name = self.word()?;
+
+        // `DefinitiveIdentification` isn't used
+        if self.is_symbol("{") {
+            self.skip_group("{", "}")?;
+        }
+
+        self.expect_word("DEFINITIONS")?;
+
+        let tag_default = if self.eat_word("EXPLICIT") {
+            TagDefault::Explicit
+        } else if self.eat_word("IMPLICIT") {
+            TagDefault::Implicit
+        } else if self.eat_word("AUTOMATIC") {
+            TagDefault::Automatic
+        } else {
+            TagDefault::Explicit
+        };
+
+        if tag_default != TagDefault::Explicit || self.is_word("TAGS") {
+            self.expect_word("TAGS")?;
+        }
+
+        if self.eat_word("EXTENSIBILITY") {
+            self.expect_word("IMPLIED")?;
+        }
+
+        self.expect_symbol("::=")?;
+        self.expect_word("BEGIN")?;
+
+        // Imported types are expected to be in scope where the generated code is used
+        if self.eat_word("EXPORTS") {
+            self.skip_statement()?;
+        }
+
+        if self.eat_word("IMPORTS") {
+            self.skip_statement()?;
+        }
+
+        let mut assignments = Vec::new();
+
+        while !self.eat_word("END") {
+            assignments.push(self.assignment()?);
+        }
+
+        Ok(Module {
+            name,
+            tag_default,
+            assignments,
+        })
+    }
+
+    /// Type or value assignment (X.680 Sections 16.1 and 16.2).
+    fn assignment(&mut self) -> Result<Assignment, Error> {
+        let position = self.position();
+        let name = self.word()?;
+
+        if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+            if self.is_symbol("{") {
+                return Err(Error::unsupported(
+                    position,
+                    format!("parameterized type `{name}`"),
+                ));
+            }
+
+            if !self.is_symbol("::=") {
+                return Err(Error::unsupported(
+                    position,
+                    format!("information object or object set `{name}`"),
+                ));
+            }
+
+            self.expect_symbol("::=")?;
+
+            if self.is_word("CLASS") {
+                return Err(Error::unsupported(
+                    position,
+                    format!("information object class `{name}`"),
+                ));
+            }
+
+            let ty = self.ty()?;
+            Ok(Assignment::Type { name, ty, position })
+        } else {
+            let ty = self.ty()?;
+            self.expect_symbol("::=")?;
+            let value = self.value()?;
+
+            Ok(Assignment::Value {
+                name,
+                ty,
+                value,
+                position,
+            })
+        }
+    }
+
+    /// Type, including any constraints.
+    fn ty(&mut self) -> Result<Type, Error> {
+        let position = self.position();
+
+        if self.eat_symbol("[") {
+            if let Some(Token::Word(class)) = self.peek() {
+                return Err(Error::unsupported(position, format!("{class} class tags")));
+            }
+
+            let number = match self.next()? {
+                Token::Number(n) => n,
+                _ => return Err(Error::syntax(position, "expected a tag number")),
+            };
+
+            self.expect_symbol("]")?;
+
+            let mode = if self.eat_word("IMPLICIT") {
+                Some(TagMode::Implicit)
+            } else if self.eat_word("EXPLICIT") {
+                Some(TagMode::Explicit)
+            } else {
+                None
+            };
+
+            let inner = Box::new(self.ty()?);
+            return Ok(Type::Tagged {
+                number,
+                mode,
+                inner,
+            });
+        }
+
+        let word = self.word()?;
+
+        let mut ty = match word.as_str() {
+            "BOOLEAN" => Type::Boolean,
+            "NULL" => Type::Null,
+            "INTEGER" => Type::Integer {
+                named_numbers: if self.is_symbol("{") {
+                    self.named_numbers()?
+                } else {
+                    Vec::new()
+                },
+                range: None,
+            },
+            "ENUMERATED" => Type::Enumerated(self.enumerations()?),
+            "BIT" => {
+                self.expect_word("STRING")?;
+
+                let mut named_bits = Vec::new();
+                if self.is_symbol("{") {
+                    for (name, bit) in self.named_numbers()? {
+                        let bit = u64::try_from(bit)
+                            .map_err(|_| Error::syntax(position, "negative named bit"))?;
+                        named_bits.push((name, bit));
+                    }
+                }
+
+                Type::BitString(named_bits)
+            }
+            "OCTET" => {
+                self.expect_word("STRING")?;
+                Type::OctetString
+            }
+            "OBJECT" => {
+                self.expect_word("IDENTIFIER")?;
+                Type::ObjectIdentifier
+            }
+            "ANY" => {
+                if self.eat_word("DEFINED") {
+                    self.expect_word("BY")?;
+                    self.word()?;
+                }
+
+                Type::Any
+            }
+            "SEQUENCE" | "SET" => {
+                if self.is_symbol("{") {
+                    if word == "SET" {
+                        return Err(Error::unsupported(position, "`SET` types"));
+                    }
+
+                    let (components, extensible) = self.components()?;
+                    Type::Sequence {
+                        components,
+                        extensible,
+                    }
+                } else {
+                    // `SIZE` constraints aren't used
+                    if self.eat_word("SIZE") || self.is_symbol("(") {
+                        self.constraint()?;
+                    }
+
+                    self.expect_word("OF")?;
+
+                    // Element types may be named, e.g. `SEQUENCE OF cert Certificate`
+                    if matches!(self.peek(), Some(Token::Word(w)) if w.starts_with(|c: char| c.is_ascii_lowercase()))
+                    {
+                        self.index += 1;
+                    }
+
+                    let element = Box::new(self.ty()?);
+
+                    if word == "SEQUENCE" {
+                        Type::SequenceOf(element)
+                    } else {
+                        Type::SetOf(element)
+                    }
+                }
+            }
+            "CHOICE" => Type::Choice(self.components()?.0),
+            _ => {
+                if let Some(name) = BUILTIN_TYPES.iter().find(|name| **name == word) {
+                    Type::Builtin(name)
+                } else if UNSUPPORTED_TYPES.contains(&word.as_str())
+                    || !word.starts_with(|c: char| c.is_ascii_uppercase())
+                    || (self.is_symbol(".") && self.peek_nth(1) == Some(&Token::Symbol("&")))
+                {
+                    return Err(Error::unsupported(position, format!("type `{word}`")));
+                } else if self.eat_symbol(".") {
+                    // External references, e.g. `PKIX1Explicit88.Name`, use the type name
+                    Type::Reference(self.word()?)
+                } else {
+                    Type::Reference(word)
+                }
+            }
+        };
+
+        while self.is_symbol("(") {
+            let range = self.constraint()?;
+
+            if let Type::Integer { range: r, .. } = &mut ty {
+                if range.is_some() {
+                    *r = range;
+                }
+            }
+        }
+
+        Ok(ty)
+    }
+
+    /// Named numbers or bits, e.g. `{ v1(0), v2(1) }` (X.680 Sections 19.1 and 22.1).
+    fn named_numbers(&mut self) -> Result<Vec<(String, i64)>, Error> {
+        self.expect_symbol("{")?;
+        let mut named_numbers = Vec::new();
+
+        loop {
+            let name = self.word()?;
+            self.expect_symbol("(")?;
+
+            let position = self.position();
+            if let Some(Token::Word(_)) = self.peek() {
+                return Err(Error::unsupported(
+                    position,
+                    "named numbers which refer to values",
+                ));
+            }
+
+            named_numbers.push((name, self.number()?));
+            self.expect_symbol(")")?;
+
+            if !self.eat_symbol(",") {
+                self.expect_symbol("}")?;
+                return Ok(named_numbers);
+            }
+        }
+    }
+
+    /// Enumerations of an `ENUMERATED` type, numbering any items without an explicit number
+    /// (X.680 Section 20).
+    fn enumerations(&mut self) -> Result<Vec<(String, i64)>, Error> {
+        self.expect_symbol("{")?;
+        let mut items = Vec::new();
+
+        loop {
+            if !self.eat_symbol("...") {
+                let name = self.word()?;
+
+                let number = if self.eat_symbol("(") {
+                    let number = self.number()?;
+                    self.expect_symbol(")")?;
+                    Some(number)
+                } else {
+                    None
+                };
+
+                items.push((name, number));
+            }
+
+            if !self.eat_symbol(",") {
+                self.expect_symbol("}")?;
+                break;
+            }
+        }
+
+        let mut used: Vec<i64> = items.iter().filter_map(|(_, number)| *number).collect();
+
+        Ok(items
+            .into_iter()
+            .map(|(name, number)| {
+                let number = number.unwrap_or_else(|| {
+                    let number = (0..).find(|n| !used.contains(n)).unwrap_or_default();
+                    used.push(number);
+                    number
+                });
+
+                (name, number)
+            })
+            .collect())
+    }
+
+    /// Components of a `SEQUENCE`, `SET` or `CHOICE` type (X.680 Sections 25, 27 and 29), along
+    /// with whether they contain an extension marker.
+    fn components(&mut self) -> Result<(Vec<Component>, bool), Error> {
+        self.expect_symbol("{")?;
+        let mut components = Vec::new();
+        let mut extensible = false;
+
+        if self.eat_symbol("}") {
+            return Ok((components, extensible));
+        }
+
+        loop {
+            // Extension additions are decoded like any other component, so only the presence of
+            // the extension marker matters
+            if self.eat_symbol("...") {
+                extensible = true;
+
+                if self.eat_symbol("!") {
+                    self.value()?;
+                }
+            } else if self.eat_symbol("[[") || self.eat_symbol("]]") {
+                continue;
+            } else if self.is_word("COMPONENTS") {
+                return Err(Error::unsupported(self.position(), "`COMPONENTS OF`"));
+            } else {
+                components.push(self.component()?);
+            }
+
+            self.eat_symbol("]]");
+
+            if !self.eat_symbol(",") {
+                self.expect_symbol("}")?;
+                return Ok((components, extensible));
+            }
+        }
+    }
+
+    /// Named type along with whether it's `OPTIONAL` or has a `DEFAULT`.
+    fn component(&mut self) -> Result<Component, Error> {
+        let position = self.position();
+        let name = self.word()?;
+        let ty = self.ty()?;
+
+        let mut optional = false;
+        let mut default = None;
+
+        if self.eat_word("OPTIONAL") {
+            optional = true;
+        } else if self.eat_word("DEFAULT") {
+            default = Some(self.value()?);
+        }
+
+        Ok(Component {
+            name,
+            ty,
+            optional,
+            default,
+            position,
+        })
+    }
+
+    /// Parenthesized constraint, returning the value range if it's a simple range constraint,
+    /// e.g. `(1..MAX)` or `(0..ub-name)`. Other constraints are skipped.
+    fn constraint(&mut self) -> Result<Option<Range>, Error> {
+        let start = self.index;
+        self.expect_symbol("(")?;
+
+        if let Ok(lower) = self.range_bound() {
+            let range = if self.eat_symbol("..") {
+                self.range_bound().ok().map(|upper| (lower, upper))
+            } else {
+                Some((lower.clone(), lower))
+            };
+
+            if let Some(range) = range {
+                if self.eat_symbol(")") {
+                    return Ok(Some(range));
+                }
+            }
+        }
+
+        self.index = start;
+        self.skip_group("(", ")")?;
+        Ok(None)
+    }
+
+    /// Lower or upper bound of a range, where `MIN` and `MAX` are represented as `None`.
+    fn range_bound(&mut self) -> Result<Option<Value>, Error> {
+        if self.eat_word("MIN") || self.eat_word("MAX") {
+            return Ok(None);
+        }
+
+        match self.peek() {
+            Some(Token::Number(_) | Token::Symbol("-")) => Ok(Some(Value::Integer(self.number()?))),
+            Some(Token::Word(word)) if word.starts_with(|c: char| c.is_ascii_lowercase()) => {
+                Ok(Some(Value::Identifier(self.word()?)))
+            }
+            _ => Err(self.unexpected("a range bound")),
+        }
+    }
+
+    /// Value, which is either fully parsed if supported or skipped otherwise.
+    fn value(&mut self) -> Result<Value, Error> {
+        match self.peek() {
+            Some(Token::Word(word)) if word == "TRUE" || word == "FALSE" => {
+                let value = word == "TRUE";
+                self.index += 1;
+                Ok(Value::Boolean(value))
+            }
+            Some(Token::Number(_) | Token::Symbol("-")) => Ok(Value::Integer(self.number()?)),
+            Some(Token::Word(word)) if word.starts_with(|c: char| c.is_ascii_lowercase()) => {
+                Ok(Value::Identifier(self.word()?))
+            }
+            Some(Token::Symbol("{")) => self.braced_value(),
+            Some(Token::Word(_)) => {
+                // e.g. `NULL` or an external value reference
+                self.word()?;
+                if self.eat_symbol(".") {
+                    self.word()?;
+                }
+                Ok(Value::Unsupported)
+            }
+            Some(Token::String(_) | Token::Bits(_)) => {
+                self.index += 1;
+                Ok(Value::Unsupported)
+            }
+            _ => Err(self.unexpected("a value")),
+        }
+    }
+
+    /// Value in braces, which is parsed if it's an object identifier value and skipped otherwise.
+    fn braced_value(&mut self) -> Result<Value, Error> {
+        let start = self.index;
+        self.expect_symbol("{")?;
+        let mut components = Vec::new();
+
+        loop {
+            match (self.peek(), self.peek_nth(1)) {
+                (Some(Token::Symbol("}")), _) if !components.is_empty() => {
+                    self.index += 1;
+                    return Ok(Value::ObjectIdentifier(components));
+                }
+                (Some(Token::Number(n)), _) => {
+                    components.push(OidComponent::Number(*n));
+                    self.index += 1;
+                }
+                (Some(Token::Word(_)), Some(Token::Symbol("("))) => {
+                    self.index += 2;
+
+                    match (self.next()?, self.next()?) {
+                        (Token::Number(n), Token::Symbol(")")) => {
+                            components.push(OidComponent::Number(n));
+                        }
+                        _ => break,
+                    }
+                }
+                (Some(Token::Word(word)), _) => {
+                    components.push(OidComponent::Reference(word.clone()));
+                    self.index += 1;
+                }
+                _ => break,
+            }
+        }
+
+        self.index = start;
+        self.skip_group("{", "}")?;
+        Ok(Value::Unsupported)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::parse;
+    use crate::ast::{Assignment, OidComponent, TagDefault, TagMode, Type, Value};
+
+    #[test]
+    fn module_header() {
+        let modules = parse(
+            "Example { iso(1) 2 } DEFINITIONS IMPLICIT TAGS ::= BEGIN
+             IMPORTS Name FROM PKIX1Explicit88 { 1 2 3 };
+             END
+             Other DEFINITIONS ::= BEGIN END",
+        )
+        .unwrap();
+
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].name, "Example");
+        assert_eq!(modules[0].tag_default, TagDefault::Implicit);
+        assert_eq!(modules[1].tag_default, TagDefault::Explicit);
+    }
+
+    #[test]
+    fn sequence() {
+        let modules = parse(
+            "M DEFINITIONS ::= BEGIN
+             Foo ::= SEQUENCE {
+                 version [0] EXPLICIT Version DEFAULT v1,
+                 serial INTEGER (1..MAX),
+                 names SEQUENCE SIZE (1..MAX) OF name UTF8String OPTIONAL,
+                 ...
+             }
+             END",
+        )
+        .unwrap();
+
+        let Assignment::Type {
+            ty:
+                Type::Sequence {
+                    components,
+                    extensible: true,
+                },
+            ..
+        } = &modules[0].assignments[0]
+        else {
+            panic!("expected a SEQUENCE");
+        };
+
+        assert_eq!(components.len(), 3);
+        assert!(matches!(
+            components[0].ty,
+            Type::Tagged {
+                number: 0,
+                mode: Some(TagMode::Explicit),
+                ..
+            }
+        ));
+        assert_eq!(components[0].default, Some(Value::Identifier("v1".into())));
+        assert!(matches!(
+            &components[1].ty,
+            Type::Integer {
+                range: Some((Some(Value::Integer(1)), None)),
+                ..
+            }
+        ));
+        assert!(components[2].optional);
+        assert!(matches!(&components[2].ty, Type::SequenceOf(_)));
+    }
+
+    #[test]
+    fn enumerated() {
+        let modules =
+            parse("M DEFINITIONS ::= BEGIN E ::= ENUMERATED { a, b(0), c, ... } END").unwrap();
+
+        let Assignment::Type {
+            ty: Type::Enumerated(items),
+            ..
+        } = &modules[0].assignments[0]
+        else {
+            panic!("expected an ENUMERATED");
+        };
+
+        assert_eq!(items, &[("a".into(), 1), ("b".into(), 0), ("c".into(), 2)]);
+    }
+
+    #[test]
+    fn object_identifier() {
+        let modules = parse(
+            "M DEFINITIONS ::= BEGIN
+             id-pkix OBJECT IDENTIFIER ::= { iso(1) identified-organization(3) dod 6 }
+             END",
+        )
+        .unwrap();
+
+        let Assignment::Value { name, value, .. } = &modules[0].assignments[0] else {
+            panic!("expected a value assignment");
+        };
+
+        assert_eq!(name, "id-pkix");
+        assert_eq!(
+            value,
+            &Value::ObjectIdentifier(vec![
+                OidComponent::Number(1),
+                OidComponent::Number(3),
+                OidComponent::Reference("dod".into()),
+                OidComponent::Number(6),
+            ])
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let err = parse("M DEFINITIONS ::= BEGIN ALGORITHM ::= CLASS { } END").unwrap_err();
+        assert!(err.to_string().contains("information object class"));
+    }
+}
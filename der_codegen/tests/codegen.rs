@@ -0,0 +1,224 @@
+//! Tests for the ASN.1 module compiler.
+//!
+//! The modules in `tests/asn1` are compiled into `tests/generated`, which is checked in so the
+//! generated code is compiled and exercised against the `der` crate. To update it after
+//! changing the compiler, run:
+//!
+//! $ DER_CODEGEN_BLESS=1 cargo test -p der_codegen
+
+#![allow(clippy::unwrap_used)]
+
+use der_codegen::{Compiler, Error};
+use std::{env, fs};
+
+/// Generated from `tests/asn1/pkix.asn`.
+#[allow(dead_code, clippy::enum_variant_names)]
+mod pkix {
+    include!("generated/pkix.rs");
+
+    // Imported from `PKIX1Implicit88`
+    pub const ID_CE: &str = "2.5.29";
+}
+
+/// Generated from `tests/asn1/automatic.asn`.
+#[allow(dead_code)]
+mod automatic {
+    include!("generated/automatic.rs");
+}
+
+/// Compile the given module and check the output matches the checked-in generated code.
+fn check_generated(compiler: Compiler, name: &str) {
+    let dir = env!("CARGO_MANIFEST_DIR");
+    let input = fs::read_to_string(format!("{dir}/tests/asn1/{name}.asn")).unwrap();
+    let output = compiler.compile(&input).unwrap();
+    let path = format!("{dir}/tests/generated/{name}.rs");
+
+    if env::var_os("DER_CODEGEN_BLESS").is_some() {
+        fs::write(&path, output).unwrap();
+    } else {
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            output,
+            "`{path}` is out of date: rerun with `DER_CODEGEN_BLESS=1`"
+        );
+    }
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    check_generated(Compiler::new().oid("id-ce", pkix::ID_CE), "pkix");
+    check_generated(Compiler::new(), "automatic");
+}
+
+mod explicit_tags {
+    use super::pkix::*;
+    use der::{
+        Decode, Encode,
+        asn1::{Any, BitString, Int, ObjectIdentifier, OctetString, SetOfVec, UtcTime},
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn oid_constants() {
+        assert_eq!(ID_PE, ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.1"));
+        assert_eq!(ID_AT_COMMON_NAME, ObjectIdentifier::new_unwrap("2.5.4.3"));
+        assert_eq!(ID_CE_KEY_USAGE, ObjectIdentifier::new_unwrap("2.5.29.15"));
+        assert_eq!(UB_COMMON_NAME, 64);
+    }
+
+    #[test]
+    fn default_component() {
+        let ext = Extension {
+            extn_id: ID_CE_KEY_USAGE,
+            critical: false,
+            extn_value: OctetString::new(hex!("03020284")).unwrap(),
+        };
+
+        let der = ext.to_der().unwrap();
+        assert_eq!(der, hex!("300b0603551d0f040403020284"));
+        assert_eq!(Extension::from_der(&der).unwrap(), ext);
+    }
+
+    #[test]
+    fn named_bits() {
+        let key_usage = KeyUsage {
+            digital_signature: true,
+            key_cert_sign: true,
+            ..Default::default()
+        };
+
+        // Trailing zero bits are omitted in DER
+        assert_eq!(KeyUsage::from_der(&hex!("03020284")).unwrap(), key_usage);
+
+        let der = key_usage.to_der().unwrap();
+        assert_eq!(KeyUsage::from_der(&der).unwrap(), key_usage);
+    }
+
+    #[test]
+    fn round_trip() {
+        let time =
+            Time::UtcTime(UtcTime::from_unix_duration(core::time::Duration::from_secs(0)).unwrap());
+        let name = Name::RdnSequence(vec![
+            SetOfVec::try_from(vec![AttributeTypeAndValue {
+                r#type: ID_AT_COMMON_NAME,
+                value: Any::new(der::Tag::Utf8String, b"test".as_slice()).unwrap(),
+            }])
+            .unwrap(),
+        ]);
+
+        let tbs = TbsCertificateExcerpt {
+            version: Version::V3,
+            serial_number: Int::new(&[0x01]).unwrap(),
+            signature: AlgorithmIdentifier {
+                algorithm: ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2"),
+                parameters: None,
+            },
+            issuer: name,
+            validity: Validity {
+                not_before: time.clone(),
+                not_after: time,
+            },
+            issuer_unique_id: Some(BitString::from_bytes(&[0xAA]).unwrap()),
+            extensions: Some(vec![Extension {
+                extn_id: ID_CE_KEY_USAGE,
+                critical: true,
+                extn_value: OctetString::new(hex!("03020284")).unwrap(),
+            }]),
+        };
+
+        let der = tbs.to_der().unwrap();
+        assert_eq!(TbsCertificateExcerpt::from_der(&der).unwrap(), tbs);
+    }
+}
+
+mod automatic_tags {
+    use super::automatic::*;
+    use der::{
+        Decode, Encode,
+        asn1::{Ia5String, OctetString, SetOfVec},
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn defaults_omitted() {
+        let message = Message {
+            version: MessageVersion::V1,
+            id: 5,
+            retries: 3,
+            priority: MessagePriority::Normal,
+            body: MessageBody::Text("hi".into()),
+            flags: None,
+            recipients: None,
+        };
+
+        let der = hex!("3009810105a40480026869");
+        assert_eq!(message.to_der().unwrap(), der);
+        assert_eq!(Message::from_der(&der).unwrap(), message);
+
+        // `Message` is extensible, so unknown trailing components are skipped
+        let der = hex!("300c810105a40480026869870100");
+        assert_eq!(Message::from_der(&der).unwrap(), message);
+    }
+
+    #[test]
+    fn round_trip() {
+        let message = Message {
+            version: MessageVersion::V2,
+            id: 65535,
+            retries: 10,
+            priority: MessagePriority::High,
+            body: MessageBody::Parts(vec![Part {
+                name: None,
+                offset: -1,
+                content: OctetString::new(hex!("CAFE")).unwrap(),
+            }]),
+            flags: Some(MessageFlags {
+                encrypted: true,
+                ..Default::default()
+            }),
+            recipients: Some(
+                SetOfVec::try_from(vec![
+                    Recipient::Address(RecipientAddress {
+                        host: Ia5String::new("example.com").unwrap(),
+                        port: 443,
+                    }),
+                    Recipient::Name("alice".into()),
+                ])
+                .unwrap(),
+            ),
+        };
+
+        let der = message.to_der().unwrap();
+        assert_eq!(Message::from_der(&der).unwrap(), message);
+    }
+}
+
+#[test]
+fn unsupported_type() {
+    let err = Compiler::new()
+        .compile("M DEFINITIONS ::= BEGIN\nT ::= SEQUENCE {\n  a VisibleString }\nEND")
+        .unwrap_err();
+
+    match err {
+        Error::Unsupported { position, .. } => assert_eq!((position.line, position.column), (3, 3)),
+        other => panic!("unexpected error: {other}"),
+    }
+}
+
+#[test]
+fn undefined_oid() {
+    let err = Compiler::new()
+        .compile("M DEFINITIONS ::= BEGIN\nid-x OBJECT IDENTIFIER ::= { id-base 1 }\nEND")
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), "2:1: undefined: `id-base`");
+}
+
+#[test]
+fn syntax_error() {
+    let err = Compiler::new()
+        .compile("M DEFINITIONS ::= BEGIN\nT ::= SEQUENCE { a INTEGER\nEND")
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Syntax { .. }), "{err}");
+}
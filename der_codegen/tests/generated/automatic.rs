@@ -0,0 +1,165 @@
+// This file was generated from the `Example-Automatic` ASN.1 module by `der_codegen`.
+// Do not edit it by hand.
+
+/// `max-retries` value.
+pub const MAX_RETRIES: i64 = 10;
+
+/// `Message` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+#[asn1(tag_mode = "IMPLICIT", extensible = "true")]
+pub struct Message {
+    /// `version` component.
+    #[asn1(context_specific = "0", default = "message_version_default")]
+    pub version: MessageVersion,
+
+    /// `id` component.
+    #[asn1(context_specific = "1")]
+    pub id: u16,
+
+    /// `retries` component.
+    #[asn1(context_specific = "2", default = "message_retries_default")]
+    pub retries: u8,
+
+    /// `priority` component.
+    #[asn1(context_specific = "3", default = "message_priority_default")]
+    pub priority: MessagePriority,
+
+    /// `body` component.
+    #[asn1(context_specific = "4", tag_mode = "EXPLICIT")]
+    pub body: MessageBody,
+
+    /// `flags` component.
+    #[asn1(context_specific = "5", optional = "true")]
+    pub flags: Option<MessageFlags>,
+
+    /// `recipients` component.
+    #[asn1(context_specific = "6", optional = "true")]
+    pub recipients: Option<der::asn1::SetOfVec<Recipient>>,
+}
+
+/// Default value of the `version` component of [`Message`].
+fn message_version_default() -> MessageVersion {
+    MessageVersion::V1
+}
+
+/// Default value of the `retries` component of [`Message`].
+fn message_retries_default() -> u8 {
+    3
+}
+
+/// Default value of the `priority` component of [`Message`].
+fn message_priority_default() -> MessagePriority {
+    MessagePriority::Normal
+}
+
+/// `MessageVersion` type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, der::Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+pub enum MessageVersion {
+    /// `v1` value.
+    V1 = 1,
+    /// `v2` value.
+    V2 = 2,
+}
+
+/// `MessagePriority` type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, der::Enumerated)]
+#[repr(u8)]
+pub enum MessagePriority {
+    /// `low` value.
+    Low = 0,
+    /// `normal` value.
+    Normal = 5,
+    /// `high` value.
+    High = 1,
+}
+
+/// `MessageBody` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]
+#[asn1(tag_mode = "IMPLICIT")]
+pub enum MessageBody {
+    /// `text` alternative.
+    #[asn1(context_specific = "0")]
+    Text(String),
+
+    /// `binary` alternative.
+    #[asn1(context_specific = "1")]
+    Binary(der::asn1::OctetString),
+
+    /// `parts` alternative.
+    #[asn1(context_specific = "2", constructed = "true")]
+    Parts(Vec<Part>),
+}
+
+/// `MessageFlags` type.
+///
+/// All bits are optional, so encodings which omit trailing zero bits can be decoded.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, der::BitString)]
+pub struct MessageFlags {
+    /// `urgent` bit.
+    #[asn1(optional = "true")]
+    pub urgent: bool,
+
+    /// Unnamed bit 1.
+    #[asn1(optional = "true")]
+    pub bit_1: bool,
+
+    /// `encrypted` bit.
+    #[asn1(optional = "true")]
+    pub encrypted: bool,
+}
+
+/// `Part` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+#[asn1(tag_mode = "IMPLICIT")]
+pub struct Part {
+    /// `name` component.
+    #[asn1(context_specific = "0", optional = "true")]
+    pub name: Option<der::asn1::PrintableString>,
+
+    /// `offset` component.
+    #[asn1(context_specific = "1")]
+    pub offset: i8,
+
+    /// `content` component.
+    #[asn1(context_specific = "2")]
+    pub content: der::asn1::OctetString,
+}
+
+/// `Recipient` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]
+#[asn1(tag_mode = "IMPLICIT")]
+pub enum Recipient {
+    /// `name` alternative.
+    #[asn1(context_specific = "0")]
+    Name(String),
+
+    /// `address` alternative.
+    #[asn1(context_specific = "1", constructed = "true")]
+    Address(RecipientAddress),
+}
+
+/// `RecipientAddress` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+#[asn1(tag_mode = "IMPLICIT")]
+pub struct RecipientAddress {
+    /// `host` component.
+    #[asn1(context_specific = "0")]
+    pub host: der::asn1::Ia5String,
+
+    /// `port` component.
+    #[asn1(context_specific = "1", default = "recipient_address_port_default")]
+    pub port: u16,
+}
+
+/// Default value of the `port` component of [`RecipientAddress`].
+fn recipient_address_port_default() -> u16 {
+    443
+}
+
+impl der::ValueOrd for Recipient {
+    fn value_cmp(&self, other: &Self) -> der::Result<core::cmp::Ordering> {
+        Ok(der::Encode::to_der(self)?.cmp(&der::Encode::to_der(other)?))
+    }
+}
@@ -0,0 +1,219 @@
+// This file was generated from the `PKIX-Excerpt` ASN.1 module by `der_codegen`.
+// Do not edit it by hand.
+
+/// `id-pkix` object identifier: `1.3.6.1.5.5.7`.
+pub const ID_PKIX: der::asn1::ObjectIdentifier =
+    der::asn1::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7");
+
+/// `id-pe` object identifier: `1.3.6.1.5.5.7.1`.
+pub const ID_PE: der::asn1::ObjectIdentifier =
+    der::asn1::ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.1");
+
+/// `id-at` object identifier: `2.5.4`.
+pub const ID_AT: der::asn1::ObjectIdentifier =
+    der::asn1::ObjectIdentifier::new_unwrap("2.5.4");
+
+/// `id-at-commonName` object identifier: `2.5.4.3`.
+pub const ID_AT_COMMON_NAME: der::asn1::ObjectIdentifier =
+    der::asn1::ObjectIdentifier::new_unwrap("2.5.4.3");
+
+/// `id-ce-keyUsage` object identifier: `2.5.29.15`.
+pub const ID_CE_KEY_USAGE: der::asn1::ObjectIdentifier =
+    der::asn1::ObjectIdentifier::new_unwrap("2.5.29.15");
+
+/// `ub-common-name` value.
+pub const UB_COMMON_NAME: i64 = 64;
+
+/// `AttributeType` type.
+pub type AttributeType = der::asn1::ObjectIdentifier;
+
+/// `AttributeValue` type.
+pub type AttributeValue = der::asn1::Any;
+
+/// `AttributeTypeAndValue` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+pub struct AttributeTypeAndValue {
+    /// `type` component.
+    pub r#type: AttributeType,
+
+    /// `value` component.
+    pub value: AttributeValue,
+}
+
+/// `Name` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]
+pub enum Name {
+    /// `rdnSequence` alternative.
+    RdnSequence(RdnSequence),
+}
+
+/// `RDNSequence` type.
+pub type RdnSequence = Vec<RelativeDistinguishedName>;
+
+/// `RelativeDistinguishedName` type.
+pub type RelativeDistinguishedName = der::asn1::SetOfVec<AttributeTypeAndValue>;
+
+/// `Version` type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, der::Enumerated)]
+#[asn1(type = "INTEGER")]
+#[repr(u8)]
+pub enum Version {
+    /// `v1` value.
+    V1 = 0,
+    /// `v2` value.
+    V2 = 1,
+    /// `v3` value.
+    V3 = 2,
+}
+
+/// `CertificateSerialNumber` type.
+pub type CertificateSerialNumber = der::asn1::Int;
+
+/// `Validity` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+pub struct Validity {
+    /// `notBefore` component.
+    pub not_before: Time,
+
+    /// `notAfter` component.
+    pub not_after: Time,
+}
+
+/// `Time` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]
+pub enum Time {
+    /// `utcTime` alternative.
+    UtcTime(der::asn1::UtcTime),
+
+    /// `generalTime` alternative.
+    GeneralTime(der::asn1::GeneralizedTime),
+}
+
+/// `UniqueIdentifier` type.
+pub type UniqueIdentifier = der::asn1::BitString;
+
+/// `AlgorithmIdentifier` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+pub struct AlgorithmIdentifier {
+    /// `algorithm` component.
+    pub algorithm: der::asn1::ObjectIdentifier,
+
+    /// `parameters` component.
+    pub parameters: Option<der::asn1::Any>,
+}
+
+/// `TBSCertificateExcerpt` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+pub struct TbsCertificateExcerpt {
+    /// `version` component.
+    #[asn1(context_specific = "0", default = "tbs_certificate_excerpt_version_default")]
+    pub version: Version,
+
+    /// `serialNumber` component.
+    pub serial_number: CertificateSerialNumber,
+
+    /// `signature` component.
+    pub signature: AlgorithmIdentifier,
+
+    /// `issuer` component.
+    pub issuer: Name,
+
+    /// `validity` component.
+    pub validity: Validity,
+
+    /// `issuerUniqueID` component.
+    #[asn1(context_specific = "1", tag_mode = "IMPLICIT", optional = "true")]
+    pub issuer_unique_id: Option<UniqueIdentifier>,
+
+    /// `extensions` component.
+    #[asn1(context_specific = "3", optional = "true")]
+    pub extensions: Option<Extensions>,
+}
+
+/// Default value of the `version` component of [`TbsCertificateExcerpt`].
+fn tbs_certificate_excerpt_version_default() -> Version {
+    Version::V1
+}
+
+/// `Extensions` type.
+pub type Extensions = Vec<Extension>;
+
+/// `Extension` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Sequence)]
+pub struct Extension {
+    /// `extnID` component.
+    pub extn_id: der::asn1::ObjectIdentifier,
+
+    /// `critical` component.
+    #[asn1(default = "extension_critical_default")]
+    pub critical: bool,
+
+    /// `extnValue` component.
+    pub extn_value: der::asn1::OctetString,
+}
+
+/// Default value of the `critical` component of [`Extension`].
+fn extension_critical_default() -> bool {
+    false
+}
+
+/// `KeyUsage` type.
+///
+/// All bits are optional, so encodings which omit trailing zero bits can be decoded.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, der::BitString)]
+pub struct KeyUsage {
+    /// `digitalSignature` bit.
+    #[asn1(optional = "true")]
+    pub digital_signature: bool,
+
+    /// `nonRepudiation` bit.
+    #[asn1(optional = "true")]
+    pub non_repudiation: bool,
+
+    /// `keyEncipherment` bit.
+    #[asn1(optional = "true")]
+    pub key_encipherment: bool,
+
+    /// `dataEncipherment` bit.
+    #[asn1(optional = "true")]
+    pub data_encipherment: bool,
+
+    /// `keyAgreement` bit.
+    #[asn1(optional = "true")]
+    pub key_agreement: bool,
+
+    /// `keyCertSign` bit.
+    #[asn1(optional = "true")]
+    pub key_cert_sign: bool,
+
+    /// `cRLSign` bit.
+    #[asn1(optional = "true")]
+    pub crl_sign: bool,
+
+    /// `encipherOnly` bit.
+    #[asn1(optional = "true")]
+    pub encipher_only: bool,
+
+    /// `decipherOnly` bit.
+    #[asn1(optional = "true")]
+    pub decipher_only: bool,
+}
+
+/// `DisplayText` type.
+#[derive(Clone, Debug, Eq, PartialEq, der::Choice)]
+pub enum DisplayText {
+    /// `ia5String` alternative.
+    Ia5String(der::asn1::Ia5String),
+
+    /// `bmpString` alternative.
+    BmpString(der::asn1::BmpString),
+
+    /// `utf8String` alternative.
+    Utf8String(String),
+}
+
+impl der::ValueOrd for AttributeTypeAndValue {
+    fn value_cmp(&self, other: &Self) -> der::Result<core::cmp::Ordering> {
+        Ok(der::Encode::to_der(self)?.cmp(&der::Encode::to_der(other)?))
+    }
+}
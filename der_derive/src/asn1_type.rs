@@ -17,6 +17,9 @@ pub(crate) enum Asn1Type {
     /// ASN.1 `GeneralizedTime`.
     GeneralizedTime,
 
+    /// ASN.1 `NumericString`.
+    NumericString,
+
     /// ASN.1 `OCTET STRING`.
     OctetString,
 
@@ -26,9 +29,15 @@ pub(crate) enum Asn1Type {
     /// ASN.1 `TeletexString`.
     TeletexString,
 
+    /// ASN.1 `UniversalString`.
+    UniversalString,
+
     /// ASN.1 `VideotexString`.
     VideotexString,
 
+    /// ASN.1 `VisibleString`.
+    VisibleString,
+
     /// ASN.1 `UTCTime`.
     UtcTime,
 
@@ -43,10 +52,13 @@ impl Asn1Type {
             Asn1Type::BitString => quote!(::der::Tag::BitString),
             Asn1Type::Ia5String => quote!(::der::Tag::Ia5String),
             Asn1Type::GeneralizedTime => quote!(::der::Tag::GeneralizedTime),
+            Asn1Type::NumericString => quote!(::der::Tag::NumericString),
             Asn1Type::OctetString => quote!(::der::Tag::OctetString),
             Asn1Type::PrintableString => quote!(::der::Tag::PrintableString),
             Asn1Type::TeletexString => quote!(::der::Tag::TeletexString),
+            Asn1Type::UniversalString => quote!(::der::Tag::UniversalString),
             Asn1Type::VideotexString => quote!(::der::Tag::VideotexString),
+            Asn1Type::VisibleString => quote!(::der::Tag::VisibleString),
             Asn1Type::UtcTime => quote!(::der::Tag::UtcTime),
             Asn1Type::Utf8String => quote!(::der::Tag::Utf8String),
         }
@@ -76,10 +88,13 @@ impl Asn1Type {
             Asn1Type::BitString => quote!(::der::asn1::BitStringRef),
             Asn1Type::Ia5String => quote!(::der::asn1::Ia5StringRef),
             Asn1Type::GeneralizedTime => quote!(::der::asn1::GeneralizedTime),
+            Asn1Type::NumericString => quote!(::der::asn1::NumericStringRef),
             Asn1Type::OctetString => quote!(::der::asn1::OctetStringRef),
             Asn1Type::PrintableString => quote!(::der::asn1::PrintableStringRef),
             Asn1Type::TeletexString => quote!(::der::asn1::TeletexStringRef),
+            Asn1Type::UniversalString => quote!(::der::asn1::UniversalStringRef),
             Asn1Type::VideotexString => quote!(::der::asn1::VideotexStringRef),
+            Asn1Type::VisibleString => quote!(::der::asn1::VisibleStringRef),
             Asn1Type::UtcTime => quote!(::der::asn1::UtcTime),
             Asn1Type::Utf8String => quote!(::der::asn1::Utf8StringRef),
         }
@@ -94,10 +109,13 @@ impl FromStr for Asn1Type {
             "BIT STRING" => Ok(Self::BitString),
             "IA5String" => Ok(Self::Ia5String),
             "GeneralizedTime" => Ok(Self::GeneralizedTime),
+            "NumericString" => Ok(Self::NumericString),
             "OCTET STRING" => Ok(Self::OctetString),
             "PrintableString" => Ok(Self::PrintableString),
             "TeletexString" => Ok(Self::TeletexString),
+            "UniversalString" => Ok(Self::UniversalString),
             "VideotexString" => Ok(Self::VideotexString),
+            "VisibleString" => Ok(Self::VisibleString),
             "UTCTime" => Ok(Self::UtcTime),
             "UTF8String" => Ok(Self::Utf8String),
             _ => Err(ParseError),
@@ -111,10 +129,13 @@ impl fmt::Display for Asn1Type {
             Asn1Type::BitString => "BIT STRING",
             Asn1Type::Ia5String => "IA5String",
             Asn1Type::GeneralizedTime => "GeneralizedTime",
+            Asn1Type::NumericString => "NumericString",
             Asn1Type::OctetString => "OCTET STRING",
             Asn1Type::PrintableString => "PrintableString",
             Asn1Type::TeletexString => "TeletexString",
+            Asn1Type::UniversalString => "UniversalString",
             Asn1Type::VideotexString => "VideotexString",
+            Asn1Type::VisibleString => "VisibleString",
             Asn1Type::UtcTime => "UTCTime",
             Asn1Type::Utf8String => "UTF8String",
         })
@@ -1,6 +1,6 @@
 //! Attribute-related types used by the proc macro
 
-use crate::{Asn1Type, Tag, TagMode, TagNumber};
+use crate::{Asn1Type, Tag, TagMode, TagNumber, constraint::Bounds};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use std::{fmt::Debug, str::FromStr};
@@ -41,6 +41,17 @@ pub(crate) struct TypeAttrs {
     /// The default value is `EXPLICIT`.
     pub tag_mode: TagMode,
     pub error: ErrorType,
+
+    /// Is this `SEQUENCE` extensible, i.e. does it contain the `...` extension marker?
+    ///
+    /// Supplied as `#[asn1(extensible = "true")]`.
+    pub extensible: bool,
+
+    /// Does this `SEQUENCE` have an encoding of a fixed length, i.e. should `FixedLen` be
+    /// derived?
+    ///
+    /// Supplied as `#[asn1(fixed_len = "true")]`.
+    pub fixed_len: bool,
 }
 
 impl TypeAttrs {
@@ -48,6 +59,8 @@ impl TypeAttrs {
     pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
         let mut tag_mode = None;
         let mut error = None;
+        let mut extensible = None;
+        let mut fixed_len = None;
 
         attrs.iter().try_for_each(|attr| {
             if !attr.path().is_ident(ATTR_NAME) {
@@ -67,10 +80,28 @@ impl TypeAttrs {
                     }
 
                     error = Some(ErrorType::Custom(meta.value()?.parse()?));
+                } else if meta.path.is_ident("extensible") {
+                    if extensible.is_some() {
+                        abort!(attr, "duplicate ASN.1 `extensible` attribute");
+                    }
+
+                    let value: LitStr = meta.value()?.parse()?;
+                    extensible = Some(value.value().parse().map_err(|_| {
+                        syn::Error::new_spanned(&value, "error parsing ASN.1 `extensible` attribute")
+                    })?);
+                } else if meta.path.is_ident("fixed_len") {
+                    if fixed_len.is_some() {
+                        abort!(attr, "duplicate ASN.1 `fixed_len` attribute");
+                    }
+
+                    let value: LitStr = meta.value()?.parse()?;
+                    fixed_len = Some(value.value().parse().map_err(|_| {
+                        syn::Error::new_spanned(&value, "error parsing ASN.1 `fixed_len` attribute")
+                    })?);
                 } else {
                     return Err(syn::Error::new_spanned(
                         attr,
-                        "invalid `asn1` attribute (valid options are `tag_mode` and `error`)",
+                        "invalid `asn1` attribute (valid options are `tag_mode`, `error`, `extensible` and `fixed_len`)",
                     ));
                 }
 
@@ -81,6 +112,8 @@ impl TypeAttrs {
         Ok(Self {
             tag_mode: tag_mode.unwrap_or_default(),
             error: error.unwrap_or_default(),
+            extensible: extensible.unwrap_or_default(),
+            fixed_len: fixed_len.unwrap_or_default(),
         })
     }
 }
@@ -107,9 +140,21 @@ pub(crate) struct FieldAttrs {
     /// Is this field "extensible", i.e. preceded by the `...` extensibility marker?
     pub extensible: bool,
 
+    /// Does this field capture the unknown extension additions of an extensible `SEQUENCE`?
+    pub extension_additions: bool,
+
     /// Is this field `OPTIONAL`?
     pub optional: bool,
 
+    /// Does this variant capture any alternative of a `CHOICE` not matched by the others?
+    pub other: bool,
+
+    /// Value of the `#[asn1(range = "...")]` attribute if provided.
+    pub range: Option<Bounds>,
+
+    /// Value of the `#[asn1(size = "...")]` attribute if provided.
+    pub size: Option<Bounds>,
+
     /// Tagging mode for this type: `EXPLICIT` or `IMPLICIT`, supplied as
     /// `#[asn1(tag_mode = "...")]`.
     ///
@@ -134,7 +179,11 @@ impl FieldAttrs {
         let mut default = None;
         let mut should_deref = None;
         let mut extensible = None;
+        let mut extension_additions = None;
         let mut optional = None;
+        let mut other = None;
+        let mut range = None;
+        let mut size = None;
         let mut tag_mode = None;
 
         let mut parsed_attrs = Vec::new();
@@ -173,6 +222,13 @@ impl FieldAttrs {
                 }
 
                 extensible = Some(ext);
+            // `extension_additions` attribute
+            } else if let Some(ext) = attr.parse_value("extension_additions")? {
+                if extension_additions.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `extension_additions` attribute");
+                }
+
+                extension_additions = Some(ext);
             // `optional` attribute
             } else if let Some(opt) = attr.parse_value("optional")? {
                 if optional.is_some() {
@@ -180,6 +236,27 @@ impl FieldAttrs {
                 }
 
                 optional = Some(opt);
+            // `other` attribute
+            } else if let Some(oth) = attr.parse_value("other")? {
+                if other.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `other` attribute");
+                }
+
+                other = Some(oth);
+            // `range = "..."` attribute
+            } else if let Some(bounds) = attr.parse_value("range")? {
+                if range.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `range` attribute");
+                }
+
+                range = Some(bounds);
+            // `size = "..."` attribute
+            } else if let Some(bounds) = attr.parse_value("size")? {
+                if size.is_some() {
+                    abort!(attr.name, "duplicate ASN.1 `size` attribute");
+                }
+
+                size = Some(bounds);
             // `tag_mode` attribute
             } else if let Some(mode) = attr.parse_value("tag_mode")? {
                 if tag_mode.is_some() {
@@ -205,7 +282,7 @@ impl FieldAttrs {
                 abort!(
                     attr.name,
                     "unknown field-level `asn1` attribute \
-                    (valid options are `constructed`, `context_specific`, `default`, `deref`, `extensible`, `optional`, `tag_mode`, `type`)",
+                    (valid options are `constructed`, `context_specific`, `default`, `deref`, `extensible`, `extension_additions`, `optional`, `other`, `range`, `size`, `tag_mode`, `type`)",
                 );
             }
         }
@@ -217,7 +294,11 @@ impl FieldAttrs {
             default,
             should_deref: should_deref.unwrap_or_default(),
             extensible: extensible.unwrap_or_default(),
+            extension_additions: extension_additions.unwrap_or_default(),
             optional: optional.unwrap_or_default(),
+            other: other.unwrap_or_default(),
+            range,
+            size,
             tag_mode: tag_mode.unwrap_or(type_attrs.tag_mode),
         })
     }
@@ -4,11 +4,11 @@
 
 mod variant;
 
-use self::variant::ChoiceVariant;
-use crate::{ErrorType, TypeAttrs, default_lifetime};
+pub(crate) use self::variant::ChoiceVariant;
+use crate::{ErrorType, TypeAttrs, current_field, default_lifetime};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
-use syn::{DeriveInput, GenericParam, Generics, Ident, LifetimeParam};
+use syn::{DeriveInput, GenericParam, Generics, Ident, LifetimeParam, ext::IdentExt};
 
 /// Derive the `Choice` trait for an enum.
 pub(crate) struct DeriveChoice {
@@ -36,13 +36,23 @@ impl DeriveChoice {
             ),
         };
 
+        // The `extensible` container attribute only affects the PER encoding of a `CHOICE`,
+        // see the `Per` derive
         let type_attrs = TypeAttrs::parse(&input.attrs)?;
-        let variants = data
+
+        let variants: Vec<ChoiceVariant> = data
             .variants
             .iter()
             .map(|variant| ChoiceVariant::new(variant, &type_attrs))
             .collect::<syn::Result<_>>()?;
 
+        if let Some(variant) = variants.iter().filter(|variant| variant.attrs.other).nth(1) {
+            abort!(
+                &variant.ident,
+                "only one variant of a CHOICE can be `other`"
+            );
+        }
+
         Ok(Self {
             ident: input.ident,
             generics: input.generics.clone(),
@@ -74,45 +84,83 @@ impl DeriveChoice {
         let (_, ty_generics, where_clause) = self.generics.split_for_impl();
         let (impl_generics, _, _) = generics.split_for_impl();
 
+        let error = self.error.to_token_stream();
+        let current_field = current_field();
+        let type_name = ident.unraw().to_string();
+
         let mut can_decode_body = Vec::new();
         let mut decode_body = Vec::new();
         let mut encode_body = Vec::new();
         let mut value_len_body = Vec::new();
+        let mut string_tag_body = Vec::new();
         let mut tagged_body = Vec::new();
 
+        let mut other = None;
+
         for variant in &self.variants {
-            can_decode_body.push(variant.tag.to_tokens());
-            decode_body.push(variant.to_decode_tokens());
+            if variant.attrs.other {
+                other = Some(variant);
+            } else {
+                can_decode_body.push(variant.tag.to_tokens());
+                decode_body.push(variant.to_decode_tokens());
+            }
+
             encode_body.push(variant.to_encode_value_tokens());
             value_len_body.push(variant.to_value_len_tokens());
+            string_tag_body.push(variant.to_string_tag_tokens());
             tagged_body.push(variant.to_tagged_tokens());
         }
 
-        let error = self.error.to_token_stream();
-
-        quote! {
-            impl #impl_generics ::der::Choice<#lifetime> for #ident #ty_generics #where_clause {
+        // An `other` variant only captures unknown tags once the value is known to be present, so
+        // it's excluded here. Otherwise an `OPTIONAL` field would capture any following field.
+        let can_decode = if can_decode_body.is_empty() {
+            quote! {
+                fn can_decode(_tag: ::der::Tag) -> bool {
+                    false
+                }
+            }
+        } else {
+            quote! {
                 fn can_decode(tag: ::der::Tag) -> bool {
                     matches!(tag, #(#can_decode_body)|*)
                 }
             }
+        };
+
+        let unmatched = match other {
+            Some(variant) => variant.to_decode_tokens(),
+            None => quote! {
+                actual => Err(::der::Error::new(
+                    ::der::ErrorKind::TagUnexpected {
+                        expected: None,
+                        actual
+                    },
+                    reader.position()
+                ).into()
+                ),
+            },
+        };
+
+        quote! {
+            impl #impl_generics ::der::Choice<#lifetime> for #ident #ty_generics #where_clause {
+                #can_decode
+            }
 
             impl #impl_generics ::der::Decode<#lifetime> for #ident #ty_generics #where_clause {
                 type Error = #error;
 
                 fn decode<R: ::der::Reader<#lifetime>>(reader: &mut R) -> ::core::result::Result<Self, #error> {
                     use der::Reader as _;
-                    match ::der::Tag::peek(reader)? {
-                        #(#decode_body)*
-                        actual => Err(::der::Error::new(
-                            ::der::ErrorKind::TagUnexpected {
-                                expected: None,
-                                actual
-                            },
-                            reader.position()
-                        ).into()
-                        ),
-                    }
+
+                    let mut #current_field = None;
+
+                    (|| -> ::core::result::Result<Self, #error> {
+                        match ::der::Tag::peek(reader)? {
+                            #(#decode_body)*
+                            #unmatched
+                        }
+                    })()
+                    .map_err(|err| ::der::Error::annotate_field(err, &#type_name, #current_field))
                 }
             }
 
@@ -128,6 +176,12 @@ impl DeriveChoice {
                         #(#value_len_body)*
                     }
                 }
+
+                fn string_tag(&self) -> ::core::option::Option<::der::Tag> {
+                    match self {
+                        #(#string_tag_body)*
+                    }
+                }
             }
 
             impl #impl_generics ::der::Tagged for #ident #ty_generics #where_clause {
@@ -261,4 +315,35 @@ mod tests {
             }
         );
     }
+
+    /// Catch-all `other` variant.
+    #[test]
+    fn other_example() {
+        let input = parse_quote! {
+            pub enum GeneralName<'a> {
+                #[asn1(context_specific = "2", type = "IA5String")]
+                DnsName(Ia5StringRef<'a>),
+
+                #[asn1(other = "true")]
+                Other(AnyRef<'a>),
+            }
+        };
+
+        let ir = DeriveChoice::new(input).unwrap();
+        assert_eq!(ir.variants.len(), 2);
+        assert!(!ir.variants[0].attrs.other);
+        assert!(ir.variants[1].attrs.other);
+
+        let input = parse_quote! {
+            pub enum Invalid<'a> {
+                #[asn1(other = "true")]
+                First(AnyRef<'a>),
+
+                #[asn1(other = "true")]
+                Second(AnyRef<'a>),
+            }
+        };
+
+        assert!(DeriveChoice::new(input).is_err());
+    }
 }
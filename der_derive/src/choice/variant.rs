@@ -1,12 +1,12 @@
 //! Choice variant IR and lowerings
 
-use crate::{FieldAttrs, Tag, TypeAttrs};
+use crate::{FieldAttrs, Tag, TypeAttrs, current_field};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Fields, Ident, Path, Type, Variant};
+use syn::{Fields, Ident, Path, Type, Variant, ext::IdentExt};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub(super) enum TagOrPath {
+pub(crate) enum TagOrPath {
     Tag(Tag),
     Path(Path),
 }
@@ -61,20 +61,20 @@ impl TagOrPath {
 }
 
 /// "IR" for a variant of a derived `Choice`.
-pub(super) struct ChoiceVariant {
+pub(crate) struct ChoiceVariant {
     /// Variant name.
-    pub(super) ident: Ident,
+    pub(crate) ident: Ident,
 
     /// "Field" (in this case variant)-level attributes.
-    pub(super) attrs: FieldAttrs,
+    pub(crate) attrs: FieldAttrs,
 
     /// Tag for the ASN.1 type.
-    pub(super) tag: TagOrPath,
+    pub(crate) tag: TagOrPath,
 }
 
 impl ChoiceVariant {
     /// Create a new [`ChoiceVariant`] from the input [`Variant`].
-    pub(super) fn new(input: &Variant, type_attrs: &TypeAttrs) -> syn::Result<Self> {
+    pub(crate) fn new(input: &Variant, type_attrs: &TypeAttrs) -> syn::Result<Self> {
         let ident = input.ident.clone();
         let attrs = FieldAttrs::parse(&input.attrs, type_attrs)?;
 
@@ -82,6 +82,29 @@ impl ChoiceVariant {
             abort!(&ident, "`extensible` is not allowed on CHOICE");
         }
 
+        if attrs.extension_additions {
+            abort!(&ident, "`extension_additions` is not allowed on CHOICE");
+        }
+
+        if attrs.range.is_some() || attrs.size.is_some() {
+            abort!(
+                &ident,
+                "`range` and `size` constraints are not supported on CHOICE variants",
+            );
+        }
+
+        if attrs.other
+            && (attrs.asn1_type.is_some()
+                || attrs.context_specific.is_some()
+                || attrs.default.is_some()
+                || attrs.optional)
+        {
+            abort!(
+                &ident,
+                "`other` can't be combined with other variant qualifiers",
+            );
+        }
+
         // Validate that variant is a 1-element tuple struct
         match &input.fields {
             // TODO(tarcieri): handle 0 bindings for ASN.1 NULL
@@ -89,23 +112,47 @@ impl ChoiceVariant {
             _ => abort!(&ident, "enum variant must be a 1-element tuple struct"),
         }
 
-        let tag = match attrs.tag()? {
-            Some(x) => x.into(),
-            None => input.try_into()?,
+        // `other` variants have the tag of their value, so their `tag` is unused
+        let tag = match attrs.other {
+            true => input.try_into()?,
+            false => match attrs.tag()? {
+                Some(x) => x.into(),
+                None => input.try_into()?,
+            },
         };
 
         Ok(Self { ident, attrs, tag })
     }
 
     /// Derive a match arm of the impl body for `TryFrom<der::asn1::Any<'_>>`.
+    ///
+    /// The name of the variant is stored in the variable returned by [`current_field`], so that
+    /// errors can be annotated with it.
+    ///
+    /// The match arm of an `other` variant matches any tag, so it must be the last arm.
     pub(super) fn to_decode_tokens(&self) -> TokenStream {
-        let tag = self.tag.to_tokens();
+        let tag = if self.attrs.other {
+            quote!(_)
+        } else {
+            self.tag.to_tokens()
+        };
         let ident = &self.ident;
-        let decoder = self.attrs.decoder();
+        let variant_name = ident.unraw().to_string();
+        let current_field = current_field();
 
-        match self.attrs.asn1_type {
-            Some(..) => quote! { #tag => Ok(Self::#ident(#decoder.try_into()?)), },
-            None => quote! { #tag => Ok(Self::#ident(#decoder)), },
+        let decoder = match self.attrs.asn1_type {
+            Some(..) => {
+                let decoder = self.attrs.decoder();
+                quote!(#decoder.try_into()?)
+            }
+            None => self.attrs.decoder(),
+        };
+
+        quote! {
+            #tag => {
+                #current_field = Some(&#variant_name);
+                Ok(Self::#ident(#decoder))
+            }
         }
     }
 
@@ -141,9 +188,38 @@ impl ChoiceVariant {
         }
     }
 
+    /// Derive a match arm for the impl body for `der::EncodeValue::string_tag`.
+    pub(super) fn to_string_tag_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        match self.attrs.context_specific {
+            Some(tag_number) => {
+                let tag_number = tag_number.to_tokens();
+                let tag_mode = self.attrs.tag_mode.to_tokens();
+
+                quote! {
+                    Self::#ident(variant) => ::der::asn1::ContextSpecificRef {
+                        tag_number: #tag_number,
+                        tag_mode: #tag_mode,
+                        value: variant,
+                    }.string_tag(),
+                }
+            }
+
+            _ => quote! { Self::#ident(variant) => variant.string_tag(), },
+        }
+    }
+
     /// Derive a match arm for the impl body for `der::Tagged::tag`.
     pub(super) fn to_tagged_tokens(&self) -> TokenStream {
         let ident = &self.ident;
+
+        if self.attrs.other {
+            return quote! {
+                Self::#ident(variant) => ::der::Tagged::tag(variant),
+            };
+        }
+
         let tag = self.tag.to_tokens();
         quote! {
             Self::#ident(_) => #tag,
@@ -169,10 +245,13 @@ mod tests {
         assert_eq!(
             variant.to_decode_tokens().to_string(),
             quote! {
-                ::der::Tag::Utf8String => Ok(Self::ExampleVariant(
-                    reader.decode()?
-                )),
+                            ::der::Tag::Utf8String => {
+            __der_field = Some(&"ExampleVariant");
+            Ok(Self::ExampleVariant(
+                                reader.decode()?
+                            ))
             }
+                        }
             .to_string()
         );
 
@@ -214,11 +293,14 @@ mod tests {
         assert_eq!(
             variant.to_decode_tokens().to_string(),
             quote! {
-                ::der::Tag::Utf8String => Ok(Self::ExampleVariant(
-                    ::der::asn1::Utf8StringRef::decode(reader)?
-                    .try_into()?
-                )),
+                            ::der::Tag::Utf8String => {
+            __der_field = Some(&"ExampleVariant");
+            Ok(Self::ExampleVariant(
+                                ::der::asn1::Utf8StringRef::decode(reader)?
+                                .try_into()?
+                            ))
             }
+                        }
             .to_string()
         );
 
@@ -273,7 +355,9 @@ mod tests {
                         ::der::Tag::ContextSpecific {
                             constructed: #constructed,
                             number: #tag_number,
-                        } => Ok(Self::ExplicitVariant(
+                        } => {
+__der_field = Some(&"ExplicitVariant");
+Ok(Self::ExplicitVariant(
                             match ::der::asn1::ContextSpecific::<>::decode(reader)? {
                                 field if field.tag_number == #tag_number => Some(field),
                                 _ => None
@@ -286,7 +370,8 @@ mod tests {
                                 .value_error()
                             })?
                             .value
-                        )),
+                        ))
+}
                     }
                     .to_string()
                 );
@@ -355,24 +440,27 @@ mod tests {
                 assert_eq!(
                     variant.to_decode_tokens().to_string(),
                     quote! {
-                        ::der::Tag::ContextSpecific {
-                            constructed: #constructed,
-                            number: #tag_number,
-                        } => Ok(Self::ImplicitVariant(
-                            ::der::asn1::ContextSpecific::<>::decode_implicit(
-                                reader,
-                                #tag_number
-                            )?
-                            .ok_or_else(|| {
-                                der::Tag::ContextSpecific {
-                                  number: #tag_number,
-                                  constructed: #constructed
-                                }
-                                .value_error()
-                            })?
-                            .value
-                        )),
+                                            ::der::Tag::ContextSpecific {
+                                                constructed: #constructed,
+                                                number: #tag_number,
+                                            } => {
+                    __der_field = Some(&"ImplicitVariant");
+                    Ok(Self::ImplicitVariant(
+                                                ::der::asn1::ContextSpecific::<>::decode_implicit(
+                                                    reader,
+                                                    #tag_number
+                                                )?
+                                                .ok_or_else(|| {
+                                                    der::Tag::ContextSpecific {
+                                                      number: #tag_number,
+                                                      constructed: #constructed
+                                                    }
+                                                    .value_error()
+                                                })?
+                                                .value
+                                            ))
                     }
+                                        }
                     .to_string()
                 );
 
@@ -402,6 +490,19 @@ mod tests {
                     .to_string()
                 );
 
+                assert_eq!(
+                    variant.to_string_tag_tokens().to_string(),
+                    quote! {
+                        Self::ImplicitVariant(variant) => ::der::asn1::ContextSpecificRef {
+                            tag_number: #tag_number,
+                            tag_mode: ::der::TagMode::Implicit,
+                            value: variant,
+                        }
+                        .string_tag(),
+                    }
+                    .to_string()
+                );
+
                 assert_eq!(
                     variant.to_tagged_tokens().to_string(),
                     quote! {
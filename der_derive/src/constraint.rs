@@ -0,0 +1,129 @@
+//! Support for ASN.1 `SIZE` and value range constraints on fields.
+
+use crate::tag::ParseError;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use std::str::FromStr;
+
+/// Bounds of a `size = "..."` or `range = "..."` constraint.
+///
+/// Parsed from a single value, e.g. `"4"`, or a range with an inclusive upper bound, e.g.
+/// `"1..=65535"`, `"1.."`, `"..=255"`. As in ASN.1, `MIN` and `MAX` can be used for unbounded
+/// ends, e.g. `"1..MAX"`.
+///
+/// Exclusive upper bounds such as `"0..255"` are rejected, since `0..255` is inclusive in ASN.1
+/// but exclusive in Rust.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Bounds {
+    /// Lower bound, if any.
+    pub lower: Option<i128>,
+
+    /// Upper bound, if any.
+    pub upper: Option<i128>,
+}
+
+impl Bounds {
+    /// Lower these bounds to a Rust range expression.
+    pub fn to_tokens(&self) -> TokenStream {
+        let lower = self.lower.map(Literal::i128_unsuffixed);
+        let upper = self.upper.map(Literal::i128_unsuffixed);
+
+        match upper {
+            Some(upper) => quote!(#lower..=#upper),
+            None => quote!(#lower..),
+        }
+    }
+
+    /// Lower these bounds to a `der::per::Bounds` expression.
+    pub fn to_per_tokens(&self) -> TokenStream {
+        let bound = |bound: Option<i128>| match bound.map(Literal::i128_unsuffixed) {
+            Some(bound) => quote!(Some(#bound)),
+            None => quote!(None),
+        };
+
+        let lower = bound(self.lower);
+        let upper = bound(self.upper);
+        quote!(::der::per::Bounds { lower: #lower, upper: #upper })
+    }
+}
+
+impl FromStr for Bounds {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        /// Parse one end of a range, where `unbounded` (i.e. `MIN` or `MAX`) means no bound.
+        fn bound(s: &str, unbounded: &str) -> Result<Option<i128>, ParseError> {
+            match s.trim() {
+                s if s.is_empty() || s == unbounded => Ok(None),
+                s => s.parse().map(Some).map_err(|_| ParseError),
+            }
+        }
+
+        if let Some((lower, upper)) = s.split_once("..=") {
+            let upper = bound(upper, "")?.ok_or(ParseError)?;
+            return Ok(Self {
+                lower: bound(lower, "MIN")?,
+                upper: Some(upper),
+            });
+        }
+
+        if let Some((lower, upper)) = s.split_once("..") {
+            if bound(upper, "MAX")?.is_some() {
+                return Err(ParseError);
+            }
+
+            return Ok(Self {
+                lower: bound(lower, "MIN")?,
+                upper: None,
+            });
+        }
+
+        let value = bound(s, "")?.ok_or(ParseError)?;
+        Ok(Self {
+            lower: Some(value),
+            upper: Some(value),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::Bounds;
+
+    #[test]
+    fn parse() {
+        let bounds = |s: &str| s.parse::<Bounds>().map(|b| (b.lower, b.upper)).ok();
+
+        assert_eq!(bounds("4"), Some((Some(4), Some(4))));
+        assert_eq!(bounds("1..=65535"), Some((Some(1), Some(65535))));
+        assert_eq!(bounds("1..MAX"), Some((Some(1), None)));
+        assert_eq!(bounds("1.."), Some((Some(1), None)));
+        assert_eq!(bounds("MIN..=-1"), Some((None, Some(-1))));
+        assert_eq!(bounds("..=255"), Some((None, Some(255))));
+
+        assert_eq!(bounds("0..255"), None);
+        assert_eq!(bounds("1..=MAX"), None);
+        assert_eq!(bounds("one"), None);
+    }
+
+    #[test]
+    fn to_tokens() {
+        assert_eq!(
+            "1..=2".parse::<Bounds>().unwrap().to_tokens().to_string(),
+            "1 ..= 2"
+        );
+        assert_eq!(
+            "1..MAX".parse::<Bounds>().unwrap().to_tokens().to_string(),
+            "1 .."
+        );
+        assert_eq!(
+            "1..MAX"
+                .parse::<Bounds>()
+                .unwrap()
+                .to_per_tokens()
+                .to_string(),
+            ":: der :: per :: Bounds { lower : Some (1) , upper : None }"
+        );
+    }
+}
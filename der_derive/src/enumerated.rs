@@ -19,10 +19,10 @@ pub(crate) struct DeriveEnumerated {
     repr: Ident,
 
     /// Whether or not to tag the enum as an integer
-    integer: bool,
+    pub(crate) integer: bool,
 
     /// Variants of this enum.
-    variants: Vec<EnumeratedVariant>,
+    pub(crate) variants: Vec<EnumeratedVariant>,
 
     /// Error type for `DecodeValue` implementation.
     error: ErrorType,
@@ -128,6 +128,22 @@ impl DeriveEnumerated {
 
         let error = self.error.to_token_stream();
 
+        // Every value has an encoding of the same length if the discriminants do
+        let lengths = self
+            .variants
+            .iter()
+            .map(EnumeratedVariant::value_len)
+            .collect::<Option<Vec<_>>>();
+
+        let fixed_len = match lengths.as_deref() {
+            Some([len, rest @ ..]) if rest.iter().all(|other| other == len) => quote! {
+                impl ::der::FixedLen for #ident {
+                    const ENCODED_LEN: ::der::Length = ::der::Length::new(#len).for_tlv_const(#tag);
+                }
+            },
+            _ => TokenStream::new(),
+        };
+
         quote! {
             impl<#default_lifetime> ::der::DecodeValue<#default_lifetime> for #ident {
                 type Error = #error;
@@ -164,6 +180,8 @@ impl DeriveEnumerated {
                     }
                 }
             }
+
+            #fixed_len
         }
     }
 }
@@ -171,10 +189,10 @@ impl DeriveEnumerated {
 /// "IR" for a variant of a derived `Enumerated`.
 pub struct EnumeratedVariant {
     /// Variant name.
-    ident: Ident,
+    pub(crate) ident: Ident,
 
     /// Integer value that this variant corresponds to.
-    discriminant: LitInt,
+    pub(crate) discriminant: LitInt,
 }
 
 impl EnumeratedVariant {
@@ -205,6 +223,15 @@ impl EnumeratedVariant {
         }
     }
 
+    /// Length of the DER encoding of the discriminant as an unsigned `INTEGER`, excluding the
+    /// header, if it can be parsed.
+    fn value_len(&self) -> Option<u16> {
+        let value = self.discriminant.base10_parse::<u64>().ok()?;
+
+        // Minimal number of octets, with a leading zero octet if the high bit would be set
+        u16::try_from((u64::BITS - value.leading_zeros()) / 8 + 1).ok()
+    }
+
     /// Write the body for the derived [`TryFrom`] impl.
     pub fn to_try_from_tokens(&self) -> TokenStream {
         let ident = &self.ident;
@@ -0,0 +1,474 @@
+//! Support for deriving the `JerEncode` and `JerDecode` traits on structs and enums for the
+//! purposes of encoding/decoding ASN.1 `SEQUENCE`, `CHOICE` and `ENUMERATED` types using the
+//! JSON Encoding Rules.
+
+use crate::{
+    TypeAttrs,
+    choice::ChoiceVariant,
+    enumerated::DeriveEnumerated,
+    sequence::{SequenceField, option_inner},
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Fields, Generics, Ident, Type, WhereClause, ext::IdentExt, parse_quote,
+};
+
+/// Derive the `JerEncode` and `JerDecode` traits for a struct or enum.
+pub(crate) struct DeriveJer {
+    /// Name of the struct or enum.
+    ident: Ident,
+
+    /// Generics of the struct or enum.
+    generics: Generics,
+
+    /// ASN.1 type being derived.
+    kind: JerKind,
+}
+
+/// ASN.1 types supported by the `Jer` derive.
+enum JerKind {
+    /// `SEQUENCE` struct.
+    Sequence {
+        /// Fields of the struct.
+        fields: Vec<SequenceField>,
+
+        /// Does the `SEQUENCE` contain the `...` extension marker?
+        extensible: bool,
+    },
+
+    /// `CHOICE` enum with a 1-element tuple variant per alternative.
+    Choice {
+        /// Names of the variants of the enum, along with the type of their value.
+        variants: Vec<(Ident, Type)>,
+    },
+
+    /// `ENUMERATED` C-like enum.
+    Enumerated {
+        /// Discriminants and names of the variants.
+        variants: Vec<(i128, Ident)>,
+
+        /// Is the enum encoded as an `INTEGER` rather than an `ENUMERATED`?
+        integer: bool,
+    },
+}
+
+impl DeriveJer {
+    /// Parse [`DeriveInput`].
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let ident = input.ident.clone();
+        let generics = input.generics.clone();
+
+        let kind = match &input.data {
+            Data::Struct(data) => {
+                let type_attrs = TypeAttrs::parse(&input.attrs)?;
+
+                let fields: Vec<SequenceField> = data
+                    .fields
+                    .iter()
+                    .map(|field| SequenceField::new(field, &type_attrs))
+                    .collect::<syn::Result<_>>()?;
+
+                for field in &fields {
+                    validate_field(field)?;
+                }
+
+                JerKind::Sequence {
+                    fields,
+                    extensible: type_attrs.extensible,
+                }
+            }
+            Data::Enum(data)
+                if data
+                    .variants
+                    .iter()
+                    .all(|variant| matches!(variant.fields, Fields::Unit)) =>
+            {
+                let enumerated = DeriveEnumerated::new(input.clone())?;
+                let variants = enumerated
+                    .variants
+                    .into_iter()
+                    .map(|variant| Ok((variant.discriminant.base10_parse()?, variant.ident)))
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                JerKind::Enumerated {
+                    variants,
+                    integer: enumerated.integer,
+                }
+            }
+            Data::Enum(data) => {
+                let type_attrs = TypeAttrs::parse(&input.attrs)?;
+                let mut variants = Vec::new();
+
+                for variant in &data.variants {
+                    let choice_variant = ChoiceVariant::new(variant, &type_attrs)?;
+
+                    if choice_variant.attrs.other {
+                        abort!(
+                            &variant.ident,
+                            "`other` isn't supported by `Jer`: JER doesn't encode tags, so \
+                             unknown alternatives can't be captured",
+                        );
+                    }
+
+                    let ty = match &variant.fields {
+                        Fields::Unnamed(fields) => fields.unnamed[0].ty.clone(),
+                        _ => abort!(
+                            &variant.ident,
+                            "enum variant must be a 1-element tuple struct"
+                        ),
+                    };
+
+                    variants.push((choice_variant.ident, ty));
+                }
+
+                JerKind::Choice { variants }
+            }
+            Data::Union(_) => abort!(
+                ident,
+                "can't derive `Jer` on this type: only `struct` and `enum` types are allowed",
+            ),
+        };
+
+        Ok(Self {
+            ident,
+            generics,
+            kind,
+        })
+    }
+
+    /// Lower the derived output into a [`TokenStream`].
+    pub fn to_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, _) = self.generics.split_for_impl();
+        let encode_where_clause = self.where_clause(quote!(::der::jer::JerEncode));
+        let decode_where_clause = self.where_clause(quote!(::der::jer::JerDecode));
+
+        let (encode_body, decode_body) = match &self.kind {
+            JerKind::Sequence { fields, extensible } => {
+                self.to_sequence_tokens(fields, *extensible)
+            }
+            JerKind::Choice { variants } => to_choice_tokens(variants),
+            JerKind::Enumerated { variants, integer } => to_enumerated_tokens(variants, *integer),
+        };
+
+        quote! {
+            impl #impl_generics ::der::jer::JerEncode for #ident #ty_generics #encode_where_clause {
+                fn to_jer_value(&self) -> ::der::Result<::der::jer::Value> {
+                    #encode_body
+                }
+            }
+
+            impl #impl_generics ::der::jer::JerDecode for #ident #ty_generics #decode_where_clause {
+                fn from_jer_value(value: &::der::jer::Value) -> ::der::Result<Self> {
+                    #decode_body
+                }
+            }
+        }
+    }
+
+    /// Build the where clause of an impl of the given trait.
+    ///
+    /// When the type has type parameters, the types of its fields are bounded by the trait,
+    /// since they may depend on them, e.g. `AlgorithmIdentifier<Params>`.
+    fn where_clause(&self, bound: TokenStream) -> Option<WhereClause> {
+        let mut where_clause = self.generics.where_clause.clone();
+
+        if self.generics.type_params().next().is_some() {
+            let field_types: Vec<&Type> = match &self.kind {
+                JerKind::Sequence { fields, .. } => fields
+                    .iter()
+                    .map(|field| option_inner(&field.field_type).unwrap_or(&field.field_type))
+                    .collect(),
+                JerKind::Choice { variants } => variants.iter().map(|(_, ty)| ty).collect(),
+                JerKind::Enumerated { .. } => Vec::new(),
+            };
+
+            let predicates = &mut where_clause
+                .get_or_insert_with(|| parse_quote!(where))
+                .predicates;
+
+            for ty in field_types {
+                predicates.push(parse_quote!(#ty: #bound));
+            }
+        }
+
+        where_clause
+    }
+
+    /// Lower the bodies of `to_jer_value` and `from_jer_value` for a `SEQUENCE` (X.697
+    /// Section 26).
+    ///
+    /// Absent `OPTIONAL` fields and `DEFAULT` fields with their default value are omitted.
+    /// Unknown members are only accepted by an extensible `SEQUENCE`.
+    fn to_sequence_tokens(
+        &self,
+        fields: &[SequenceField],
+        extensible: bool,
+    ) -> (TokenStream, TokenStream) {
+        let type_name = self.ident.unraw().to_string();
+
+        let mut encode_checks = Vec::new();
+        let mut members = Vec::new();
+        let mut decode_fields = Vec::new();
+        let mut names = Vec::new();
+        let mut idents = Vec::new();
+
+        for field in fields {
+            let ident = &field.ident;
+            let ty = &field.field_type;
+            let name = identifier(ident);
+            let field_name = ident.unraw().to_string();
+            let annotate = quote!(.map_err(|err| err.in_field(&#type_name, &#field_name))?);
+            let decode_checks = field.to_constraint_tokens(quote!(&#ident));
+
+            encode_checks.push(field.to_constraint_tokens(quote!(&self.#ident)));
+
+            if let Some(inner) = option_inner(ty) {
+                members.push(quote! {
+                    (#name, self.#ident.as_ref().map(::der::jer::JerEncode::to_jer_value).transpose()#annotate)
+                });
+                decode_fields.push(quote! {
+                    let #ident = value
+                        .get(#name)
+                        .map(<#inner as ::der::jer::JerDecode>::from_jer_value)
+                        .transpose()
+                        #annotate;
+                });
+            } else if let Some(default) = &field.attrs.default {
+                members.push(quote! {
+                    (#name, if self.#ident != #default() {
+                        Some(::der::jer::JerEncode::to_jer_value(&self.#ident)#annotate)
+                    } else {
+                        None
+                    })
+                });
+                decode_fields.push(quote! {
+                    let #ident = match value.get(#name) {
+                        Some(member) => <#ty as ::der::jer::JerDecode>::from_jer_value(member)#annotate,
+                        None => #default(),
+                    };
+                });
+            } else {
+                members.push(quote! {
+                    (#name, Some(::der::jer::JerEncode::to_jer_value(&self.#ident)#annotate))
+                });
+                decode_fields.push(quote! {
+                    let #ident = ::der::jer::member(value, #name)
+                        .and_then(<#ty as ::der::jer::JerDecode>::from_jer_value)
+                        #annotate;
+                });
+            }
+
+            decode_fields.push(decode_checks);
+            names.push(name);
+            idents.push(ident);
+        }
+
+        let check_members = if extensible {
+            quote!(value.as_object()?;)
+        } else {
+            quote!(::der::jer::check_members(value, &[#(#names),*])?;)
+        };
+
+        let encode = quote! {
+            #(#encode_checks)*
+            Ok(::der::jer::object([#(#members),*]))
+        };
+
+        let decode = quote! {
+            #check_members
+            #(#decode_fields)*
+            Ok(Self { #(#idents),* })
+        };
+
+        (encode, decode)
+    }
+}
+
+/// Check that a field of a `SEQUENCE` can be encoded by the `Jer` derive.
+fn validate_field(field: &SequenceField) -> syn::Result<()> {
+    if field.attrs.asn1_type.is_some() {
+        abort!(
+            &field.ident,
+            "`type` isn't supported by `Jer`: fields are encoded according to their Rust type, \
+             so use the ASN.1 type, e.g. `der::asn1::PrintableString`, as the field's type",
+        );
+    }
+
+    if field.attrs.extension_additions {
+        abort!(
+            &field.ident,
+            "`extension_additions` isn't supported by `Jer`: unknown members are skipped",
+        );
+    }
+
+    if field.attrs.optional && option_inner(&field.field_type).is_none() {
+        abort!(&field.ident, "`optional` fields must have an `Option` type");
+    }
+
+    Ok(())
+}
+
+/// Lower the bodies of `to_jer_value` and `from_jer_value` for a `CHOICE` (X.697 Section 29).
+///
+/// Values are encoded as an object with a single member named after the chosen alternative.
+fn to_choice_tokens(variants: &[(Ident, Type)]) -> (TokenStream, TokenStream) {
+    let mut encode_arms = Vec::new();
+    let mut decode_arms = Vec::new();
+
+    for (ident, ty) in variants {
+        let name = identifier(ident);
+
+        encode_arms.push(quote! {
+            Self::#ident(value) => (#name, ::der::jer::JerEncode::to_jer_value(value)?),
+        });
+
+        decode_arms.push(quote! {
+            #name => Ok(Self::#ident(<#ty as ::der::jer::JerDecode>::from_jer_value(member)?)),
+        });
+    }
+
+    let encode = quote! {
+        let (name, value) = match self {
+            #(#encode_arms)*
+        };
+        Ok(::der::jer::object([(name, Some(value))]))
+    };
+
+    let decode = quote! {
+        match value.as_object()? {
+            [(name, member)] => match name.as_str() {
+                #(#decode_arms)*
+                _ => Err(::der::ErrorKind::Json.into()),
+            },
+            _ => Err(::der::ErrorKind::Json.into()),
+        }
+    };
+
+    (encode, decode)
+}
+
+/// Lower the bodies of `to_jer_value` and `from_jer_value` for an `ENUMERATED` (X.697
+/// Section 21).
+///
+/// Values are encoded as a string containing the name of the variant, or as a number
+/// containing the discriminant when the enum is encoded as an `INTEGER`.
+fn to_enumerated_tokens(variants: &[(i128, Ident)], integer: bool) -> (TokenStream, TokenStream) {
+    let idents: Vec<_> = variants.iter().map(|(_, ident)| ident).collect();
+
+    if integer {
+        let values: Vec<_> = variants
+            .iter()
+            .map(|(value, _)| Literal::i128_unsuffixed(*value))
+            .collect();
+
+        let encode = quote! {
+            let value: i128 = match self {
+                #(Self::#idents => #values,)*
+            };
+            ::der::jer::JerEncode::to_jer_value(&value)
+        };
+
+        let decode = quote! {
+            match <i128 as ::der::jer::JerDecode>::from_jer_value(value)? {
+                #(#values => Ok(Self::#idents),)*
+                _ => Err(::der::ErrorKind::Value { tag: ::der::Tag::Integer }.into()),
+            }
+        };
+
+        (encode, decode)
+    } else {
+        let names: Vec<_> = idents.iter().map(|ident| identifier(ident)).collect();
+
+        let encode = quote! {
+            let name = match self {
+                #(Self::#idents => #names,)*
+            };
+            Ok(::der::jer::Value::String(name.into()))
+        };
+
+        let decode = quote! {
+            match value.as_str()? {
+                #(#names => Ok(Self::#idents),)*
+                _ => Err(::der::ErrorKind::Value { tag: ::der::Tag::Enumerated }.into()),
+            }
+        };
+
+        (encode, decode)
+    }
+}
+
+/// ASN.1 identifier for a Rust field or variant name, i.e. its lowerCamelCase form.
+fn identifier(ident: &Ident) -> String {
+    let mut identifier = String::new();
+    let mut upper = false;
+
+    for c in ident.unraw().to_string().chars() {
+        if c == '_' {
+            upper = !identifier.is_empty();
+        } else if identifier.is_empty() {
+            identifier.extend(c.to_lowercase());
+        } else if upper {
+            identifier.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            identifier.push(c);
+        }
+    }
+
+    identifier
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DeriveJer, identifier};
+    use proc_macro2::Span;
+    use syn::{Ident, parse_quote};
+
+    #[test]
+    fn identifiers() {
+        for (ident, expected) in [
+            ("tbs_certificate", "tbsCertificate"),
+            ("r#type", "type"),
+            ("KeyCompromise", "keyCompromise"),
+            ("Rfc822Name", "rfc822Name"),
+            ("version", "version"),
+        ] {
+            let ident = match ident.strip_prefix("r#") {
+                Some(raw) => Ident::new_raw(raw, Span::call_site()),
+                None => Ident::new(ident, Span::call_site()),
+            };
+
+            assert_eq!(identifier(&ident), expected);
+        }
+    }
+
+    #[test]
+    fn type_rejected() {
+        let input = parse_quote! {
+            #[derive(Jer)]
+            pub struct Example {
+                #[asn1(type = "PrintableString")]
+                pub name: String,
+            }
+        };
+
+        assert!(DeriveJer::new(input).is_err());
+    }
+
+    #[test]
+    fn other_rejected() {
+        let input = parse_quote! {
+            #[derive(Jer)]
+            pub enum Example {
+                Name(Ia5String),
+
+                #[asn1(other = "true")]
+                Other(Any),
+            }
+        };
+
+        assert!(DeriveJer::new(input).is_err());
+    }
+}
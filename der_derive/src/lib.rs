@@ -8,7 +8,10 @@
 //!
 //! - [`Choice`][`derive@Choice`]: map ASN.1 `CHOICE` to a Rust enum.
 //! - [`Enumerated`][`derive@Enumerated`]: map ASN.1 `ENUMERATED` to a C-like Rust enum.
+//! - [`Jer`][`derive@Jer`]: encode any of the above using the JSON Encoding Rules.
+//! - [`Per`][`derive@Per`]: encode any of the above using the Packed Encoding Rules.
 //! - [`Sequence`][`derive@Sequence`]: map ASN.1 `SEQUENCE` to a Rust struct.
+//! - [`Set`][`derive@Set`]: map ASN.1 `SET` to a Rust struct.
 //! - [`ValueOrd`][`derive@ValueOrd`]: determine DER ordering for ASN.1 `SET OF`.
 //!
 //! Note that this crate shouldn't be used directly, but instead accessed
@@ -60,6 +63,32 @@
 //! `From<<U as TryFrom<T>>::Error>`. Since `U` and `T` types are usually the same
 //! implementing `From<Infallible>` should do it.
 //!
+//! ### `#[asn1(extensible = "true")]` attribute: extensible `SEQUENCE` types
+//!
+//! This attribute can be added to a `struct` deriving [`Sequence`] to declare
+//! that the ASN.1 `SEQUENCE` contains the `...` extension marker.
+//!
+//! It can also be added to a `struct` deriving [`Set`], in which case elements
+//! whose tags don't match any field are skipped when decoding, and to an `enum`
+//! deriving [`Choice`], where it only affects the encoding produced by [`Per`].
+//!
+//! When decoding, any unknown elements which follow the last known field are
+//! skipped rather than resulting in a `TrailingData` error, so that encodings
+//! produced by later versions of a specification can still be decoded.
+//!
+//! The skipped elements can be captured instead using the
+//! `extension_additions` field-level attribute described below.
+//!
+//! ### `#[asn1(fixed_len = "true")]` attribute: fixed-length `SEQUENCE` types
+//!
+//! This attribute can be added to a `struct` deriving [`Sequence`] whose fields
+//! all have an encoding of a fixed length, to also impl the `FixedLen` trait,
+//! e.g. for use with `Encode::encode_to_array`. The length is computed at compile
+//! time from the `FixedLen` impls of the field types.
+//!
+//! Fields can't be `optional`, `default` or `IMPLICIT`, or have a `type`
+//! attribute, and the `SEQUENCE` can't be `extensible`.
+//!
 //! ## Field-level attributes
 //!
 //! The following attributes can be added to either the fields of a particular
@@ -83,10 +112,56 @@
 //! skip over unrecognized lower-numbered `CONTEXT-SPECIFIC` fields when
 //! looking for a particular field of a struct.
 //!
+//! ### `#[asn1(extension_additions = "true")]` attribute: capturing unknown elements
+//!
+//! This attribute can be applied to the last field of a `struct` which has the
+//! `extensible` attribute, and captures the unknown elements which follow the
+//! last known field so they're re-encoded as-is, e.g. `Vec<der::asn1::Any>`.
+//!
+//! The field's type must impl [`Default`] and [`Extend`] over an element type
+//! which impls `Decode` and `Encode`, and its references must impl
+//! [`IntoIterator`] over that element type.
+//!
 //! ### `#[asn1(optional = "true")]` attribute: support for `OPTIONAL` fields
 //!
 //! This attribute explicitly annotates a field as `OPTIONAL`.
 //!
+//! ### `#[asn1(other = "true")]` attribute: capturing unknown `CHOICE` alternatives
+//!
+//! This attribute can be applied to one variant of an `enum` deriving [`Choice`]
+//! to capture any alternative whose tag doesn't match the other variants, rather
+//! than failing to decode. The variant must hold a type which decodes any tag,
+//! e.g. `der::asn1::Any` or `der::asn1::AnyRef`, and is re-encoded as-is.
+//!
+//! The `other` variant only applies once the value is known to be present:
+//! `Choice::can_decode` only matches the tags of the other variants, so an
+//! `OPTIONAL` or `DEFAULT` field of this type is considered absent when it's
+//! followed by an element with an unknown tag.
+//!
+//! ### `#[asn1(range = "...")]` attribute: value range constraints
+//!
+//! This attribute can be applied to the fields of a `struct` deriving [`Sequence`]
+//! or [`Set`] to enforce a value range constraint, e.g. `INTEGER (1..65535)`, when
+//! both decoding and encoding. Values outside of the range result in an
+//! `ErrorKind::ConstraintViolation` error.
+//!
+//! The bounds are given as a single value, e.g. `"4"`, or as a range with an
+//! inclusive upper bound, e.g. `"1..=65535"`, `"..=255"` or `"1.."`, where `MIN`
+//! and `MAX` can be used for unbounded ends as in ASN.1, e.g. `"1..MAX"`. Ranges
+//! with an exclusive upper bound, e.g. `"0..255"`, are rejected since they're
+//! inclusive in ASN.1 but exclusive in Rust. The field's type must impl
+//! `PartialOrd` and be inferrable from an integer literal, e.g. `u16`.
+//!
+//! ### `#[asn1(size = "...")]` attribute: `SIZE` constraints
+//!
+//! This attribute is like `range`, but constrains the size of a field's value
+//! rather than the value itself, e.g. `SEQUENCE SIZE (1..MAX) OF`: the number of
+//! elements of a `SEQUENCE OF` or `SET OF`, characters of a string, octets of an
+//! `OCTET STRING`, or bits of a `BIT STRING`, as given by the `der::constraint::Size`
+//! trait.
+//!
+//! Both constraints only apply to the values of `OPTIONAL` fields which are present.
+//!
 //! ### `#[asn1(type = "...")]` attribute: ASN.1 type declaration
 //!
 //! This attribute can be used to specify the ASN.1 type for a particular
@@ -103,10 +178,13 @@
 //! - `BIT STRING`: performs an intermediate conversion to [`der::asn1::BitString`]
 //! - `IA5String`: performs an intermediate conversion to [`der::asn1::IA5String`]
 //! - `GeneralizedTime`: performs an intermediate conversion to [`der::asn1::GeneralizedTime`]
+//! - `NumericString`: performs an intermediate conversion to [`der::asn1::NumericString`]
 //! - `OCTET STRING`: performs an intermediate conversion to [`der::asn1::OctetString`]
 //! - `PrintableString`: performs an intermediate conversion to [`der::asn1::PrintableString`]
+//! - `UniversalString`: performs an intermediate conversion to [`der::asn1::UniversalString`]
 //! - `UTCTime`: performs an intermediate conversion to [`der::asn1::UtcTime`]
 //! - `UTF8String`: performs an intermediate conversion to [`der::asn1::Utf8String`]
+//! - `VisibleString`: performs an intermediate conversion to [`der::asn1::VisibleString`]
 //!
 //! ### `#[asn1(constructed = "...")]` attribute: support for constructed inner types
 //!
@@ -125,14 +203,19 @@
 //!
 //! [`der`]: https://docs.rs/der/
 //! [`Choice`]: derive@Choice
+//! [`Per`]: derive@Per
 //! [`Sequence`]: derive@Sequence
+//! [`Set`]: derive@Set
 //! [`der::asn1::BitString`]: https://docs.rs/der/latest/der/asn1/struct.BitString.html
 //! [`der::asn1::Ia5String`]: https://docs.rs/der/latest/der/asn1/struct.Ia5String.html
 //! [`der::asn1::GeneralizedTime`]: https://docs.rs/der/latest/der/asn1/struct.GeneralizedTime.html
+//! [`der::asn1::NumericString`]: https://docs.rs/der/latest/der/asn1/struct.NumericString.html
 //! [`der::asn1::OctetString`]: https://docs.rs/der/latest/der/asn1/struct.OctetString.html
 //! [`der::asn1::PrintableString`]: https://docs.rs/der/latest/der/asn1/struct.PrintableString.html
+//! [`der::asn1::UniversalString`]: https://docs.rs/der/latest/der/asn1/struct.UniversalString.html
 //! [`der::asn1::UtcTime`]: https://docs.rs/der/latest/der/asn1/struct.UtcTime.html
 //! [`der::asn1::Utf8String`]: https://docs.rs/der/latest/der/asn1/struct.Utf8String.html
+//! [`der::asn1::VisibleString`]: https://docs.rs/der/latest/der/asn1/struct.VisibleString.html
 
 #![crate_type = "proc-macro"]
 #![forbid(unsafe_code)]
@@ -153,8 +236,12 @@ mod asn1_type;
 mod attributes;
 mod bitstring;
 mod choice;
+mod constraint;
 mod enumerated;
+mod jer;
+mod per;
 mod sequence;
+mod set;
 mod tag;
 mod value_ord;
 
@@ -164,19 +251,43 @@ use crate::{
     bitstring::DeriveBitString,
     choice::DeriveChoice,
     enumerated::DeriveEnumerated,
+    jer::DeriveJer,
+    per::DerivePer,
     sequence::DeriveSequence,
+    set::DeriveSet,
     tag::{Tag, TagMode, TagNumber},
     value_ord::DeriveValueOrd,
 };
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use syn::{DeriveInput, Lifetime, parse_macro_input};
+use quote::quote;
+use syn::{DeriveInput, Ident, Lifetime, parse_macro_input};
 
 /// Get the default lifetime.
 fn default_lifetime() -> Lifetime {
     Lifetime::new("'__der_lifetime", Span::call_site())
 }
 
+/// Get the variable holding the name of the field currently being decoded, which is used to
+/// annotate errors.
+fn current_field() -> Ident {
+    Ident::new("__der_field", Span::call_site())
+}
+
+/// Wrap the statements decoding a field from `reader` so that readers which record spans record
+/// the field's span.
+fn record_span(
+    type_name: &str,
+    field_name: &str,
+    decode: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    quote! {
+        let __der_span = ::der::Span::start(&*reader);
+        #decode
+        ::der::FieldSpan::record(reader, __der_span, #type_name, #field_name);
+    }
+}
+
 /// Derive the [`Choice`][1] trait on an `enum`.
 ///
 /// This custom derive macro can be used to automatically impl the
@@ -252,6 +363,9 @@ pub fn derive_choice(input: TokenStream) -> TokenStream {
 ///
 /// Note that the derive macro will write a `TryFrom<...>` impl for the
 /// provided `#[repr]`, which is used by the decoder.
+///
+/// If every discriminant is encoded using the same number of octets, as in
+/// the example above, the `FixedLen` trait is also impl'd.
 #[proc_macro_derive(Enumerated, attributes(asn1))]
 pub fn derive_enumerated(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -305,6 +419,136 @@ pub fn derive_sequence(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derive the [`DecodeValue`][1], [`EncodeValue`][2], [`FixedTag`][3] traits on a `struct`.
+///
+/// This custom derive macro can be used to automatically impl the traits needed to
+/// decode/encode any struct as an ASN.1 `SET`, i.e. a collection of elements which may
+/// appear in any order.
+///
+/// Fields support the same `#[asn1]` attributes as [`Sequence`], with the exception of
+/// `extension_additions`. Each field must have a distinct tag: untagged fields are matched by
+/// the tag(s) of their type, so fields whose types can't be identified by their tag should be
+/// `context_specific`.
+///
+/// When decoding BER the elements may appear in any order, while DER requires them to be
+/// sorted by the class and number of their tags, which is the order they're encoded in.
+/// Setting the `extensible` container attribute skips elements whose tags don't match
+/// any field rather than returning an error.
+///
+/// # Usage
+///
+/// ```ignore
+/// use der::{asn1::Utf8StringRef, Set};
+///
+/// /// `Contact ::= SET { name UTF8String, age [0] INTEGER OPTIONAL }`
+/// #[derive(Set)]
+/// pub struct Contact<'a> {
+///     pub name: Utf8StringRef<'a>,
+///
+///     #[asn1(context_specific = "0", optional = "true")]
+///     pub age: Option<u8>,
+/// }
+/// ```
+///
+/// [1]: https://docs.rs/der/latest/der/trait.DecodeValue.html
+/// [2]: https://docs.rs/der/latest/der/trait.EncodeValue.html
+/// [3]: https://docs.rs/der/latest/der/trait.FixedTag.html
+#[proc_macro_derive(Set, attributes(asn1))]
+pub fn derive_set(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match DeriveSet::new(input) {
+        Ok(t) => t.to_tokens_all().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Derive the [`JerEncode`][1] and [`JerDecode`][2] traits, which encode a type using the
+/// JSON Encoding Rules (JER) of ITU-T X.697.
+///
+/// This custom derive macro can be used alongside [`Sequence`] on a `struct`, [`Choice`] on an
+/// `enum` with 1-element tuple variants, or [`Enumerated`] on a C-like `enum`, and accepts the
+/// same `#[asn1]` attributes, with some differences:
+///
+/// - Fields are encoded according to their Rust type, which must impl `JerEncode` and
+///   `JerDecode`, so the `type` attribute isn't supported on `struct` fields. `OPTIONAL`
+///   fields must have an `Option` type.
+/// - Members of a `SEQUENCE` and alternatives of a `CHOICE` are named after the lowerCamelCase
+///   form of the field or variant name, e.g. `tbs_certificate` becomes `tbsCertificate`.
+/// - The `extensible` container attribute makes a `SEQUENCE` accept unknown members, which are
+///   skipped, so `extension_additions` and `other` aren't supported.
+/// - Tags aren't encoded.
+///
+/// # Usage
+///
+/// ```ignore
+/// use der::{asn1::Ia5String, Jer, Sequence};
+///
+/// /// `Contact ::= SEQUENCE { name IA5String, age INTEGER OPTIONAL }`
+/// #[derive(Jer, Sequence)]
+/// pub struct Contact {
+///     pub name: Ia5String,
+///
+///     #[asn1(optional = "true")]
+///     pub age: Option<u8>,
+/// }
+/// ```
+///
+/// [1]: https://docs.rs/der/latest/der/jer/trait.JerEncode.html
+/// [2]: https://docs.rs/der/latest/der/jer/trait.JerDecode.html
+#[proc_macro_derive(Jer, attributes(asn1))]
+pub fn derive_jer(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match DeriveJer::new(input) {
+        Ok(t) => t.to_tokens().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Derive the [`PerEncode`][1] and [`PerDecode`][2] traits, which encode a type using the
+/// Packed Encoding Rules (PER) of ITU-T X.691.
+///
+/// This custom derive macro can be used alongside [`Sequence`] on a `struct`, [`Choice`] on an
+/// `enum` with 1-element tuple variants, or [`Enumerated`] on a C-like `enum`, and accepts the
+/// same `#[asn1]` attributes, with some differences:
+///
+/// - Fields are encoded according to their Rust type, which must impl `PerEncode` and
+///   `PerDecode`, so the `type` attribute isn't supported on `struct` fields. `OPTIONAL`
+///   fields must have an `Option` type.
+/// - The `range` and `size` attributes are PER-visible constraints, which determine how
+///   values are encoded rather than merely being checked.
+/// - The `extensible` container attribute adds the extension bit to a `SEQUENCE` or
+///   `CHOICE`. Extension additions of a `SEQUENCE` are skipped when decoding, while those
+///   of a `CHOICE` result in an error, so `extension_additions` and `other` aren't supported.
+/// - Tags aren't encoded, except that the alternatives of a `CHOICE` are numbered in the
+///   order of their tags.
+///
+/// # Usage
+///
+/// ```ignore
+/// use der::{asn1::Ia5String, Per, Sequence};
+///
+/// /// `Contact ::= SEQUENCE { name IA5String (SIZE (1..64)), age INTEGER (0..150) OPTIONAL }`
+/// #[derive(Per, Sequence)]
+/// pub struct Contact {
+///     #[asn1(size = "1..=64")]
+///     pub name: Ia5String,
+///
+///     #[asn1(range = "0..=150", optional = "true")]
+///     pub age: Option<u8>,
+/// }
+/// ```
+///
+/// [1]: https://docs.rs/der/latest/der/per/trait.PerEncode.html
+/// [2]: https://docs.rs/der/latest/der/per/trait.PerDecode.html
+#[proc_macro_derive(Per, attributes(asn1))]
+pub fn derive_per(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match DerivePer::new(input) {
+        Ok(t) => t.to_tokens().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 /// Derive the [`EncodeValue`][1] trait on a `struct`.
 ///
 /// [1]: https://docs.rs/der/latest/der/trait.EncodeValue.html
@@ -0,0 +1,462 @@
+//! Support for deriving the `PerEncode` and `PerDecode` traits on structs and enums for the
+//! purposes of encoding/decoding ASN.1 `SEQUENCE`, `CHOICE` and `ENUMERATED` types using the
+//! Packed Encoding Rules.
+
+use crate::{
+    FieldAttrs, TypeAttrs,
+    choice::ChoiceVariant,
+    enumerated::DeriveEnumerated,
+    sequence::{SequenceField, option_inner},
+};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Generics, Ident, Type, ext::IdentExt};
+
+/// Derive the `PerEncode` and `PerDecode` traits for a struct or enum.
+pub(crate) struct DerivePer {
+    /// Name of the struct or enum.
+    ident: Ident,
+
+    /// Generics of the struct or enum.
+    generics: Generics,
+
+    /// ASN.1 type being derived.
+    kind: PerKind,
+}
+
+/// ASN.1 types supported by the `Per` derive.
+enum PerKind {
+    /// `SEQUENCE` struct.
+    Sequence {
+        /// Fields of the struct.
+        fields: Vec<SequenceField>,
+
+        /// Does the `SEQUENCE` contain the `...` extension marker?
+        extensible: bool,
+    },
+
+    /// `CHOICE` enum with a 1-element tuple variant per alternative.
+    Choice {
+        /// Variants of the enum, along with the type of their value.
+        variants: Vec<(ChoiceVariant, Type)>,
+
+        /// Does the `CHOICE` contain the `...` extension marker?
+        extensible: bool,
+    },
+
+    /// `ENUMERATED` C-like enum.
+    Enumerated {
+        /// Discriminants and names of the variants, in ascending order of discriminant.
+        variants: Vec<(i128, Ident)>,
+
+        /// Is the enum encoded as an `INTEGER` rather than an `ENUMERATED`?
+        integer: bool,
+    },
+}
+
+impl DerivePer {
+    /// Parse [`DeriveInput`].
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let ident = input.ident.clone();
+        let generics = input.generics.clone();
+
+        let kind = match &input.data {
+            Data::Struct(data) => {
+                let type_attrs = TypeAttrs::parse(&input.attrs)?;
+
+                let fields: Vec<SequenceField> = data
+                    .fields
+                    .iter()
+                    .map(|field| SequenceField::new(field, &type_attrs))
+                    .collect::<syn::Result<_>>()?;
+
+                for field in &fields {
+                    validate_field(field)?;
+                }
+
+                PerKind::Sequence {
+                    fields,
+                    extensible: type_attrs.extensible,
+                }
+            }
+            Data::Enum(data)
+                if data
+                    .variants
+                    .iter()
+                    .all(|variant| matches!(variant.fields, Fields::Unit)) =>
+            {
+                let enumerated = DeriveEnumerated::new(input.clone())?;
+                let mut variants = enumerated
+                    .variants
+                    .into_iter()
+                    .map(|variant| Ok((variant.discriminant.base10_parse()?, variant.ident)))
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                variants.sort_by_key(|(value, _)| *value);
+
+                PerKind::Enumerated {
+                    variants,
+                    integer: enumerated.integer,
+                }
+            }
+            Data::Enum(data) => {
+                let type_attrs = TypeAttrs::parse(&input.attrs)?;
+                let mut variants = Vec::new();
+
+                for variant in &data.variants {
+                    let choice_variant = ChoiceVariant::new(variant, &type_attrs)?;
+
+                    if choice_variant.attrs.other {
+                        abort!(
+                            &variant.ident,
+                            "`other` isn't supported by `Per`: PER doesn't encode tags, so \
+                             unknown alternatives can't be captured",
+                        );
+                    }
+
+                    let ty = match &variant.fields {
+                        Fields::Unnamed(fields) => fields.unnamed[0].ty.clone(),
+                        _ => abort!(
+                            &variant.ident,
+                            "enum variant must be a 1-element tuple struct"
+                        ),
+                    };
+
+                    variants.push((choice_variant, ty));
+                }
+
+                PerKind::Choice {
+                    variants,
+                    extensible: type_attrs.extensible,
+                }
+            }
+            Data::Union(_) => abort!(
+                ident,
+                "can't derive `Per` on this type: only `struct` and `enum` types are allowed",
+            ),
+        };
+
+        Ok(Self {
+            ident,
+            generics,
+            kind,
+        })
+    }
+
+    /// Lower the derived output into a [`TokenStream`].
+    pub fn to_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let (encode_body, decode_body) = match &self.kind {
+            PerKind::Sequence { fields, extensible } => {
+                self.to_sequence_tokens(fields, *extensible)
+            }
+            PerKind::Choice {
+                variants,
+                extensible,
+            } => to_choice_tokens(variants, *extensible),
+            PerKind::Enumerated { variants, integer } => to_enumerated_tokens(variants, *integer),
+        };
+
+        quote! {
+            impl #impl_generics ::der::per::PerEncode for #ident #ty_generics #where_clause {
+                fn per_encode(
+                    &self,
+                    writer: &mut ::der::per::PerWriter,
+                    _constraints: &::der::per::Constraints,
+                ) -> ::der::Result<()> {
+                    #encode_body
+                }
+            }
+
+            impl #impl_generics ::der::per::PerDecode for #ident #ty_generics #where_clause {
+                fn per_decode(
+                    reader: &mut ::der::per::PerReader<'_>,
+                    _constraints: &::der::per::Constraints,
+                ) -> ::der::Result<Self> {
+                    #decode_body
+                }
+            }
+        }
+    }
+
+    /// Lower the bodies of `per_encode` and `per_decode` for a `SEQUENCE` (X.691 Section 19).
+    ///
+    /// The preamble contains the extension bit of an extensible `SEQUENCE`, followed by a
+    /// presence bit for each `OPTIONAL` or `DEFAULT` field.
+    fn to_sequence_tokens(
+        &self,
+        fields: &[SequenceField],
+        extensible: bool,
+    ) -> (TokenStream, TokenStream) {
+        let type_name = self.ident.unraw().to_string();
+
+        let mut preamble = Vec::new();
+        let mut encode_fields = Vec::new();
+        let mut decode_preamble = Vec::new();
+        let mut decode_fields = Vec::new();
+        let mut idents = Vec::new();
+
+        if extensible {
+            preamble.push(quote!(writer.write_bit(false);));
+            decode_preamble.push(quote!(let extended = reader.read_bit()?;));
+        }
+
+        for field in fields {
+            let ident = &field.ident;
+            let ty = &field.field_type;
+            let present = format_ident!("{}_present", ident.unraw());
+            let field_name = ident.unraw().to_string();
+            let constraints = to_constraints_tokens(&field.attrs);
+            let annotate = quote!(.map_err(|err| err.in_field(&#type_name, &#field_name))?);
+
+            if let Some(inner) = option_inner(ty) {
+                preamble.push(quote!(writer.write_bit(self.#ident.is_some());));
+                encode_fields.push(quote! {
+                    if let Some(value) = &self.#ident {
+                        ::der::per::PerEncode::per_encode(value, writer, &#constraints)#annotate;
+                    }
+                });
+
+                decode_preamble.push(quote!(let #present = reader.read_bit()?;));
+                decode_fields.push(quote! {
+                    let #ident = if #present {
+                        Some(<#inner as ::der::per::PerDecode>::per_decode(reader, &#constraints)#annotate)
+                    } else {
+                        None
+                    };
+                });
+            } else if let Some(default) = &field.attrs.default {
+                preamble.push(quote!(writer.write_bit(self.#ident != #default());));
+                encode_fields.push(quote! {
+                    if self.#ident != #default() {
+                        ::der::per::PerEncode::per_encode(&self.#ident, writer, &#constraints)#annotate;
+                    }
+                });
+
+                decode_preamble.push(quote!(let #present = reader.read_bit()?;));
+                decode_fields.push(quote! {
+                    let #ident = if #present {
+                        <#ty as ::der::per::PerDecode>::per_decode(reader, &#constraints)#annotate
+                    } else {
+                        #default()
+                    };
+                });
+            } else {
+                encode_fields.push(quote! {
+                    ::der::per::PerEncode::per_encode(&self.#ident, writer, &#constraints)#annotate;
+                });
+                decode_fields.push(quote! {
+                    let #ident = <#ty as ::der::per::PerDecode>::per_decode(reader, &#constraints)#annotate;
+                });
+            }
+
+            idents.push(ident);
+        }
+
+        let skip_extension_additions = extensible.then(|| {
+            quote! {
+                if extended {
+                    reader.skip_extension_additions()?;
+                }
+            }
+        });
+
+        let encode = quote! {
+            #(#preamble)*
+            #(#encode_fields)*
+            Ok(())
+        };
+
+        let decode = quote! {
+            #(#decode_preamble)*
+            #(#decode_fields)*
+            #skip_extension_additions
+            Ok(Self { #(#idents),* })
+        };
+
+        (encode, decode)
+    }
+}
+
+/// Check that a field of a `SEQUENCE` can be encoded by the `Per` derive.
+fn validate_field(field: &SequenceField) -> syn::Result<()> {
+    if field.attrs.asn1_type.is_some() {
+        abort!(
+            &field.ident,
+            "`type` isn't supported by `Per`: fields are encoded according to their Rust type, \
+             so use the ASN.1 type, e.g. `der::asn1::PrintableString`, as the field's type",
+        );
+    }
+
+    if field.attrs.extension_additions {
+        abort!(
+            &field.ident,
+            "`extension_additions` isn't supported by `Per`: extension additions are skipped",
+        );
+    }
+
+    if field.attrs.optional && option_inner(&field.field_type).is_none() {
+        abort!(&field.ident, "`optional` fields must have an `Option` type");
+    }
+
+    Ok(())
+}
+
+/// Lower the `range` and `size` attributes of a field to a `der::per::Constraints` expression.
+fn to_constraints_tokens(attrs: &FieldAttrs) -> TokenStream {
+    let unbounded = || quote!(::der::per::Bounds::UNBOUNDED);
+    let value = attrs
+        .range
+        .as_ref()
+        .map_or_else(unbounded, |bounds| bounds.to_per_tokens());
+    let size = attrs
+        .size
+        .as_ref()
+        .map_or_else(unbounded, |bounds| bounds.to_per_tokens());
+
+    quote!(::der::per::Constraints { value: #value, size: #size })
+}
+
+/// Lower the bodies of `per_encode` and `per_decode` for a `CHOICE` (X.691 Section 23).
+///
+/// Alternatives are identified by their index in the canonical order of their tags.
+fn to_choice_tokens(
+    variants: &[(ChoiceVariant, Type)],
+    extensible: bool,
+) -> (TokenStream, TokenStream) {
+    let count = variants.len();
+    let tags: Vec<_> = variants
+        .iter()
+        .map(|(variant, _)| variant.tag.to_tokens())
+        .collect();
+    let tags = quote!(let tags: [::der::Tag; #count] = [#(#tags),*];);
+
+    let mut encode_arms = Vec::new();
+    let mut decode_arms = Vec::new();
+
+    for (i, (variant, ty)) in variants.iter().enumerate() {
+        let ident = &variant.ident;
+
+        encode_arms.push(quote! {
+            Self::#ident(value) => {
+                let index = ::der::per::choice_index(&tags, tags[#i]);
+                writer.write_choice_index(index, #count, #extensible)?;
+                ::der::per::PerEncode::per_encode(value, writer, &::der::per::Constraints::NONE)
+            }
+        });
+
+        decode_arms.push(quote! {
+            if index == ::der::per::choice_index(&tags, tags[#i]) {
+                let value = <#ty as ::der::per::PerDecode>::per_decode(
+                    reader,
+                    &::der::per::Constraints::NONE,
+                )?;
+                return Ok(Self::#ident(value));
+            }
+        });
+    }
+
+    let encode = quote! {
+        #tags
+        match self {
+            #(#encode_arms)*
+        }
+    };
+
+    let decode = quote! {
+        #tags
+        let index = reader.read_choice_index(#count, #extensible)?;
+        #(#decode_arms)*
+        Err(reader.error(::der::ErrorKind::ConstraintViolation {
+            constraint: ::der::constraint::Constraint::ValueRange,
+        }))
+    };
+
+    (encode, decode)
+}
+
+/// Lower the bodies of `per_encode` and `per_decode` for an `ENUMERATED` (X.691 Section 14).
+///
+/// Values are encoded as their index in ascending order of their discriminants, or as the
+/// discriminant itself when the enum is encoded as an `INTEGER`.
+fn to_enumerated_tokens(variants: &[(i128, Ident)], integer: bool) -> (TokenStream, TokenStream) {
+    let idents: Vec<_> = variants.iter().map(|(_, ident)| ident).collect();
+
+    if integer {
+        let values: Vec<_> = variants
+            .iter()
+            .map(|(value, _)| Literal::i128_unsuffixed(*value))
+            .collect();
+
+        let encode = quote! {
+            let value: i128 = match self {
+                #(Self::#idents => #values,)*
+            };
+            writer.write_unconstrained_whole_number(value)
+        };
+
+        let decode = quote! {
+            match reader.read_unconstrained_whole_number()? {
+                #(#values => Ok(Self::#idents),)*
+                _ => Err(reader.error(::der::ErrorKind::Value { tag: ::der::Tag::Integer })),
+            }
+        };
+
+        (encode, decode)
+    } else {
+        let count = Literal::usize_unsuffixed(variants.len());
+        let indexes: Vec<_> = (0..variants.len()).map(Literal::usize_unsuffixed).collect();
+
+        let encode = quote! {
+            let index = match self {
+                #(Self::#idents => #indexes,)*
+            };
+            writer.write_constrained_whole_number(index, #count)
+        };
+
+        let decode = quote! {
+            match reader.read_constrained_whole_number(#count)? {
+                #(#indexes => Ok(Self::#idents),)*
+                _ => Err(reader.error(::der::ErrorKind::Value { tag: ::der::Tag::Enumerated })),
+            }
+        };
+
+        (encode, decode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DerivePer;
+    use syn::parse_quote;
+
+    #[test]
+    fn type_rejected() {
+        let input = parse_quote! {
+            #[derive(Per)]
+            pub struct Example {
+                #[asn1(type = "PrintableString")]
+                pub name: String,
+            }
+        };
+
+        assert!(DerivePer::new(input).is_err());
+    }
+
+    #[test]
+    fn other_rejected() {
+        let input = parse_quote! {
+            #[derive(Per)]
+            pub enum Example {
+                Name(Ia5String),
+
+                #[asn1(other = "true")]
+                Other(Any),
+            }
+        };
+
+        assert!(DerivePer::new(input).is_err());
+    }
+}
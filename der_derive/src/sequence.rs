@@ -3,11 +3,11 @@
 
 mod field;
 
-use crate::{ErrorType, TypeAttrs, default_lifetime};
-use field::SequenceField;
+use crate::{ErrorType, Tag, TagMode, TypeAttrs, current_field, default_lifetime, record_span};
+pub(crate) use field::{SequenceField, option_inner};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
-use syn::{DeriveInput, GenericParam, Generics, Ident, Lifetime, LifetimeParam};
+use syn::{DeriveInput, GenericParam, Generics, Ident, Lifetime, LifetimeParam, ext::IdentExt};
 
 /// Derive the `Sequence` trait for a struct
 pub(crate) struct DeriveSequence {
@@ -22,6 +22,12 @@ pub(crate) struct DeriveSequence {
 
     /// Error type for `DecodeValue` implementation.
     error: ErrorType,
+
+    /// Are unknown trailing elements (i.e. extension additions) allowed?
+    extensible: bool,
+
+    /// Should `FixedLen` be derived?
+    fixed_len: bool,
 }
 
 impl DeriveSequence {
@@ -37,20 +43,83 @@ impl DeriveSequence {
 
         let type_attrs = TypeAttrs::parse(&input.attrs)?;
 
-        let fields = data
+        let fields: Vec<SequenceField> = data
             .fields
             .iter()
             .map(|field| SequenceField::new(field, &type_attrs))
             .collect::<syn::Result<_>>()?;
 
+        if let Some(field) = fields
+            .iter()
+            .rev()
+            .skip(1)
+            .find(|field| field.attrs.extension_additions)
+        {
+            abort!(
+                &field.ident,
+                "`extension_additions` field must be the last field of the struct",
+            );
+        }
+
+        if type_attrs.fixed_len {
+            if type_attrs.extensible {
+                abort!(
+                    input.ident,
+                    "`fixed_len` can't be combined with the `extensible` attribute",
+                );
+            }
+
+            if let Some(field) = fields.iter().find(|field| {
+                let attrs = &field.attrs;
+
+                attrs.optional
+                    || attrs.default.is_some()
+                    || attrs.asn1_type.is_some()
+                    || attrs.extensible
+                    || (attrs.context_specific.is_some() && attrs.tag_mode == TagMode::Implicit)
+            }) {
+                abort!(
+                    &field.ident,
+                    "fields of a `fixed_len` struct can't be `optional`, `default`, `extensible`, \
+                    `IMPLICIT` or have a `type`",
+                );
+            }
+        }
+
         Ok(Self {
             ident: input.ident,
             generics: input.generics.clone(),
             fields,
             error: type_attrs.error.clone(),
+            extensible: type_attrs.extensible,
+            fixed_len: type_attrs.fixed_len,
         })
     }
 
+    /// Expressions for the constant encoded lengths of the fields of a `fixed_len` struct.
+    fn fixed_len_fields(&self) -> Vec<TokenStream> {
+        self.fields
+            .iter()
+            .map(|field| {
+                let ty = &field.field_type;
+                let len = quote!(<#ty as ::der::FixedLen>::ENCODED_LEN);
+
+                match field.attrs.context_specific {
+                    Some(number) => {
+                        let tag = Tag::ContextSpecific {
+                            constructed: true,
+                            number,
+                        }
+                        .to_tokens();
+
+                        quote!(#len.for_tlv_const(#tag))
+                    }
+                    None => len,
+                }
+            })
+            .collect()
+    }
+
     /// Use the first lifetime parameter as lifetime for Decode/Encode lifetime
     /// if none found, add one.
     fn calc_lifetime(&self) -> (Generics, Lifetime) {
@@ -96,16 +165,39 @@ impl DeriveSequence {
         let mut decode_body = Vec::new();
         let mut decode_result = Vec::new();
 
+        let error = self.error.to_token_stream();
+        let current_field = current_field();
+        let type_name = ident.unraw().to_string();
+
         for field in &self.fields {
-            decode_body.push(field.to_decode_tokens());
+            let field_name = field.ident.unraw().to_string();
+            decode_body.push(record_span(
+                &type_name,
+                &field_name,
+                field.to_decode_tokens(),
+            ));
             decode_result.push(&field.ident);
         }
 
-        let error = self.error.to_token_stream();
+        // Skip over unknown extension additions unless they're captured by a field
+        if self.extensible
+            && !self
+                .fields
+                .iter()
+                .any(|field| field.attrs.extension_additions)
+        {
+            decode_body.push(quote! {
+                while !reader.is_finished() {
+                    let header = ::der::Header::decode(reader)?;
+                    reader.skip_value(header)?;
+                }
+            });
+        }
 
         quote! {
             impl #impl_generics ::der::DecodeValue<#lifetime> for #ident #ty_generics #where_clause {
                 type Error = #error;
+                const INDEFINITE_LENGTH: bool = true;
 
                 fn decode_value<R: ::der::Reader<#lifetime>>(
                     reader: &mut R,
@@ -113,13 +205,16 @@ impl DeriveSequence {
                 ) -> ::core::result::Result<Self, #error> {
                     use ::der::{Decode as _, DecodeValue as _, Reader as _};
 
-                    reader.read_nested(header.length, |reader| {
+                    let mut #current_field = None;
+
+                    reader.read_contents(header, |reader| {
                         #(#decode_body)*
 
                         Ok(Self {
                             #(#decode_result),*
                         })
                     })
+                    .map_err(|err| ::der::Error::annotate_field(err, &#type_name, #current_field))
                 }
             }
         }
@@ -134,28 +229,57 @@ impl DeriveSequence {
 
         let mut encoded_lengths = Vec::new();
         let mut encode_fields = Vec::new();
+        let mut constraint_checks = Vec::new();
 
         for field in &self.fields {
+            if field.attrs.extension_additions {
+                let ident = &field.ident;
+
+                encoded_lengths.push(quote! {
+                    ::core::iter::IntoIterator::into_iter(&self.#ident)
+                        .try_fold(::der::Length::ZERO, |acc, element| acc + element.encoded_len()?)?
+                });
+                encode_fields.push(quote! {
+                    for element in &self.#ident {
+                        element.encode(writer)?;
+                    }
+                });
+                continue;
+            }
+
+            let ident = &field.ident;
+            constraint_checks.push(field.to_constraint_tokens(quote!(&self.#ident)));
+
             let field = field.to_encode_tokens();
             encoded_lengths.push(quote!(#field.encoded_len()?));
             encode_fields.push(quote!(#field.encode(writer)?;));
         }
 
+        let value_len = if self.fixed_len {
+            let fixed_lengths = self.fixed_len_fields();
+            quote!(Ok(::der::Length::sum(&[#(#fixed_lengths),*])))
+        } else {
+            quote! {
+                use ::der::Encode as _;
+
+                [
+                    #(#encoded_lengths),*
+                ]
+                    .into_iter()
+                    .try_fold(::der::Length::ZERO, |acc, len| acc + len)
+            }
+        };
+
         quote! {
 
             impl #impl_generics ::der::EncodeValue for #ident #ty_generics #where_clause {
                 fn value_len(&self) -> ::der::Result<::der::Length> {
-                    use ::der::Encode as _;
-
-                    [
-                        #(#encoded_lengths),*
-                    ]
-                        .into_iter()
-                        .try_fold(::der::Length::ZERO, |acc, len| acc + len)
+                    #value_len
                 }
 
                 fn encode_value(&self, writer: &mut impl ::der::Writer) -> ::der::Result<()> {
                     use ::der::Encode as _;
+                    #(#constraint_checks)*
                     #(#encode_fields)*
                     Ok(())
                 }
@@ -163,19 +287,41 @@ impl DeriveSequence {
         }
     }
 
+    /// Lower the derived output into a [`TokenStream`] for the FixedLen trait impl, if the
+    /// struct has the `fixed_len` attribute.
+    pub fn to_tokens_fixed_len(&self) -> TokenStream {
+        if !self.fixed_len {
+            return TokenStream::new();
+        }
+
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let fixed_lengths = self.fixed_len_fields();
+
+        quote! {
+            impl #impl_generics ::der::FixedLen for #ident #ty_generics #where_clause {
+                const ENCODED_LEN: ::der::Length = ::der::Length::sum(&[#(#fixed_lengths),*])
+                    .for_tlv_const(::der::Tag::Sequence);
+            }
+        }
+    }
+
     /// Lower the derived output into a [`TokenStream`] for trait impls:
     /// - EncodeValue
     /// - DecodeValue
     /// - Sequence
+    /// - FixedLen, with the `fixed_len` attribute
     pub fn to_tokens_all(&self) -> TokenStream {
         let decode_tokens = self.to_tokens_decode();
         let encode_tokens = self.to_tokens_encode();
         let sequence_trait_tokens = self.to_tokens_sequence_trait();
+        let fixed_len_tokens = self.to_tokens_fixed_len();
 
         quote! {
             #decode_tokens
             #encode_tokens
             #sequence_trait_tokens
+            #fixed_len_tokens
         }
     }
 }
@@ -408,4 +554,86 @@ mod tests {
         );
         assert_eq!(utf8_string.attrs.tag_mode, TagMode::Implicit);
     }
+
+    /// Extensible `SEQUENCE` example
+    #[test]
+    fn extensible_example() {
+        let input = parse_quote! {
+            #[asn1(extensible = "true")]
+            pub struct ExtensibleSequence {
+                version: u8,
+
+                #[asn1(extension_additions = "true")]
+                additions: Vec<Any>,
+            }
+        };
+
+        let ir = DeriveSequence::new(input).unwrap();
+        assert!(ir.extensible);
+        assert!(!ir.fields[0].attrs.extension_additions);
+        assert!(ir.fields[1].attrs.extension_additions);
+
+        // Extension additions must be captured by the last field
+        let input = parse_quote! {
+            #[asn1(extensible = "true")]
+            pub struct ExtensibleSequence {
+                #[asn1(extension_additions = "true")]
+                additions: Vec<Any>,
+
+                version: u8,
+            }
+        };
+
+        assert!(DeriveSequence::new(input).is_err());
+
+        // ...of an extensible `SEQUENCE`
+        let input = parse_quote! {
+            pub struct NonExtensibleSequence {
+                version: u8,
+
+                #[asn1(extension_additions = "true")]
+                additions: Vec<Any>,
+            }
+        };
+
+        assert!(DeriveSequence::new(input).is_err());
+    }
+
+    #[test]
+    fn fixed_len_example() {
+        let input = parse_quote! {
+            #[asn1(fixed_len = "true")]
+            pub struct Record {
+                flag: bool,
+
+                #[asn1(context_specific = "0")]
+                digest: OctetStringArray<32>,
+            }
+        };
+
+        let ir = DeriveSequence::new(input).unwrap();
+        assert!(ir.fixed_len);
+
+        // Fields whose encoding may be omitted are rejected
+        let input = parse_quote! {
+            #[asn1(fixed_len = "true")]
+            pub struct Record {
+                #[asn1(optional = "true")]
+                flag: Option<bool>,
+            }
+        };
+
+        assert!(DeriveSequence::new(input).is_err());
+
+        // ...as are `IMPLICIT` fields, whose header can't be derived from their type
+        let input = parse_quote! {
+            #[asn1(fixed_len = "true", tag_mode = "IMPLICIT")]
+            pub struct Record {
+                #[asn1(context_specific = "0")]
+                flag: bool,
+            }
+        };
+
+        assert!(DeriveSequence::new(input).is_err());
+    }
 }
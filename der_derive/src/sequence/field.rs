@@ -1,25 +1,25 @@
 //! Sequence field IR and lowerings
 
-use crate::{Asn1Type, FieldAttrs, TagMode, TagNumber, TypeAttrs};
+use crate::{Asn1Type, FieldAttrs, TagMode, TagNumber, TypeAttrs, current_field};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Field, Ident, Path, Type};
+use syn::{Field, GenericArgument, Ident, Path, PathArguments, Type, ext::IdentExt};
 
-/// "IR" for a field of a derived `Sequence`.
-pub(super) struct SequenceField {
+/// "IR" for a field of a derived `Sequence` or `Set`.
+pub(crate) struct SequenceField {
     /// Variant name.
-    pub(super) ident: Ident,
+    pub(crate) ident: Ident,
 
     /// Field-level attributes.
-    pub(super) attrs: FieldAttrs,
+    pub(crate) attrs: FieldAttrs,
 
     /// Field type
-    pub(super) field_type: Type,
+    pub(crate) field_type: Type,
 }
 
 impl SequenceField {
     /// Create a new [`SequenceField`] from the input [`Field`].
-    pub(super) fn new(field: &Field, type_attrs: &TypeAttrs) -> syn::Result<Self> {
+    pub(crate) fn new(field: &Field, type_attrs: &TypeAttrs) -> syn::Result<Self> {
         let ident = field.ident.as_ref().cloned().ok_or_else(|| {
             syn::Error::new_spanned(
                 field,
@@ -43,6 +43,41 @@ impl SequenceField {
             ));
         }
 
+        if attrs.other {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`other` is only allowed on CHOICE variants",
+            ));
+        }
+
+        if attrs.extension_additions {
+            if type_attrs.fixed_len {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "a `fixed_len` struct can't have an `extension_additions` field, as the \
+                    length of the extension additions it holds varies",
+                ));
+            }
+
+            if !type_attrs.extensible {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "`extension_additions` requires the `extensible` container attribute",
+                ));
+            }
+
+            if attrs.asn1_type.is_some()
+                || attrs.context_specific.is_some()
+                || attrs.default.is_some()
+                || attrs.optional
+            {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "`extension_additions` can't be combined with other field qualifiers",
+                ));
+            }
+        }
+
         Ok(Self {
             ident,
             attrs,
@@ -51,9 +86,23 @@ impl SequenceField {
     }
 
     /// Derive code for decoding a field of a sequence.
-    pub(super) fn to_decode_tokens(&self) -> TokenStream {
+    pub(crate) fn to_decode_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let decoder = self.to_decode_expr();
+        quote!(let #ident = #decoder;)
+    }
+
+    /// Derive an expression which decodes this field.
+    ///
+    /// The name of the field is stored in the variable returned by [`current_field`] while it's
+    /// being decoded, so that the containing type can annotate errors with it.
+    pub(crate) fn to_decode_expr(&self) -> TokenStream {
         let mut lowerer = LowerFieldDecoder::new(&self.attrs);
 
+        if self.attrs.extension_additions {
+            lowerer.apply_extension_additions(&self.field_type);
+        }
+
         if self.attrs.asn1_type.is_some() {
             lowerer.apply_asn1_type(self.attrs.optional);
         }
@@ -71,11 +120,47 @@ impl SequenceField {
             }
         }
 
+        lowerer.checks = self.to_constraint_tokens(quote!(&value));
         lowerer.into_tokens(&self.ident)
     }
 
+    /// Derive code for checking the `size` and `range` constraints of this field's value, which
+    /// is borrowed by `binding`.
+    ///
+    /// Absent values of `OPTIONAL` fields aren't checked.
+    pub(crate) fn to_constraint_tokens(&self, binding: TokenStream) -> TokenStream {
+        let mut checks = Vec::new();
+
+        if let Some(size) = &self.attrs.size {
+            let bounds = size.to_tokens();
+            checks.push(quote!(::der::constraint::check_size(value, #bounds)?;));
+        }
+
+        if let Some(range) = &self.attrs.range {
+            let bounds = range.to_tokens();
+            checks.push(quote!(::der::constraint::check_range(value, #bounds)?;));
+        }
+
+        if checks.is_empty() {
+            TokenStream::new()
+        } else if self.attrs.optional || option_inner(&self.field_type).is_some() {
+            quote! {
+                if let Some(value) = #binding {
+                    #(#checks)*
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let value = #binding;
+                    #(#checks)*
+                }
+            }
+        }
+    }
+
     /// Derive code for encoding a field of a sequence.
-    pub(super) fn to_encode_tokens(&self) -> TokenStream {
+    pub(crate) fn to_encode_tokens(&self) -> TokenStream {
         let mut lowerer = LowerFieldEncoder::new(&self.ident);
         let attrs = &self.attrs;
 
@@ -112,6 +197,9 @@ impl SequenceField {
 struct LowerFieldDecoder {
     /// Decoder-in-progress.
     decoder: TokenStream,
+
+    /// Constraint checks of the decoded `value`.
+    checks: TokenStream,
 }
 
 impl LowerFieldDecoder {
@@ -119,15 +207,26 @@ impl LowerFieldDecoder {
     fn new(attrs: &FieldAttrs) -> Self {
         Self {
             decoder: attrs.decoder(),
+            checks: TokenStream::new(),
         }
     }
 
     ///  the field decoder to tokens.
     fn into_tokens(self, ident: &Ident) -> TokenStream {
         let decoder = self.decoder;
+        let checks = self.checks;
+        let field_name = ident.unraw().to_string();
+
+        let current_field = current_field();
 
         quote! {
-            let #ident = #decoder;
+            {
+                #current_field = Some(&#field_name);
+                let value = #decoder;
+                #checks
+                #current_field = None;
+                value
+            }
         }
     }
 
@@ -146,10 +245,28 @@ impl LowerFieldDecoder {
         }
     }
 
+    /// Collect all of the remaining elements of the sequence.
+    fn apply_extension_additions(&mut self, field_type: &Type) {
+        self.decoder = quote! {
+            {
+                let mut additions = <#field_type as ::core::default::Default>::default();
+
+                while !reader.is_finished() {
+                    ::core::iter::Extend::extend(
+                        &mut additions,
+                        ::core::iter::once(reader.decode()?),
+                    );
+                }
+
+                additions
+            }
+        };
+    }
+
     /// Handle default value for a type.
     fn apply_default(&mut self, default: &Path, field_type: &Type) {
         self.decoder = quote! {
-            Option::<#field_type>::decode(reader)?.unwrap_or_else(#default);
+            Option::<#field_type>::decode(reader)?.unwrap_or_else(#default)
         };
     }
 }
@@ -253,6 +370,26 @@ impl LowerFieldEncoder {
     }
 }
 
+/// Get `T` if the given type is `Option<T>`.
+pub(crate) fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SequenceField;
@@ -288,7 +425,11 @@ mod tests {
             context_specific: None,
             default: None,
             extensible: false,
+            extension_additions: false,
             optional: false,
+            other: false,
+            range: None,
+            size: None,
             tag_mode: TagMode::Explicit,
             constructed: false,
             should_deref: false,
@@ -305,8 +446,13 @@ mod tests {
         assert_eq!(
             field.to_decode_tokens().to_string(),
             quote! {
-                let example_field = reader.decode()?;
-            }
+                            let example_field = {
+            __der_field = Some(&"example_field");
+            let value = reader.decode()?;
+            __der_field = None;
+            value
+            };
+                        }
             .to_string()
         );
 
@@ -329,7 +475,11 @@ mod tests {
             context_specific: Some(TagNumber(0)),
             default: None,
             extensible: false,
+            extension_additions: false,
             optional: false,
+            other: false,
+            range: None,
+            size: None,
             tag_mode: TagMode::Implicit,
             constructed: false,
             should_deref: false,
@@ -346,19 +496,24 @@ mod tests {
         assert_eq!(
             field.to_decode_tokens().to_string(),
             quote! {
-                let implicit_field = ::der::asn1::ContextSpecific::<>::decode_implicit(
-                        reader,
-                        ::der::TagNumber(0u32)
-                    )?
-                    .ok_or_else(|| {
-                        der::Tag::ContextSpecific {
-                            number: ::der::TagNumber(0u32),
-                            constructed: false
+                            let implicit_field = {
+            __der_field = Some(&"implicit_field");
+            let value = ::der::asn1::ContextSpecific::<>::decode_implicit(
+                                    reader,
+                                    ::der::TagNumber(0u32)
+                                )?
+                                .ok_or_else(|| {
+                                    der::Tag::ContextSpecific {
+                                        number: ::der::TagNumber(0u32),
+                                        constructed: false
+                                    }
+                                    .value_error()
+                                })?
+                                .value;
+            __der_field = None;
+            value
+            };
                         }
-                        .value_error()
-                    })?
-                    .value;
-            }
             .to_string()
         );
 
@@ -0,0 +1,347 @@
+//! Support for deriving the `DecodeValue`, `EncodeValue` and `FixedTag` traits on structs for
+//! the purposes of decoding/encoding ASN.1 `SET` types as mapped to struct fields.
+
+use crate::{
+    ErrorType, TypeAttrs, current_field, default_lifetime, record_span,
+    sequence::{SequenceField, option_inner},
+};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{DeriveInput, GenericParam, Generics, Ident, Lifetime, LifetimeParam, ext::IdentExt};
+
+/// Derive the `Set` trait for a struct
+pub(crate) struct DeriveSet {
+    /// Name of the set struct.
+    ident: Ident,
+
+    /// Generics of the struct.
+    generics: Generics,
+
+    /// Fields of the struct.
+    fields: Vec<SequenceField>,
+
+    /// Error type for `DecodeValue` implementation.
+    error: ErrorType,
+
+    /// Are elements with unknown tags (i.e. extension additions) allowed?
+    extensible: bool,
+}
+
+impl DeriveSet {
+    /// Parse [`DeriveInput`].
+    pub fn new(input: DeriveInput) -> syn::Result<Self> {
+        let data = match input.data {
+            syn::Data::Struct(data) => data,
+            _ => abort!(
+                input.ident,
+                "can't derive `Set` on this type: only `struct` types are allowed",
+            ),
+        };
+
+        let type_attrs = TypeAttrs::parse(&input.attrs)?;
+
+        let fields: Vec<SequenceField> = data
+            .fields
+            .iter()
+            .map(|field| SequenceField::new(field, &type_attrs))
+            .collect::<syn::Result<_>>()?;
+
+        if let Some(field) = fields.iter().find(|field| field.attrs.extension_additions) {
+            abort!(
+                &field.ident,
+                "`extension_additions` isn't supported by `Set`: elements of a `SET` are \
+                 unordered, so unknown elements can only be skipped using `extensible`",
+            );
+        }
+
+        Ok(Self {
+            ident: input.ident,
+            generics: input.generics.clone(),
+            fields,
+            error: type_attrs.error.clone(),
+            extensible: type_attrs.extensible,
+        })
+    }
+
+    /// Use the first lifetime parameter as lifetime for Decode/Encode lifetime
+    /// if none found, add one.
+    fn calc_lifetime(&self) -> (Generics, Lifetime) {
+        let mut generics = self.generics.clone();
+        let lifetime = generics
+            .lifetimes()
+            .next()
+            .map(|lt| lt.lifetime.clone())
+            .unwrap_or_else(|| {
+                let lt = default_lifetime();
+                generics
+                    .params
+                    .insert(0, GenericParam::Lifetime(LifetimeParam::new(lt.clone())));
+                lt
+            });
+        // We may or may not have inserted a lifetime.
+        (generics, lifetime)
+    }
+
+    /// Lower the derived output into a [`TokenStream`] for FixedTag trait impl.
+    pub fn to_tokens_fixed_tag(&self) -> TokenStream {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics ::der::FixedTag for #ident #ty_generics #where_clause {
+                const TAG: ::der::Tag = ::der::Tag::Set;
+            }
+        }
+    }
+
+    /// Lower the derived output into a [`TokenStream`] for DecodeValue trait impl.
+    ///
+    /// Elements are matched to fields by their tag, and may appear in any order when decoding
+    /// BER. DER requires them to appear in ascending tag order.
+    pub fn to_tokens_decode(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        let (der_generics, lifetime) = self.calc_lifetime();
+
+        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+        let (impl_generics, _, _) = der_generics.split_for_impl();
+
+        let error = self.error.to_token_stream();
+        let current_field = current_field();
+        let type_name = ident.unraw().to_string();
+
+        let mut slots = Vec::new();
+        let mut match_arms = Vec::new();
+        let mut decode_result = Vec::new();
+
+        for field in &self.fields {
+            let field_ident = &field.ident;
+            let slot = format_ident!("{}_slot", field_ident.unraw());
+            let decoder = field.to_decode_expr();
+            let predicate = can_decode(field, &lifetime);
+            let decode = record_span(
+                &type_name,
+                &field_ident.unraw().to_string(),
+                quote!(#slot = Some(#decoder);),
+            );
+
+            slots.push(quote!(let mut #slot = None;));
+            match_arms.push(quote! {
+                if #predicate {
+                    if #slot.is_some() {
+                        return Err(reader.error(::der::ErrorKind::SetDuplicate).into());
+                    }
+
+                    #decode
+                    continue;
+                }
+            });
+
+            // Missing fields are decoded from the exhausted reader, which yields the
+            // `None` or default value of optional fields and an error otherwise
+            decode_result.push(quote! {
+                let #field_ident = match #slot {
+                    Some(value) => value,
+                    None => #decoder,
+                };
+            });
+        }
+
+        let unknown = if self.extensible {
+            quote! {
+                let header = ::der::Header::decode(reader)?;
+                reader.skip_value(header)?;
+            }
+        } else {
+            quote! {
+                return Err(reader
+                    .error(::der::ErrorKind::TagUnexpected {
+                        expected: None,
+                        actual: tag,
+                    })
+                    .into());
+            }
+        };
+
+        let field_idents = self.fields.iter().map(|field| &field.ident);
+
+        quote! {
+            impl #impl_generics ::der::DecodeValue<#lifetime> for #ident #ty_generics #where_clause {
+                type Error = #error;
+                const INDEFINITE_LENGTH: bool = true;
+
+                fn decode_value<R: ::der::Reader<#lifetime>>(
+                    reader: &mut R,
+                    header: ::der::Header,
+                ) -> ::core::result::Result<Self, #error> {
+                    use ::der::{Decode as _, DecodeValue as _, Reader as _};
+
+                    let mut #current_field = None;
+
+                    reader.read_contents(header, |reader| {
+                        #(#slots)*
+                        let mut previous: Option<(::der::Class, ::der::TagNumber)> = None;
+
+                        while !reader.is_finished() {
+                            let tag = reader.peek_tag()?;
+                            let key = (tag.class(), tag.number());
+
+                            if !reader.encoding_rules().is_ber()
+                                && previous.is_some_and(|previous| previous >= key)
+                            {
+                                return Err(reader.error(::der::ErrorKind::SetOrdering).into());
+                            }
+
+                            previous = Some(key);
+
+                            #(#match_arms)*
+
+                            #unknown
+                        }
+
+                        #(#decode_result)*
+
+                        Ok(Self {
+                            #(#field_idents),*
+                        })
+                    })
+                    .map_err(|err| ::der::Error::annotate_field(err, &#type_name, #current_field))
+                }
+            }
+        }
+    }
+
+    /// Lower the derived output into a [`TokenStream`] for EncodeValue trait impl.
+    ///
+    /// Elements are encoded in ascending tag order as required by DER.
+    pub fn to_tokens_encode(&self) -> TokenStream {
+        let ident = &self.ident;
+
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        let mut encoded_lengths = Vec::new();
+        let mut element_tags = Vec::new();
+        let mut encode_arms = Vec::new();
+        let mut constraint_checks = Vec::new();
+
+        for (index, field) in self.fields.iter().enumerate() {
+            let ident = &field.ident;
+            constraint_checks.push(field.to_constraint_tokens(quote!(&self.#ident)));
+
+            let encoder = field.to_encode_tokens();
+
+            encoded_lengths.push(quote!(#encoder.encoded_len()?));
+            element_tags.push(if is_optional(field) {
+                quote!((#encoder).as_ref().map(|value| value.tag()))
+            } else {
+                quote!(Some((#encoder).tag()))
+            });
+            encode_arms.push(quote!(#index => #encoder.encode(writer)?,));
+        }
+
+        let count = self.fields.len();
+
+        quote! {
+            impl #impl_generics ::der::EncodeValue for #ident #ty_generics #where_clause {
+                fn value_len(&self) -> ::der::Result<::der::Length> {
+                    use ::der::Encode as _;
+
+                    [
+                        #(#encoded_lengths),*
+                    ]
+                        .into_iter()
+                        .try_fold(::der::Length::ZERO, |acc, len| acc + len)
+                }
+
+                fn encode_value(&self, writer: &mut impl ::der::Writer) -> ::der::Result<()> {
+                    use ::der::{Encode as _, Tagged as _};
+                    #(#constraint_checks)*
+
+                    let tags: [Option<(::der::Class, ::der::TagNumber)>; #count] = [
+                        #(#element_tags.map(|tag| (tag.class(), tag.number()))),*
+                    ];
+
+                    let mut order: [usize; #count] = ::core::array::from_fn(|index| index);
+                    order.sort_unstable_by_key(|&index| tags[index]);
+
+                    if order
+                        .windows(2)
+                        .any(|pair| tags[pair[0]].is_some() && tags[pair[0]] == tags[pair[1]])
+                    {
+                        return Err(::der::ErrorKind::SetDuplicate.into());
+                    }
+
+                    for index in order {
+                        match index {
+                            #(#encode_arms)*
+                            _ => {}
+                        }
+                    }
+
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Lower the derived output into a [`TokenStream`] for trait impls:
+    /// - EncodeValue
+    /// - DecodeValue
+    /// - FixedTag
+    pub fn to_tokens_all(&self) -> TokenStream {
+        let decode_tokens = self.to_tokens_decode();
+        let encode_tokens = self.to_tokens_encode();
+        let fixed_tag_tokens = self.to_tokens_fixed_tag();
+
+        quote! {
+            #decode_tokens
+            #encode_tokens
+            #fixed_tag_tokens
+        }
+    }
+}
+
+/// Is the encoder of this field an `Option` which is `None` when the field is omitted?
+fn is_optional(field: &SequenceField) -> bool {
+    field.attrs.optional
+        || field.attrs.default.is_some()
+        || option_inner(&field.field_type).is_some()
+}
+
+/// Derive an expression which checks whether an element with the given `tag` is an encoding
+/// of this field.
+fn can_decode(field: &SequenceField, lifetime: &Lifetime) -> TokenStream {
+    if let Some(tag_number) = field.attrs.context_specific {
+        let tag_number = tag_number.to_tokens();
+        quote!(tag.class() == ::der::Class::ContextSpecific && tag.number() == #tag_number)
+    } else if let Some(asn1_type) = field.attrs.asn1_type {
+        let asn1_tag = asn1_type.tag();
+        quote!(tag == #asn1_tag)
+    } else {
+        let field_type = option_inner(&field.field_type).unwrap_or(&field.field_type);
+        quote!(<#field_type as ::der::Choice<#lifetime>>::can_decode(tag))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::DeriveSet;
+    use syn::parse_quote;
+
+    #[test]
+    fn extension_additions_rejected() {
+        let input = parse_quote! {
+            #[derive(Set)]
+            #[asn1(extensible = "true")]
+            pub struct Example {
+                pub a: u8,
+
+                #[asn1(extension_additions = "true")]
+                pub additions: Vec<Any>,
+            }
+        };
+
+        assert!(DeriveSet::new(input).is_err());
+    }
+}
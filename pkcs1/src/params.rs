@@ -181,9 +181,10 @@ impl Default for RsaPssParams<'_> {
 
 impl<'a> DecodeValue<'a> for RsaPssParams<'a> {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             Ok(Self {
                 hash: reader
                     .context_specific(TagNumber(0), TagMode::Explicit)?
@@ -347,8 +348,9 @@ impl Default for RsaOaepParams<'_> {
 
 impl<'a> DecodeValue<'a> for RsaOaepParams<'a> {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             Ok(Self {
                 hash: reader
                     .context_specific(TagNumber(0), TagMode::Explicit)?
@@ -94,9 +94,10 @@ impl<'a> RsaPrivateKey<'a> {
 
 impl<'a> DecodeValue<'a> for RsaPrivateKey<'a> {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             let version = Version::decode(reader)?;
 
             let result = Self {
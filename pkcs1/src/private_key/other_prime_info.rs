@@ -31,9 +31,10 @@ pub struct OtherPrimeInfo<'a> {
 
 impl<'a> DecodeValue<'a> for OtherPrimeInfo<'a> {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             Ok(Self {
                 prime: reader.decode()?,
                 exponent: reader.decode()?,
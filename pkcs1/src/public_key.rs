@@ -35,8 +35,9 @@ pub struct RsaPublicKey<'a> {
 
 impl<'a> DecodeValue<'a> for RsaPublicKey<'a> {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             Ok(Self {
                 modulus: reader.decode()?,
                 public_exponent: reader.decode()?,
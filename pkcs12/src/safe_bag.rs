@@ -38,12 +38,13 @@ pub struct SafeBag {
 
 impl<'a> ::der::DecodeValue<'a> for SafeBag {
     type Error = ::der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: ::der::Reader<'a>>(
         reader: &mut R,
         header: ::der::Header,
     ) -> ::der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             let bag_id = reader.decode()?;
             let bag_value = match reader.tlv_bytes() {
                 Ok(v) => v.to_vec(),
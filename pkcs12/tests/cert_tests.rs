@@ -665,3 +665,23 @@ fn decode_sample_pfx2() {
     );
     assert_eq!(2048, mac_data.iterations);
 }
+
+/// Firefox exports PFX files using BER indefinite-length encodings
+#[test]
+fn decode_ber_pfx() {
+    let bytes = include_bytes!("examples/ValidCertificatePathTest1EE_firefox.p12");
+
+    // Indefinite lengths are not allowed in DER
+    assert!(Pfx::from_der(bytes).is_err());
+
+    let pfx = Pfx::from_ber(bytes).expect("expected valid data");
+    assert_eq!(Version::V3, pfx.version);
+    assert_eq!(ID_DATA, pfx.auth_safe.content_type);
+
+    let mac_data = pfx.mac_data.expect("expected MAC data");
+    assert_eq!(600000, mac_data.iterations);
+    assert_eq!(
+        hex!("CBCA8A91351043907005E7005871B9471D62CF1C"),
+        mac_data.mac.digest.as_bytes()
+    );
+}
@@ -148,6 +148,8 @@ impl EncryptionScheme {
 
 impl<'a> DecodeValue<'a> for EncryptionScheme {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool =
+        <AlgorithmIdentifierRef<'a> as DecodeValue<'a>>::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(decoder: &mut R, header: Header) -> der::Result<Self> {
         AlgorithmIdentifierRef::decode_value(decoder, header)?.try_into()
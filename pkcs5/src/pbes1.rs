@@ -68,6 +68,8 @@ impl Algorithm {
 
 impl<'a> DecodeValue<'a> for Algorithm {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool =
+        <AlgorithmIdentifierRef<'a> as DecodeValue<'a>>::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
         AlgorithmIdentifierRef::decode_value(reader, header)?.try_into()
@@ -128,6 +130,7 @@ pub struct Parameters {
 
 impl<'a> DecodeValue<'a> for Parameters {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = <AnyRef<'a> as DecodeValue<'a>>::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
         AnyRef::decode_value(reader, header)?.try_into()
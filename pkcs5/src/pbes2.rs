@@ -311,6 +311,7 @@ impl Parameters {
 
 impl<'a> DecodeValue<'a> for Parameters {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = <AnyRef<'a> as DecodeValue<'a>>::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
         AnyRef::decode_value(reader, header)?.try_into()
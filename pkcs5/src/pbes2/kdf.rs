@@ -108,6 +108,8 @@ impl Kdf {
 
 impl<'a> DecodeValue<'a> for Kdf {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool =
+        <AlgorithmIdentifierRef<'a> as DecodeValue<'a>>::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
         AlgorithmIdentifierRef::decode_value(reader, header)?.try_into()
@@ -228,6 +230,7 @@ impl Pbkdf2Params {
 
 impl<'a> DecodeValue<'a> for Pbkdf2Params {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = <AnyRef<'a> as DecodeValue<'a>>::INDEFINITE_LENGTH;
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
         AnyRef::decode_value(reader, header)?.try_into()
     }
@@ -423,6 +426,7 @@ impl ScryptParams {
 
 impl<'a> DecodeValue<'a> for ScryptParams {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = <AnyRef<'a> as DecodeValue<'a>>::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: der::Header) -> der::Result<Self> {
         AnyRef::decode_value(reader, header)?.try_into()
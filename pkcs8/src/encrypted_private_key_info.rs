@@ -97,9 +97,10 @@ where
     Data: DecodeValue<'a, Error = der::Error> + FixedTag + 'a,
 {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             Ok(Self {
                 encryption_algorithm: reader.decode()?,
                 encrypted_data: reader.decode()?,
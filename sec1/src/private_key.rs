@@ -96,9 +96,10 @@ impl<'a> EcPrivateKey<'a> {
 
 impl<'a> DecodeValue<'a> for EcPrivateKey<'a> {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             if u8::decode(reader)? != VERSION {
                 return Err(Tag::Integer.value_error());
             }
@@ -11,6 +11,9 @@ use der::{
 #[cfg(feature = "alloc")]
 use der::asn1::Any;
 
+#[cfg(feature = "jer")]
+use der::jer::{self, JerDecode, JerEncode, Value};
+
 /// X.509 `AlgorithmIdentifier` as defined in [RFC 5280 Section 4.1.1.2].
 ///
 /// ```text
@@ -36,9 +39,10 @@ where
     Params: Choice<'a, Error = der::Error>,
 {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             Ok(Self {
                 oid: reader.decode()?,
                 parameters: reader.decode()?,
@@ -90,6 +94,43 @@ where
     }
 }
 
+#[cfg(feature = "jer")]
+impl<Params> JerEncode for AlgorithmIdentifier<Params>
+where
+    Params: JerEncode,
+{
+    fn to_jer_value(&self) -> der::Result<Value> {
+        Ok(jer::object([
+            ("algorithm", Some(self.oid.to_jer_value()?)),
+            (
+                "parameters",
+                self.parameters
+                    .as_ref()
+                    .map(JerEncode::to_jer_value)
+                    .transpose()?,
+            ),
+        ]))
+    }
+}
+
+#[cfg(feature = "jer")]
+impl<Params> JerDecode for AlgorithmIdentifier<Params>
+where
+    Params: JerDecode,
+{
+    fn from_jer_value(value: &Value) -> der::Result<Self> {
+        jer::check_members(value, &["algorithm", "parameters"])?;
+
+        Ok(Self {
+            oid: ObjectIdentifier::from_jer_value(jer::member(value, "algorithm")?)?,
+            parameters: value
+                .get("parameters")
+                .map(Params::from_jer_value)
+                .transpose()?,
+        })
+    }
+}
+
 /// `AlgorithmIdentifier` reference which has `AnyRef` parameters.
 pub type AlgorithmIdentifierRef<'a> = AlgorithmIdentifier<AnyRef<'a>>;
 
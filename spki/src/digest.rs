@@ -1,7 +1,10 @@
+#![allow(deprecated)]
+
 use der::{Result, Writer};
 use digest::Digest;
 
 /// Adapter object to write to a digest backend
+#[deprecated(since = "0.8.0", note = "use `der::DigestWriter` instead")]
 pub struct DigestWriter<'d, D>(pub &'d mut D);
 
 impl<D> Writer for DigestWriter<'_, D>
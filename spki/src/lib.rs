@@ -68,6 +68,7 @@ pub use {
 };
 
 #[cfg(feature = "digest")]
+#[allow(deprecated)]
 pub use crate::digest::DigestWriter;
 
 /// Size of a SHA-256 SPKI fingerprint in bytes.
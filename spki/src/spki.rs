@@ -15,11 +15,7 @@ use der::{
 };
 
 #[cfg(feature = "fingerprint")]
-use {
-    crate::{DigestWriter, FingerprintBytes},
-    digest::Digest,
-    sha2::Sha256,
-};
+use {crate::FingerprintBytes, sha2::Sha256};
 
 #[cfg(feature = "pem")]
 use der::pem::PemLabel;
@@ -48,6 +44,7 @@ pub type SubjectPublicKeyInfoOwned = SubjectPublicKeyInfo<Any, BitString>;
 ///
 /// [RFC 5280 § 4.1.2.7]: https://tools.ietf.org/html/rfc5280#section-4.1.2.7
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SubjectPublicKeyInfo<Params, Key> {
     /// X.509 [`AlgorithmIdentifier`] for the public key type
@@ -84,9 +81,7 @@ where
     /// [RFC7469 § 2.1.1]: https://datatracker.ietf.org/doc/html/rfc7469#section-2.1.1
     #[cfg(feature = "fingerprint")]
     pub fn fingerprint_bytes(&self) -> Result<FingerprintBytes> {
-        let mut hash = Sha256::new();
-        self.encode(&mut DigestWriter(&mut hash))?;
-        Ok(hash.finalize().into())
+        Ok(self.digest::<Sha256>()?.into())
     }
 }
 
@@ -96,9 +91,10 @@ where
     Key: Decode<'a, Error = der::Error>,
 {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = true;
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             Ok(Self {
                 algorithm: reader.decode()?,
                 subject_public_key: Key::decode(reader)?,
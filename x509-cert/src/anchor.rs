@@ -13,6 +13,7 @@ use der::{
 };
 
 /// Version identifier for TrustAnchorInfo
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Default, Copy, PartialEq, Eq, Enumerated)]
 #[asn1(type = "INTEGER")]
 #[repr(u8)]
@@ -37,6 +38,7 @@ pub enum Version {
 ///
 /// TrustAnchorTitle ::= UTF8String (SIZE (1..64))
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, PartialEq, Eq, Sequence)]
 #[allow(missing_docs)]
 pub struct TrustAnchorInfo<P: Profile = Rfc5280> {
@@ -70,6 +72,7 @@ pub struct TrustAnchorInfo<P: Profile = Rfc5280> {
 ///     pathLenConstraint   [4] INTEGER (0..MAX) OPTIONAL
 /// }
 /// ```
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct CertPathControls<P: Profile = Rfc5280> {
@@ -128,6 +131,7 @@ pub type CertPolicyFlags = FlagSet<CertPolicies>;
 /// ```
 ///
 /// [RFC 5914 Section 3]: https://www.rfc-editor.org/rfc/rfc5914#section-3
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, PartialEq, Eq, Choice)]
 #[allow(clippy::large_enum_variant)]
 #[allow(missing_docs)]
@@ -17,7 +17,6 @@ use der::{
 use {
     der::Encode,
     digest::{Digest, Output},
-    spki::DigestWriter,
 };
 
 use crate::time::Time;
@@ -38,6 +37,22 @@ pub trait Profile: PartialEq + Debug + Eq + Clone + Copy + Default + 'static {
         }
     }
 
+    /// Checks to run when parsing validity times.
+    /// See [RFC 5280 Section 4.1.2.5.2]:
+    /// ```text
+    /// GeneralizedTime values MUST NOT include fractional seconds.
+    /// ```
+    ///
+    /// [RFC 5280 Section 4.1.2.5.2]: https://www.rfc-editor.org/rfc/rfc5280#section-4.1.2.5.2
+    fn check_time(time: &Time) -> der::Result<()> {
+        match time {
+            Time::GeneralTime(time) if time.to_date_time().nanoseconds() != 0 => {
+                Err(Tag::GeneralizedTime.value_error())
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Adjustments to the time to run while serializing validity.
     /// See [RFC 5280 Section 4.1.2.5]:
     /// ```text
@@ -71,6 +86,9 @@ impl Profile for Raw {
     fn check_serial_number(_serial: &SerialNumber<Self>) -> der::Result<()> {
         Ok(())
     }
+    fn check_time(_time: &Time) -> der::Result<()> {
+        Ok(())
+    }
     fn time_encoding(time: Time) -> der::Result<Time> {
         Ok(time)
     }
@@ -84,6 +102,7 @@ impl Profile for Raw {
 ///
 /// [RFC 5280 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Enumerated)]
 #[asn1(type = "INTEGER")]
 #[repr(u8)]
@@ -139,6 +158,7 @@ pub type TbsCertificate = TbsCertificateInner<Rfc5280>;
 ///
 /// [RFC 5280 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
 #[allow(missing_docs)]
 pub struct TbsCertificateInner<P: Profile = Rfc5280> {
@@ -332,6 +352,7 @@ pub type Certificate = CertificateInner<Rfc5280>;
 ///
 /// [RFC 5280 Section 4.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.1
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
 #[allow(missing_docs)]
 pub struct CertificateInner<P: Profile = Rfc5280> {
@@ -438,10 +459,6 @@ where
     where
         D: Digest,
     {
-        let mut digest = D::new();
-
-        self.encode(&mut DigestWriter(&mut digest))?;
-
-        Ok(digest.finalize())
+        self.digest::<D>()
     }
 }
@@ -99,6 +99,7 @@ impl_extension!(FreshestCrl, critical = false);
 /// ```
 ///
 /// [RFC 5280 Section 5.3.1]: https://datatracker.ietf.org/doc/html/rfc5280#section-5.3.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Enumerated)]
 #[allow(missing_docs)]
 #[repr(u32)]
@@ -22,6 +22,7 @@ use crate::ext::pkix::name::{DistributionPointName, GeneralNames};
 /// ```
 ///
 /// [RFC 5280 Section 5.2.5]: https://datatracker.ietf.org/doc/html/rfc5280#section-5.2.5
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct IssuingDistributionPoint {
@@ -76,6 +77,7 @@ impl_extension!(IssuingDistributionPoint, critical = true);
 /// ```
 ///
 /// [RFC 5280 Section 4.2.1.13]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.13
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, PartialEq, Eq, Sequence, ValueOrd)]
 #[allow(missing_docs)]
 pub struct DistributionPoint {
@@ -152,6 +152,7 @@ impl_extension!(ExtendedKeyUsage, critical = false);
 /// ```
 ///
 /// [RFC 3280 Section 4.2.1.12]: https://datatracker.ietf.org/doc/html/rfc3280#section-4.2.1.4
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, PartialEq, Eq, Sequence)]
 #[allow(missing_docs)]
 pub struct PrivateKeyUsagePeriod {
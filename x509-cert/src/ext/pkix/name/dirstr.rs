@@ -1,7 +1,7 @@
 use alloc::string::String;
 use der::{
     Choice, FixedTag, Header, Reader, ValueOrd,
-    asn1::{Any, PrintableString, TeletexString},
+    asn1::{Any, PrintableString, TeletexString, UniversalString},
 };
 
 /// DirectoryString as defined in [RFC 5280 Section 4.2.1.4].
@@ -37,10 +37,12 @@ use der::{
 /// ```
 ///
 /// The implication of the above paragraph is that `PrintableString` and
-/// `UTF8String` are the new types and the other types are legacy. Until
-/// the need arises, we only support `PrintableString` and `UTF8String`.
+/// `UTF8String` are the new types and the other types are legacy. We support
+/// `PrintableString` and `UTF8String`, along with the legacy `TeletexString`
+/// and `UniversalString` found in older certificates.
 ///
 /// [RFC 5280 Section 4.2.1.4]: https://datatracker.ietf.org/doc/html/rfc5280#section-4.2.1.4
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Choice, ValueOrd)]
 #[allow(missing_docs)]
 pub enum DirectoryString {
@@ -50,6 +52,9 @@ pub enum DirectoryString {
     #[asn1(type = "TeletexString")]
     TeletexString(TeletexString),
 
+    #[asn1(type = "UniversalString")]
+    UniversalString(UniversalString),
+
     #[asn1(type = "UTF8String")]
     Utf8String(String),
 }
@@ -72,6 +77,9 @@ impl<'a> der::DecodeValue<'a> for DirectoryString {
             TeletexString::TAG => {
                 TeletexString::decode_value(reader, header).map(Self::TeletexString)
             }
+            UniversalString::TAG => {
+                UniversalString::decode_value(reader, header).map(Self::UniversalString)
+            }
             String::TAG => String::decode_value(reader, header).map(Self::Utf8String),
             actual => Err(der::ErrorKind::TagUnexpected {
                 expected: None,
@@ -87,6 +95,7 @@ impl AsRef<str> for DirectoryString {
         match self {
             Self::PrintableString(s) => s.as_ref(),
             Self::TeletexString(s) => s.as_ref(),
+            Self::UniversalString(s) => s.as_ref(),
             Self::Utf8String(s) => s.as_ref(),
         }
     }
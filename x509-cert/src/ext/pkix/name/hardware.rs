@@ -15,6 +15,7 @@ use der::{
 /// ```
 ///
 /// [RFC 4108 Section 5]: https://www.rfc-editor.org/rfc/rfc4108#section-5
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
 #[allow(missing_docs)]
 pub struct HardwareModuleName {
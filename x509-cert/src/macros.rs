@@ -6,6 +6,7 @@
 /// - `AsRef` and `AsMut`
 /// - `DecodeValue` and `EncodeValue`
 /// - `FixedTag` mapping to the inner value's `FixedTag::TAG`
+/// - `JerEncode` and `JerDecode` when the `jer` feature of the calling crate is enabled
 ///
 /// The main case is simplifying newtypes which need an `AssociatedOid`
 #[macro_export]
@@ -50,6 +51,7 @@ macro_rules! impl_newtype {
 
         impl<'a> ::der::DecodeValue<'a> for $newtype {
             type Error = ::der::Error;
+            const INDEFINITE_LENGTH: bool = <$inner as ::der::DecodeValue<'a>>::INDEFINITE_LENGTH;
 
             fn decode_value<R: ::der::Reader<'a>>(
                 decoder: &mut R,
@@ -78,6 +80,24 @@ macro_rules! impl_newtype {
                 self.0.value_cmp(&other.0)
             }
         }
+
+        #[cfg(feature = "jer")]
+        #[allow(unused_lifetimes)]
+        impl<'a> ::der::jer::JerEncode for $newtype {
+            fn to_jer_value(&self) -> ::der::Result<::der::jer::Value> {
+                self.0.to_jer_value()
+            }
+        }
+
+        #[cfg(feature = "jer")]
+        #[allow(unused_lifetimes)]
+        impl<'a> ::der::jer::JerDecode for $newtype {
+            fn from_jer_value(value: &::der::jer::Value) -> ::der::Result<Self> {
+                Ok(Self(<$inner as ::der::jer::JerDecode>::from_jer_value(
+                    value,
+                )?))
+            }
+        }
     };
 }
 
@@ -12,6 +12,9 @@ use der::{
     asn1::{Any, Ia5StringRef, PrintableStringRef, SetOfVec},
 };
 
+#[cfg(feature = "jer")]
+use der::jer::{JerDecode, JerEncode, Value};
+
 /// X.501 Name as defined in [RFC 5280 Section 4.1.2.4]. X.501 Name is used to represent distinguished names.
 ///
 /// ```text
@@ -105,6 +108,7 @@ impl FixedTag for Name {
 
 impl<'a> DecodeValue<'a> for Name {
     type Error = der::Error;
+    const INDEFINITE_LENGTH: bool = <RdnSequence as DecodeValue<'a>>::INDEFINITE_LENGTH;
 
     fn decode_value<R: Reader<'a>>(decoder: &mut R, header: Header) -> der::Result<Self> {
         Ok(Self(RdnSequence::decode_value(decoder, header)?))
@@ -127,6 +131,20 @@ impl ValueOrd for Name {
     }
 }
 
+#[cfg(feature = "jer")]
+impl JerEncode for Name {
+    fn to_jer_value(&self) -> der::Result<Value> {
+        self.0.to_jer_value()
+    }
+}
+
+#[cfg(feature = "jer")]
+impl JerDecode for Name {
+    fn from_jer_value(value: &Value) -> der::Result<Self> {
+        Ok(Self(RdnSequence::from_jer_value(value)?))
+    }
+}
+
 impl Name {
     /// Is this [`Name`] empty?
     #[inline]
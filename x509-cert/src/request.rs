@@ -28,6 +28,7 @@ pub use self::builder::RequestBuilder;
 /// Version identifier for certification request information.
 ///
 /// (RFC 2986 designates `0` as the only valid version)
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Enumerated, Default)]
 #[asn1(type = "INTEGER")]
 #[repr(u8)]
@@ -49,6 +50,7 @@ pub enum Version {
 /// ```
 ///
 /// [RFC 2986 Section 4]: https://datatracker.ietf.org/doc/html/rfc2986#section-4
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, PartialEq, Eq, Sequence)]
 pub struct CertReqInfo {
     /// Certification request version.
@@ -76,6 +78,7 @@ pub struct CertReqInfo {
 /// ```
 ///
 /// [RFC 2986 Section 4]: https://datatracker.ietf.org/doc/html/rfc2986#section-4
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, PartialEq, Eq, Sequence)]
 pub struct CertReq {
     /// Certification request information.
@@ -3,13 +3,16 @@
 use core::{fmt::Display, marker::PhantomData};
 
 use der::{
-    DecodeValue, EncodeValue, ErrorKind, FixedTag, Header, Length, Reader, Result, Tag, ValueOrd,
-    Writer,
+    DecodeQuirk, DecodeValue, EncodeValue, ErrorKind, FixedTag, Header, Length, Reader, Result,
+    Tag, ValueOrd, Writer,
     asn1::{self, Int},
 };
 #[cfg(feature = "builder")]
 use {alloc::vec, signature::rand_core::CryptoRng};
 
+#[cfg(feature = "jer")]
+use der::jer::{JerDecode, JerEncode, Value};
+
 use crate::certificate::{Profile, Rfc5280};
 
 /// [RFC 5280 Section 4.1.2.2.]  Serial Number
@@ -135,6 +138,17 @@ impl<'a, P: Profile> DecodeValue<'a> for SerialNumber<P> {
 
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self> {
         let inner = Int::decode_value(reader, header)?;
+
+        // RFC 5280 forbids negative serial numbers, but non-conforming CAs have issued them, so
+        // they're only reported
+        if inner
+            .as_bytes()
+            .first()
+            .is_some_and(|&byte| byte & 0x80 != 0)
+        {
+            reader.record_quirk(DecodeQuirk::NegativeSerial);
+        }
+
         let serial = Self {
             inner,
             _profile: PhantomData,
@@ -150,6 +164,27 @@ impl<P: Profile> FixedTag for SerialNumber<P> {
     const TAG: Tag = <Int as FixedTag>::TAG;
 }
 
+#[cfg(feature = "jer")]
+impl<P: Profile> JerEncode for SerialNumber<P> {
+    fn to_jer_value(&self) -> Result<Value> {
+        self.inner.to_jer_value()
+    }
+}
+
+#[cfg(feature = "jer")]
+impl<P: Profile> JerDecode for SerialNumber<P> {
+    fn from_jer_value(value: &Value) -> Result<Self> {
+        let serial = Self {
+            inner: Int::from_jer_value(value)?,
+            _profile: PhantomData,
+        };
+
+        P::check_serial_number(&serial)?;
+
+        Ok(serial)
+    }
+}
+
 impl Display for SerialNumber {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut iter = self.as_bytes().iter().peekable();
@@ -249,6 +284,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn serial_number_negative() {
+        use der::{Decode, DecodeWarning, SliceReader};
+        use hex_literal::hex;
+
+        // Always tolerated, but reported
+        let mut reader = SliceReader::new(&hex!("0201FF")).unwrap();
+        let sn = SerialNumber::<Rfc5280>::decode(&mut reader).unwrap();
+        assert_eq!(sn.as_bytes(), &[0xFF]);
+        assert_eq!(
+            reader.warnings(),
+            [DecodeWarning {
+                quirk: DecodeQuirk::NegativeSerial,
+                position: Length::new(3),
+            }]
+        );
+
+        let mut reader = SliceReader::new(&hex!("020200FF")).unwrap();
+        SerialNumber::<Rfc5280>::decode(&mut reader).unwrap();
+        assert!(reader.warnings().is_empty());
+    }
+
     #[cfg(feature = "builder")]
     #[test]
     fn serial_number_generate() {
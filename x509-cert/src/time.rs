@@ -4,6 +4,9 @@ use core::{fmt, marker::PhantomData, time::Duration};
 use der::asn1::{GeneralizedTime, UtcTime};
 use der::{Choice, DateTime, DecodeValue, Encode, Header, Length, Reader, Sequence, ValueOrd};
 
+#[cfg(feature = "jer")]
+use der::jer::{self, JerDecode, JerEncode, Value};
+
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
@@ -23,6 +26,7 @@ use crate::certificate::{Profile, Rfc5280};
 /// [RFC 5280 Section 4.1.2.5]: https://tools.ietf.org/html/rfc5280#section-4.1.2.5
 /// [RFC 5280 Appendix A]: https://tools.ietf.org/html/rfc5280#page-117
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Choice, Copy, Clone, Debug, Eq, PartialEq, ValueOrd)]
 pub enum Time {
     /// Legacy UTC time (has 2-digit year, valid from 1970 to 2049).
@@ -190,10 +194,13 @@ where
 
 impl<'a, P: Profile> DecodeValue<'a> for Validity<P> {
     type Error = ::der::Error;
+    const INDEFINITE_LENGTH: bool = true;
     fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
-        reader.read_nested(header.length, |reader| {
+        reader.read_contents(header, |reader| {
             let not_before = reader.decode()?;
+            P::check_time(&not_before)?;
             let not_after = reader.decode()?;
+            P::check_time(&not_after)?;
             let out = Self {
                 not_before,
                 not_after,
@@ -222,3 +229,33 @@ impl<P: Profile> ::der::EncodeValue for Validity<P> {
 }
 
 impl<P: Profile> Sequence<'_> for Validity<P> {}
+
+#[cfg(feature = "jer")]
+impl<P: Profile> JerEncode for Validity<P> {
+    fn to_jer_value(&self) -> der::Result<Value> {
+        Ok(jer::object([
+            (
+                "notBefore",
+                Some(P::time_encoding(self.not_before)?.to_jer_value()?),
+            ),
+            (
+                "notAfter",
+                Some(P::time_encoding(self.not_after)?.to_jer_value()?),
+            ),
+        ]))
+    }
+}
+
+#[cfg(feature = "jer")]
+impl<P: Profile> JerDecode for Validity<P> {
+    fn from_jer_value(value: &Value) -> der::Result<Self> {
+        jer::check_members(value, &["notBefore", "notAfter"])?;
+
+        let not_before = Time::from_jer_value(jer::member(value, "notBefore")?)?;
+        P::check_time(&not_before)?;
+        let not_after = Time::from_jer_value(jer::member(value, "notAfter")?)?;
+        P::check_time(&not_after)?;
+
+        Ok(Self::new(not_before, not_after))
+    }
+}
@@ -468,3 +468,23 @@ fn certificate_arbitrary() {
         check_arbitrary(certificate);
     }
 }
+
+#[cfg(feature = "jer")]
+#[test]
+fn certificate_jer_round_trip() {
+    use der::jer::{JerDecode, JerEncode};
+
+    for der_encoded_cert in [
+        &include_bytes!("examples/amazon.der")[..],
+        &include_bytes!("examples/eca.der")[..],
+        &include_bytes!("examples/raytheon.der")[..],
+    ] {
+        let cert = Certificate::from_der(der_encoded_cert).unwrap();
+        let json = cert.to_jer().unwrap();
+        assert!(json.starts_with(r#"{"tbsCertificate":{"version":2,"serialNumber":"#));
+
+        let decoded = Certificate::from_jer(&json).unwrap();
+        assert_eq!(decoded, cert);
+        assert_eq!(decoded.to_der().unwrap(), der_encoded_cert);
+    }
+}
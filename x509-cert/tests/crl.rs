@@ -15,3 +15,18 @@ fn decode_crl() {
     assert_eq!(2, crl.tbs_cert_list.crl_extensions.unwrap().len());
     assert_eq!(4, crl.tbs_cert_list.revoked_certificates.unwrap().len());
 }
+
+#[cfg(feature = "jer")]
+#[test]
+fn crl_jer_round_trip() {
+    use der::{
+        Encode,
+        jer::{JerDecode, JerEncode},
+    };
+
+    let der_encoded_crl = include_bytes!("examples/GoodCACRL.crl");
+    let crl = CertificateList::<Rfc5280>::from_der(der_encoded_crl).unwrap();
+    let decoded = CertificateList::<Rfc5280>::from_jer(&crl.to_jer().unwrap()).unwrap();
+    assert_eq!(decoded, crl);
+    assert_eq!(decoded.to_der().unwrap(), der_encoded_crl);
+}
@@ -156,3 +156,22 @@ fn encode_validity() {
         &hex!("301E170D3032303130313132303130305A170D3330313233313038333030305A")[..]
     );
 }
+
+#[test]
+fn reject_fractional_seconds() {
+    //  0  34: SEQUENCE {
+    //  2  17:   GeneralizedTime 01/01/2050 12:01:00.5 GMT
+    // 21  13:   UTCTime 31/12/2030 08:30:00 GMT
+    //       : }
+    let der =
+        hex!("3022" "181132303530303130313132303130302E355A" "170D3330313233313038333030305A");
+    assert!(Validity::<Rfc5280>::from_der(&der).is_err());
+
+    #[cfg(feature = "hazmat")]
+    {
+        use x509_cert::certificate::Raw;
+
+        let val = Validity::<Raw>::from_der(&der).unwrap();
+        assert_eq!(val.not_before.to_date_time().nanoseconds(), 500_000_000);
+    }
+}
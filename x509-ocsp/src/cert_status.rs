@@ -16,6 +16,7 @@ use x509_cert::{crl::RevokedCert, ext::pkix::CrlReason};
 /// ```
 ///
 /// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Choice)]
 #[allow(missing_docs)]
 pub enum CertStatus {
@@ -61,6 +62,7 @@ impl CertStatus {
 /// ```
 ///
 /// [RFC 6960 Section 4.2.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.2.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct RevokedInfo {
@@ -89,6 +89,7 @@ pub type CrlReferences = CrlId;
 /// ```
 ///
 /// [RFC 6960 Section 4.4.2]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.4.2
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
 #[allow(missing_docs)]
 pub struct CrlId {
@@ -163,6 +164,7 @@ impl AssociatedOid for ArchiveCutoff {
 /// ```
 ///
 /// [RFC 6960 Section 4.4.6]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.4.6
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence)]
 #[allow(missing_docs)]
 pub struct ServiceLocator {
@@ -205,6 +207,7 @@ impl AssociatedOid for PreferredSignatureAlgorithms {
 /// ```
 ///
 /// [RFC 6960 Section 4.4.7.1]: https://datatracker.ietf.org/doc/html/rfc6960#section-4.4.7.1
+#[cfg_attr(feature = "jer", derive(der::Jer))]
 #[derive(Clone, Debug, Eq, PartialEq, Sequence, ValueOrd)]
 #[allow(missing_docs)]
 pub struct PreferredSignatureAlgorithm {
@@ -442,3 +442,15 @@ fn decode_ocsp_req_signed() {
         None => panic!("no signature"),
     }
 }
+
+#[cfg(feature = "jer")]
+#[test]
+fn ocsp_req_jer_round_trip() {
+    use der::jer::{JerDecode, JerEncode};
+
+    let data = std::fs::read("tests/examples/ocsp-multiple-requests-req.der").unwrap();
+    let req: OcspRequest = OcspRequest::from_der(&data[..]).unwrap();
+    let decoded = OcspRequest::from_jer(&req.to_jer().unwrap()).unwrap();
+    assert_eq!(decoded, req);
+    assert_eq!(decoded.to_der().unwrap(), data);
+}
@@ -566,3 +566,23 @@ fn decode_ocsp_resp_revoked_response() {
         },
     }
 }
+
+#[cfg(feature = "jer")]
+#[test]
+fn ocsp_resp_jer_round_trip() {
+    use der::jer::{JerDecode, JerEncode};
+
+    let data = std::fs::read("tests/examples/ocsp-multiple-responses-res.der").unwrap();
+    let res = OcspResponse::from_der(&data[..]).unwrap();
+    let decoded = OcspResponse::from_jer(&res.to_jer().unwrap()).unwrap();
+    assert_eq!(decoded, res);
+    assert_eq!(decoded.to_der().unwrap(), data);
+
+    let basic = assert_ocsp_response(&res);
+    let decoded = BasicOcspResponse::from_jer(&basic.to_jer().unwrap()).unwrap();
+    assert_eq!(decoded, basic);
+    assert_eq!(
+        decoded.to_der().unwrap(),
+        res.response_bytes.unwrap().response.as_bytes()
+    );
+}